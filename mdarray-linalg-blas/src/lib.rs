@@ -13,6 +13,7 @@
 //! - **Level 1** — vector operations: `dot`, `dotc`, `norm2`, `norm1`, `add_to_scaled`
 //! - **Level 2** — matrix-vector & outer product: `matvec`, `outer`
 //! - **Level 3** — matrix multiplication: `matmul`
+//! - **Structured matrices** — triangular, symmetric, Hermitian and packed kernels: `tri_matmul` (trmm), `tri_solve` (trsm), `sym_matmul` (symm), `herm_matmul` (hemm), `packed_sym_matvec` (spmv), `packed_herm_matvec` (hpmv)
 //! - **Tensor contraction** — `contract_all`, `contract_n`, `contract_pairs`, `contract`
 //! - **Argmax** — `argmax`, `argmax_abs`
 //!
@@ -115,6 +116,7 @@ extern crate openblas_src as _;
 
 mod contract;
 mod matvec;
+mod structured;
 
 /// BLAS backend.
 ///
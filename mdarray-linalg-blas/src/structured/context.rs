@@ -0,0 +1,123 @@
+use mdarray::{DArray, Dyn, Layout, Slice};
+use mdarray_linalg::{
+    solve::SolveError,
+    structured::{
+        Diagonal, Hermitian, PackedHermitian, PackedSymmetric, Structured, Symmetric, Triangular,
+        check_tri_nonsingular,
+    },
+};
+
+use super::{
+    scalar::BlasScalar,
+    simple::{TriOp, spmv_hpmv, symm_hemm, trmm_trsm},
+};
+use crate::Blas;
+
+impl<T: BlasScalar> Structured<T> for Blas {
+    fn diag_scale<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mut c = b.to_array();
+        self.diag_scale_write(d, &mut c);
+        c
+    }
+
+    fn diag_scale_write<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        let (m, n) = *b.shape();
+        assert!(d.size() == m, "Diagonal size must match rows of B");
+        for i in 0..m {
+            let di = d.diag()[[i]];
+            for j in 0..n {
+                b[[i, j]] = di * b[[i, j]];
+            }
+        }
+    }
+
+    fn tri_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mut c = b.to_array();
+        trmm_trsm(TriOp::Mul, a, &mut c);
+        c
+    }
+
+    fn tri_matmul_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        trmm_trsm(TriOp::Mul, a, b);
+    }
+
+    fn tri_solve<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<DArray<T, 2>, SolveError> {
+        let mut x = b.to_array();
+        self.tri_solve_write(a, &mut x)?;
+        Ok(x)
+    }
+
+    fn tri_solve_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<(), SolveError> {
+        // trsm does not check for singularity.
+        check_tri_nonsingular(a)?;
+        trmm_trsm(TriOp::Solve, a, b);
+        Ok(())
+    }
+
+    fn sym_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Symmetric<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        symm_hemm(false, a.matrix(), a.uplo(), b)
+    }
+
+    fn herm_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Hermitian<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        symm_hemm(true, a.matrix(), a.uplo(), b)
+    }
+
+    fn packed_sym_matvec<Lx: Layout>(
+        &self,
+        a: &PackedSymmetric<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        if T::HAS_SPMV {
+            spmv_hpmv(false, a.size(), a.uplo(), a.data().as_ref(), x)
+        } else {
+            let n = a.size();
+            assert!(
+                n == x.shape().0,
+                "Matrix columns must match x vector length"
+            );
+            DArray::<T, 1>::from_fn([n], |i| {
+                (0..n).fold(T::zero(), |acc, j| acc + a.get(i[0], j) * x[[j]])
+            })
+        }
+    }
+
+    fn packed_herm_matvec<Lx: Layout>(
+        &self,
+        a: &PackedHermitian<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        spmv_hpmv(true, a.size(), a.uplo(), a.data().as_ref(), x)
+    }
+}
@@ -0,0 +1,445 @@
+//! Abstracting the BLAS scalar types for the structured kernels
+use cblas_sys::{CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub(super) trait BlasScalar: Sized + ComplexFloat {
+    /// Whether BLAS provides a packed symmetric product (`spmv`) for this
+    /// type; it only exists for real types.
+    const HAS_SPMV: bool;
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_trmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_trsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_symm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: Self,
+        c: *mut Self,
+        ldc: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_hemm_or_symm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: Self,
+        c: *mut Self,
+        ldc: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.  Only available if [`Self::HAS_SPMV`].
+    unsafe fn cblas_spmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: i32,
+        alpha: Self,
+        ap: *const Self,
+        x: *const Self,
+        incx: i32,
+        beta: Self,
+        y: *mut Self,
+        incy: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_hpmv_or_spmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: i32,
+        alpha: Self,
+        ap: *const Self,
+        x: *const Self,
+        incx: i32,
+        beta: Self,
+        y: *mut Self,
+        incy: i32,
+    );
+}
+
+macro_rules! impl_blas_real {
+    ($t:ty, $prefix:ident) => {
+        impl BlasScalar for $t {
+            const HAS_SPMV: bool = true;
+
+            unsafe fn cblas_trmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix trmm>](
+                            layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_trsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix trsm>](
+                            layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                beta: Self,
+                c: *mut Self,
+                ldc: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix symm>](
+                            layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_hemm_or_symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                beta: Self,
+                c: *mut Self,
+                ldc: i32,
+            ) {
+                unsafe {
+                    Self::cblas_symm(
+                        layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+                    )
+                }
+            }
+
+            unsafe fn cblas_spmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: i32,
+                alpha: Self,
+                ap: *const Self,
+                x: *const Self,
+                incx: i32,
+                beta: Self,
+                y: *mut Self,
+                incy: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix spmv>](
+                            layout, uplo, n, alpha, ap, x, incx, beta, y, incy,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_hpmv_or_spmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: i32,
+                alpha: Self,
+                ap: *const Self,
+                x: *const Self,
+                incx: i32,
+                beta: Self,
+                y: *mut Self,
+                incy: i32,
+            ) {
+                unsafe { Self::cblas_spmv(layout, uplo, n, alpha, ap, x, incx, beta, y, incy) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_blas_complex {
+    ($t:ty, $prefix:ident) => {
+        impl BlasScalar for $t {
+            const HAS_SPMV: bool = false;
+
+            unsafe fn cblas_trmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix trmm>](
+                            layout,
+                            side,
+                            uplo,
+                            transa,
+                            diag,
+                            m,
+                            n,
+                            &alpha as *const _ as *const _,
+                            a as *const _,
+                            lda,
+                            b as *mut _,
+                            ldb,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_trsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix trsm>](
+                            layout,
+                            side,
+                            uplo,
+                            transa,
+                            diag,
+                            m,
+                            n,
+                            &alpha as *const _ as *const _,
+                            a as *const _,
+                            lda,
+                            b as *mut _,
+                            ldb,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                beta: Self,
+                c: *mut Self,
+                ldc: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix symm>](
+                            layout,
+                            side,
+                            uplo,
+                            m,
+                            n,
+                            &alpha as *const _ as *const _,
+                            a as *const _,
+                            lda,
+                            b as *const _,
+                            ldb,
+                            &beta as *const _ as *const _,
+                            c as *mut _,
+                            ldc,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_hemm_or_symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: i32,
+                n: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                beta: Self,
+                c: *mut Self,
+                ldc: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix hemm>](
+                            layout,
+                            side,
+                            uplo,
+                            m,
+                            n,
+                            &alpha as *const _ as *const _,
+                            a as *const _,
+                            lda,
+                            b as *const _,
+                            ldb,
+                            &beta as *const _ as *const _,
+                            c as *mut _,
+                            ldc,
+                        )
+                    }
+                }
+            }
+
+            unsafe fn cblas_spmv(
+                _layout: CBLAS_LAYOUT,
+                _uplo: CBLAS_UPLO,
+                _n: i32,
+                _alpha: Self,
+                _ap: *const Self,
+                _x: *const Self,
+                _incx: i32,
+                _beta: Self,
+                _y: *mut Self,
+                _incy: i32,
+            ) {
+                unreachable!("callers check `HAS_SPMV`: BLAS has no packed symmetric product for complex types")
+            }
+
+            unsafe fn cblas_hpmv_or_spmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: i32,
+                alpha: Self,
+                ap: *const Self,
+                x: *const Self,
+                incx: i32,
+                beta: Self,
+                y: *mut Self,
+                incy: i32,
+            ) {
+                unsafe {
+                    paste! {
+                        cblas_sys::[<cblas_ $prefix hpmv>](
+                            layout,
+                            uplo,
+                            n,
+                            &alpha as *const _ as *const _,
+                            ap as *const _,
+                            x as *const _,
+                            incx,
+                            &beta as *const _ as *const _,
+                            y as *mut _,
+                            incy,
+                        )
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_blas_real!(f32, s);
+impl_blas_real!(f64, d);
+impl_blas_complex!(Complex<f32>, c);
+impl_blas_complex!(Complex<f64>, z);
@@ -0,0 +1,201 @@
+//! Simple function-based interface to the structured BLAS kernels
+
+use cblas_sys::{CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use mdarray::{DArray, Dyn, Layout, Slice};
+use mdarray_linalg::structured::{Diag, Triangular, Uplo};
+use mdarray_linalg::utils::into_i32;
+
+use super::scalar::BlasScalar;
+
+/// Returns whether `x` is row-major and its leading dimension, or `None` if
+/// it is not contiguous in either dimension.
+fn order<T, L: Layout>(x: &Slice<T, (Dyn, Dyn), L>) -> Option<(bool, i32)> {
+    if x.stride(1) == 1 {
+        Some((true, into_i32(x.stride(0))))
+    } else if x.stride(0) == 1 {
+        Some((false, into_i32(x.stride(1))))
+    } else {
+        None
+    }
+}
+
+fn cblas_layout(row_major: bool) -> CBLAS_LAYOUT {
+    if row_major {
+        CBLAS_LAYOUT::CblasRowMajor
+    } else {
+        CBLAS_LAYOUT::CblasColMajor
+    }
+}
+
+pub(super) fn cblas_uplo(uplo: Uplo) -> CBLAS_UPLO {
+    match uplo {
+        Uplo::Upper => CBLAS_UPLO::CblasUpper,
+        Uplo::Lower => CBLAS_UPLO::CblasLower,
+    }
+}
+
+/// Kind of triangular kernel
+#[derive(Clone, Copy)]
+pub(super) enum TriOp {
+    /// `trmm`
+    Mul,
+    /// `trsm`
+    Solve,
+}
+
+/// Overwrites `b` with `A B` or `A⁻¹ B` for the triangular `A`.
+pub(super) fn trmm_trsm<T, La, Lb>(
+    op: TriOp,
+    a: &Triangular<'_, T, La>,
+    b: &mut Slice<T, (Dyn, Dyn), Lb>,
+) where
+    T: BlasScalar,
+    La: Layout,
+    Lb: Layout,
+{
+    let (m, n) = *b.shape();
+    assert!(a.size() == m, "Matrix columns must match rows of B");
+
+    let Some((a_row_major, lda)) = order(a.matrix()) else {
+        let a_owned = a.matrix().to_array();
+        return trmm_trsm(op, &Triangular::new(&a_owned, a.uplo(), a.diag()), b);
+    };
+    let Some((b_row_major, ldb)) = order(b) else {
+        let mut b_owned = b.to_array();
+        trmm_trsm(op, a, &mut b_owned);
+        b.assign(&b_owned);
+        return;
+    };
+
+    // In the memory order of `b`, a matrix stored in the other order is seen
+    // transposed, with the other triangle.
+    let (uplo, trans) = if a_row_major == b_row_major {
+        (a.uplo(), CBLAS_TRANSPOSE::CblasNoTrans)
+    } else {
+        (a.uplo().flipped(), CBLAS_TRANSPOSE::CblasTrans)
+    };
+    let diag = match a.diag() {
+        Diag::NonUnit => CBLAS_DIAG::CblasNonUnit,
+        Diag::Unit => CBLAS_DIAG::CblasUnit,
+    };
+
+    let kernel = match op {
+        TriOp::Mul => T::cblas_trmm,
+        TriOp::Solve => T::cblas_trsm,
+    };
+    unsafe {
+        kernel(
+            cblas_layout(b_row_major),
+            CBLAS_SIDE::CblasLeft,
+            cblas_uplo(uplo),
+            trans,
+            diag,
+            into_i32(m),
+            into_i32(n),
+            T::one(),
+            a.matrix().as_ptr(),
+            lda,
+            b.as_mut_ptr(),
+            ldb,
+        )
+    }
+}
+
+/// Returns `A B` for the self-adjoint `A` stored in the `uplo` triangle of
+/// `a`, with `symm` or, if `hermitian`, `hemm`.
+pub(super) fn symm_hemm<T, La, Lb>(
+    hermitian: bool,
+    a: &Slice<T, (Dyn, Dyn), La>,
+    uplo: Uplo,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+) -> DArray<T, 2>
+where
+    T: BlasScalar,
+    La: Layout,
+    Lb: Layout,
+{
+    let (m, n) = *b.shape();
+    assert!(a.shape().0 == m, "Matrix columns must match rows of B");
+
+    // The result is row-major, and so is `b` as passed to BLAS.  A
+    // column-major symmetric matrix reads as its own transpose, stored in the
+    // other triangle, but a column-major Hermitian one would be conjugated.
+    let a_order = match order(a) {
+        Some((false, _)) if hermitian => None,
+        a_order => a_order,
+    };
+    let Some((a_row_major, lda)) = a_order else {
+        return symm_hemm(hermitian, &a.to_array(), uplo, b);
+    };
+    let Some((true, ldb)) = order(b) else {
+        return symm_hemm(hermitian, a, uplo, &b.to_array());
+    };
+    let uplo = if a_row_major { uplo } else { uplo.flipped() };
+
+    let mut c = DArray::<T, 2>::from_elem([m, n], T::zero());
+    let ldc = into_i32(c.stride(0));
+    let kernel = if hermitian {
+        T::cblas_hemm_or_symm
+    } else {
+        T::cblas_symm
+    };
+    unsafe {
+        kernel(
+            CBLAS_LAYOUT::CblasRowMajor,
+            CBLAS_SIDE::CblasLeft,
+            cblas_uplo(uplo),
+            into_i32(m),
+            into_i32(n),
+            T::one(),
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            T::zero(),
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+    c
+}
+
+/// Returns `A x` for the self-adjoint `A` packed column by column in `ap`,
+/// with `spmv` or, if `hermitian`, `hpmv`.
+pub(super) fn spmv_hpmv<T, Lx>(
+    hermitian: bool,
+    n: usize,
+    uplo: Uplo,
+    ap: &[T],
+    x: &Slice<T, (Dyn,), Lx>,
+) -> DArray<T, 1>
+where
+    T: BlasScalar,
+    Lx: Layout,
+{
+    assert!(
+        n == x.shape().0,
+        "Matrix columns must match x vector length"
+    );
+
+    let mut y = DArray::<T, 1>::from_elem([n], T::zero());
+    let kernel = if hermitian {
+        T::cblas_hpmv_or_spmv
+    } else {
+        T::cblas_spmv
+    };
+    unsafe {
+        kernel(
+            CBLAS_LAYOUT::CblasColMajor,
+            cblas_uplo(uplo),
+            into_i32(n),
+            T::one(),
+            ap.as_ptr(),
+            x.as_ptr(),
+            into_i32(x.stride(0)),
+            T::zero(),
+            y.as_mut_ptr(),
+            1,
+        )
+    }
+    y
+}
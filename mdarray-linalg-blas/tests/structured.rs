@@ -0,0 +1,33 @@
+extern crate openblas_src as _;
+use mdarray_linalg::testing::structured::*;
+use mdarray_linalg_blas::Blas;
+
+#[test]
+fn diag_scale() {
+    test_diag_scale(&Blas);
+}
+
+#[test]
+fn tri_matmul() {
+    test_tri_matmul(&Blas);
+}
+
+#[test]
+fn tri_solve() {
+    test_tri_solve(&Blas);
+}
+
+#[test]
+fn tri_solve_singular() {
+    test_tri_solve_singular(&Blas);
+}
+
+#[test]
+fn sym_matmul() {
+    test_sym_matmul(&Blas);
+}
+
+#[test]
+fn packed_matvec() {
+    test_packed_matvec(&Blas);
+}
@@ -265,7 +265,7 @@ where
         La: Layout,
         Lb: Layout,
     {
-        _contract(Faer, a, b, Axes::All, T::one()).into_scalar()
+        _contract(Faer::default(), a, b, Axes::All, T::one()).into_scalar()
     }
 
     fn contract_n<'a, Sa, Sb, La, Lb>(
@@ -21,8 +21,16 @@
 
 use dyn_stack::{MemBuffer, MemStack};
 use faer_traits::ComplexField;
-use mdarray::{Array, Dense, Dim, Layout, Shape, Slice};
-use mdarray_linalg::eig::{Eig, EigDecomp, EigError, EighDecomp, SchurDecomp, SchurError};
+use mdarray::{Array, Dense, Dim, Dyn, Layout, Shape, Slice};
+use mdarray_linalg::{
+    eig::{
+        Eig, EigCondition, EigDecomp, EigError, EigSide, EighAlgorithm, EighDecomp, EighRange,
+        SchurCondition, SchurDecomp, SchurError, SchurReorder, SchurSelect, balance,
+        eig_condition, jacobi_eigh, reorder_schur, schur_eigenvectors, select_eigh,
+        tridiagonal_eigh,
+    },
+    gauge::{gauge_eig, gauge_eig_condition, gauge_eigh},
+};
 use num_complex::{Complex, ComplexFloat};
 
 use crate::{Faer, into_faer, into_faer_diag_mut, into_faer_mut};
@@ -39,7 +47,8 @@ macro_rules! complex_from_faer {
 }
 
 // Faer exposes the Hessenberg reduction publicly, but not the final Schur QR step.
-// We keep the same A = Z * T * Z^H interface with the reduced Hessenberg form.
+// The Schur forms finish the reduction with `real_hessenberg_qr` or
+// `complex_hessenberg_qr`.
 fn schur_faer_in_place<T, D0: Dim, D1: Dim, L: Layout, Lz: Layout>(
     t: &mut Slice<T, (D0, D1), L>,
     z: &mut Slice<T, (D0, D1), Lz>,
@@ -117,6 +126,329 @@ where
     Ok(())
 }
 
+/// Reduces the upper Hessenberg `h` to upper triangular form with single-shift
+/// QR iterations (as LAPACK's `lahqr`), accumulating the rotations into `z`.
+fn complex_hessenberg_qr<R: num_traits::Float, D0: Dim, D1: Dim, L: Layout, Lz: Layout>(
+    h: &mut Slice<Complex<R>, (D0, D1), L>,
+    z: &mut Slice<Complex<R>, (D0, D1), Lz>,
+) -> Result<(), SchurError> {
+    let n = h.shape().dim(0);
+    let eps = R::epsilon();
+    let smlnum = R::min_positive_value() / eps;
+    let zero = Complex::new(R::zero(), R::zero());
+    let half = R::one() / (R::one() + R::one());
+    let max_iterations = 30 * n.max(10);
+
+    let mut hi = n.saturating_sub(1);
+    let mut iterations = 0;
+    while hi > 0 {
+        // Deflate at the last negligible subdiagonal entry of the active block.
+        let mut lo = hi;
+        while lo > 0 {
+            let scale = h[[lo - 1, lo - 1]].norm() + h[[lo, lo]].norm();
+            if h[[lo, lo - 1]].norm() <= (eps * scale).max(smlnum) {
+                h[[lo, lo - 1]] = zero;
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == hi {
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+
+        iterations += 1;
+        if iterations > max_iterations {
+            return Err(SchurError::BackendDidNotConverge {
+                iterations: iterations as i32,
+            });
+        }
+
+        // Wilkinson shift: the eigenvalue of the trailing 2 × 2 block closest
+        // to its last diagonal entry, with exceptional shifts to break cycles.
+        let (a, b, c, d) = (
+            h[[hi - 1, hi - 1]],
+            h[[hi - 1, hi]],
+            h[[hi, hi - 1]],
+            h[[hi, hi]],
+        );
+        let shift = if iterations % 10 == 0 {
+            d + h[[hi, hi - 1]].norm() * R::from(0.75).unwrap()
+        } else {
+            let mean = (a - d) * half;
+            let disc = (mean * mean + b * c).sqrt();
+            let (s1, s2) = (d - mean + disc, d - mean - disc);
+            if (s1 - d).norm() <= (s2 - d).norm() {
+                s1
+            } else {
+                s2
+            }
+        };
+
+        // Chase the bulge from (lo + 2, lo) down the subdiagonal.
+        let (mut x, mut y) = (h[[lo, lo]] - shift, h[[lo + 1, lo]]);
+        for k in lo..hi {
+            let r = x.norm().hypot(y.norm());
+            if r == R::zero() {
+                break;
+            }
+            // G = [c s; -conj(s) c] maps (x, y) to (r', 0).
+            let (cs, sn) = if x.norm() == R::zero() {
+                (
+                    R::zero(),
+                    Complex::new(R::one(), R::zero()) * (y.conj() / y.norm()),
+                )
+            } else {
+                (x.norm() / r, (x / x.norm()) * y.conj() / r)
+            };
+            let rotate = |p: Complex<R>, q: Complex<R>| (p * cs + sn * q, q * cs - sn.conj() * p);
+
+            for j in (if k > lo { k - 1 } else { k })..n {
+                let (p, q) = rotate(h[[k, j]], h[[k + 1, j]]);
+                h[[k, j]] = p;
+                h[[k + 1, j]] = q;
+            }
+            if k > lo {
+                h[[k + 1, k - 1]] = zero;
+            }
+            for i in 0..=(k + 2).min(hi) {
+                let (p, q) = rotate(h[[i, k]].conj(), h[[i, k + 1]].conj());
+                h[[i, k]] = p.conj();
+                h[[i, k + 1]] = q.conj();
+            }
+            for i in 0..n {
+                let (p, q) = rotate(z[[i, k]].conj(), z[[i, k + 1]].conj());
+                z[[i, k]] = p.conj();
+                z[[i, k + 1]] = q.conj();
+            }
+            if k + 1 < hi {
+                (x, y) = (h[[k + 1, k]], h[[k + 2, k]]);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reduces the real upper Hessenberg `h` to real Schur form with Francis
+/// double-shift QR iterations (as LAPACK's `lahqr`), accumulating the
+/// reflections into `z`.
+fn real_hessenberg_qr<R: num_traits::Float, D0: Dim, D1: Dim, L: Layout, Lz: Layout>(
+    h: &mut Slice<R, (D0, D1), L>,
+    z: &mut Slice<R, (D0, D1), Lz>,
+) -> Result<(), SchurError> {
+    let n = h.shape().dim(0);
+    let eps = R::epsilon();
+    let smlnum = R::min_positive_value() / eps;
+    let two = R::one() + R::one();
+    let max_iterations = 30 * n.max(10);
+
+    let mut hi = n.saturating_sub(1);
+    let mut iterations = 0;
+    while hi > 0 {
+        // Deflate at the last negligible subdiagonal entry of the active block.
+        let mut lo = hi;
+        while lo > 0 {
+            let scale = h[[lo - 1, lo - 1]].abs() + h[[lo, lo]].abs();
+            if h[[lo, lo - 1]].abs() <= (eps * scale).max(smlnum) {
+                h[[lo, lo - 1]] = R::zero();
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == hi {
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        if lo + 1 == hi {
+            standardize_block(h, z, lo);
+            hi = hi.saturating_sub(2);
+            iterations = 0;
+            continue;
+        }
+
+        iterations += 1;
+        if iterations > max_iterations {
+            return Err(SchurError::BackendDidNotConverge {
+                iterations: iterations as i32,
+            });
+        }
+
+        // The two shifts are the eigenvalues of the trailing 2 × 2 block,
+        // given by their sum and product, with exceptional shifts to break
+        // cycles.
+        let (sum, product) = if iterations % 10 == 0 {
+            let shift = h[[hi, hi]]
+                + (h[[hi, hi - 1]].abs() + h[[hi - 1, hi - 2]].abs()) * R::from(0.75).unwrap();
+            (two * shift, shift * shift)
+        } else {
+            let (a, b, c, d) = (
+                h[[hi - 1, hi - 1]],
+                h[[hi - 1, hi]],
+                h[[hi, hi - 1]],
+                h[[hi, hi]],
+            );
+            (a + d, a * d - b * c)
+        };
+
+        // First column of (H - s1 I)(H - s2 I), then chase the bulge down.
+        let (h00, h01, h10, h11, h21) = (
+            h[[lo, lo]],
+            h[[lo, lo + 1]],
+            h[[lo + 1, lo]],
+            h[[lo + 1, lo + 1]],
+            h[[lo + 2, lo + 1]],
+        );
+        let mut x = h00 * h00 + h01 * h10 - sum * h00 + product;
+        let mut y = h10 * (h00 + h11 - sum);
+        let mut w = h10 * h21;
+        for k in lo..hi {
+            let nr = 3.min(hi - k + 1);
+            if k > lo {
+                x = h[[k, k - 1]];
+                y = h[[k + 1, k - 1]];
+                w = if nr == 3 {
+                    h[[k + 2, k - 1]]
+                } else {
+                    R::zero()
+                };
+            }
+            if y == R::zero() && w == R::zero() {
+                continue;
+            }
+            // P = I - tau v v^T with v = (1, v1, v2) maps (x, y, w) to (beta, 0, 0).
+            let norm = x.hypot(y).hypot(w);
+            let beta = if x >= R::zero() { -norm } else { norm };
+            let tau = (beta - x) / beta;
+            let (v1, v2) = (y / (x - beta), w / (x - beta));
+            let reflect = |p0: R, p1: R, p2: R| {
+                let f = tau * (p0 + v1 * p1 + v2 * p2);
+                (p0 - f, p1 - f * v1, p2 - f * v2)
+            };
+
+            for j in (if k > lo { k - 1 } else { k })..n {
+                let p2 = if nr == 3 { h[[k + 2, j]] } else { R::zero() };
+                let (p0, p1, p2) = reflect(h[[k, j]], h[[k + 1, j]], p2);
+                h[[k, j]] = p0;
+                h[[k + 1, j]] = p1;
+                if nr == 3 {
+                    h[[k + 2, j]] = p2;
+                }
+            }
+            if k > lo {
+                h[[k + 1, k - 1]] = R::zero();
+                if nr == 3 {
+                    h[[k + 2, k - 1]] = R::zero();
+                }
+            }
+            for i in 0..=(k + 3).min(hi) {
+                let p2 = if nr == 3 { h[[i, k + 2]] } else { R::zero() };
+                let (p0, p1, p2) = reflect(h[[i, k]], h[[i, k + 1]], p2);
+                h[[i, k]] = p0;
+                h[[i, k + 1]] = p1;
+                if nr == 3 {
+                    h[[i, k + 2]] = p2;
+                }
+            }
+            for i in 0..n {
+                let p2 = if nr == 3 { z[[i, k + 2]] } else { R::zero() };
+                let (p0, p1, p2) = reflect(z[[i, k]], z[[i, k + 1]], p2);
+                z[[i, k]] = p0;
+                z[[i, k + 1]] = p1;
+                if nr == 3 {
+                    z[[i, k + 2]] = p2;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Splits the deflated `2 × 2` block at `k` if its eigenvalues are real, and
+/// otherwise rotates it to the standard form with equal diagonal entries.
+fn standardize_block<R: num_traits::Float, D0: Dim, D1: Dim, L: Layout, Lz: Layout>(
+    h: &mut Slice<R, (D0, D1), L>,
+    z: &mut Slice<R, (D0, D1), Lz>,
+    k: usize,
+) {
+    let n = h.shape().dim(0);
+    let (a, b, c, d) = (h[[k, k]], h[[k, k + 1]], h[[k + 1, k]], h[[k + 1, k + 1]]);
+    let two = R::one() + R::one();
+    let p = (a - d) / two;
+    let disc = p * p + b * c;
+    let real_pair = disc >= R::zero();
+    let (cs, sn) = if real_pair {
+        // Rotate the eigenvector (λ - d, c) onto e_1.
+        let lambda = d + p + disc.sqrt().copysign(p);
+        let r = (lambda - d).hypot(c);
+        ((lambda - d) / r, c / r)
+    } else {
+        // (a' - d') = (a - d) cos 2θ + (b + c) sin 2θ vanishes.
+        let theta = (d - a).atan2(b + c) / two;
+        (theta.cos(), theta.sin())
+    };
+    for j in k..n {
+        let (x, y) = (h[[k, j]], h[[k + 1, j]]);
+        h[[k, j]] = cs * x + sn * y;
+        h[[k + 1, j]] = cs * y - sn * x;
+    }
+    for i in 0..=k + 1 {
+        let (x, y) = (h[[i, k]], h[[i, k + 1]]);
+        h[[i, k]] = cs * x + sn * y;
+        h[[i, k + 1]] = cs * y - sn * x;
+    }
+    for i in 0..n {
+        let (x, y) = (z[[i, k]], z[[i, k + 1]]);
+        z[[i, k]] = cs * x + sn * y;
+        z[[i, k + 1]] = cs * y - sn * x;
+    }
+    if real_pair {
+        h[[k + 1, k]] = R::zero();
+    } else {
+        let mean = (h[[k, k]] + h[[k + 1, k + 1]]) / two;
+        h[[k, k]] = mean;
+        h[[k + 1, k + 1]] = mean;
+    }
+}
+
+/// Finishes the Hessenberg form computed by `schur_faer_in_place` with the QR
+/// iterations for `T`: quasi-triangular for real types, triangular for complex
+/// ones.
+fn hessenberg_qr<T, D0: Dim, D1: Dim, L: Layout, Lz: Layout>(
+    h: &mut Slice<T, (D0, D1), L>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+) -> Result<(), SchurError>
+where
+    T: ComplexFloat + std::convert::From<<T as num_complex::ComplexFloat>::Real>,
+{
+    let shape = *h.shape();
+    let i = (-T::one()).sqrt();
+    if i.is_nan() {
+        let mut hr = Array::from_fn(shape, |idx| h[idx].re());
+        let mut zr = Array::from_fn(shape, |idx| z[idx].re());
+        real_hessenberg_qr(&mut hr, &mut zr)?;
+        for (dst, src) in h.iter_mut().zip(hr.iter()) {
+            *dst = (*src).into();
+        }
+        for (dst, src) in z.iter_mut().zip(zr.iter()) {
+            *dst = (*src).into();
+        }
+    } else {
+        let mut hc = Array::from_fn(shape, |idx| Complex::new(h[idx].re(), h[idx].im()));
+        let mut zc = Array::from_fn(shape, |idx| Complex::new(z[idx].re(), z[idx].im()));
+        complex_hessenberg_qr(&mut hc, &mut zc)?;
+        let i = if i.im() < T::zero().re() { -i } else { i };
+        for (dst, src) in h.iter_mut().zip(hc.iter()) {
+            *dst = <T as From<_>>::from(src.re) + i * <T as From<_>>::from(src.im);
+        }
+        for (dst, src) in z.iter_mut().zip(zc.iter()) {
+            *dst = <T as From<_>>::from(src.re) + i * <T as From<_>>::from(src.im);
+        }
+    }
+    Ok(())
+}
+
 fn swap_matrices<T, D0: Dim, D1: Dim, L0: Layout, L1: Layout>(
     a: &mut Slice<T, (D0, D1), L0>,
     b: &mut Slice<T, (D0, D1), L1>,
@@ -131,6 +463,85 @@ fn swap_matrices<T, D0: Dim, D1: Dim, L0: Layout, L1: Layout>(
     }
 }
 
+/// Computes the eigenvalues, and the eigenvectors if requested, of the
+/// self-adjoint matrix `a` with faer, or with Jacobi rotations.
+fn self_adjoint_evd<T, D0: Dim, D1: Dim, L: Layout>(
+    a: &Slice<T, (D0, D1), L>,
+    eigenvalues: &mut Slice<<T as ComplexFloat>::Real, (D0,), Dense>,
+    eigenvectors: Option<&mut Slice<T, (D0, D1), Dense>>,
+    algorithm: EighAlgorithm,
+) -> Result<(), EigError>
+where
+    T: ComplexFloat + ComplexField + Default,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+    assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+
+    // faer has no Jacobi eigensolver for self-adjoint matrices.
+    if algorithm == EighAlgorithm::Jacobi {
+        let decomp = jacobi_eigh(a)?;
+        for j in 0..n {
+            eigenvalues[j] = decomp.eigenvalues[j];
+        }
+        if let Some(eigenvectors) = eigenvectors {
+            for j in 0..n {
+                for i in 0..n {
+                    eigenvectors[[i, j]] = decomp.eigenvectors[[i, j]];
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let par = faer::get_global_parallelism();
+    let mut params = <faer::linalg::evd::SelfAdjointEvdParams as faer::Auto<T>>::auto();
+    if algorithm == EighAlgorithm::Qr {
+        // faer uses QR iteration on the tridiagonal form below this size and
+        // divide and conquer above it.
+        params.recursion_threshold = usize::MAX;
+    }
+    let compute = if eigenvectors.is_some() {
+        faer::linalg::evd::ComputeEigenvectors::Yes
+    } else {
+        faer::linalg::evd::ComputeEigenvectors::No
+    };
+
+    // faer returns the eigenvalues in the scalar field of `a`.
+    let mut s = Array::from_elem(<(D0,) as Shape>::from_dims(&[n]), T::default());
+    // faer reads the lower triangle, so mirror the upper triangle that the
+    // other algorithms and LAPACK read into it.
+    let lower = faer::Mat::<T>::from_fn(n, n, |i, j| {
+        if i >= j {
+            ComplexFloat::conj(a[[j, i]])
+        } else {
+            T::default()
+        }
+    });
+    let result = faer::linalg::evd::self_adjoint_evd(
+        lower.as_ref(),
+        into_faer_diag_mut(&mut s),
+        eigenvectors.map(into_faer_mut),
+        par,
+        MemStack::new(&mut MemBuffer::new(
+            faer::linalg::evd::self_adjoint_evd_scratch::<T>(n, compute, par, params.into()),
+        )),
+        params.into(),
+    );
+
+    match result {
+        Ok(_) => {
+            for i in 0..n {
+                eigenvalues[i] = s[i].re();
+            }
+            Ok(())
+        }
+        Err(_) => Err(EigError::BackendDidNotConverge { iterations: 0 }),
+    }
+}
+
 impl<T, D0: Dim, D1: Dim> Eig<T, D0, D1> for Faer
 where
     T: ComplexFloat
@@ -142,6 +553,7 @@ where
         + Default
         + std::convert::From<<T as ComplexFloat>::Real>
         + std::convert::From<<Complex<<T as ComplexFloat>::Real> as ComplexFloat>::Real>,
+    <T as ComplexFloat>::Real: ComplexFloat<Real = <T as ComplexFloat>::Real>,
 {
     type SpectralScalar = Complex<<T as ComplexFloat>::Real>;
     type RealScalar = <T as ComplexFloat>::Real;
@@ -156,6 +568,7 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
+        let balancing = balance(a, self.eig_config.balance);
         let a_faer = into_faer(a);
         let eig_result = a_faer.eigen();
 
@@ -179,11 +592,17 @@ where
                     }
                 }
 
-                Ok(EigDecomp {
+                balancing.unbalance(&mut right_vecs_mda, EigSide::Right);
+
+                let mut decomp = EigDecomp {
                     eigenvalues: eigenvalues_mda,
                     left_eigenvectors: None,
                     right_eigenvectors: Some(right_vecs_mda),
-                })
+                };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
             }
             Err(_) => Err(EigError::BackendDidNotConverge { iterations: 0 }),
         }
@@ -207,6 +626,7 @@ where
         };
         let ash1 = <(D0,) as Shape>::from_dims(&[n]);
         let mut eigenvalues_mda = Array::from_elem(ash1, Complex::new(xr, xr));
+        let balancing = balance(a, self.eig_config.balance);
         let a_faer = into_faer(a);
 
         if T::IS_REAL {
@@ -323,11 +743,18 @@ where
                         }
                     }
 
-                    Ok(EigDecomp {
+                    balancing.unbalance(&mut left_vecs_mda, EigSide::Left);
+                    balancing.unbalance(&mut right_vecs_mda, EigSide::Right);
+
+                    let mut decomp = EigDecomp {
                         eigenvalues: eigenvalues_mda,
                         left_eigenvectors: Some(left_vecs_mda),
                         right_eigenvectors: Some(right_vecs_mda),
-                    })
+                    };
+                    if let Some(gauge) = &self.gauge {
+                        decomp.canonicalize(gauge);
+                    }
+                    Ok(decomp)
                 }
                 Err(_) => Err(EigError::BackendDidNotConverge { iterations: 0 }),
             }
@@ -387,11 +814,18 @@ where
                         }
                     }
 
-                    Ok(EigDecomp {
+                    balancing.unbalance(&mut left_vecs_mda, EigSide::Left);
+                    balancing.unbalance(&mut right_vecs_mda, EigSide::Right);
+
+                    let mut decomp = EigDecomp {
                         eigenvalues: eigenvalues_mda,
                         left_eigenvectors: Some(left_vecs_mda),
                         right_eigenvectors: Some(right_vecs_mda),
-                    })
+                    };
+                    if let Some(gauge) = &self.gauge {
+                        decomp.canonicalize(gauge);
+                    }
+                    Ok(decomp)
                 }
                 Err(_) => Err(EigError::BackendDidNotConverge { iterations: 0 }),
             }
@@ -407,6 +841,7 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
+        balance(a, self.eig_config.balance);
         let a_faer = into_faer(a);
 
         let eigenvalues_result = a_faer.eigenvalues();
@@ -420,6 +855,9 @@ where
                 for i in 0..n {
                     eigenvalues_mda[i] = complex_from_faer!(&eigenvalues[i], T);
                 }
+                if let Some(gauge) = &self.gauge {
+                    gauge_eig::<_, D0, D1>(gauge, &mut eigenvalues_mda, None, None);
+                }
 
                 Ok(eigenvalues_mda)
             }
@@ -427,7 +865,21 @@ where
         }
     }
 
+    /// Compute eigenvalues, left/right eigenvectors and their condition numbers
+    /// from the complex Schur form
+    fn eig_condition<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(EigDecomp<Self::SpectralScalar, D0, D1>, EigCondition<Self::RealScalar, D0>), EigError> {
+        let (mut decomp, mut condition) = eig_condition(self, a, self.eig_config.balance)?;
+        if let Some(gauge) = &self.gauge {
+            gauge_eig_condition(gauge, &mut decomp, &mut condition);
+        }
+        Ok((decomp, condition))
+    }
+
     /// Compute eigenvalues and eigenvectors of a Hermitian matrix (input should be complex)
+    /// with the configured algorithm; MRRR is not available and uses the default
     fn eigh<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EighDecomp<T, Self::RealScalar, D0, D1>, EigError> {
         let ash = *a.shape();
         let (m, n) = (ash.dim(0), ash.dim(1));
@@ -436,37 +888,102 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
-        let a_faer = into_faer(a);
-        let eig_result = a_faer.self_adjoint_eigen(faer::Side::Lower);
+        let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+        let mut eigenvalues = Array::from_elem(ash1, T::default().re());
+        let mut eigenvectors = Array::from_elem(ash, T::default());
+        self.eigh_write(a, &mut eigenvalues, &mut eigenvectors)?;
+
+        Ok(EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
 
-        match eig_result {
-            Ok(eig) => {
-                let eigenvalues = eig.S();
-                let eigenvectors = eig.U();
+    /// Compute eigenvalues and eigenvectors of a Hermitian matrix, overwriting
+    /// existing buffers
+    fn eigh_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+        eigenvectors: &mut Slice<T, (D0, D1), Dense>,
+    ) -> Result<(), EigError> {
+        self_adjoint_evd(a, eigenvalues, Some(&mut *eigenvectors), self.eigh_config.algorithm)?;
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh(gauge, eigenvalues, Some(eigenvectors));
+        }
+        Ok(())
+    }
 
-                let x = T::default();
-                let ash1 = <(D0,) as Shape>::from_dims(&[n]);
-                let mut eigenvalues_mda = Array::from_elem(ash1, x.re());
-                let mut eigenvectors_mda = Array::from_elem(ash, T::default());
+    /// Compute only the eigenvalues of a Hermitian matrix
+    fn eigh_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::RealScalar, (D0,)>, EigError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
 
-                for i in 0..n {
-                    eigenvalues_mda[i] = eigenvalues[i].re();
-                }
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
 
-                let mut eigenvectors_faer = into_faer_mut(&mut eigenvectors_mda);
-                for i in 0..n {
-                    for j in 0..n {
-                        eigenvectors_faer[(i, j)] = eigenvectors[(i, j)];
-                    }
-                }
+        let mut eigenvalues = Array::from_elem(<(D0,) as Shape>::from_dims(&[n]), T::default().re());
+        self.eigh_values_write(a, &mut eigenvalues)?;
 
-                Ok(EighDecomp {
-                    eigenvalues: eigenvalues_mda,
-                    eigenvectors: eigenvectors_mda,
-                })
-            }
-            Err(_) => Err(EigError::BackendDidNotConverge { iterations: 0 }),
+        Ok(eigenvalues)
+    }
+
+    /// Compute only the eigenvalues of a Hermitian matrix, overwriting an
+    /// existing buffer
+    fn eigh_values_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+    ) -> Result<(), EigError> {
+        self_adjoint_evd::<T, D0, D1, L>(a, eigenvalues, None, self.eigh_config.algorithm)?;
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, D0, D1, Dense>(gauge, eigenvalues, None);
+        }
+        Ok(())
+    }
+
+    /// Compute a subset of eigenpairs of a Hermitian matrix
+    ///
+    /// faer has no subset selection, so the full decomposition is filtered.
+    fn eigh_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<T, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let mut decomp = select_eigh(self.eigh(a)?, &range)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
         }
+        Ok(decomp)
+    }
+
+    /// Compute selected eigenpairs of a symmetric tridiagonal matrix
+    fn eigh_tridiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<Self::RealScalar, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let mut decomp = tridiagonal_eigh(d, e, &range, true)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    /// Compute selected eigenvalues of a symmetric tridiagonal matrix
+    fn eigh_tridiagonal_values<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<Array<Self::RealScalar, (Dyn,)>, EigError> {
+        let mut eigenvalues = tridiagonal_eigh(d, e, &range, false)?.eigenvalues;
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, Dyn, Dyn, Dense>(gauge, &mut eigenvalues, None);
+        }
+        Ok(eigenvalues)
     }
 
     /// Compute Schur decomposition with new allocated matrices
@@ -481,6 +998,7 @@ where
         let mut t = a.to_tensor();
         let mut z = Array::from_elem(ash, T::zero());
         schur_faer_in_place(&mut t, &mut z)?;
+        hessenberg_qr(&mut t, &mut z)?;
 
         Ok(SchurDecomp { t, z })
     }
@@ -493,6 +1011,7 @@ where
         z: &mut Slice<T, (D0, D1), Dense>,
     ) -> Result<(), SchurError> {
         schur_faer_in_place(a, z)?;
+        hessenberg_qr(a, z)?;
         swap_matrices(a, t);
         Ok(())
     }
@@ -514,6 +1033,7 @@ where
         });
         let mut z = Array::from_elem(shape, Complex::new(zero, zero));
         schur_faer_in_place(&mut t, &mut z)?;
+        complex_hessenberg_qr(&mut t, &mut z)?;
 
         Ok(SchurDecomp { t, z })
     }
@@ -534,4 +1054,48 @@ where
         }
         Ok(())
     }
+
+    /// Reorder a Schur decomposition by swapping adjacent diagonal blocks
+    fn schur_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        reorder_schur(t, z, &select, condition)
+    }
+
+    /// Reorder a complex Schur decomposition by swapping adjacent diagonal entries
+    fn schur_complex_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &mut Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        reorder_schur(t, z, &select, condition)
+    }
+
+    /// Compute selected eigenvectors from a Schur decomposition by back substitution
+    fn schur_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<T, (D0, D1), Lt>,
+        z: &Slice<T, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        schur_eigenvectors(t, z, side, select.as_ref())
+    }
+
+    /// Compute selected eigenvectors from a complex Schur decomposition by back substitution
+    fn schur_complex_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        schur_eigenvectors(t, z, side, select.as_ref())
+    }
 }
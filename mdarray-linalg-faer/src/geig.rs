@@ -0,0 +1,360 @@
+// Generalized Eigenvalue Decomposition:
+//     β * A * v = α * B * v  (right eigenvectors)
+//     β * u^H * A = α * u^H * B  (left eigenvectors)
+// where:
+//     - A, B are n × n     (input square matrices)
+//     - λ = α / β          (infinite for β = 0)
+//
+// Generalized Schur (QZ) Decomposition:
+//     A = Q * S * Z^H,  B = Q * T * Z^H
+// where:
+//     - Q, Z are n × n     (unitary Schur vectors)
+//     - S is n × n         (upper triangular for complex, quasi-upper triangular for real)
+//     - T is n × n         (upper triangular)
+
+use dyn_stack::{MemBuffer, MemStack};
+use faer::linalg::gevd::{
+    self, ComputeEigenvectors, GeneralizedSchurParams, GevdParams,
+    gen_hessenberg::{GeneralizedHessenbergParams, generalized_hessenberg, generalized_hessenberg_scratch},
+    qz_cplx, qz_real,
+};
+use faer::{Auto, Col, Mat, MatMut, diag::Diag};
+use faer_traits::ComplexField;
+use mdarray::{Array, Dim, Layout, Shape, Slice};
+use mdarray_linalg::eig::{SchurSelect, normalize_eigenvector};
+use mdarray_linalg::geig::{GEig, GEigDecomp, GEigError, QzDecomp, reorder_qz};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::Zero;
+
+use crate::{Faer, into_faer};
+
+type FaerReal<T> = <T as ComplexField>::Real;
+type FaerGEigDecomp<T, D0, D1> = GEigDecomp<Complex<<T as ComplexFloat>::Real>, T, D0, D1>;
+
+fn from_faer_real<T: ComplexFloat + ComplexField>(x: &FaerReal<T>) -> <T as ComplexFloat>::Real {
+    // SAFETY: for the scalar types supported by this backend, faer and num_complex use the
+    // same real component type, so this is a bitwise-preserving reinterpretation.
+    unsafe { std::mem::transmute_copy(x) }
+}
+
+/// Copies a matrix of a real scalar type `T` into a faer matrix of its faer
+/// real scalar.
+fn real_faer<T: ComplexField, D0: Dim, D1: Dim, L: Layout>(m: &Slice<T, (D0, D1), L>) -> Mat<FaerReal<T>> {
+    let (rows, cols) = (m.shape().dim(0), m.shape().dim(1));
+    Mat::from_fn(rows, cols, |i, j| {
+        // SAFETY: this is only called for real scalar types, whose faer real scalar is `T`
+        // itself.
+        unsafe { std::mem::transmute_copy(&m[[i, j]]) }
+    })
+}
+
+fn check_pencil<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<usize, GEigError> {
+    let (ash, bsh) = (*a.shape(), *b.shape());
+    let n = ash.dim(0);
+    if ash.dim(1) != n {
+        return Err(GEigError::NotSquareMatrix);
+    }
+    if bsh.dim(0) != n || bsh.dim(1) != n {
+        return Err(GEigError::ShapeMismatch);
+    }
+    Ok(n)
+}
+
+/// Copies the eigenvectors computed by faer into complex columns normalized as
+/// documented by `GEigDecomp`.  For a real pencil, a complex conjugate pair
+/// takes two columns holding the real and imaginary parts of the eigenvector
+/// of the eigenvalue with positive imaginary part; faer stores the imaginary
+/// part of left eigenvectors with the opposite sign, hence `left`.
+fn eigenvectors_from_faer<T, D0: Dim, D1: Dim>(
+    u: &Mat<T>,
+    alpha: &Array<Complex<<T as ComplexFloat>::Real>, (D0,)>,
+    shape: (D0, D1),
+    left: bool,
+) -> Array<Complex<<T as ComplexFloat>::Real>, (D0, D1)>
+where
+    T: ComplexFloat + ComplexField,
+{
+    let n = shape.dim(0);
+    let c = |i: usize, j: usize| Complex::new(u[(i, j)].re(), u[(i, j)].im());
+    let mut v = Array::from_fn(shape, |idx| c(idx[0], idx[1]));
+    let mut j = 0;
+    while j < n {
+        if T::IS_REAL && j + 1 < n && !alpha[j].im.is_zero() {
+            for i in 0..n {
+                let (re, mut im) = (u[(i, j)].re(), u[(i, j + 1)].re());
+                if left {
+                    im = -im;
+                }
+                v[[i, j]] = Complex::new(re, im);
+                v[[i, j + 1]] = Complex::new(re, -im);
+            }
+            j += 2;
+        } else {
+            j += 1;
+        }
+    }
+    for j in 0..n {
+        let mut col: Vec<_> = (0..n).map(|i| v[[i, j]]).collect();
+        normalize_eigenvector(&mut col);
+        for (i, x) in col.into_iter().enumerate() {
+            v[[i, j]] = x;
+        }
+    }
+    v
+}
+
+fn gevd_faer<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+    left: bool,
+    right: bool,
+) -> Result<FaerGEigDecomp<T, D0, D1>, GEigError>
+where
+    T: ComplexFloat + ComplexField + Default + From<<T as ComplexFloat>::Real>,
+{
+    let n = check_pencil(a, b)?;
+    let shape = *a.shape();
+    let par = faer::get_global_parallelism();
+    let compute = |wanted: bool| {
+        if wanted {
+            ComputeEigenvectors::Yes
+        } else {
+            ComputeEigenvectors::No
+        }
+    };
+    let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+    let mut alpha = Array::from_elem(ash1, Complex::new(T::default().re(), T::default().re()));
+    let mut beta = Array::from_elem(ash1, T::default());
+    let mut u_left = left.then(|| Mat::<T>::zeros(n, n));
+    let mut u_right = right.then(|| Mat::<T>::zeros(n, n));
+
+    let result = if T::IS_REAL {
+        let (mut ar, mut br) = (real_faer(a), real_faer(b));
+        let mut s_re = Diag::<FaerReal<T>>::zeros(n);
+        let mut s_im = Diag::<FaerReal<T>>::zeros(n);
+        let mut beta_re = Diag::<FaerReal<T>>::zeros(n);
+        let mut ul = left.then(|| Mat::<FaerReal<T>>::zeros(n, n));
+        let mut ur = right.then(|| Mat::<FaerReal<T>>::zeros(n, n));
+        let params = <GevdParams as Auto<FaerReal<T>>>::auto();
+        let result = gevd::gevd_real::<FaerReal<T>>(
+            ar.as_mut(),
+            br.as_mut(),
+            s_re.as_mut(),
+            s_im.as_mut(),
+            beta_re.as_mut(),
+            ul.as_mut().map(|u| u.as_mut()),
+            ur.as_mut().map(|u| u.as_mut()),
+            par,
+            MemStack::new(&mut MemBuffer::new(gevd::gevd_scratch::<FaerReal<T>>(
+                n,
+                compute(left),
+                compute(right),
+                par,
+                params.into(),
+            ))),
+            params.into(),
+        );
+        for i in 0..n {
+            alpha[i] = Complex::new(
+                from_faer_real::<T>(&s_re[i]),
+                from_faer_real::<T>(&s_im[i]),
+            );
+            beta[i] = <T as From<_>>::from(from_faer_real::<T>(&beta_re[i]));
+        }
+        // faer does not rescale the second member of a complex pair, so mirror the first
+        let mut i = 0;
+        while i + 1 < n {
+            if alpha[i].im.is_zero() {
+                i += 1;
+            } else {
+                alpha[i + 1] = alpha[i].conj();
+                beta[i + 1] = beta[i];
+                i += 2;
+            }
+        }
+        let to_t = |u: Mat<FaerReal<T>>| Mat::<T>::from_fn(n, n, |i, j| <T as From<_>>::from(from_faer_real::<T>(&u[(i, j)])));
+        u_left = ul.map(to_t);
+        u_right = ur.map(to_t);
+        result
+    } else {
+        let mut ac = into_faer(a).to_owned();
+        let mut bc = into_faer(b).to_owned();
+        let mut s = Diag::<T>::zeros(n);
+        let mut beta_c = Diag::<T>::zeros(n);
+        let params = <GevdParams as Auto<T>>::auto();
+        let result = gevd::gevd_cplx::<T>(
+            ac.as_mut(),
+            bc.as_mut(),
+            s.as_mut(),
+            beta_c.as_mut(),
+            u_left.as_mut().map(|u| u.as_mut()),
+            u_right.as_mut().map(|u| u.as_mut()),
+            par,
+            MemStack::new(&mut MemBuffer::new(gevd::gevd_scratch::<T>(
+                n,
+                compute(left),
+                compute(right),
+                par,
+                params.into(),
+            ))),
+            params.into(),
+        );
+        for i in 0..n {
+            alpha[i] = Complex::new(s[i].re(), s[i].im());
+            beta[i] = beta_c[i];
+        }
+        result
+    };
+    result.map_err(|_| GEigError::BackendDidNotConverge { iterations: 0 })?;
+
+    Ok(GEigDecomp {
+        left_eigenvectors: u_left.map(|u| eigenvectors_from_faer(&u, &alpha, shape, true)),
+        right_eigenvectors: u_right.map(|u| eigenvectors_from_faer(&u, &alpha, shape, false)),
+        alpha,
+        beta,
+    })
+}
+
+// Faer runs the QZ iterations inside its generalized eigendecomposition, but does not return
+// the Schur form.  The steps are chained here from its public building blocks.
+fn qz_faer<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<QzDecomp<T, D0, D1>, GEigError>
+where
+    T: ComplexFloat + ComplexField + Default,
+{
+    let n = check_pencil(a, b)?;
+    let shape = *a.shape();
+    let par = faer::get_global_parallelism();
+
+    // B = Q R, so that (Q^H A, R) has a triangular second matrix.
+    let qr = faer::linalg::solvers::Qr::new(into_faer(b));
+    let mut q: Mat<T> = qr.compute_Q();
+    let mut t = Mat::<T>::from_fn(n, n, |i, j| if i <= j { qr.R()[(i, j)] } else { T::default() });
+    let mut s: Mat<T> = q.adjoint() * into_faer(a);
+    let mut z = Mat::<T>::identity(n, n);
+
+    let hessenberg_params = <GeneralizedHessenbergParams as Auto<T>>::auto();
+    generalized_hessenberg(
+        s.as_mut(),
+        t.as_mut(),
+        Some(q.as_mut()),
+        Some(z.as_mut()),
+        par,
+        MemStack::new(&mut MemBuffer::new(generalized_hessenberg_scratch::<T>(n, hessenberg_params))),
+        hessenberg_params,
+    );
+
+    if T::IS_REAL {
+        // SAFETY: this branch is only taken for real scalar types, whose faer real scalar is
+        // `T` itself, so the matrices are reinterpreted without changing their layout.
+        let real = |m: &mut Mat<T>| unsafe { faer::hacks::coerce::<MatMut<'_, T>, MatMut<'_, FaerReal<T>>>(m.as_mut()) };
+        let params = <GeneralizedSchurParams as Auto<FaerReal<T>>>::auto();
+        let mut alphar = Col::<FaerReal<T>>::zeros(n);
+        let mut alphai = Col::<FaerReal<T>>::zeros(n);
+        let mut beta = Col::<FaerReal<T>>::zeros(n);
+        let stack_req = qz_real::hessenberg_to_qz_scratch::<FaerReal<T>>(n, par, params);
+        qz_real::hessenberg_to_qz(
+            real(&mut s),
+            real(&mut t),
+            Some(real(&mut q)),
+            Some(real(&mut z)),
+            alphar.as_mut(),
+            alphai.as_mut(),
+            beta.as_mut(),
+            ComputeEigenvectors::Yes,
+            par,
+            params,
+            MemStack::new(&mut MemBuffer::new(stack_req)),
+        );
+    } else {
+        let params = <GeneralizedSchurParams as Auto<T>>::auto();
+        let mut alpha = Col::<T>::zeros(n);
+        let mut beta = Col::<T>::zeros(n);
+        qz_cplx::hessenberg_to_qz(
+            s.as_mut(),
+            t.as_mut(),
+            Some(q.as_mut()),
+            Some(z.as_mut()),
+            alpha.as_mut(),
+            beta.as_mut(),
+            ComputeEigenvectors::Yes,
+            par,
+            params,
+            MemStack::new(&mut MemBuffer::new(qz_cplx::hessenberg_to_qz_scratch::<T>(n, par, params))),
+        );
+    }
+
+    let to_mda = |m: &Mat<T>| Array::from_fn(shape, |idx| m[(idx[0], idx[1])]);
+    Ok(QzDecomp {
+        s: to_mda(&s),
+        t: to_mda(&t),
+        q: to_mda(&q),
+        z: to_mda(&z),
+    })
+}
+
+impl<T, D0: Dim, D1: Dim> GEig<T, D0, D1> for Faer
+where
+    T: ComplexFloat
+        + ComplexField
+        + Default
+        + std::convert::From<<T as num_complex::ComplexFloat>::Real>,
+    Complex<<T as ComplexFloat>::Real>: ComplexFloat
+        + ComplexField
+        + Default
+        + std::convert::From<<T as ComplexFloat>::Real>
+        + std::convert::From<<Complex<<T as ComplexFloat>::Real> as ComplexFloat>::Real>,
+{
+    /// Compute generalized eigenvalues and right eigenvectors with faer's generalized EVD
+    fn geig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        gevd_faer(a, b, false, true)
+    }
+
+    /// Compute generalized eigenvalues and left/right eigenvectors with faer's generalized EVD
+    fn geig_full<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        gevd_faer(a, b, true, true)
+    }
+
+    /// Compute only the generalized eigenvalues with faer's generalized EVD
+    fn geig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        gevd_faer(a, b, false, false)
+    }
+
+    /// Compute the generalized Schur decomposition with faer's QZ iterations
+    fn qz<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<QzDecomp<T, D0, D1>, GEigError> {
+        qz_faer(a, b)
+    }
+
+    /// Reorder a generalized Schur decomposition by swapping adjacent diagonal blocks
+    fn qz_reorder<Ls: Layout, Lt: Layout, Lq: Layout, Lz: Layout>(
+        &self,
+        s: &mut Slice<T, (D0, D1), Ls>,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+    ) -> Result<usize, GEigError> {
+        reorder_qz(s, t, q, z, &select)
+    }
+}
@@ -16,14 +16,17 @@
 //! - **Level 1** — vector operations: `dot`, `dotc`, `norm2`, `norm1`, `add_to_scaled`
 //! - **Level 2** — matrix-vector & outer product: `matvec`, `outer`
 //! - **Level 3** — matrix multiplication: `matmul`
+//! - **Sparse** — CSR/CSC products with dense vectors and matrices: `spmv`, `spmm`
 //! - **Tensor contraction** — `contract_all`, `contract_n`, `contract_pairs`, `contract`
-//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eigh`
+//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eig_condition`, `eigh`, `eigh_values`
 //! - **Schur decomposition** — `schur`, `schur_complex`
 //! - **SVD** — `svd`, `svd_thin`, `svd_s`
 //! - **LU decomposition** — `lu`, `det`, `inv`
 //! - **Cholesky decomposition** — `cholesky`
 //! - **QR decomposition** — `qr`
 //! - **Linear system solving** — `solve`
+//! - **Sparse direct solvers** — Cholesky, LU and QR with reusable symbolic analysis: `analyze`, `factorize`, `solve_factored`
+//! - **Structured matrices** — triangular, symmetric, Hermitian, diagonal and packed: `tri_matmul`, `tri_solve`, `sym_matmul`, `herm_matmul`, `diag_scale`, `packed_sym_matvec`
 //!
 //! ## Setup
 //!
@@ -103,23 +106,63 @@
 ")]
 
 mod eig;
+mod geig;
 mod lu;
 mod contract;
 mod matvec;
 mod qr;
 mod solve;
+mod sparse;
+mod sparse_solve;
+mod structured;
 mod svd;
 
+pub use sparse_solve::{FaerSparseFactor, FaerSparseSymbolic};
+
 /// Faer backend.
 ///
 /// Implements the linear algebra traits from [`mdarray_linalg`] by delegating
 /// to the pure-Rust `faer` library.  This backend supports the broadest range of
 /// operations — from basic BLAS to full decompositions and tensor contractions —
 /// without requiring any system BLAS/LAPACK installation.
-#[derive(Default)]
-pub struct Faer;
+#[derive(Debug, Default, Clone)]
+pub struct Faer {
+    eig_config: EigConfig,
+    eigh_config: EighConfig,
+    gauge: Option<Gauge>,
+}
+
+impl Faer {
+    /// Configures the general eigenvalue decompositions.
+    #[must_use]
+    pub fn config_eig(mut self, config: EigConfig) -> Self {
+        self.eig_config = config;
+        self
+    }
+
+    /// Configures the self-adjoint eigenvalue decomposition.  faer offers QR
+    /// iteration and divide and conquer (the default), and Jacobi rotations
+    /// are provided by `mdarray_linalg`; MRRR falls back to the default.
+    #[must_use]
+    pub fn config_eigh(mut self, config: EighConfig) -> Self {
+        self.eigh_config = config;
+        self
+    }
+
+    /// Canonicalizes the results of the eigenvalue decompositions and SVDs,
+    /// see [`mdarray_linalg::gauge`].
+    #[must_use]
+    pub fn config_gauge(mut self, gauge: Gauge) -> Self {
+        self.gauge = Some(gauge);
+        self
+    }
+}
 
 use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    eig::{EigConfig, EighConfig},
+    gauge::Gauge,
+};
 
 /// Converts a `Slice<T, (_, _), L>` (from `mdarray`) into a `faer::MatRef<'a, T>`.
 /// This function **does not copy** any data.
@@ -179,11 +179,17 @@ where
 
     fn add_to_scaled<Lx: Layout, Ly: Layout>(
         &self,
-        _alpha: T,
-        _x: &Slice<T, (D,), Lx>,
-        _y: &mut Slice<T, (D,), Ly>,
+        alpha: T,
+        x: &Slice<T, (D,), Lx>,
+        y: &mut Slice<T, (D,), Ly>,
     ) {
-        unimplemented!();
+        assert_eq!(
+            x.shape().dim(0),
+            y.shape().dim(0),
+            "Vectors must have same length"
+        );
+        faer::zip!(into_faer_col_mut(y), into_faer_col(x))
+            .for_each(|faer::unzip!(yi, xi)| *yi = alpha * *xi + *yi);
     }
 
     fn dot<Lx: Layout, Ly: Layout>(&self, x: &Slice<T, (D,), Lx>, y: &Slice<T, (D,), Ly>) -> T {
@@ -0,0 +1,215 @@
+use faer::{
+    Accum, MatMut, MatRef, Par,
+    sparse::{
+        SparseColMatRef, SparseRowMatRef, SymbolicSparseColMatRef, SymbolicSparseRowMatRef,
+        linalg::matmul::sparse_dense_matmul,
+    },
+};
+use faer_traits::ComplexField;
+use mdarray::{DArray, Dyn, Layout, Slice};
+use mdarray_linalg::sparse::{
+    SparseFormat, SparseMatVec, SparseMatVecBuilder, SparseMatmul, SparseMatmulBuilder,
+    SparseMatrix,
+};
+use num_complex::ComplexFloat;
+
+use crate::{Faer, into_faer, into_faer_col, into_faer_col_mut, into_faer_mut};
+
+/// Views a CSR matrix as a faer sparse row matrix.
+pub(crate) fn into_faer_csr<T>(a: &SparseMatrix<T>) -> SparseRowMatRef<'_, usize, T> {
+    assert_eq!(a.format(), SparseFormat::Csr);
+    let (rows, cols) = a.shape();
+
+    // SAFETY:
+    // `SparseMatrix` guarantees what faer requires from a symbolic structure:
+    // - `indptr` has one entry more than the compressed dimension, starts at 0
+    //   and is nondecreasing, and its last entry is `indices.len()`,
+    // - the indices of every row are in bounds and strictly increasing.
+    let symbolic = unsafe {
+        SymbolicSparseRowMatRef::new_unchecked(rows, cols, a.indptr().as_ref(), None, a.indices().as_ref())
+    };
+    SparseRowMatRef::new(symbolic, a.values().as_ref())
+}
+
+/// Views a CSC matrix as a faer sparse column matrix.
+pub(crate) fn into_faer_csc<T>(a: &SparseMatrix<T>) -> SparseColMatRef<'_, usize, T> {
+    assert_eq!(a.format(), SparseFormat::Csc);
+    let (rows, cols) = a.shape();
+
+    // SAFETY: as for `into_faer_csr`, with columns in place of rows.
+    let symbolic = unsafe {
+        SymbolicSparseColMatRef::new_unchecked(rows, cols, a.indptr().as_ref(), None, a.indices().as_ref())
+    };
+    SparseColMatRef::new(symbolic, a.values().as_ref())
+}
+
+/// `dst := α·A·rhs` (`Accum::Replace`) or `dst := α·A·rhs + dst` (`Accum::Add`)
+fn sparse_matmul<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    accum: Accum,
+    a: &SparseMatrix<T>,
+    rhs: MatRef<'_, T>,
+    alpha: T,
+    par: Par,
+) {
+    match a.format() {
+        SparseFormat::Csr => sparse_dense_matmul(dst, accum, into_faer_csr(a), rhs, alpha, par),
+        SparseFormat::Csc => sparse_dense_matmul(dst, accum, into_faer_csc(a), rhs, alpha, par),
+    }
+}
+
+struct FaerSparseMatVecBuilder<'a, T, Lx: Layout> {
+    alpha: T,
+    a: &'a SparseMatrix<T>,
+    x: &'a Slice<T, (Dyn,), Lx>,
+    par: Par,
+}
+
+impl<'a, T, Lx> FaerSparseMatVecBuilder<'a, T, Lx>
+where
+    Lx: Layout,
+    T: ComplexFloat + ComplexField,
+{
+    fn run<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>, accum: Accum) {
+        let (m, n) = self.a.shape();
+        assert_eq!(n, self.x.shape().0, "Matrix columns must match vector length");
+        assert_eq!(m, y.shape().0, "Matrix rows must match output vector length");
+
+        sparse_matmul(
+            into_faer_col_mut(y).as_mat_mut(),
+            accum,
+            self.a,
+            into_faer_col(self.x).as_mat(),
+            self.alpha,
+            self.par,
+        );
+    }
+}
+
+impl<'a, T, Lx> SparseMatVecBuilder<'a, T, Lx> for FaerSparseMatVecBuilder<'a, T, Lx>
+where
+    Lx: Layout,
+    T: ComplexFloat + ComplexField,
+{
+    fn scale(mut self, alpha: T) -> Self {
+        self.alpha *= alpha;
+        self
+    }
+
+    fn eval(self) -> DArray<T, 1> {
+        let mut y = DArray::<T, 1>::from_elem([self.a.shape().0], T::zero());
+        self.write(&mut y);
+        y
+    }
+
+    fn write<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>) {
+        self.run(y, Accum::Replace);
+    }
+
+    fn add_to_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>) {
+        self.run(y, Accum::Add);
+    }
+
+    fn add_to_scaled_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>, beta: T) {
+        for yi in y.iter_mut() {
+            *yi = beta * *yi;
+        }
+        self.run(y, Accum::Add);
+    }
+}
+
+impl<T> SparseMatVec<T> for Faer
+where
+    T: ComplexFloat + ComplexField,
+{
+    fn spmv<'a, Lx>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        x: &'a Slice<T, (Dyn,), Lx>,
+    ) -> impl SparseMatVecBuilder<'a, T, Lx>
+    where
+        Lx: Layout,
+    {
+        FaerSparseMatVecBuilder {
+            alpha: T::one(),
+            a,
+            x,
+            par: faer::get_global_parallelism(),
+        }
+    }
+}
+
+struct FaerSparseMatmulBuilder<'a, T, Lb: Layout> {
+    alpha: T,
+    a: &'a SparseMatrix<T>,
+    b: &'a Slice<T, (Dyn, Dyn), Lb>,
+    par: Par,
+}
+
+impl<'a, T, Lb> FaerSparseMatmulBuilder<'a, T, Lb>
+where
+    Lb: Layout,
+    T: ComplexFloat + ComplexField,
+{
+    fn run<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>, accum: Accum) {
+        let (m, k) = self.a.shape();
+        let n = self.b.shape().1;
+        assert_eq!(k, self.b.shape().0, "Matrix columns must match rows of B");
+        assert_eq!(*c.shape(), (m, n), "Output shape must be {m}x{n}");
+
+        sparse_matmul(into_faer_mut(c), accum, self.a, into_faer(self.b), self.alpha, self.par);
+    }
+}
+
+impl<'a, T, Lb> SparseMatmulBuilder<'a, T, Lb> for FaerSparseMatmulBuilder<'a, T, Lb>
+where
+    Lb: Layout,
+    T: ComplexFloat + ComplexField,
+{
+    fn scale(mut self, factor: T) -> Self {
+        self.alpha *= factor;
+        self
+    }
+
+    fn eval(self) -> DArray<T, 2> {
+        let mut c = DArray::<T, 2>::from_elem([self.a.shape().0, self.b.shape().1], T::zero());
+        self.write(&mut c);
+        c
+    }
+
+    fn write<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>) {
+        self.run(c, Accum::Replace);
+    }
+
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>) {
+        self.run(c, Accum::Add);
+    }
+
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>, beta: T) {
+        for ci in c.iter_mut() {
+            *ci = beta * *ci;
+        }
+        self.run(c, Accum::Add);
+    }
+}
+
+impl<T> SparseMatmul<T> for Faer
+where
+    T: ComplexFloat + ComplexField,
+{
+    fn spmm<'a, Lb>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        b: &'a Slice<T, (Dyn, Dyn), Lb>,
+    ) -> impl SparseMatmulBuilder<'a, T, Lb>
+    where
+        Lb: Layout,
+    {
+        FaerSparseMatmulBuilder {
+            alpha: T::one(),
+            a,
+            b,
+            par: faer::get_global_parallelism(),
+        }
+    }
+}
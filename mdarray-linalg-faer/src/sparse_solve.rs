@@ -0,0 +1,281 @@
+// Sparse direct solvers built on faer's supernodal factorizations:
+//     Cholesky:  P A P^T = L L^H   (AMD, natural or custom ordering)
+//     LU:        P A Q = L U       (COLAMD column ordering)
+//     QR:        A Q = Q_r R       (COLAMD column ordering)
+// Matrices are handed to faer in CSC format; CSR input is converted.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use dyn_stack::{MemBuffer, MemStack};
+use faer::{
+    Conj, MatMut, Side,
+    linalg::solvers::{SolveCore, SolveLstsqCore},
+    perm::PermRef,
+    sparse::linalg::{
+        LuError,
+        cholesky::{LltRef, SymbolicCholesky, SymmetricOrdering, factorize_symbolic_cholesky},
+        solvers::{Lu, Qr, SymbolicLu, SymbolicQr},
+    },
+};
+use faer_traits::ComplexField;
+use mdarray::{DArray, Dyn, Layout, Slice};
+use mdarray_linalg::sparse::{SparseFormat, SparseMatrix};
+use mdarray_linalg::sparse_solve::{
+    SparseFactorization, SparseOrdering, SparseSolve, SparseSolveError,
+};
+use num_complex::ComplexFloat;
+
+use crate::{Faer, into_faer_mut, sparse::into_faer_csc};
+
+#[derive(Debug, Clone)]
+enum SymbolicKind {
+    Cholesky(Arc<SymbolicCholesky<usize>>),
+    Lu(SymbolicLu<usize>),
+    Qr(SymbolicQr<usize>),
+}
+
+/// Symbolic analysis computed by the [`Faer`] backend.
+///
+/// It keeps the analyzed pattern to reject matrices with another one.
+#[derive(Debug, Clone)]
+pub struct FaerSparseSymbolic {
+    shape: (usize, usize),
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    kind: SymbolicKind,
+}
+
+#[derive(Debug, Clone)]
+enum FactorKind<T> {
+    Cholesky {
+        symbolic: Arc<SymbolicCholesky<usize>>,
+        values: Vec<T>,
+    },
+    Lu(Box<Lu<usize, T>>),
+    Qr(Box<Qr<usize, T>>),
+}
+
+/// Numeric sparse factorization computed by the [`Faer`] backend.
+#[derive(Debug, Clone)]
+pub struct FaerSparseFactor<T> {
+    shape: (usize, usize),
+    kind: FactorKind<T>,
+}
+
+fn backend_error(err: impl std::fmt::Debug) -> SparseSolveError {
+    SparseSolveError::Backend(format!("{err:?}"))
+}
+
+fn to_csc<T: ComplexFloat>(a: &SparseMatrix<T>) -> Cow<'_, SparseMatrix<T>> {
+    match a.format() {
+        SparseFormat::Csc => Cow::Borrowed(a),
+        SparseFormat::Csr => Cow::Owned(a.to_csc()),
+    }
+}
+
+/// Checks that `perm` is a permutation of `0..n` and returns its inverse.
+fn inverse_permutation(perm: &DArray<usize, 1>, n: usize) -> Result<Vec<usize>, SparseSolveError> {
+    let invalid = SparseSolveError::InvalidPermutation { n };
+    if perm.len() != n {
+        return Err(invalid);
+    }
+    let mut inverse = vec![usize::MAX; n];
+    for (i, &p) in perm.iter().enumerate() {
+        if p >= n || inverse[p] != usize::MAX {
+            return Err(invalid);
+        }
+        inverse[p] = i;
+    }
+    Ok(inverse)
+}
+
+fn solve_in_place<T>(factor: &FaerSparseFactor<T>, rhs: MatMut<'_, T>)
+where
+    T: ComplexFloat + ComplexField,
+{
+    match &factor.kind {
+        FactorKind::Cholesky { symbolic, values } => {
+            let par = faer::get_global_parallelism();
+            let mut mem = MemBuffer::new(symbolic.solve_in_place_scratch::<T>(rhs.ncols(), par));
+            LltRef::<'_, usize, T>::new(symbolic, values).solve_in_place_with_conj(
+                Conj::No,
+                rhs,
+                par,
+                MemStack::new(&mut mem),
+            );
+        }
+        FactorKind::Lu(lu) => lu.solve_in_place_with_conj(Conj::No, rhs),
+        FactorKind::Qr(qr) => qr.solve_lstsq_in_place_with_conj(Conj::No, rhs),
+    }
+}
+
+impl<T> SparseSolve<T> for Faer
+where
+    T: ComplexFloat + ComplexField,
+{
+    type Symbolic = FaerSparseSymbolic;
+    type Factor = FaerSparseFactor<T>;
+
+    fn analyze(
+        &self,
+        a: &SparseMatrix<T>,
+        kind: SparseFactorization,
+        ordering: SparseOrdering,
+    ) -> Result<FaerSparseSymbolic, SparseSolveError> {
+        let (rows, cols) = a.shape();
+        match kind {
+            SparseFactorization::Cholesky | SparseFactorization::Lu if rows != cols => {
+                return Err(SparseSolveError::NotSquare { rows, cols });
+            }
+            SparseFactorization::Qr if rows < cols => {
+                return Err(SparseSolveError::Underdetermined { rows, cols });
+            }
+            _ => {}
+        }
+
+        let a = to_csc(a);
+        let pattern = into_faer_csc(&a).symbolic();
+        let kind = match (kind, ordering) {
+            (SparseFactorization::Cholesky, ordering) => {
+                let (perm, inverse);
+                let ordering = match &ordering {
+                    SparseOrdering::Auto | SparseOrdering::Amd => SymmetricOrdering::Amd,
+                    // `SymmetricOrdering::Identity` makes faer read both
+                    // triangles of `A`, whereas a permutation extracts the
+                    // lower one.
+                    SparseOrdering::Natural => {
+                        perm = (0..rows).collect::<Vec<_>>();
+                        SymmetricOrdering::Custom(PermRef::new_checked(&perm, &perm, rows))
+                    }
+                    SparseOrdering::Custom(custom) => {
+                        inverse = inverse_permutation(custom, rows)?;
+                        SymmetricOrdering::Custom(PermRef::new_checked(
+                            custom.as_ref(),
+                            &inverse,
+                            rows,
+                        ))
+                    }
+                    SparseOrdering::Colamd => return Err(SparseSolveError::UnsupportedOrdering),
+                };
+                let symbolic =
+                    factorize_symbolic_cholesky(pattern, Side::Lower, ordering, Default::default())
+                        .map_err(backend_error)?;
+                SymbolicKind::Cholesky(Arc::new(symbolic))
+            }
+            // faer always orders the columns with COLAMD for LU and QR.
+            (SparseFactorization::Lu, SparseOrdering::Auto | SparseOrdering::Colamd) => {
+                SymbolicKind::Lu(SymbolicLu::try_new(pattern).map_err(backend_error)?)
+            }
+            (SparseFactorization::Qr, SparseOrdering::Auto | SparseOrdering::Colamd) => {
+                SymbolicKind::Qr(SymbolicQr::try_new(pattern).map_err(backend_error)?)
+            }
+            _ => return Err(SparseSolveError::UnsupportedOrdering),
+        };
+
+        Ok(FaerSparseSymbolic {
+            shape: (rows, cols),
+            col_ptr: a.indptr().iter().copied().collect(),
+            row_idx: a.indices().iter().copied().collect(),
+            kind,
+        })
+    }
+
+    fn factorize(
+        &self,
+        symbolic: &FaerSparseSymbolic,
+        a: &SparseMatrix<T>,
+    ) -> Result<FaerSparseFactor<T>, SparseSolveError> {
+        let a = to_csc(a);
+        let same_pattern = a.shape() == symbolic.shape
+            && a.indptr().iter().eq(symbolic.col_ptr.iter())
+            && a.indices().iter().eq(symbolic.row_idx.iter());
+        if !same_pattern {
+            return Err(SparseSolveError::PatternMismatch);
+        }
+        let mat = into_faer_csc(&a);
+
+        let kind = match &symbolic.kind {
+            SymbolicKind::Cholesky(cholesky) => {
+                let par = faer::get_global_parallelism();
+                let mut values = vec![T::zero(); cholesky.len_val()];
+                let mut mem = MemBuffer::try_new(
+                    cholesky.factorize_numeric_llt_scratch::<T>(par, Default::default()),
+                )
+                .map_err(backend_error)?;
+                cholesky
+                    .factorize_numeric_llt(
+                        &mut values,
+                        mat,
+                        Side::Lower,
+                        Default::default(),
+                        par,
+                        MemStack::new(&mut mem),
+                        Default::default(),
+                    )
+                    .map_err(|err| match err {
+                        faer::linalg::cholesky::llt::factor::LltError::NonPositivePivot {
+                            index,
+                        } => SparseSolveError::NotPositiveDefinite { index },
+                    })?;
+                FactorKind::Cholesky {
+                    symbolic: cholesky.clone(),
+                    values,
+                }
+            }
+            SymbolicKind::Lu(lu) => FactorKind::Lu(Box::new(
+                Lu::try_new_with_symbolic(lu.clone(), mat).map_err(|err| match err {
+                    LuError::SymbolicSingular { index } => SparseSolveError::Singular { index },
+                    LuError::Generic(err) => backend_error(err),
+                })?,
+            )),
+            SymbolicKind::Qr(qr) => FactorKind::Qr(Box::new(
+                Qr::try_new_with_symbolic(qr.clone(), mat).map_err(backend_error)?,
+            )),
+        };
+
+        Ok(FaerSparseFactor {
+            shape: symbolic.shape,
+            kind,
+        })
+    }
+
+    fn solve_factored<L: Layout>(
+        &self,
+        factor: &FaerSparseFactor<T>,
+        b: &Slice<T, (Dyn, Dyn), L>,
+    ) -> Result<DArray<T, 2>, SparseSolveError> {
+        let (m, n) = factor.shape;
+        let (rows, k) = *b.shape();
+        if rows != m {
+            return Err(SparseSolveError::DimensionMismatch { expected: m, rows });
+        }
+
+        // QR overwrites the first `n` rows of the right-hand side with the
+        // least-squares solution.
+        let mut x = DArray::<T, 2>::from_fn([m, k], |i| b[[i[0], i[1]]]);
+        solve_in_place(factor, into_faer_mut(&mut x));
+        if m == n {
+            Ok(x)
+        } else {
+            Ok(DArray::<T, 2>::from_fn([n, k], |i| x[[i[0], i[1]]]))
+        }
+    }
+
+    fn solve_factored_write<L: Layout>(
+        &self,
+        factor: &FaerSparseFactor<T>,
+        b: &mut Slice<T, (Dyn, Dyn), L>,
+    ) -> Result<(), SparseSolveError> {
+        let (m, n) = factor.shape;
+        if m != n {
+            return Err(SparseSolveError::NotSquare { rows: m, cols: n });
+        }
+        let rows = b.shape().0;
+        if rows != m {
+            return Err(SparseSolveError::DimensionMismatch { expected: m, rows });
+        }
+        solve_in_place(factor, into_faer_mut(b));
+        Ok(())
+    }
+}
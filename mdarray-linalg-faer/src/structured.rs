@@ -0,0 +1,277 @@
+// Structured matrices on top of faer's triangular kernels:
+//     triangular product:  faer::linalg::matmul::triangular::matmul
+//     triangular solve:    faer::linalg::triangular_solve
+//     symmetric product:   diag(A) B + strict_tri(A) B + strict_tri(A)ᵀ B
+// Diagonal scaling and packed products are plain loops.
+
+use faer::{
+    Accum, MatRef,
+    linalg::{
+        matmul::triangular::{BlockStructure, matmul},
+        triangular_solve::{
+            solve_lower_triangular_in_place, solve_unit_lower_triangular_in_place,
+            solve_unit_upper_triangular_in_place, solve_upper_triangular_in_place,
+        },
+    },
+};
+use faer_traits::{ComplexField, Conjugate};
+use mdarray::{DArray, Dyn, Layout, Slice};
+use mdarray_linalg::solve::SolveError;
+use mdarray_linalg::structured::{
+    Diag, Diagonal, Hermitian, PackedHermitian, PackedSymmetric, Structured, Symmetric, Triangular,
+    Uplo, check_tri_nonsingular,
+};
+use num_complex::ComplexFloat;
+
+use crate::{Faer, into_faer, into_faer_mut};
+
+fn tri_structure(uplo: Uplo, diag: Diag) -> BlockStructure {
+    match (uplo, diag) {
+        (Uplo::Upper, Diag::NonUnit) => BlockStructure::TriangularUpper,
+        (Uplo::Upper, Diag::Unit) => BlockStructure::UnitTriangularUpper,
+        (Uplo::Lower, Diag::NonUnit) => BlockStructure::TriangularLower,
+        (Uplo::Lower, Diag::Unit) => BlockStructure::UnitTriangularLower,
+    }
+}
+
+fn strict_structure(uplo: Uplo) -> BlockStructure {
+    match uplo {
+        Uplo::Upper => BlockStructure::StrictTriangularUpper,
+        Uplo::Lower => BlockStructure::StrictTriangularLower,
+    }
+}
+
+/// C = A B for the triangular A
+fn trmm<T, La: Layout, Lb: Layout>(
+    a: &Triangular<'_, T, La>,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+) -> DArray<T, 2>
+where
+    T: ComplexFloat + ComplexField,
+{
+    let (m, n) = *b.shape();
+    assert!(a.size() == m, "Matrix columns must match rows of B");
+
+    let mut c = DArray::<T, 2>::from_elem([m, n], T::zero());
+    matmul(
+        into_faer_mut(&mut c),
+        BlockStructure::Rectangular,
+        Accum::Replace,
+        into_faer(a.matrix()),
+        tri_structure(a.uplo(), a.diag()),
+        into_faer(b),
+        BlockStructure::Rectangular,
+        T::one(),
+        faer::get_global_parallelism(),
+    );
+    c
+}
+
+/// C = A B for the self-adjoint A stored in the `uplo` triangle of `a`.
+/// `mirror` is the transpose (symmetric) or adjoint (Hermitian) view of `a`,
+/// whose opposite triangle supplies the rest of A.
+fn self_adjoint_matmul<T, M, Lb: Layout>(
+    a: MatRef<'_, T>,
+    mirror: MatRef<'_, M>,
+    uplo: Uplo,
+    diag: impl Fn(T) -> T,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+) -> DArray<T, 2>
+where
+    T: ComplexFloat + ComplexField,
+    M: Conjugate<Canonical = T>,
+{
+    let (m, n) = *b.shape();
+    assert!(a.nrows() == m, "Matrix columns must match rows of B");
+
+    let par = faer::get_global_parallelism();
+    let mut c = DArray::<T, 2>::from_fn([m, n], |i| diag(a[(i[0], i[0])]) * b[[i[0], i[1]]]);
+    matmul(
+        into_faer_mut(&mut c),
+        BlockStructure::Rectangular,
+        Accum::Add,
+        a,
+        strict_structure(uplo),
+        into_faer(b),
+        BlockStructure::Rectangular,
+        T::one(),
+        par,
+    );
+    matmul(
+        into_faer_mut(&mut c),
+        BlockStructure::Rectangular,
+        Accum::Add,
+        mirror,
+        strict_structure(uplo.flipped()),
+        into_faer(b),
+        BlockStructure::Rectangular,
+        T::one(),
+        par,
+    );
+    c
+}
+
+/// y = A x for the packed triangle `data` of the self-adjoint A
+fn packed_matvec<T: ComplexFloat, Lx: Layout>(
+    n: usize,
+    uplo: Uplo,
+    data: impl Iterator<Item = T>,
+    mirror: impl Fn(T) -> T,
+    diag: impl Fn(T) -> T,
+    x: &Slice<T, (Dyn,), Lx>,
+) -> DArray<T, 1> {
+    assert!(
+        n == x.shape().0,
+        "Matrix columns must match x vector length"
+    );
+
+    let mut y = DArray::<T, 1>::from_elem([n], T::zero());
+    let entries = (0..n).flat_map(|j| {
+        let rows = match uplo {
+            Uplo::Upper => 0..j + 1,
+            Uplo::Lower => j..n,
+        };
+        rows.map(move |i| (i, j))
+    });
+    for ((i, j), aij) in entries.zip(data) {
+        if i == j {
+            y[[i]] = y[[i]] + diag(aij) * x[[i]];
+        } else {
+            y[[i]] = y[[i]] + aij * x[[j]];
+            y[[j]] = y[[j]] + mirror(aij) * x[[i]];
+        }
+    }
+    y
+}
+
+fn real_part<T: ComplexFloat>(x: T) -> T {
+    T::from(x.re()).unwrap()
+}
+
+impl<T> Structured<T> for Faer
+where
+    T: ComplexFloat + ComplexField,
+{
+    fn diag_scale<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mut c = b.to_array();
+        self.diag_scale_write(d, &mut c);
+        c
+    }
+
+    fn diag_scale_write<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        let (m, n) = *b.shape();
+        assert!(d.size() == m, "Diagonal size must match rows of B");
+        for i in 0..m {
+            let di = d.diag()[[i]];
+            for j in 0..n {
+                b[[i, j]] = di * b[[i, j]];
+            }
+        }
+    }
+
+    fn tri_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        trmm(a, b)
+    }
+
+    fn tri_matmul_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        // faer's product does not allow the output to alias an operand.
+        let c = trmm(a, b);
+        into_faer_mut(b).copy_from(into_faer(&c));
+    }
+
+    fn tri_solve<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<DArray<T, 2>, SolveError> {
+        let mut x = b.to_array();
+        self.tri_solve_write(a, &mut x)?;
+        Ok(x)
+    }
+
+    fn tri_solve_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<(), SolveError> {
+        assert!(
+            a.size() == b.shape().0,
+            "Matrix columns must match rows of B"
+        );
+        check_tri_nonsingular(a)?;
+
+        let (tri, rhs) = (into_faer(a.matrix()), into_faer_mut(b));
+        let par = faer::get_global_parallelism();
+        match (a.uplo(), a.diag()) {
+            (Uplo::Upper, Diag::NonUnit) => solve_upper_triangular_in_place(tri, rhs, par),
+            (Uplo::Upper, Diag::Unit) => solve_unit_upper_triangular_in_place(tri, rhs, par),
+            (Uplo::Lower, Diag::NonUnit) => solve_lower_triangular_in_place(tri, rhs, par),
+            (Uplo::Lower, Diag::Unit) => solve_unit_lower_triangular_in_place(tri, rhs, par),
+        }
+        Ok(())
+    }
+
+    fn sym_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Symmetric<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mat = into_faer(a.matrix());
+        self_adjoint_matmul(mat, mat.transpose(), a.uplo(), |x| x, b)
+    }
+
+    fn herm_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Hermitian<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mat = into_faer(a.matrix());
+        self_adjoint_matmul(mat, mat.adjoint(), a.uplo(), real_part, b)
+    }
+
+    fn packed_sym_matvec<Lx: Layout>(
+        &self,
+        a: &PackedSymmetric<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        packed_matvec(
+            a.size(),
+            a.uplo(),
+            a.data().iter().copied(),
+            |x| x,
+            |x| x,
+            x,
+        )
+    }
+
+    fn packed_herm_matvec<Lx: Layout>(
+        &self,
+        a: &PackedHermitian<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        packed_matvec(
+            a.size(),
+            a.uplo(),
+            a.data().iter().copied(),
+            |x| x.conj(),
+            real_part,
+            x,
+        )
+    }
+}
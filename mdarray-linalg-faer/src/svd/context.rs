@@ -8,8 +8,12 @@
 //     - s (Σ) contains min(m, n) singular values (non-negative, sorted in descending order)
 
 use faer_traits::ComplexField;
-use mdarray::{Array, Dense, Dim, Layout, Shape, Slice};
-use mdarray_linalg::svd::{SVD, SVDDecomp, SVDError};
+use mdarray::{Array, Dense, Dim, Dyn, Layout, Shape, Slice};
+use mdarray_linalg::{
+    gauge::gauge_svd,
+    structured::Uplo,
+    svd::{SVD, SVDDecomp, SVDError, jacobi_svd_bidiagonal, select_svd},
+};
 use num_complex::ComplexFloat;
 
 use super::simple::svd_faer;
@@ -18,6 +22,7 @@ use crate::Faer;
 impl<T, D> SVD<T, D> for Faer
 where
     T: ComplexFloat + ComplexField + Default,
+    <T as ComplexFloat>::Real: ComplexFloat<Real = <T as ComplexFloat>::Real>,
     D: Dim,
 {
     type SingularValue = T;
@@ -58,11 +63,17 @@ where
             Err(_) => Err(SVDError::BackendDidNotConverge {
                 superdiagonals: (0),
             }),
-            Ok(_) => Ok(SVDDecomp {
-                s: s_mda,
-                u: u_mda,
-                vt: vt_mda,
-            }),
+            Ok(_) => {
+                let mut decomp = SVDDecomp {
+                    s: s_mda,
+                    u: u_mda,
+                    vt: vt_mda,
+                };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
+            }
         }
     }
 
@@ -88,14 +99,43 @@ where
             Err(_) => Err(SVDError::BackendDidNotConverge {
                 superdiagonals: (0),
             }),
-            Ok(_) => Ok(SVDDecomp {
-                s: s_mda,
-                u: u_mda,
-                vt: vt_mda,
-            }),
+            Ok(_) => {
+                let mut decomp = SVDDecomp {
+                    s: s_mda,
+                    u: u_mda,
+                    vt: vt_mda,
+                };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
+            }
         }
     }
 
+    /// Compute the k largest singular triplets with new allocated matrices
+    fn svd_partial<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        k: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        self.svd_range(a, 0, k)
+    }
+
+    /// Compute the singular triplets lo..hi with new allocated matrices
+    fn svd_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        lo: usize,
+        hi: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        let mut decomp = select_svd(self.svd_thin(a)?, lo, hi)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
     /// Compute only singular values with new allocated matrix
     fn svd_s<L: Layout>(
         &self,
@@ -130,7 +170,17 @@ where
         vt: &mut Slice<T, (D, D), Lvt>,
     ) -> Result<(), SVDError> {
         let compute_svd_full_vectors = u.shape().0 == u.shape().1;
-        svd_faer::<T, D, L, Ls, Lu, Lvt>(a, s, Some(u), Some(vt), compute_svd_full_vectors)
+        svd_faer::<T, D, L, Ls, Lu, Lvt>(
+            a,
+            &mut *s,
+            Some(&mut *u),
+            Some(&mut *vt),
+            compute_svd_full_vectors,
+        )?;
+        if let Some(gauge) = &self.gauge {
+            gauge_svd(gauge, s, u, vt);
+        }
+        Ok(())
     }
 
     /// Compute only singular values, overwriting existing matrix
@@ -141,4 +191,28 @@ where
     ) -> Result<(), SVDError> {
         svd_faer::<T, D, L, Ls, Dense, Dense>(a, s, None, None, false)
     }
+
+    /// Compute the SVD of a bidiagonal matrix
+    fn svd_bidiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<<T as ComplexFloat>::Real, (D,), Ld>,
+        e: &Slice<<T as ComplexFloat>::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<SVDDecomp<<T as ComplexFloat>::Real, <T as ComplexFloat>::Real, D>, SVDError> {
+        let mut decomp = jacobi_svd_bidiagonal(d, e, uplo)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    /// Compute only the singular values of a bidiagonal matrix
+    fn svd_bidiagonal_s<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<<T as ComplexFloat>::Real, (D,), Ld>,
+        e: &Slice<<T as ComplexFloat>::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<Array<<T as ComplexFloat>::Real, (D,)>, SVDError> {
+        Ok(jacobi_svd_bidiagonal(d, e, uplo)?.s)
+    }
 }
@@ -0,0 +1,42 @@
+use mdarray_linalg::testing::geig::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn geig() {
+    test_geig(&Faer::default());
+}
+
+#[test]
+fn geig_cplx() {
+    test_geig_cplx(&Faer::default());
+}
+
+#[test]
+fn geig_values() {
+    test_geig_values(&Faer::default());
+}
+
+#[test]
+fn qz() {
+    test_qz(&Faer::default());
+}
+
+#[test]
+fn qz_cplx() {
+    test_qz_cplx(&Faer::default());
+}
+
+#[test]
+fn qz_reorder() {
+    test_qz_reorder(&Faer::default());
+}
+
+#[test]
+fn qz_reorder_cplx() {
+    test_qz_reorder_cplx(&Faer::default());
+}
+
+#[test]
+fn geig_invalid() {
+    test_geig_invalid(&Faer::default());
+}
@@ -0,0 +1,62 @@
+use mdarray_linalg::testing::krylov::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn cg() {
+    test_cg(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn cg_preconditioned() {
+    test_cg_preconditioned(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn cg_complex() {
+    test_cg_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn cg_breakdown() {
+    test_cg_breakdown(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn minres_indefinite() {
+    test_minres_indefinite(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn minres_preconditioned() {
+    test_minres_preconditioned(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn gmres() {
+    test_gmres(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn gmres_complex() {
+    test_gmres_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn bicgstab() {
+    test_bicgstab(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn bicgstab_complex() {
+    test_bicgstab_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn krylov_not_converged() {
+    test_krylov_not_converged(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn krylov_trivial() {
+    test_krylov_trivial(&Faer::default(), &Faer::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::lobpcg::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn lobpcg_smallest() {
+    test_lobpcg_smallest(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn lobpcg_largest() {
+    test_lobpcg_largest(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn lobpcg_generalized() {
+    test_lobpcg_generalized(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn lobpcg_clustered() {
+    test_lobpcg_clustered(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn lobpcg_complex() {
+    test_lobpcg_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn lobpcg_errors() {
+    test_lobpcg_errors(&Faer::default(), &Faer::default());
+}
@@ -37,6 +37,11 @@ fn add_outer_subview() {
     test_add_outer_subview(Faer::default())
 }
 
+#[test]
+fn add_to_scaled_vecvec() {
+    test_add_to_scaled_vecvec(Faer::default())
+}
+
 #[test]
 fn dot_real() {
     test_dot_real(Faer::default())
@@ -0,0 +1,23 @@
+use mdarray_linalg::testing::operator::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn dense_operator() {
+    test_dense_operator(&Faer::default());
+}
+
+#[test]
+fn operator_combinations() {
+    test_operator_combinations(&Faer::default());
+}
+
+#[test]
+fn fn_operator_with_cg() {
+    test_fn_operator_with_cg(&Faer::default());
+}
+
+#[test]
+#[should_panic]
+fn operator_shape_mismatch() {
+    test_operator_shape_mismatch(&Faer::default());
+}
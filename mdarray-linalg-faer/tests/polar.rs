@@ -0,0 +1,52 @@
+use mdarray_linalg::testing::polar::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn polar_svd() {
+    test_polar_svd(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_svd_complex() {
+    test_polar_svd_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_svd_rank_deficient() {
+    test_polar_svd_rank_deficient(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_newton() {
+    test_polar_newton(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_newton_complex() {
+    test_polar_newton_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_newton_not_square() {
+    test_polar_newton_not_square(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_qdwh() {
+    test_polar_qdwh(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_qdwh_complex() {
+    test_polar_qdwh_complex(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_qdwh_rank_deficient() {
+    test_polar_qdwh_rank_deficient(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn polar_methods_agree() {
+    test_polar_methods_agree(&Faer::default());
+}
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::precond::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn block_jacobi() {
+    test_block_jacobi(&Faer::default());
+}
+
+#[test]
+fn block_jacobi_cg() {
+    test_block_jacobi_cg(&Faer::default(), &Faer::default(), &Faer::default());
+}
+
+#[test]
+fn ssor() {
+    test_ssor(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn ilu0() {
+    test_ilu0(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn ic0() {
+    test_ic0(&Faer::default(), &Faer::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::randomized::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn randomized_range_finder() {
+    test_randomized_range_finder(&Faer::default(), &Faer::default());
+}
+
+#[test]
+fn randomized_svd() {
+    test_randomized_svd(&Faer::default(), &Faer::default(), &Faer::default());
+}
+
+#[test]
+fn randomized_svd_seeded() {
+    test_randomized_svd_seeded(&Faer::default(), &Faer::default(), &Faer::default());
+}
+
+#[test]
+fn randomized_svd_complex() {
+    test_randomized_svd_complex(&Faer::default(), &Faer::default(), &Faer::default());
+}
+
+#[test]
+fn randomized_svd_invalid_rank() {
+    test_randomized_svd_invalid_rank(&Faer::default(), &Faer::default(), &Faer::default());
+}
+
+#[test]
+fn randomized_svd_const() {
+    test_randomized_svd_const(&Faer::default(), &Faer::default(), &Faer::default());
+}
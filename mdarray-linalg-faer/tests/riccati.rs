@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::riccati::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn care() {
+    test_care(&Faer::default());
+}
+
+#[test]
+fn care_complex() {
+    test_care_complex(&Faer::default());
+}
+
+#[test]
+fn dare() {
+    test_dare(&Faer::default());
+}
+
+#[test]
+fn dare_complex() {
+    test_dare_complex(&Faer::default());
+}
+
+#[test]
+fn riccati_errors() {
+    test_riccati_errors(&Faer::default());
+}
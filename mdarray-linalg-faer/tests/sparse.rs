@@ -0,0 +1,28 @@
+use mdarray_linalg::testing::sparse::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn spmv() {
+    test_spmv(&Faer::default());
+}
+
+#[test]
+fn spmv_complex() {
+    test_spmv_complex(&Faer::default());
+}
+
+#[test]
+fn spmm() {
+    test_spmm(&Faer::default());
+}
+
+#[test]
+fn sparse_operator_cg() {
+    test_sparse_operator_cg(&Faer::default());
+}
+
+#[test]
+#[should_panic]
+fn spmv_dimension_mismatch() {
+    test_spmv_dimension_mismatch(&Faer::default());
+}
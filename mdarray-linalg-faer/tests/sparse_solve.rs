@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::sparse_solve::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn sparse_cholesky() {
+    test_sparse_cholesky(&Faer::default());
+}
+
+#[test]
+fn sparse_cholesky_complex() {
+    test_sparse_cholesky_complex(&Faer::default());
+}
+
+#[test]
+fn sparse_lu() {
+    test_sparse_lu(&Faer::default());
+}
+
+#[test]
+fn sparse_qr() {
+    test_sparse_qr(&Faer::default());
+}
+
+#[test]
+fn sparse_symbolic_reuse() {
+    test_sparse_symbolic_reuse(&Faer::default());
+}
+
+#[test]
+fn sparse_solve_errors() {
+    test_sparse_solve_errors(&Faer::default());
+}
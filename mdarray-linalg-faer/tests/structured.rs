@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::structured::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn diag_scale() {
+    test_diag_scale(&Faer::default());
+}
+
+#[test]
+fn tri_matmul() {
+    test_tri_matmul(&Faer::default());
+}
+
+#[test]
+fn tri_solve() {
+    test_tri_solve(&Faer::default());
+}
+
+#[test]
+fn tri_solve_singular() {
+    test_tri_solve_singular(&Faer::default());
+}
+
+#[test]
+fn sym_matmul() {
+    test_sym_matmul(&Faer::default());
+}
+
+#[test]
+fn packed_matvec() {
+    test_packed_matvec(&Faer::default());
+}
@@ -1,4 +1,4 @@
-use mdarray_linalg::testing::svd::*;
+use mdarray_linalg::{gauge::Gauge, testing::svd::*};
 use mdarray_linalg_faer::Faer;
 
 #[test]
@@ -35,3 +35,64 @@ fn test_backend_svd_cplx_square_matrix() {
 fn test_backend_svd_cplx_random_matrix() {
     test_svd_cplx_random_matrix(&Faer::default());
 }
+
+#[test]
+fn test_backend_svd_partial() {
+    test_svd_partial(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_range() {
+    test_svd_range(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_partial_const() {
+    test_svd_partial_const(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_range_invalid() {
+    test_svd_range_invalid(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_max_rank() {
+    test_svd_truncate_max_rank(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_cutoffs() {
+    test_svd_truncate_cutoffs(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_renormalize_absorb() {
+    test_svd_truncate_renormalize_absorb(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_gauge() {
+    test_svd_gauge(&Faer::default().config_gauge(Gauge::default()));
+}
+
+#[test]
+fn test_backend_svd_suites_gauge() {
+    let bd = Faer::default().config_gauge(Gauge::default());
+    test_svd_square_matrix(&bd);
+    test_svd_rectangular_m_gt_n(&bd);
+    test_svd_rectangular_n_gt_m(&bd);
+    test_svd_random_matrix(&bd);
+    test_svd_cplx_square_matrix(&bd);
+    test_svd_cplx_random_matrix(&bd);
+}
+
+#[test]
+fn test_backend_svd_bidiagonal() {
+    test_svd_bidiagonal(&Faer::default());
+}
+
+#[test]
+fn test_backend_svd_bidiagonal_const() {
+    test_svd_bidiagonal_const(&Faer::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::sylvester::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn sylvester() {
+    test_sylvester(&Faer::default());
+}
+
+#[test]
+fn sylvester_complex() {
+    test_sylvester_complex(&Faer::default());
+}
+
+#[test]
+fn lyapunov() {
+    test_lyapunov(&Faer::default());
+}
+
+#[test]
+fn lyapunov_complex() {
+    test_lyapunov_complex(&Faer::default());
+}
+
+#[test]
+fn sylvester_scale() {
+    test_sylvester_scale(&Faer::default());
+}
+
+#[test]
+fn sylvester_errors() {
+    test_sylvester_errors(&Faer::default());
+}
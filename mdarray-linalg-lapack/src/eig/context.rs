@@ -14,17 +14,23 @@
 //!     - λ are real eigenvalues
 //!     - v are orthonormal eigenvectors
 
-use mdarray::{Array, Dense, Dim, Layout, Shape, Slice};
+use mdarray::{Array, Dense, Dim, Dyn, Layout, Shape, Slice};
 use mdarray_linalg::{
-    eig::{Eig, EigDecomp, EigError, EighDecomp, SchurDecomp, SchurError},
+    eig::{
+        Eig, EigBalance, EigCondition, EigDecomp, EigError, EigSide, EighAlgorithm, EighDecomp,
+        EighRange, SchurCondition, SchurDecomp, SchurError, SchurReorder, SchurSelect,
+        jacobi_eigh, normalize_eigenvector, schur_eigenvalue_mask, schur_eigenvalues,
+        schur_select_mask,
+    },
+    gauge::{gauge_eig, gauge_eig_condition, gauge_eigh},
     utils::transpose_in_place,
 };
 use num_complex::{Complex, ComplexFloat};
-use num_traits::identities::Zero;
+use num_traits::{NumCast, identities::Zero};
 
 use super::{
-    scalar::{LapackScalar, NeedsRwork},
-    simple::{gees, gees_complex, geig, geigh},
+    scalar::{LapackRealScalar, LapackScalar, NeedsRwork},
+    simple::{ConditionOutput, gees, gees_complex, geig, geigh, geighr, stevr, trevc, trsen},
 };
 use crate::Lapack;
 
@@ -33,7 +39,7 @@ where
     T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
     Complex<T::Real>: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = Complex<T::Real>>,
     i8: Into<T::Real>,
-    T::Real: Into<T>,
+    T::Real: Into<T> + ComplexFloat<Real = T::Real> + LapackRealScalar,
 {
     type SpectralScalar = Complex<T::Real>;
     type RealScalar = T::Real;
@@ -66,6 +72,8 @@ where
             &mut eigenvalues_imag,
             None, // no left eigenvectors
             Some(&mut right_eigenvectors_tmp),
+            self.eig_config.balance,
+            None,
         ) {
             Ok(_) => {
                 for i in 0..n {
@@ -96,11 +104,15 @@ where
                     }
                 }
 
-                Ok(EigDecomp {
+                let mut decomp = EigDecomp {
                     eigenvalues,
                     left_eigenvectors: None,
                     right_eigenvectors: Some(right_eigenvectors),
-                })
+                };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
             }
             Err(e) => Err(e),
         }
@@ -108,6 +120,15 @@ where
 
     /// Compute eigenvalues and both left/right eigenvectors with new allocated matrices
     fn eig_full<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, EigError> {
+        let mut decomp = geevx_full(a, self.eig_config.balance, None)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    /// Compute only eigenvalues with new allocated vectors
+    fn eig_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::SpectralScalar, (D0,)>, EigError> {
         let ash = *a.shape();
         let (m, n) = (ash.dim(0), ash.dim(1));
 
@@ -122,71 +143,54 @@ where
         let mut eigenvalues_imag = Array::from_elem(ash1, T::default());
         let mut eigenvalues = Array::from_elem(ash1, Complex::new(x.re(), x.re()));
 
-        let mut left_eigenvectors_tmp = Array::from_elem(ash, T::default());
-        let mut right_eigenvectors_tmp = Array::from_elem(ash, T::default());
-        let mut left_eigenvectors = Array::from_elem(ash, Complex::new(x.re(), x.re()));
-        let mut right_eigenvectors = Array::from_elem(ash, Complex::new(x.re(), x.re()));
-
         match geig::<L, Dense, Dense, Dense, Dense, T, D0, D1>(
             a,
             &mut eigenvalues_real,
             &mut eigenvalues_imag,
-            Some(&mut left_eigenvectors_tmp),
-            Some(&mut right_eigenvectors_tmp),
+            None,
+            None,
+            self.eig_config.balance,
+            None,
         ) {
             Ok(_) => {
                 for i in 0..n {
-                    eigenvalues[i] = if !eigenvalues_real[i].im().is_zero() {
-                        Complex::new(eigenvalues_real[i].re(), eigenvalues_real[i].im())
-                    } else {
-                        Complex::new(eigenvalues_real[i].re(), eigenvalues_imag[i].re())
-                    };
+                    eigenvalues[i] =
+                        Complex::new(eigenvalues_real[i].re(), eigenvalues_imag[i].re());
                 }
-
-                let mut j = 0_usize;
-                while j < n {
-                    let imag = eigenvalues_imag[[j]];
-                    if imag == T::default() {
-                        for i in 0..n {
-                            let re_right = right_eigenvectors_tmp[[i, j]];
-                            let re_left = left_eigenvectors_tmp[[i, j]];
-                            right_eigenvectors[[i, j]] =
-                                Complex::new(re_right.re(), re_right.im());
-                            left_eigenvectors[[i, j]] = Complex::new(re_left.re(), re_left.im());
-                        }
-                        j += 1;
-                    } else {
-                        for i in 0..n {
-                            let re_right = right_eigenvectors_tmp[[i, j]];
-                            let im_right = right_eigenvectors_tmp[[i, j + 1]];
-                            let re_left = left_eigenvectors_tmp[[i, j]];
-                            let im_left = left_eigenvectors_tmp[[i, j + 1]];
-
-                            right_eigenvectors[[i, j]] =
-                                Complex::new(re_right.re(), im_right.re());
-                            right_eigenvectors[[i, j + 1]] =
-                                ComplexFloat::conj(Complex::new(re_right.re(), im_right.re()));
-
-                            left_eigenvectors[[i, j]] = Complex::new(re_left.re(), im_left.re());
-                            left_eigenvectors[[i, j + 1]] =
-                                ComplexFloat::conj(Complex::new(re_left.re(), im_left.re()));
-                        }
-                        j += 2;
-                    }
+                if let Some(gauge) = &self.gauge {
+                    gauge_eig::<_, D0, D1>(gauge, &mut eigenvalues, None, None);
                 }
 
-                Ok(EigDecomp {
-                    eigenvalues,
-                    left_eigenvectors: Some(left_eigenvectors),
-                    right_eigenvectors: Some(right_eigenvectors),
-                })
+                Ok(eigenvalues)
             }
             Err(e) => Err(e),
         }
     }
 
-    /// Compute only eigenvalues with new allocated vectors
-    fn eig_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::SpectralScalar, (D0,)>, EigError> {
+    /// Compute eigenvalues, left/right eigenvectors and their condition numbers using `geevx`
+    fn eig_condition<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(EigDecomp<Self::SpectralScalar, D0, D1>, EigCondition<Self::RealScalar, D0>), EigError> {
+        let n = a.shape().dim(0);
+        let mut rconde = vec![T::Real::zero(); n];
+        let mut rcondv = vec![T::Real::zero(); n];
+        let mut decomp = geevx_full(a, self.eig_config.balance, Some((&mut rconde, &mut rcondv)))?;
+
+        let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+        let mut condition = EigCondition {
+            eigenvalues: Array::from_fn(ash1, |i| rconde[i[0]]),
+            eigenvectors: Array::from_fn(ash1, |i| rcondv[i[0]]),
+        };
+        if let Some(gauge) = &self.gauge {
+            gauge_eig_condition(gauge, &mut decomp, &mut condition);
+        }
+        Ok((decomp, condition))
+    }
+
+    /// Compute eigenvalues and eigenvectors of a self-adjoint matrix with the
+    /// configured algorithm
+    fn eigh<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EighDecomp<T, Self::RealScalar, D0, D1>, EigError> {
         let ash = *a.shape();
         let (m, n) = (ash.dim(0), ash.dim(1));
 
@@ -194,34 +198,67 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
-        let x = T::default();
         let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+        let mut eigenvalues = Array::from_elem(ash1, T::Real::zero());
+        let mut eigenvectors = Array::from_elem(ash, T::default());
+        self.eigh_write(a, &mut eigenvalues, &mut eigenvectors)?;
 
-        let mut eigenvalues_real = Array::from_elem(ash1, T::default());
-        let mut eigenvalues_imag = Array::from_elem(ash1, T::default());
-        let mut eigenvalues = Array::from_elem(ash1, Complex::new(x.re(), x.re()));
+        Ok(EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
 
-        match geig::<L, Dense, Dense, Dense, Dense, T, D0, D1>(
-            a,
-            &mut eigenvalues_real,
-            &mut eigenvalues_imag,
-            None,
-            None,
-        ) {
-            Ok(_) => {
+    /// Compute eigenvalues and eigenvectors of a self-adjoint matrix with the
+    /// configured algorithm, overwriting existing buffers
+    fn eigh_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+        eigenvectors: &mut Slice<T, (D0, D1), Dense>,
+    ) -> Result<(), EigError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+
+        assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+        assert_eq!(
+            (eigenvectors.shape().dim(0), eigenvectors.shape().dim(1)),
+            (n, n),
+            "Eigenvectors must be n × n"
+        );
+
+        // LAPACK has no Jacobi eigensolver for self-adjoint matrices.
+        if self.eigh_config.algorithm == EighAlgorithm::Jacobi {
+            let decomp = jacobi_eigh(a)?;
+            for j in 0..n {
+                eigenvalues[j] = decomp.eigenvalues[j];
                 for i in 0..n {
-                    eigenvalues[i] =
-                        Complex::new(eigenvalues_real[i].re(), eigenvalues_imag[i].re());
+                    eigenvectors[[i, j]] = decomp.eigenvectors[[i, j]];
+                }
+            }
+        } else {
+            geigh(a, eigenvalues, self.eigh_config.algorithm, true)?;
+            for j in 0..n {
+                for i in 0..n {
+                    eigenvectors[[i, j]] = a[[j, i]];
                 }
-
-                Ok(eigenvalues)
             }
-            Err(e) => Err(e),
         }
+
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh(gauge, eigenvalues, Some(eigenvectors));
+        }
+
+        Ok(())
     }
 
-    /// Compute eigenvalues and eigenvectors of a self-adjoint matrix
-    fn eigh<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EighDecomp<T, Self::RealScalar, D0, D1>, EigError> {
+    /// Compute only the eigenvalues of a self-adjoint matrix with the
+    /// configured algorithm
+    fn eigh_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::RealScalar, (D0,)>, EigError> {
         let ash = *a.shape();
         let (m, n) = (ash.dim(0), ash.dim(1));
 
@@ -229,25 +266,135 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
-        let ash1 = <(D0,) as Shape>::from_dims(&[n]);
-        let mut eigenvalues = Array::from_elem(ash1, T::Real::zero());
-        let mut eigenvectors = Array::from_elem(ash, T::default());
+        let mut eigenvalues = Array::from_elem(<(D0,) as Shape>::from_dims(&[n]), T::Real::zero());
+        self.eigh_values_write(a, &mut eigenvalues)?;
 
-        match geigh(a, &mut eigenvalues) {
-            Ok(_) => {
-                for j in 0..n {
-                    for i in 0..n {
-                        eigenvectors[[i, j]] = a[[j, i]];
-                    }
-                }
+        Ok(eigenvalues)
+    }
 
-                Ok(EighDecomp {
-                    eigenvalues,
-                    eigenvectors,
-                })
+    /// Compute only the eigenvalues of a self-adjoint matrix with the
+    /// configured algorithm, overwriting an existing buffer
+    fn eigh_values_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+    ) -> Result<(), EigError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+
+        assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+
+        // The Jacobi fallback always accumulates the eigenvectors.
+        if self.eigh_config.algorithm == EighAlgorithm::Jacobi {
+            let decomp = jacobi_eigh(a)?;
+            for i in 0..n {
+                eigenvalues[i] = decomp.eigenvalues[i];
             }
-            Err(e) => Err(e),
+        } else {
+            geigh(a, eigenvalues, self.eigh_config.algorithm, false)?;
         }
+
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, D0, D1, Dense>(gauge, eigenvalues, None);
+        }
+
+        Ok(())
+    }
+
+    /// Compute a subset of eigenpairs of a self-adjoint matrix using the MRRR driver
+    fn eigh_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<T, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+
+        let max_found = match &range {
+            EighRange::Index(r) => r.end.saturating_sub(r.start).min(n),
+            EighRange::Value(..) => n,
+        };
+
+        let mut w = vec![T::Real::zero(); n];
+        let mut z = vec![T::default(); n * max_found];
+
+        let k = geighr(a, &range, &mut w, &mut z)?;
+
+        let eigenvalues = Array::from_fn((k,), |idx| w[idx[0]]);
+        // LAPACK returns the eigenvectors column-major with leading dimension n.
+        let eigenvectors = Array::from_fn((n, k), |idx| z[idx[0] + idx[1] * n]);
+
+        let mut decomp = EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    /// Compute selected eigenpairs of a symmetric tridiagonal matrix with stevr
+    fn eigh_tridiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<Self::RealScalar, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let n = d.len();
+        let max_found = match &range {
+            EighRange::Index(r) => r.end.saturating_sub(r.start).min(n),
+            EighRange::Value(..) => n,
+        };
+
+        let d: Vec<T::Real> = d.iter().copied().collect();
+        let e: Vec<T::Real> = e.iter().copied().collect();
+        let mut w = vec![T::Real::zero(); n];
+        let mut z = vec![T::Real::zero(); n * max_found];
+
+        let k = stevr(&d, &e, &range, &mut w, Some(&mut z))?;
+
+        let eigenvalues = Array::from_fn((k,), |idx| w[idx[0]]);
+        // LAPACK returns the eigenvectors column-major with leading dimension n.
+        let eigenvectors = Array::from_fn((n, k), |idx| z[idx[0] + idx[1] * n]);
+
+        let mut decomp = EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    /// Compute selected eigenvalues of a symmetric tridiagonal matrix with stevr
+    fn eigh_tridiagonal_values<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<Array<Self::RealScalar, (Dyn,)>, EigError> {
+        let n = d.len();
+        let d: Vec<T::Real> = d.iter().copied().collect();
+        let e: Vec<T::Real> = e.iter().copied().collect();
+        let mut w = vec![T::Real::zero(); n];
+
+        let k = stevr(&d, &e, &range, &mut w, None)?;
+
+        let mut eigenvalues = Array::from_fn((k,), |idx| w[idx[0]]);
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, Dyn, Dyn, Dense>(gauge, &mut eigenvalues, None);
+        }
+        Ok(eigenvalues)
     }
 
     /// Compute Schur decomposition with new allocated matrices
@@ -383,4 +530,264 @@ where
         }
         Ok(())
     }
+
+    /// Reorder a Schur decomposition using `trsen`
+    fn schur_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        let (m, n) = (t.shape().dim(0), t.shape().dim(1));
+        if m != n {
+            return Err(SchurError::NotSquareMatrix);
+        }
+
+        let mask = schur_select_mask(t, &select)?;
+        let select: Vec<i32> = mask.iter().map(|&x| x as i32).collect();
+        let (dim, s, sep) = trsen(t, z, &select, condition)?;
+        Ok(reorder_result(dim, s, sep, condition))
+    }
+
+    /// Reorder a complex Schur decomposition using `trsen`
+    fn schur_complex_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &mut Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        let (m, n) = (t.shape().dim(0), t.shape().dim(1));
+        if m != n {
+            return Err(SchurError::NotSquareMatrix);
+        }
+
+        let mask = schur_select_mask(t, &select)?;
+        let select: Vec<i32> = mask.iter().map(|&x| x as i32).collect();
+        let (dim, s, sep) = trsen(t, z, &select, condition)?;
+        let cast = |x| <T::Real as NumCast>::from(x).unwrap();
+        let (s, sep) = (cast(s), cast(sep));
+        Ok(reorder_result(dim, s, sep, condition))
+    }
+
+    /// Compute selected eigenvectors from a Schur decomposition using `trevc`
+    fn schur_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<T, (D0, D1), Lt>,
+        z: &Slice<T, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        trevc_eigenvectors(t, z, side, select.as_ref())
+    }
+
+    /// Compute selected eigenvectors from a complex Schur decomposition using `trevc`
+    fn schur_complex_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        trevc_eigenvectors(t, z, side, select.as_ref())
+    }
+}
+
+/// Computes eigenvalues and both left/right eigenvectors with `geevx`,
+/// unpacking complex conjugate pairs of a real matrix, and the reciprocal
+/// condition numbers if `condition` is given.
+fn geevx_full<T, D0: Dim, D1: Dim, L: Layout>(
+    a: &mut Slice<T, (D0, D1), L>,
+    balance: EigBalance,
+    condition: Option<ConditionOutput<'_, T::Real>>,
+) -> Result<EigDecomp<Complex<T::Real>, D0, D1>, EigError>
+where
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    T::Real: Into<T>,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    if m != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+
+    let x = T::default();
+    let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+
+    let mut eigenvalues_real = Array::from_elem(ash1, T::default());
+    let mut eigenvalues_imag = Array::from_elem(ash1, T::default());
+    let mut eigenvalues = Array::from_elem(ash1, Complex::new(x.re(), x.re()));
+
+    let mut left_eigenvectors_tmp = Array::from_elem(ash, T::default());
+    let mut right_eigenvectors_tmp = Array::from_elem(ash, T::default());
+    let mut left_eigenvectors = Array::from_elem(ash, Complex::new(x.re(), x.re()));
+    let mut right_eigenvectors = Array::from_elem(ash, Complex::new(x.re(), x.re()));
+
+    match geig::<L, Dense, Dense, Dense, Dense, T, D0, D1>(
+        a,
+        &mut eigenvalues_real,
+        &mut eigenvalues_imag,
+        Some(&mut left_eigenvectors_tmp),
+        Some(&mut right_eigenvectors_tmp),
+        balance,
+        condition,
+    ) {
+        Ok(_) => {
+            for i in 0..n {
+                eigenvalues[i] = if !eigenvalues_real[i].im().is_zero() {
+                    Complex::new(eigenvalues_real[i].re(), eigenvalues_real[i].im())
+                } else {
+                    Complex::new(eigenvalues_real[i].re(), eigenvalues_imag[i].re())
+                };
+            }
+
+            let mut j = 0_usize;
+            while j < n {
+                let imag = eigenvalues_imag[[j]];
+                if imag == T::default() {
+                    for i in 0..n {
+                        let re_right = right_eigenvectors_tmp[[i, j]];
+                        let re_left = left_eigenvectors_tmp[[i, j]];
+                        right_eigenvectors[[i, j]] =
+                            Complex::new(re_right.re(), re_right.im());
+                        left_eigenvectors[[i, j]] = Complex::new(re_left.re(), re_left.im());
+                    }
+                    j += 1;
+                } else {
+                    for i in 0..n {
+                        let re_right = right_eigenvectors_tmp[[i, j]];
+                        let im_right = right_eigenvectors_tmp[[i, j + 1]];
+                        let re_left = left_eigenvectors_tmp[[i, j]];
+                        let im_left = left_eigenvectors_tmp[[i, j + 1]];
+
+                        right_eigenvectors[[i, j]] =
+                            Complex::new(re_right.re(), im_right.re());
+                        right_eigenvectors[[i, j + 1]] =
+                            ComplexFloat::conj(Complex::new(re_right.re(), im_right.re()));
+
+                        left_eigenvectors[[i, j]] = Complex::new(re_left.re(), im_left.re());
+                        left_eigenvectors[[i, j + 1]] =
+                            ComplexFloat::conj(Complex::new(re_left.re(), im_left.re()));
+                    }
+                    j += 2;
+                }
+            }
+
+            Ok(EigDecomp {
+                eigenvalues,
+                left_eigenvectors: Some(left_eigenvectors),
+                right_eigenvectors: Some(right_eigenvectors),
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Computes the eigenvectors of the selected eigenvalues of `t` with `trevc`
+/// and back-transforms them with `z`.
+fn trevc_eigenvectors<T, S, D0, D1, Lt, Lz>(
+    t: &Slice<T, (D0, D1), Lt>,
+    z: &Slice<T, (D0, D1), Lz>,
+    side: EigSide,
+    select: Option<&SchurSelect<'_, S>>,
+) -> Result<EigDecomp<S, D0, D1>, SchurError>
+where
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Lt: Layout,
+    Lz: Layout,
+{
+    let n = t.shape().dim(0);
+    if t.shape().dim(1) != n || z.shape().dim(0) != n || z.shape().dim(1) != n {
+        return Err(SchurError::NotSquareMatrix);
+    }
+    let mask = schur_eigenvalue_mask(t, select)?;
+    let eigenvalues: Vec<S> = schur_eigenvalues(t);
+
+    let mut lapack_select: Vec<i32> = mask.iter().map(|&x| x as i32).collect();
+    let job = match side {
+        EigSide::Right => b'R',
+        EigSide::Left => b'L',
+        EigSide::Both => b'B',
+    };
+    let (vl, vr) = trevc(t, job, &mut lapack_select)?;
+
+    // The output column of each selected eigenvalue, and for complex pairs
+    // of a real T whether the eigenvalue is the conjugate one.
+    let real = (-T::one()).sqrt().is_nan();
+    let mut columns = Vec::new();
+    let (mut k, mut col) = (0, 0);
+    while k < n {
+        if real && k + 1 < n && t[[k + 1, k]] != T::zero() {
+            if mask[k] || mask[k + 1] {
+                for i in 0..2 {
+                    if mask[k + i] {
+                        columns.push((k + i, col, Some(i == 1)));
+                    }
+                }
+                col += 2;
+            }
+            k += 2;
+        } else {
+            if mask[k] {
+                columns.push((k, col, None));
+                col += 1;
+            }
+            k += 1;
+        }
+    }
+
+    let i = (-S::one()).sqrt();
+    let i = if i.im() < S::zero().re() { -i } else { i };
+    let to_s = |x: T| S::from(x.re()).unwrap() + i * S::from(x.im()).unwrap();
+    let vectors = |v: &[T]| {
+        let mut out = Vec::with_capacity(columns.len());
+        for &(_, col, conjugate) in &columns {
+            let x: Vec<S> = (0..n)
+                .map(|r| match conjugate {
+                    None => to_s(v[r + col * n]),
+                    Some(conj) => {
+                        let (re, im) = (to_s(v[r + col * n]), to_s(v[r + (col + 1) * n]));
+                        if conj { re - i * im } else { re + i * im }
+                    }
+                })
+                .collect();
+            let mut y: Vec<S> = (0..n)
+                .map(|r| (0..n).fold(S::zero(), |acc, l| acc + to_s(z[[r, l]]) * x[l]))
+                .collect();
+            normalize_eigenvector(&mut y);
+            out.push(y);
+        }
+        out
+    };
+    let m = columns.len();
+    let vectors_shape = <(D0, D1) as Shape>::from_dims(&[n, m]);
+    let to_array =
+        |columns: Vec<Vec<S>>| Array::from_fn(vectors_shape, |idx| columns[idx[1]][idx[0]]);
+
+    Ok(EigDecomp {
+        eigenvalues: Array::from_fn(<(D0,) as Shape>::from_dims(&[m]), |idx| {
+            eigenvalues[columns[idx[0]].0]
+        }),
+        left_eigenvectors: (side != EigSide::Right).then(|| to_array(vectors(&vl))),
+        right_eigenvectors: (side != EigSide::Left).then(|| to_array(vectors(&vr))),
+    })
+}
+
+fn reorder_result<R>(dim: usize, s: R, sep: R, condition: SchurCondition) -> SchurReorder<R> {
+    let (want_s, want_sep) = match condition {
+        SchurCondition::None => (false, false),
+        SchurCondition::Eigenvalues => (true, false),
+        SchurCondition::Subspace => (false, true),
+        SchurCondition::Both => (true, true),
+    };
+    SchurReorder {
+        dim,
+        s: want_s.then_some(s),
+        sep: want_sep.then_some(sep),
+    }
 }
@@ -5,10 +5,12 @@ use paste::paste;
 
 #[allow(clippy::too_many_arguments)]
 pub(super) trait LapackScalar {
-    // General eigenvalue decomposition (GEEV)
-    unsafe fn lapack_geev(
+    // General eigenvalue decomposition with balancing and condition numbers (GEEVX)
+    unsafe fn lapack_geevx(
+        balanc: i8,
         jobvl: i8,
         jobvr: i8,
+        sense: i8,
         n: i32,
         a: *mut Self,
         lda: i32,
@@ -18,9 +20,16 @@ pub(super) trait LapackScalar {
         ldvl: i32,
         vr: *mut Self,
         ldvr: i32,
+        ilo: *mut i32,
+        ihi: *mut i32,
+        scale: *mut c_void,
+        abnrm: *mut c_void,
+        rconde: *mut c_void,
+        rcondv: *mut c_void,
         work: *mut Self,
         lwork: i32,
         rwork: *mut Self,
+        iwork: *mut i32,
         info: *mut i32,
     );
 
@@ -38,6 +47,50 @@ pub(super) trait LapackScalar {
         info: *mut i32,
     );
 
+    // Hermitian/symmetric eigenvalue decomposition, divide and conquer (SYEVD/HEEVD)
+    unsafe fn lapack_syevd(
+        jobz: i8,
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        w: *mut c_void,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        lrwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+
+    // Subset Hermitian/symmetric eigenvalue decomposition, MRRR (SYEVR/HEEVR)
+    unsafe fn lapack_syevr(
+        jobz: i8,
+        range: i8,
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        vl: *const c_void,
+        vu: *const c_void,
+        il: i32,
+        iu: i32,
+        abstol: *const c_void,
+        m: *mut i32,
+        w: *mut c_void,
+        z: *mut Self,
+        ldz: i32,
+        isuppz: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        lrwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+
     // Schur decomposition (GEES)
     unsafe fn lapack_gees(
         jobvs: i8,
@@ -57,15 +110,56 @@ pub(super) trait LapackScalar {
         bwork: *mut i32,
         info: *mut i32,
     );
+    // Reordering of a Schur form (TRSEN)
+    unsafe fn lapack_trsen(
+        job: i8,
+        compq: i8,
+        select: *const i32,
+        n: i32,
+        t: *mut Self,
+        ldt: i32,
+        q: *mut Self,
+        ldq: i32,
+        wr: *mut Self,
+        wi: *mut Self,
+        m: *mut i32,
+        s: *mut c_void,
+        sep: *mut c_void,
+        work: *mut Self,
+        lwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+    // Eigenvectors of a Schur form (TREVC)
+    unsafe fn lapack_trevc(
+        side: i8,
+        howmny: i8,
+        select: *mut i32,
+        n: i32,
+        t: *mut Self,
+        ldt: i32,
+        vl: *mut Self,
+        ldvl: i32,
+        vr: *mut Self,
+        ldvr: i32,
+        mm: i32,
+        m: *mut i32,
+        work: *mut Self,
+        rwork: *mut c_void,
+        info: *mut i32,
+    );
 }
 
 macro_rules! impl_lapack_scalar_real {
     ($t:ty, $prefix:ident) => {
         impl LapackScalar for $t {
             #[inline]
-            unsafe fn lapack_geev(
+            unsafe fn lapack_geevx(
+                balanc: i8,
                 jobvl: i8,
                 jobvr: i8,
+                sense: i8,
                 n: i32,
                 a: *mut Self,
                 lda: i32,
@@ -75,16 +169,25 @@ macro_rules! impl_lapack_scalar_real {
                 ldvl: i32,
                 vr: *mut Self,
                 ldvr: i32,
+                ilo: *mut i32,
+                ihi: *mut i32,
+                scale: *mut c_void,
+                abnrm: *mut c_void,
+                rconde: *mut c_void,
+                rcondv: *mut c_void,
                 work: *mut Self,
                 lwork: i32,
                 _rwork: *mut Self, // unused for real types
+                iwork: *mut i32,
                 info: *mut i32,
             ) {
                 unsafe {
                     paste! {
-                        lapack_sys::[<$prefix geev_>](
+                        lapack_sys::[<$prefix geevx_>](
+                            &balanc as *const i8,
                             &jobvl as *const i8,
                             &jobvr as *const i8,
+                            &sense as *const i8,
                             &n as *const i32,
                             a as *mut _,
                             &lda as *const i32,
@@ -94,8 +197,15 @@ macro_rules! impl_lapack_scalar_real {
                             &ldvl as *const i32,
                             vr as *mut _,
                             &ldvr as *const i32,
+                            ilo,
+                            ihi,
+                            scale as *mut _,
+                            abnrm as *mut _,
+                            rconde as *mut _,
+                            rcondv as *mut _,
                             work as *mut _,
                             &lwork as *const i32,
+                            iwork,
                             info as *mut i32,
                         );
                     }
@@ -132,6 +242,96 @@ macro_rules! impl_lapack_scalar_real {
                 }
             }
 
+            #[inline]
+            unsafe fn lapack_syevd(
+                jobz: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                w: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                _lrwork: i32,        // unused for real types
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix syevd_>](
+                            &jobz as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            w as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_syevr(
+                jobz: i8,
+                range: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: *const c_void,
+                vu: *const c_void,
+                il: i32,
+                iu: i32,
+                abstol: *const c_void,
+                m: *mut i32,
+                w: *mut c_void,
+                z: *mut Self,
+                ldz: i32,
+                isuppz: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                _lrwork: i32,        // unused for real types
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix syevr_>](
+                            &jobz as *const i8,
+                            &range as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            vl as *const _,
+                            vu as *const _,
+                            &il as *const i32,
+                            &iu as *const i32,
+                            abstol as *const _,
+                            m,
+                            w as *mut _,
+                            z as *mut _,
+                            &ldz as *const i32,
+                            isuppz,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+
             #[inline]
             unsafe fn lapack_gees(
                 jobvs: i8,
@@ -174,6 +374,91 @@ macro_rules! impl_lapack_scalar_real {
                                         }
                 }
             }
+            #[inline]
+            unsafe fn lapack_trsen(
+                job: i8,
+                compq: i8,
+                select: *const i32,
+                n: i32,
+                t: *mut Self,
+                ldt: i32,
+                q: *mut Self,
+                ldq: i32,
+                wr: *mut Self,
+                wi: *mut Self,
+                m: *mut i32,
+                s: *mut c_void,
+                sep: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trsen_>](
+                            &job as *const i8,
+                            &compq as *const i8,
+                            select,
+                            &n as *const i32,
+                            t as *mut _,
+                            &ldt as *const i32,
+                            q as *mut _,
+                            &ldq as *const i32,
+                            wr as *mut _,
+                            wi as *mut _,
+                            m,
+                            s as *mut _,
+                            sep as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+            #[inline]
+            unsafe fn lapack_trevc(
+                side: i8,
+                howmny: i8,
+                select: *mut i32,
+                n: i32,
+                t: *mut Self,
+                ldt: i32,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                mm: i32,
+                m: *mut i32,
+                work: *mut Self,
+                _rwork: *mut c_void, // unused for real types
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trevc_>](
+                            &side as *const i8,
+                            &howmny as *const i8,
+                            select,
+                            &n as *const i32,
+                            t as *const _,
+                            &ldt as *const i32,
+                            vl as *mut _,
+                            &ldvl as *const i32,
+                            vr as *mut _,
+                            &ldvr as *const i32,
+                            &mm as *const i32,
+                            m,
+                            work as *mut _,
+                            info,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -200,9 +485,11 @@ macro_rules! impl_lapack_scalar_cplx {
     ($t:ty, $prefix:ident) => {
         impl LapackScalar for $t {
             #[inline]
-            unsafe fn lapack_geev(
+            unsafe fn lapack_geevx(
+                balanc: i8,
                 jobvl: i8,
                 jobvr: i8,
+                sense: i8,
                 n: i32,
                 a: *mut Self,
                 lda: i32,
@@ -212,16 +499,25 @@ macro_rules! impl_lapack_scalar_cplx {
                 ldvl: i32,
                 vr: *mut Self,
                 ldvr: i32,
+                ilo: *mut i32,
+                ihi: *mut i32,
+                scale: *mut c_void,
+                abnrm: *mut c_void,
+                rconde: *mut c_void,
+                rcondv: *mut c_void,
                 work: *mut Self,
                 lwork: i32,
                 rwork: *mut Self,
+                _iwork: *mut i32, // unused for complex types
                 info: *mut i32,
             ) {
                 unsafe {
                     paste! {
-                        lapack_sys::[<$prefix geev_>](
+                        lapack_sys::[<$prefix geevx_>](
+                            &balanc as *const i8,
                             &jobvl as *const i8,
                             &jobvr as *const i8,
+                            &sense as *const i8,
                             &n as *const i32,
                             a as *mut lapack_sys_cast!($prefix),
                             &lda as *const i32,
@@ -230,6 +526,12 @@ macro_rules! impl_lapack_scalar_cplx {
                             &ldvl as *const i32,
                             vr as *mut lapack_sys_cast!($prefix),
                             &ldvr as *const i32,
+                            ilo,
+                            ihi,
+                            scale as *mut real_cast!($prefix),
+                            abnrm as *mut real_cast!($prefix),
+                            rconde as *mut real_cast!($prefix),
+                            rcondv as *mut real_cast!($prefix),
                             work as *mut lapack_sys_cast!($prefix),
                             &lwork as *const i32,
                             rwork as *mut real_cast!($prefix),
@@ -270,6 +572,100 @@ macro_rules! impl_lapack_scalar_cplx {
                 }
             }
 
+            #[inline]
+            unsafe fn lapack_syevd(
+                jobz: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                w: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                lrwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix heevd_>](
+                            &jobz as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            w as *mut real_cast!($prefix),
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut real_cast!($prefix),
+                            &lrwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_syevr(
+                jobz: i8,
+                range: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: *const c_void,
+                vu: *const c_void,
+                il: i32,
+                iu: i32,
+                abstol: *const c_void,
+                m: *mut i32,
+                w: *mut c_void,
+                z: *mut Self,
+                ldz: i32,
+                isuppz: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                lrwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix heevr_>](
+                            &jobz as *const i8,
+                            &range as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            vl as *const real_cast!($prefix),
+                            vu as *const real_cast!($prefix),
+                            &il as *const i32,
+                            &iu as *const i32,
+                            abstol as *const real_cast!($prefix),
+                            m,
+                            w as *mut real_cast!($prefix),
+                            z as *mut lapack_sys_cast!($prefix),
+                            &ldz as *const i32,
+                            isuppz,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut real_cast!($prefix),
+                            &lrwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+
             #[inline]
             unsafe fn lapack_gees(
                 jobvs: i8,
@@ -312,6 +708,89 @@ macro_rules! impl_lapack_scalar_cplx {
                     }
                 }
             }
+            #[inline]
+            unsafe fn lapack_trsen(
+                job: i8,
+                compq: i8,
+                select: *const i32,
+                n: i32,
+                t: *mut Self,
+                ldt: i32,
+                q: *mut Self,
+                ldq: i32,
+                wr: *mut Self,  // For complex, this holds the eigenvalues directly
+                _wi: *mut Self, // unused for complex types
+                m: *mut i32,
+                s: *mut c_void,
+                sep: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                _iwork: *mut i32, // unused for complex types
+                _liwork: i32,     // unused for complex types
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trsen_>](
+                            &job as *const i8,
+                            &compq as *const i8,
+                            select,
+                            &n as *const i32,
+                            t as *mut lapack_sys_cast!($prefix),
+                            &ldt as *const i32,
+                            q as *mut lapack_sys_cast!($prefix),
+                            &ldq as *const i32,
+                            wr as *mut lapack_sys_cast!($prefix),
+                            m,
+                            s as *mut real_cast!($prefix),
+                            sep as *mut real_cast!($prefix),
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+            #[inline]
+            unsafe fn lapack_trevc(
+                side: i8,
+                howmny: i8,
+                select: *mut i32,
+                n: i32,
+                t: *mut Self,
+                ldt: i32,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                mm: i32,
+                m: *mut i32,
+                work: *mut Self,
+                rwork: *mut c_void,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trevc_>](
+                            &side as *const i8,
+                            &howmny as *const i8,
+                            select,
+                            &n as *const i32,
+                            t as *mut lapack_sys_cast!($prefix),
+                            &ldt as *const i32,
+                            vl as *mut lapack_sys_cast!($prefix),
+                            &ldvl as *const i32,
+                            vr as *mut lapack_sys_cast!($prefix),
+                            &ldvr as *const i32,
+                            &mm as *const i32,
+                            m,
+                            work as *mut lapack_sys_cast!($prefix),
+                            rwork as *mut real_cast!($prefix),
+                            info,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -321,6 +800,94 @@ impl_lapack_scalar_real!(f64, d);
 impl_lapack_scalar_cplx!(Complex<f32>, c);
 impl_lapack_scalar_cplx!(Complex<f64>, z);
 
+/// LAPACK routines that only exist for real scalars.
+#[allow(clippy::too_many_arguments)]
+pub(super) trait LapackRealScalar: Sized {
+    // Symmetric tridiagonal eigenvalue decomposition, MRRR (STEVR)
+    unsafe fn lapack_stevr(
+        jobz: i8,
+        range: i8,
+        n: i32,
+        d: *mut Self,
+        e: *mut Self,
+        vl: Self,
+        vu: Self,
+        il: i32,
+        iu: i32,
+        abstol: Self,
+        m: *mut i32,
+        w: *mut Self,
+        z: *mut Self,
+        ldz: i32,
+        isuppz: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_real_scalar {
+    ($t:ty, $prefix:ident) => {
+        impl LapackRealScalar for $t {
+            #[inline]
+            unsafe fn lapack_stevr(
+                jobz: i8,
+                range: i8,
+                n: i32,
+                d: *mut Self,
+                e: *mut Self,
+                vl: Self,
+                vu: Self,
+                il: i32,
+                iu: i32,
+                abstol: Self,
+                m: *mut i32,
+                w: *mut Self,
+                z: *mut Self,
+                ldz: i32,
+                isuppz: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix stevr_>](
+                            &jobz as *const i8,
+                            &range as *const i8,
+                            &n as *const i32,
+                            d,
+                            e,
+                            &vl as *const Self,
+                            &vu as *const Self,
+                            &il as *const i32,
+                            &iu as *const i32,
+                            &abstol as *const Self,
+                            m,
+                            w,
+                            z,
+                            &ldz as *const i32,
+                            isuppz,
+                            work,
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_real_scalar!(f32, s);
+impl_lapack_real_scalar!(f64, d);
+
 pub(super) trait NeedsRwork {
     type RworkType;
     type Elem;
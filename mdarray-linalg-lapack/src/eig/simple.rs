@@ -1,13 +1,18 @@
-use std::{ptr, ptr::null_mut};
+use std::{ffi::c_void, ptr, ptr::null_mut};
 
 use mdarray::{Dim, Layout, Shape, Slice};
 use mdarray_linalg::{
-    eig::{EigError, SchurError},
-    utils::transpose_in_place,
+    eig::{EigBalance, EigError, EighAlgorithm, EighRange, SchurCondition, SchurError},
+    utils::{into_i32, transpose_in_place},
 };
 use num_complex::ComplexFloat;
+use num_traits::{Float, ToPrimitive, Zero};
 
-use super::scalar::{LapackScalar, NeedsRwork};
+use super::scalar::{LapackRealScalar, LapackScalar, NeedsRwork};
+
+/// Output slices for the reciprocal condition numbers of the eigenvalues and
+/// of the right eigenvectors computed by `geevx`.
+pub(super) type ConditionOutput<'a, R> = (&'a mut [R], &'a mut [R]);
 
 pub(super) fn geig<
     La: Layout,
@@ -24,6 +29,8 @@ pub(super) fn geig<
     eigenvalues_imag: &mut Slice<T, (D0,), Lei>,
     mut left_eigenvectors: Option<&mut Slice<T, (D0, D1), Lvl>>,
     mut right_eigenvectors: Option<&mut Slice<T, (D0, D1), Lvr>>,
+    balance: EigBalance,
+    condition: Option<ConditionOutput<'_, T::Real>>,
 ) -> Result<(), EigError>
 where
     T::Real: Into<T>,
@@ -86,7 +93,14 @@ where
         .as_mut()
         .map_or(null_mut(), |x| x.as_mut_ptr());
 
-    let info = call_geev(
+    let balanc = match balance {
+        EigBalance::None => 'N',
+        EigBalance::Permute => 'P',
+        EigBalance::Scale => 'S',
+        EigBalance::Both => 'B',
+    };
+
+    let info = call_geevx(
         a,
         n.try_into().unwrap(),
         eigenvalues_real.as_mut_ptr(),
@@ -95,6 +109,8 @@ where
         vr_ptr,
         jobvl,
         jobvr,
+        balanc,
+        condition,
     );
 
     if info < 0 {
@@ -116,6 +132,11 @@ where
     }
 }
 
+/// Computes the eigenvalues, and the eigenvectors if `vectors` is set, of a
+/// self-adjoint matrix with `syev`, `syevd` or `syevr`.  Jacobi rotations are
+/// left to the caller.
+///
+/// As with `syev`, the eigenvectors overwrite `a` in column-major order.
 pub(super) fn geigh<
     La: Layout,
     Lw: Layout,
@@ -125,6 +146,8 @@ pub(super) fn geigh<
 >(
     a: &mut Slice<T, (D0, D1), La>,
     eigenvalues: &mut Slice<T::Real, (D0,), Lw>,
+    algorithm: EighAlgorithm,
+    vectors: bool,
 ) -> Result<(), EigError>
 where
     T::Real: Into<T>,
@@ -142,12 +165,134 @@ where
         "Eigenvalues must have n elements"
     );
 
-    let info = call_syev(
+    if n == 0 {
+        return Ok(());
+    }
+
+    let jobz = if vectors { 'V' } else { 'N' };
+    let info = match algorithm {
+        EighAlgorithm::DivideAndConquer => call_syevd(
+            a,
+            into_i32(n),
+            eigenvalues.as_mut_ptr(),
+            jobz,
+            'U', // Use upper triangle
+        ),
+        EighAlgorithm::Mrrr => {
+            let zero = T::Real::zero();
+            let mut z = vec![T::default(); if vectors { n * n } else { 1 }];
+            let (info, _) = call_syevr(
+                a,
+                into_i32(n),
+                jobz,
+                'A',
+                zero,
+                zero,
+                0,
+                0,
+                eigenvalues.as_mut_ptr(),
+                z.as_mut_ptr(),
+            );
+            // syevr returns the eigenvectors separately; store them in `a` as
+            // syev does.
+            if vectors {
+                for j in 0..n {
+                    for i in 0..n {
+                        a[[j, i]] = z[i + j * n];
+                    }
+                }
+            }
+            info
+        }
+        EighAlgorithm::Auto | EighAlgorithm::Qr | EighAlgorithm::Jacobi => call_syev(
+            a,
+            into_i32(n),
+            eigenvalues.as_mut_ptr(),
+            jobz,
+            'U', // Use upper triangle
+        ),
+    };
+
+    if info < 0 {
+        panic!(
+            "Invalid argument to EIGH: the {}-th parameter had an illegal value.",
+            -info
+        );
+    } else if info > 0 {
+        Err(EigError::BackendDidNotConverge { iterations: info })
+    } else {
+        Ok(())
+    }
+}
+
+/// Computes the eigenpairs of a self-adjoint matrix selected by `range`.
+///
+/// `eigenvalues` must hold `n` values and `eigenvectors` must hold an `n × k`
+/// column-major matrix, where `k` is the largest possible number of selected
+/// eigenpairs.  Returns the number of eigenpairs that were found.
+pub(super) fn geighr<
+    La: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &mut Slice<T, (D0, D1), La>,
+    range: &EighRange<T::Real>,
+    eigenvalues: &mut [T::Real],
+    eigenvectors: &mut [T],
+) -> Result<usize, EigError>
+where
+    T::Real: Into<T>,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    if m != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+
+    let zero = T::Real::zero();
+    let (range_char, vl, vu, il, iu) = match range {
+        EighRange::Index(r) => {
+            if r.start > r.end || r.end > n {
+                return Err(EigError::InvalidRange);
+            }
+            if r.is_empty() {
+                return Ok(0);
+            }
+            // LAPACK uses 1-based inclusive bounds.
+            ('I', zero, zero, into_i32(r.start + 1), into_i32(r.end))
+        }
+        EighRange::Value(vl, vu) => {
+            if vl.partial_cmp(vu) != Some(std::cmp::Ordering::Less) {
+                return Err(EigError::InvalidRange);
+            }
+            ('V', *vl, *vu, 0, 0)
+        }
+    };
+
+    if n == 0 {
+        return Ok(0);
+    }
+
+    assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+    assert_eq!(
+        eigenvectors.len() % n,
+        0,
+        "Eigenvectors must have n rows"
+    );
+
+    let (info, found) = call_syevr(
         a,
-        n.try_into().unwrap(),
+        into_i32(n),
+        'V',
+        range_char,
+        vl,
+        vu,
+        il,
+        iu,
         eigenvalues.as_mut_ptr(),
-        'V', // Always compute eigenvectors for geigh
-        'U', // Use upper triangle
+        eigenvectors.as_mut_ptr(),
     );
 
     if info < 0 {
@@ -158,12 +303,12 @@ where
     } else if info > 0 {
         Err(EigError::BackendDidNotConverge { iterations: info })
     } else {
-        Ok(())
+        Ok(found as usize)
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-fn call_geev<
+fn call_geevx<
     T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
     La: Layout,
     D0: Dim,
@@ -177,6 +322,8 @@ fn call_geev<
     vr_ptr: *mut T,
     jobvl: char,
     jobvr: char,
+    balanc: char,
+    condition: Option<ConditionOutput<'_, T::Real>>,
 ) -> i32
 where
     T::Real: Into<T>,
@@ -195,13 +342,27 @@ where
         transpose_in_place(a);
     }
 
-    let mut rwork = vec![0.0; T::rwork_len_geev(n)];
+    let len = (n as usize).max(1);
+    let mut rwork = vec![T::Real::zero(); T::rwork_len_geev(n)];
+    let mut iwork = vec![0i32; 2 * len];
+    let (mut ilo, mut ihi) = (0, 0);
+    let mut scale = vec![T::Real::zero(); len];
+    let mut abnrm = T::Real::zero();
+    // Condition numbers are not referenced unless requested (sense = 'B').
+    let sense = if condition.is_some() { 'B' } else { 'N' };
+    let (mut rconde_tmp, mut rcondv_tmp) = (vec![T::Real::zero(); len], vec![T::Real::zero(); len]);
+    let (rconde, rcondv) = match condition {
+        Some((rconde, rcondv)) => (rconde.as_mut_ptr(), rcondv.as_mut_ptr()),
+        None => (rconde_tmp.as_mut_ptr(), rcondv_tmp.as_mut_ptr()),
+    };
 
     // First call to query optimal workspace size
     unsafe {
-        T::lapack_geev(
+        T::lapack_geevx(
+            balanc as i8,
             jobvl as i8,
             jobvr as i8,
+            sense as i8,
             n,
             a.as_mut_ptr(),
             n,
@@ -211,9 +372,16 @@ where
             n,
             vr_ptr,
             n,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_ptr() as *mut c_void,
+            (&mut abnrm as *mut T::Real).cast::<c_void>(),
+            rconde as *mut c_void,
+            rcondv as *mut c_void,
             work.as_mut_ptr(),
             lwork,
             rwork.as_mut_ptr() as *mut _,
+            iwork.as_mut_ptr(),
             &mut info,
         );
     }
@@ -223,9 +391,11 @@ where
 
     // Second call with optimal workspace
     unsafe {
-        T::lapack_geev(
+        T::lapack_geevx(
+            balanc as i8,
             jobvl as i8,
             jobvr as i8,
+            sense as i8,
             n,
             a.as_mut_ptr(),
             n,
@@ -235,9 +405,16 @@ where
             n,
             vr_ptr,
             n,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_ptr() as *mut c_void,
+            (&mut abnrm as *mut T::Real).cast::<c_void>(),
+            rconde as *mut c_void,
+            rcondv as *mut c_void,
             work.as_mut_ptr(),
             lwork,
             rwork.as_mut_ptr() as *mut _,
+            iwork.as_mut_ptr(),
             &mut info,
         );
     }
@@ -314,6 +491,324 @@ where
     info
 }
 
+fn call_syevd<
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    La: Layout,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &mut Slice<T, (D0, D1), La>,
+    n: i32,
+    w_ptr: *mut T::Real,
+    jobz: char,
+    uplo: char,
+) -> i32
+where
+    T::Real: Into<T>,
+{
+    let row_major = a.stride(1) == 1;
+    assert!(
+        row_major || a.stride(0) == 1,
+        "a must be contiguous in one dimension"
+    );
+
+    if row_major {
+        transpose_in_place(a);
+    }
+
+    let mut info = 0;
+    let mut work = T::allocate(1);
+    let mut rwork = vec![T::Real::zero(); 1];
+    let mut iwork = vec![0i32; 1];
+
+    // First call to query optimal workspace sizes
+    unsafe {
+        T::lapack_syevd(
+            jobz as i8,
+            uplo as i8,
+            n,
+            a.as_mut_ptr(),
+            n,
+            w_ptr.cast(),
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr().cast(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = T::lwork_from_query(&work[0]);
+    let mut work = T::allocate(lwork);
+    // The real workspace is only used by complex types; real types leave the query at zero.
+    let lrwork = rwork[0].to_i32().unwrap_or(0).max(1);
+    let mut rwork = vec![T::Real::zero(); lrwork as usize];
+    let liwork = iwork[0].max(1);
+    let mut iwork = vec![0i32; liwork as usize];
+
+    // Second call with optimal workspace
+    unsafe {
+        T::lapack_syevd(
+            jobz as i8,
+            uplo as i8,
+            n,
+            a.as_mut_ptr(),
+            n,
+            w_ptr.cast(),
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr().cast(),
+            lrwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    info
+}
+
+/// Computes selected eigenvalues, and optionally eigenvectors, of the real
+/// symmetric tridiagonal matrix with diagonal `d` and off-diagonal `e` with
+/// stevr.
+///
+/// Returns the number of eigenvalues found. The eigenvectors are written
+/// column-major with leading dimension n.
+pub(super) fn stevr<R: Float + LapackRealScalar>(
+    d: &[R],
+    e: &[R],
+    range: &EighRange<R>,
+    eigenvalues: &mut [R],
+    eigenvectors: Option<&mut [R]>,
+) -> Result<usize, EigError> {
+    let n = d.len();
+    assert_eq!(e.len(), n.saturating_sub(1), "Off-diagonal must have n - 1 elements");
+
+    let zero = R::zero();
+    let (range_char, vl, vu, il, iu) = match range {
+        EighRange::Index(r) => {
+            if r.start > r.end || r.end > n {
+                return Err(EigError::InvalidRange);
+            }
+            if r.is_empty() {
+                return Ok(0);
+            }
+            // LAPACK uses 1-based inclusive bounds.
+            ('I', zero, zero, into_i32(r.start + 1), into_i32(r.end))
+        }
+        EighRange::Value(vl, vu) => {
+            if vl.partial_cmp(vu) != Some(std::cmp::Ordering::Less) {
+                return Err(EigError::InvalidRange);
+            }
+            ('V', *vl, *vu, 0, 0)
+        }
+    };
+
+    if n == 0 {
+        return Ok(0);
+    }
+
+    assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+    let (jobz, z_ptr) = match eigenvectors {
+        Some(z) => {
+            assert_eq!(z.len() % n, 0, "Eigenvectors must have n rows");
+            ('V', z.as_mut_ptr())
+        }
+        None => ('N', null_mut()),
+    };
+
+    // stevr overwrites both diagonals; the off-diagonal needs at least one entry.
+    let mut d = d.to_vec();
+    let mut e: Vec<R> = e.iter().copied().chain(std::iter::once(zero)).collect();
+    let n_i32 = into_i32(n);
+    let abstol = zero;
+    let mut found = 0;
+    let mut info = 0;
+    let mut isuppz = vec![0i32; 2 * n];
+
+    let mut work = vec![zero; 1];
+    let mut iwork = vec![0i32; 1];
+
+    // First call to query optimal workspace sizes
+    unsafe {
+        R::lapack_stevr(
+            jobz as i8,
+            range_char as i8,
+            n_i32,
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            vl,
+            vu,
+            il,
+            iu,
+            abstol,
+            &mut found,
+            eigenvalues.as_mut_ptr(),
+            z_ptr,
+            n_i32,
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = work[0].to_i32().unwrap_or(0).max(20 * n_i32);
+    let mut work = vec![zero; lwork as usize];
+    let liwork = iwork[0].max(10 * n_i32);
+    let mut iwork = vec![0i32; liwork as usize];
+
+    // Second call with optimal workspace
+    unsafe {
+        R::lapack_stevr(
+            jobz as i8,
+            range_char as i8,
+            n_i32,
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            vl,
+            vu,
+            il,
+            iu,
+            abstol,
+            &mut found,
+            eigenvalues.as_mut_ptr(),
+            z_ptr,
+            n_i32,
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        panic!(
+            "Invalid argument to STEVR: the {}-th parameter had an illegal value.",
+            -info
+        );
+    } else if info > 0 {
+        Err(EigError::BackendDidNotConverge { iterations: info })
+    } else {
+        Ok(found as usize)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn call_syevr<
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    La: Layout,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &mut Slice<T, (D0, D1), La>,
+    n: i32,
+    jobz: char,
+    range: char,
+    vl: T::Real,
+    vu: T::Real,
+    il: i32,
+    iu: i32,
+    w_ptr: *mut T::Real,
+    z_ptr: *mut T,
+) -> (i32, i32)
+where
+    T::Real: Into<T>,
+{
+    let row_major = a.stride(1) == 1;
+    assert!(
+        row_major || a.stride(0) == 1,
+        "a must be contiguous in one dimension"
+    );
+
+    if row_major {
+        transpose_in_place(a);
+    }
+
+    let abstol = T::Real::zero();
+    let mut found = 0;
+    let mut info = 0;
+    let mut isuppz = vec![0i32; 2 * n.max(1) as usize];
+
+    let mut work = T::allocate(1);
+    let mut rwork = vec![T::Real::zero(); 1];
+    let mut iwork = vec![0i32; 1];
+
+    // First call to query optimal workspace sizes
+    unsafe {
+        T::lapack_syevr(
+            jobz as i8,
+            range as i8,
+            b'U' as i8,
+            n,
+            a.as_mut_ptr(),
+            n,
+            (&vl as *const T::Real).cast::<c_void>(),
+            (&vu as *const T::Real).cast::<c_void>(),
+            il,
+            iu,
+            (&abstol as *const T::Real).cast::<c_void>(),
+            &mut found,
+            w_ptr.cast(),
+            z_ptr,
+            n,
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr().cast(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = T::lwork_from_query(&work[0]);
+    let mut work = T::allocate(lwork);
+    // The real workspace is only used by complex types; real types leave the query at zero.
+    let lrwork = rwork[0].to_i32().unwrap_or(0).max(1);
+    let mut rwork = vec![T::Real::zero(); lrwork as usize];
+    let liwork = iwork[0].max(1);
+    let mut iwork = vec![0i32; liwork as usize];
+
+    // Second call with optimal workspace
+    unsafe {
+        T::lapack_syevr(
+            jobz as i8,
+            range as i8,
+            b'U' as i8,
+            n,
+            a.as_mut_ptr(),
+            n,
+            (&vl as *const T::Real).cast::<c_void>(),
+            (&vu as *const T::Real).cast::<c_void>(),
+            il,
+            iu,
+            (&abstol as *const T::Real).cast::<c_void>(),
+            &mut found,
+            w_ptr.cast(),
+            z_ptr,
+            n,
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr().cast(),
+            lrwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    (info, found)
+}
+
 pub(super) fn gees<
     La: Layout,
     Lwr: Layout,
@@ -556,3 +1051,175 @@ pub(super) fn gees_complex<
 
     Ok(())
 }
+
+/// Reorders the Schur form `t` with Schur vectors `z` so that the eigenvalues
+/// selected by `select` lead the diagonal.  Returns the dimension of the
+/// selected invariant subspace and the reciprocal condition numbers `s` and
+/// `sep` (left at zero when not requested).
+pub(super) fn trsen<
+    Lt: Layout,
+    Lz: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    t: &mut Slice<T, (D0, D1), Lt>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    select: &[i32],
+    condition: SchurCondition,
+) -> Result<(usize, T::Real, T::Real), SchurError> {
+    let n = t.shape().dim(0);
+    let t_row_major = t.stride(1) == 1;
+    let z_row_major = z.stride(1) == 1;
+    assert!(
+        (t_row_major || t.stride(0) == 1) && (z_row_major || z.stride(0) == 1),
+        "t and z must be contiguous in one dimension"
+    );
+    if t_row_major {
+        transpose_in_place(t);
+    }
+    if z_row_major {
+        transpose_in_place(z);
+    }
+
+    let job = match condition {
+        SchurCondition::None => b'N',
+        SchurCondition::Eigenvalues => b'E',
+        SchurCondition::Subspace => b'V',
+        SchurCondition::Both => b'B',
+    };
+    let ld = into_i32(n).max(1);
+    let mut wr = vec![T::default(); n];
+    let mut wi = vec![T::default(); n];
+    let mut m = 0;
+    let mut s = T::Real::zero();
+    let mut sep = T::Real::zero();
+    let mut info = 0;
+
+    let mut work = T::allocate(1);
+    let mut iwork = vec![0i32; 1];
+
+    // First call to query optimal workspace sizes
+    unsafe {
+        T::lapack_trsen(
+            job as i8,
+            b'V' as i8,
+            select.as_ptr(),
+            into_i32(n),
+            t.as_mut_ptr(),
+            ld,
+            z.as_mut_ptr(),
+            ld,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            &mut m,
+            (&mut s as *mut T::Real).cast::<c_void>(),
+            (&mut sep as *mut T::Real).cast::<c_void>(),
+            work.as_mut_ptr(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = T::lwork_from_query(&work[0]).max(1);
+    let mut work = T::allocate(lwork);
+    // The integer workspace is only used by real types.
+    let liwork = iwork[0].max(1);
+    let mut iwork = vec![0i32; liwork as usize];
+
+    // Second call with optimal workspace
+    unsafe {
+        T::lapack_trsen(
+            job as i8,
+            b'V' as i8,
+            select.as_ptr(),
+            into_i32(n),
+            t.as_mut_ptr(),
+            ld,
+            z.as_mut_ptr(),
+            ld,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            &mut m,
+            (&mut s as *mut T::Real).cast::<c_void>(),
+            (&mut sep as *mut T::Real).cast::<c_void>(),
+            work.as_mut_ptr(),
+            lwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    if t_row_major {
+        transpose_in_place(t);
+    }
+    if z_row_major {
+        transpose_in_place(z);
+    }
+
+    if info < 0 {
+        Err(SchurError::BackendError(-info))
+    } else if info > 0 {
+        // Two adjacent blocks were too close to swap.
+        Err(SchurError::ReorderFailed)
+    } else {
+        Ok((m as usize, s, sep))
+    }
+}
+
+/// Computes the eigenvectors of the upper (quasi-)triangular `t` selected by
+/// `select` with `trevc`, without back-transformation.  `side` is `b'L'`,
+/// `b'R'` or `b'B'`.  Returns the left and right eigenvectors column-major
+/// with leading dimension `n`; a complex conjugate pair of a real `t` takes
+/// two columns holding the real and imaginary parts of the eigenvector of the
+/// eigenvalue with positive imaginary part.
+pub(super) fn trevc<
+    Lt: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    t: &Slice<T, (D0, D1), Lt>,
+    side: u8,
+    select: &mut [i32],
+) -> Result<(Vec<T>, Vec<T>), SchurError> {
+    let n = t.shape().dim(0);
+    // `trevc` reads T column-major and may scale its diagonal temporarily.
+    let mut tc: Vec<T> = (0..n * n).map(|k| t[[k % n, k / n]]).collect();
+    let ld = into_i32(n).max(1);
+    let len = |wanted: bool| if wanted { (n * n).max(1) } else { 1 };
+    let mut vl = vec![T::default(); len(side != b'R')];
+    let mut vr = vec![T::default(); len(side != b'L')];
+    let mut work = T::allocate(into_i32(3 * n.max(1)));
+    let mut rwork = vec![T::Real::zero(); n.max(1)];
+    let mut m = 0;
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_trevc(
+            side as i8,
+            b'S' as i8,
+            select.as_mut_ptr(),
+            into_i32(n),
+            tc.as_mut_ptr(),
+            ld,
+            vl.as_mut_ptr(),
+            ld,
+            vr.as_mut_ptr(),
+            ld,
+            into_i32(n),
+            &mut m,
+            work.as_mut_ptr(),
+            rwork.as_mut_ptr().cast(),
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        return Err(SchurError::BackendError(-info));
+    }
+    Ok((vl, vr))
+}
@@ -0,0 +1,179 @@
+//! Generalized Eigenvalue Decomposition (GGEV):
+//!     β * A * v = α * B * v (right eigenvectors)
+//!     β * u^H * A = α * u^H * B (left eigenvectors)
+//! where:
+//!     - A, B are n × n     (input square matrices)
+//!     - λ = α / β          (infinite for β = 0)
+//!
+//! Generalized Schur Decomposition (GGES):
+//!     A = Q * S * Z^H,  B = Q * T * Z^H
+//! where:
+//!     - Q, Z are n × n     (unitary Schur vectors)
+//!     - S is n × n         (upper triangular for complex, quasi-upper triangular for real)
+//!     - T is n × n         (upper triangular)
+
+use mdarray::{Array, Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    eig::{Eig, SchurSelect, normalize_eigenvector},
+    geig::{GEig, GEigDecomp, GEigError, QzDecomp, qz_select_mask},
+};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::Zero;
+
+use super::{
+    scalar::{LapackScalar, NeedsRwork},
+    simple::{PencilOutput, ggev, gges, tgsen},
+};
+use crate::Lapack;
+
+type LapackGEigDecomp<T, D0, D1> = GEigDecomp<Complex<<T as ComplexFloat>::Real>, T, D0, D1>;
+
+fn check_pencil<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<usize, GEigError> {
+    let (ash, bsh) = (*a.shape(), *b.shape());
+    let n = ash.dim(0);
+    if ash.dim(1) != n {
+        return Err(GEigError::NotSquareMatrix);
+    }
+    if bsh.dim(0) != n || bsh.dim(1) != n {
+        return Err(GEigError::ShapeMismatch);
+    }
+    Ok(n)
+}
+
+/// Computes the generalized eigendecomposition with `ggev`, unpacking the
+/// eigenvalue pairs and the eigenvectors of complex conjugate pairs of a
+/// real pencil.
+fn ggev_decomp<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+    left: bool,
+    right: bool,
+) -> Result<LapackGEigDecomp<T, D0, D1>, GEigError>
+where
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+{
+    let n = check_pencil(a, b)?;
+    let PencilOutput {
+        alphar,
+        alphai,
+        beta,
+        left: vl,
+        right: vr,
+    } = ggev(a, b, left, right)?;
+
+    let ash1 = <(D0,) as Shape>::from_dims(&[n]);
+    // For complex types `alphar` holds alpha and `alphai` is left at zero.
+    let alpha = Array::from_fn(ash1, |idx| {
+        let (re, im) = (alphar[idx[0]], alphai[idx[0]]);
+        Complex::new(re.re(), re.im() + im.re())
+    });
+    let beta = Array::from_fn(ash1, |idx| beta[idx[0]]);
+
+    let shape = <(D0, D1) as Shape>::from_dims(&[n, n]);
+    let eigenvectors = |v: &[T]| {
+        let c = |i: usize, j: usize| Complex::new(v[i + j * n].re(), v[i + j * n].im());
+        let mut out = Array::from_fn(shape, |idx| c(idx[0], idx[1]));
+        let mut j = 0;
+        while j < n {
+            if !alphai[j].re().is_zero() && j + 1 < n {
+                for i in 0..n {
+                    let (re, im) = (v[i + j * n].re(), v[i + (j + 1) * n].re());
+                    out[[i, j]] = Complex::new(re, im); // v = Re + i Im
+                    out[[i, j + 1]] = Complex::new(re, -im); // v̄ = Re - i Im
+                }
+                j += 2;
+            } else {
+                j += 1;
+            }
+        }
+        for j in 0..n {
+            let mut col: Vec<_> = (0..n).map(|i| out[[i, j]]).collect();
+            normalize_eigenvector(&mut col);
+            for (i, x) in col.into_iter().enumerate() {
+                out[[i, j]] = x;
+            }
+        }
+        out
+    };
+
+    Ok(GEigDecomp {
+        left_eigenvectors: left.then(|| eigenvectors(&vl)),
+        right_eigenvectors: right.then(|| eigenvectors(&vr)),
+        alpha,
+        beta,
+    })
+}
+
+impl<T, D0: Dim, D1: Dim> GEig<T, D0, D1> for Lapack
+where
+    Lapack: Eig<T, D0, D1, SpectralScalar = Complex<T::Real>>,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+{
+    /// Compute generalized eigenvalues and right eigenvectors using `ggev`
+    fn geig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        ggev_decomp(a, b, false, true)
+    }
+
+    /// Compute generalized eigenvalues and left/right eigenvectors using `ggev`
+    fn geig_full<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        ggev_decomp(a, b, true, true)
+    }
+
+    /// Compute only the generalized eigenvalues using `ggev`
+    fn geig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError> {
+        ggev_decomp(a, b, false, false)
+    }
+
+    /// Compute the generalized Schur decomposition using `gges`
+    fn qz<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<QzDecomp<T, D0, D1>, GEigError> {
+        let n = check_pencil(a, b)?;
+        let PencilOutput { left, right, .. } = gges(a, b)?;
+
+        let shape = <(D0, D1) as Shape>::from_dims(&[n, n]);
+        let from_column_major = |v: &[T]| Array::from_fn(shape, |idx| v[idx[0] + idx[1] * n]);
+        Ok(QzDecomp {
+            s: Array::from_fn(shape, |idx| a[[idx[0], idx[1]]]),
+            t: Array::from_fn(shape, |idx| b[[idx[0], idx[1]]]),
+            q: from_column_major(&left),
+            z: from_column_major(&right),
+        })
+    }
+
+    /// Reorder a generalized Schur decomposition using `tgsen`
+    fn qz_reorder<Ls: Layout, Lt: Layout, Lq: Layout, Lz: Layout>(
+        &self,
+        s: &mut Slice<T, (D0, D1), Ls>,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+    ) -> Result<usize, GEigError> {
+        let n = check_pencil(s, t)?;
+        if [q.shape(), z.shape()].iter().any(|sh| sh.dim(0) != n || sh.dim(1) != n) {
+            return Err(GEigError::ShapeMismatch);
+        }
+
+        let mask = qz_select_mask(s, t, &select)?;
+        let select: Vec<i32> = mask.iter().map(|&x| x as i32).collect();
+        tgsen(s, t, q, z, &select)
+    }
+}
@@ -0,0 +1,3 @@
+mod context;
+mod scalar;
+mod simple;
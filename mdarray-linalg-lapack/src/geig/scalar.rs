@@ -0,0 +1,487 @@
+use std::ffi::c_void;
+
+use num_complex::Complex;
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub(super) trait LapackScalar {
+    // Generalized eigenvalue decomposition (GGEV)
+    unsafe fn lapack_ggev(
+        jobvl: i8,
+        jobvr: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        alphar: *mut Self,
+        alphai: *mut Self,
+        beta: *mut Self,
+        vl: *mut Self,
+        ldvl: i32,
+        vr: *mut Self,
+        ldvr: i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        info: *mut i32,
+    );
+
+    // Generalized Schur decomposition (GGES), without sorting
+    unsafe fn lapack_gges(
+        jobvsl: i8,
+        jobvsr: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        sdim: *mut i32,
+        alphar: *mut Self,
+        alphai: *mut Self,
+        beta: *mut Self,
+        vsl: *mut Self,
+        ldvsl: i32,
+        vsr: *mut Self,
+        ldvsr: i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        bwork: *mut i32,
+        info: *mut i32,
+    );
+
+    // Reordering of a generalized Schur form (TGSEN)
+    unsafe fn lapack_tgsen(
+        ijob: i32,
+        wantq: i32,
+        wantz: i32,
+        select: *const i32,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        alphar: *mut Self,
+        alphai: *mut Self,
+        beta: *mut Self,
+        q: *mut Self,
+        ldq: i32,
+        z: *mut Self,
+        ldz: i32,
+        m: *mut i32,
+        pl: *mut c_void,
+        pr: *mut c_void,
+        dif: *mut c_void,
+        work: *mut Self,
+        lwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggev(
+                jobvl: i8,
+                jobvr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alphar: *mut Self,
+                alphai: *mut Self,
+                beta: *mut Self,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggev_>](
+                            &jobvl as *const i8,
+                            &jobvr as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            alphar as *mut _,
+                            alphai as *mut _,
+                            beta as *mut _,
+                            vl as *mut _,
+                            &ldvl as *const i32,
+                            vr as *mut _,
+                            &ldvr as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gges(
+                jobvsl: i8,
+                jobvsr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                sdim: *mut i32,
+                alphar: *mut Self,
+                alphai: *mut Self,
+                beta: *mut Self,
+                vsl: *mut Self,
+                ldvsl: i32,
+                vsr: *mut Self,
+                ldvsr: i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                bwork: *mut i32,
+                info: *mut i32,
+            ) {
+                type SelectFunc<T> = unsafe extern "C" fn(*const T, *const T, *const T) -> i32;
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gges_>](
+                            &jobvsl as *const i8,
+                            &jobvsr as *const i8,
+                            &(b'N' as i8) as *const i8, // no sorting
+                            None::<SelectFunc<Self>>, // not used
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            sdim,
+                            alphar as *mut _,
+                            alphai as *mut _,
+                            beta as *mut _,
+                            vsl as *mut _,
+                            &ldvsl as *const i32,
+                            vsr as *mut _,
+                            &ldvsr as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            bwork,
+                            info,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_tgsen(
+                ijob: i32,
+                wantq: i32,
+                wantz: i32,
+                select: *const i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alphar: *mut Self,
+                alphai: *mut Self,
+                beta: *mut Self,
+                q: *mut Self,
+                ldq: i32,
+                z: *mut Self,
+                ldz: i32,
+                m: *mut i32,
+                pl: *mut c_void,
+                pr: *mut c_void,
+                dif: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix tgsen_>](
+                            &ijob as *const i32,
+                            &wantq as *const i32,
+                            &wantz as *const i32,
+                            select,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            alphar as *mut _,
+                            alphai as *mut _,
+                            beta as *mut _,
+                            q as *mut _,
+                            &ldq as *const i32,
+                            z as *mut _,
+                            &ldz as *const i32,
+                            m,
+                            pl as *mut _,
+                            pr as *mut _,
+                            dif as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! real_cast {
+    (c) => {
+        f32
+    };
+    (z) => {
+        f64
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggev(
+                jobvl: i8,
+                jobvr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alphar: *mut Self, // For complex, this holds alpha directly
+                _alphai: *mut Self, // unused for complex types
+                beta: *mut Self,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggev_>](
+                            &jobvl as *const i8,
+                            &jobvr as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            alphar as *mut lapack_sys_cast!($prefix),
+                            beta as *mut lapack_sys_cast!($prefix),
+                            vl as *mut lapack_sys_cast!($prefix),
+                            &ldvl as *const i32,
+                            vr as *mut lapack_sys_cast!($prefix),
+                            &ldvr as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut real_cast!($prefix),
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gges(
+                jobvsl: i8,
+                jobvsr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                sdim: *mut i32,
+                alphar: *mut Self, // For complex, this holds alpha directly
+                _alphai: *mut Self, // unused for complex types
+                beta: *mut Self,
+                vsl: *mut Self,
+                ldvsl: i32,
+                vsr: *mut Self,
+                ldvsr: i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                bwork: *mut i32,
+                info: *mut i32,
+            ) {
+                type SelectFunc<T> = unsafe extern "C" fn(*const T, *const T) -> i32;
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gges_>](
+                            &jobvsl as *const i8,
+                            &jobvsr as *const i8,
+                            &(b'N' as i8) as *const i8, // no sorting
+                            None::<SelectFunc<lapack_sys_cast!($prefix)>>, // not used
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            sdim,
+                            alphar as *mut lapack_sys_cast!($prefix),
+                            beta as *mut lapack_sys_cast!($prefix),
+                            vsl as *mut lapack_sys_cast!($prefix),
+                            &ldvsl as *const i32,
+                            vsr as *mut lapack_sys_cast!($prefix),
+                            &ldvsr as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut real_cast!($prefix),
+                            bwork,
+                            info,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_tgsen(
+                ijob: i32,
+                wantq: i32,
+                wantz: i32,
+                select: *const i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alphar: *mut Self, // For complex, this holds alpha directly
+                _alphai: *mut Self, // unused for complex types
+                beta: *mut Self,
+                q: *mut Self,
+                ldq: i32,
+                z: *mut Self,
+                ldz: i32,
+                m: *mut i32,
+                pl: *mut c_void,
+                pr: *mut c_void,
+                dif: *mut c_void,
+                work: *mut Self,
+                lwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix tgsen_>](
+                            &ijob as *const i32,
+                            &wantq as *const i32,
+                            &wantz as *const i32,
+                            select,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            alphar as *mut lapack_sys_cast!($prefix),
+                            beta as *mut lapack_sys_cast!($prefix),
+                            q as *mut lapack_sys_cast!($prefix),
+                            &ldq as *const i32,
+                            z as *mut lapack_sys_cast!($prefix),
+                            &ldz as *const i32,
+                            m,
+                            pl as *mut real_cast!($prefix),
+                            pr as *mut real_cast!($prefix),
+                            dif as *mut real_cast!($prefix),
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            iwork,
+                            &liwork as *const i32,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
+
+pub(super) trait NeedsRwork {
+    type Elem;
+    // Length of the real workspace of GGEV and GGES
+    fn rwork_len(n: i32) -> usize;
+    fn lwork_from_query(query: &Self::Elem) -> i32;
+    fn allocate(lwork: i32) -> Vec<Self::Elem>;
+}
+
+macro_rules! impl_needs_rwork {
+    ($type:ty, $elem:ty, no_rwork) => {
+        impl NeedsRwork for $type {
+            type Elem = $elem;
+
+            fn rwork_len(_: i32) -> usize {
+                0
+            }
+
+            fn lwork_from_query(query: &Self::Elem) -> i32 {
+                *query as i32
+            }
+
+            fn allocate(lwork: i32) -> Vec<Self::Elem> {
+                vec![<$elem>::default(); lwork as usize]
+            }
+        }
+    };
+
+    ($type:ty, $elem:ty, $rwork:ty) => {
+        impl NeedsRwork for $type {
+            type Elem = $elem;
+
+            fn rwork_len(n: i32) -> usize {
+                (8 * n).max(1) as usize
+            }
+
+            fn lwork_from_query(query: &Self::Elem) -> i32 {
+                query.re as i32
+            }
+
+            fn allocate(lwork: i32) -> Vec<Self::Elem> {
+                vec![<$elem>::default(); lwork as usize]
+            }
+        }
+    };
+}
+
+impl_needs_rwork!(f32, f32, no_rwork);
+impl_needs_rwork!(f64, f64, no_rwork);
+impl_needs_rwork!(Complex<f32>, Complex<f32>, f32);
+impl_needs_rwork!(Complex<f64>, Complex<f64>, f64);
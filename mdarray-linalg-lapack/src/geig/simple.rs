@@ -0,0 +1,354 @@
+use std::ffi::c_void;
+
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{geig::GEigError, utils::into_i32};
+use num_complex::ComplexFloat;
+use num_traits::Zero;
+
+use super::scalar::{LapackScalar, NeedsRwork};
+
+/// Output of `ggev` and `gges`: the eigenvalue pairs `(alphar + i alphai,
+/// beta)` (`alphar` holds the whole `alpha` for complex types) and two
+/// column-major `n × n` matrices, the eigenvectors or the Schur vectors.
+pub(super) struct PencilOutput<T> {
+    pub alphar: Vec<T>,
+    pub alphai: Vec<T>,
+    pub beta: Vec<T>,
+    pub left: Vec<T>,
+    pub right: Vec<T>,
+}
+
+/// Copies a square matrix into a column-major vector.
+pub(super) fn to_column_major<T: Copy, D0: Dim, D1: Dim, L: Layout>(m: &Slice<T, (D0, D1), L>) -> Vec<T> {
+    let n = m.shape().dim(0);
+    (0..n * n).map(|k| m[[k % n, k / n]]).collect()
+}
+
+/// Copies a column-major vector back into a square matrix.
+pub(super) fn from_column_major<T: Copy, D0: Dim, D1: Dim, L: Layout>(v: &[T], m: &mut Slice<T, (D0, D1), L>) {
+    let n = m.shape().dim(0);
+    for (k, &x) in v.iter().enumerate() {
+        m[[k % n, k / n]] = x;
+    }
+}
+
+fn check_info(info: i32) -> Result<(), GEigError> {
+    if info < 0 {
+        Err(GEigError::BackendError(-info))
+    } else if info > 0 {
+        Err(GEigError::BackendDidNotConverge { iterations: info })
+    } else {
+        Ok(())
+    }
+}
+
+/// Computes the generalized eigenvalues of the pencil `(a, b)` and, on
+/// request, its left and right eigenvectors with `ggev`.  A complex
+/// conjugate pair of a real pencil takes two eigenvector columns holding the
+/// real and imaginary parts of the eigenvector of the eigenvalue with
+/// positive imaginary part.
+pub(super) fn ggev<
+    La: Layout,
+    Lb: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+    left: bool,
+    right: bool,
+) -> Result<PencilOutput<T>, GEigError> {
+    let n = a.shape().dim(0);
+    let mut ac = to_column_major(a);
+    let mut bc = to_column_major(b);
+    let ld = into_i32(n).max(1);
+    let jobvl = if left { b'V' } else { b'N' };
+    let jobvr = if right { b'V' } else { b'N' };
+    let len = |wanted: bool| if wanted { (n * n).max(1) } else { 1 };
+
+    let mut alphar = vec![T::default(); n.max(1)];
+    let mut alphai = vec![T::default(); n.max(1)];
+    let mut beta = vec![T::default(); n.max(1)];
+    let mut vl = vec![T::default(); len(left)];
+    let mut vr = vec![T::default(); len(right)];
+    let mut rwork = vec![T::Real::zero(); T::rwork_len(into_i32(n))];
+    let mut info = 0;
+
+    // First call to query optimal workspace size
+    let mut work = T::allocate(1);
+    unsafe {
+        T::lapack_ggev(
+            jobvl as i8,
+            jobvr as i8,
+            into_i32(n),
+            ac.as_mut_ptr(),
+            ld,
+            bc.as_mut_ptr(),
+            ld,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ld,
+            vr.as_mut_ptr(),
+            ld,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    // Second call with optimal workspace
+    let lwork = T::lwork_from_query(&work[0]).max(1);
+    let mut work = T::allocate(lwork);
+    unsafe {
+        T::lapack_ggev(
+            jobvl as i8,
+            jobvr as i8,
+            into_i32(n),
+            ac.as_mut_ptr(),
+            ld,
+            bc.as_mut_ptr(),
+            ld,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ld,
+            vr.as_mut_ptr(),
+            ld,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    Ok(PencilOutput {
+        alphar,
+        alphai,
+        beta,
+        left: vl,
+        right: vr,
+    })
+}
+
+/// Computes the generalized Schur decomposition of the pencil `(a, b)` with
+/// `gges`.  `a` and `b` are overwritten by `S` and `T`; the left and right
+/// Schur vectors `Q` and `Z` are returned column-major.
+pub(super) fn gges<
+    La: Layout,
+    Lb: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<PencilOutput<T>, GEigError> {
+    let n = a.shape().dim(0);
+    let mut ac = to_column_major(a);
+    let mut bc = to_column_major(b);
+    let ld = into_i32(n).max(1);
+
+    let mut sdim = 0;
+    let mut alphar = vec![T::default(); n.max(1)];
+    let mut alphai = vec![T::default(); n.max(1)];
+    let mut beta = vec![T::default(); n.max(1)];
+    let mut vsl = vec![T::default(); (n * n).max(1)];
+    let mut vsr = vec![T::default(); (n * n).max(1)];
+    let mut rwork = vec![T::Real::zero(); T::rwork_len(into_i32(n))];
+    // Not referenced without sorting, but kept valid.
+    let mut bwork = vec![0i32; n.max(1)];
+    let mut info = 0;
+
+    // First call to query optimal workspace size
+    let mut work = T::allocate(1);
+    unsafe {
+        T::lapack_gges(
+            b'V' as i8,
+            b'V' as i8,
+            into_i32(n),
+            ac.as_mut_ptr(),
+            ld,
+            bc.as_mut_ptr(),
+            ld,
+            &mut sdim,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            vsl.as_mut_ptr(),
+            ld,
+            vsr.as_mut_ptr(),
+            ld,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    // Second call with optimal workspace
+    let lwork = T::lwork_from_query(&work[0]).max(1);
+    let mut work = T::allocate(lwork);
+    unsafe {
+        T::lapack_gges(
+            b'V' as i8,
+            b'V' as i8,
+            into_i32(n),
+            ac.as_mut_ptr(),
+            ld,
+            bc.as_mut_ptr(),
+            ld,
+            &mut sdim,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            vsl.as_mut_ptr(),
+            ld,
+            vsr.as_mut_ptr(),
+            ld,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    from_column_major(&ac, a);
+    from_column_major(&bc, b);
+    Ok(PencilOutput {
+        alphar,
+        alphai,
+        beta,
+        left: vsl,
+        right: vsr,
+    })
+}
+
+/// Reorders the generalized Schur form `(s, t)` with Schur vectors `q`, `z`
+/// so that the eigenvalues selected by `select` lead the diagonal.  Returns
+/// the dimension of the selected deflating subspaces.
+pub(super) fn tgsen<
+    Ls: Layout,
+    Lt: Layout,
+    Lq: Layout,
+    Lz: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    s: &mut Slice<T, (D0, D1), Ls>,
+    t: &mut Slice<T, (D0, D1), Lt>,
+    q: &mut Slice<T, (D0, D1), Lq>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    select: &[i32],
+) -> Result<usize, GEigError> {
+    let n = s.shape().dim(0);
+    let mut sc = to_column_major(s);
+    let mut tc = to_column_major(t);
+    let mut qc = to_column_major(q);
+    let mut zc = to_column_major(z);
+    let ld = into_i32(n).max(1);
+
+    let mut alphar = vec![T::default(); n.max(1)];
+    let mut alphai = vec![T::default(); n.max(1)];
+    let mut beta = vec![T::default(); n.max(1)];
+    let mut m = 0;
+    // Condition estimates are not requested (ijob = 0).
+    let mut pl = T::Real::zero();
+    let mut pr = T::Real::zero();
+    let mut dif = [T::Real::zero(); 2];
+    let mut info = 0;
+
+    // First call to query optimal workspace sizes
+    let mut work = T::allocate(1);
+    let mut iwork = vec![0i32; 1];
+    unsafe {
+        T::lapack_tgsen(
+            0,
+            1,
+            1,
+            select.as_ptr(),
+            into_i32(n),
+            sc.as_mut_ptr(),
+            ld,
+            tc.as_mut_ptr(),
+            ld,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            qc.as_mut_ptr(),
+            ld,
+            zc.as_mut_ptr(),
+            ld,
+            &mut m,
+            (&mut pl as *mut T::Real).cast::<c_void>(),
+            (&mut pr as *mut T::Real).cast::<c_void>(),
+            dif.as_mut_ptr().cast::<c_void>(),
+            work.as_mut_ptr(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+    if info < 0 {
+        return Err(GEigError::BackendError(-info));
+    }
+
+    // Second call with optimal workspace
+    let lwork = T::lwork_from_query(&work[0]).max(1);
+    let mut work = T::allocate(lwork);
+    let liwork = iwork[0].max(1);
+    let mut iwork = vec![0i32; liwork as usize];
+    unsafe {
+        T::lapack_tgsen(
+            0,
+            1,
+            1,
+            select.as_ptr(),
+            into_i32(n),
+            sc.as_mut_ptr(),
+            ld,
+            tc.as_mut_ptr(),
+            ld,
+            alphar.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            qc.as_mut_ptr(),
+            ld,
+            zc.as_mut_ptr(),
+            ld,
+            &mut m,
+            (&mut pl as *mut T::Real).cast::<c_void>(),
+            (&mut pr as *mut T::Real).cast::<c_void>(),
+            dif.as_mut_ptr().cast::<c_void>(),
+            work.as_mut_ptr(),
+            lwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        return Err(GEigError::BackendError(-info));
+    } else if info > 0 {
+        // Two adjacent blocks were too close to swap.
+        return Err(GEigError::ReorderFailed);
+    }
+    from_column_major(&sc, s);
+    from_column_major(&tc, t);
+    from_column_major(&qc, q);
+    from_column_major(&zc, z);
+    Ok(m as usize)
+}
@@ -0,0 +1,83 @@
+//! Generalized Singular Value Decomposition (GGSVD3):
+//!     A = U * Σa * [0 R] * Q^H
+//!     B = V * Σb * [0 R] * Q^H
+//! where:
+//!     - A is m × n, B is p × n   (input matrices)
+//!     - U, V, Q are m × m, p × p, n × n (unitary)
+//!     - R is r × r               (nonsingular upper triangular, r = k + l)
+//!     - Σa, Σb are m × r, p × r  (diagonal blocks of the pairs (α, β))
+
+use mdarray::{Array, Dim, Layout, Shape, Slice};
+use mdarray_linalg::gsvd::{GSVD, GSVDDecomp, GSVDError};
+use num_complex::ComplexFloat;
+
+use super::{
+    scalar::{LapackScalar, NeedsRwork},
+    simple::{GsvdOutput, ggsvd3},
+};
+use crate::Lapack;
+
+impl<T, D> GSVD<T, D> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    T::Real: Into<T>,
+    D: Dim,
+{
+    type SingularValue = T;
+
+    /// Compute the GSVD with `ggsvd3`
+    fn gsvd<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), La>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<GSVDDecomp<T, Self::SingularValue, D>, GSVDError> {
+        let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+        let p = b.shape().dim(0);
+        let GsvdOutput {
+            k,
+            l,
+            alpha,
+            beta,
+            a: ac,
+            b: bc,
+            u,
+            v,
+            q,
+        } = ggsvd3(a, b)?;
+        let r = k + l;
+
+        // R is stored in A(0..min(m, r), n - r..n); if m < r, its trailing
+        // rows are in B(m - k..l, n + m - r..n).
+        let r_entry = |i: usize, j: usize| {
+            if i > j {
+                T::default()
+            } else if i < m {
+                ac[i + (n - r + j) * m]
+            } else {
+                bc[(i - k) + (n - r + j) * p]
+            }
+        };
+
+        let square = |size: usize, c: &[T]| {
+            Array::from_fn(<(D, D) as Shape>::from_dims(&[size, size]), |idx| {
+                c[idx[0] + idx[1] * size]
+            })
+        };
+        let pairs = |x: &[T::Real]| {
+            Array::from_fn(<(D,) as Shape>::from_dims(&[n]), |idx| x[idx[0]].into())
+        };
+
+        Ok(GSVDDecomp {
+            alpha: pairs(&alpha),
+            beta: pairs(&beta),
+            u: square(m, &u),
+            v: square(p, &v),
+            q: square(n, &q),
+            r: Array::from_fn(<(D, D) as Shape>::from_dims(&[r, r]), |idx| {
+                r_entry(idx[0], idx[1])
+            }),
+            k,
+            l,
+        })
+    }
+}
@@ -0,0 +1,243 @@
+use std::ffi::c_void;
+
+use num_complex::Complex;
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub(super) trait LapackScalar {
+    // Generalized singular value decomposition (GGSVD3)
+    unsafe fn lapack_ggsvd3(
+        jobu: i8,
+        jobv: i8,
+        jobq: i8,
+        m: i32,
+        n: i32,
+        p: i32,
+        k: *mut i32,
+        l: *mut i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        alpha: *mut c_void,
+        beta: *mut c_void,
+        u: *mut Self,
+        ldu: i32,
+        v: *mut Self,
+        ldv: i32,
+        q: *mut Self,
+        ldq: i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        iwork: *mut i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggsvd3(
+                jobu: i8,
+                jobv: i8,
+                jobq: i8,
+                m: i32,
+                n: i32,
+                p: i32,
+                k: *mut i32,
+                l: *mut i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alpha: *mut c_void,
+                beta: *mut c_void,
+                u: *mut Self,
+                ldu: i32,
+                v: *mut Self,
+                ldv: i32,
+                q: *mut Self,
+                ldq: i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggsvd3_>](
+                            &jobu as *const i8,
+                            &jobv as *const i8,
+                            &jobq as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            &p as *const i32,
+                            k,
+                            l,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            alpha as *mut _,
+                            beta as *mut _,
+                            u as *mut _,
+                            &ldu as *const i32,
+                            v as *mut _,
+                            &ldv as *const i32,
+                            q as *mut _,
+                            &ldq as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! real_cast {
+    (c) => {
+        f32
+    };
+    (z) => {
+        f64
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggsvd3(
+                jobu: i8,
+                jobv: i8,
+                jobq: i8,
+                m: i32,
+                n: i32,
+                p: i32,
+                k: *mut i32,
+                l: *mut i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alpha: *mut c_void,
+                beta: *mut c_void,
+                u: *mut Self,
+                ldu: i32,
+                v: *mut Self,
+                ldv: i32,
+                q: *mut Self,
+                ldq: i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggsvd3_>](
+                            &jobu as *const i8,
+                            &jobv as *const i8,
+                            &jobq as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            &p as *const i32,
+                            k,
+                            l,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            alpha as *mut real_cast!($prefix),
+                            beta as *mut real_cast!($prefix),
+                            u as *mut lapack_sys_cast!($prefix),
+                            &ldu as *const i32,
+                            v as *mut lapack_sys_cast!($prefix),
+                            &ldv as *const i32,
+                            q as *mut lapack_sys_cast!($prefix),
+                            &ldq as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut real_cast!($prefix),
+                            iwork,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
+
+pub(super) trait NeedsRwork {
+    type Elem;
+    // Length of the real workspace of GGSVD3
+    fn rwork_len(n: i32) -> usize;
+    fn lwork_from_query(query: &Self::Elem) -> i32;
+    fn allocate(lwork: i32) -> Vec<Self::Elem>;
+}
+
+macro_rules! impl_needs_rwork {
+    ($type:ty, $elem:ty, no_rwork) => {
+        impl NeedsRwork for $type {
+            type Elem = $elem;
+
+            fn rwork_len(_: i32) -> usize {
+                0
+            }
+
+            fn lwork_from_query(query: &Self::Elem) -> i32 {
+                *query as i32
+            }
+
+            fn allocate(lwork: i32) -> Vec<Self::Elem> {
+                vec![<$elem>::default(); lwork as usize]
+            }
+        }
+    };
+
+    ($type:ty, $elem:ty, $rwork:ty) => {
+        impl NeedsRwork for $type {
+            type Elem = $elem;
+
+            fn rwork_len(n: i32) -> usize {
+                (2 * n).max(1) as usize
+            }
+
+            fn lwork_from_query(query: &Self::Elem) -> i32 {
+                query.re as i32
+            }
+
+            fn allocate(lwork: i32) -> Vec<Self::Elem> {
+                vec![<$elem>::default(); lwork as usize]
+            }
+        }
+    };
+}
+
+impl_needs_rwork!(f32, f32, no_rwork);
+impl_needs_rwork!(f64, f64, no_rwork);
+impl_needs_rwork!(Complex<f32>, Complex<f32>, f32);
+impl_needs_rwork!(Complex<f64>, Complex<f64>, f64);
@@ -0,0 +1,153 @@
+use std::ffi::c_void;
+
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{gsvd::GSVDError, utils::into_i32};
+use num_complex::ComplexFloat;
+use num_traits::Zero;
+
+use super::scalar::{LapackScalar, NeedsRwork};
+
+/// Output of `ggsvd3`: `k`, `l`, the generalized singular value pairs and,
+/// column-major, `A` and `B` overwritten by the triangular factor `R` and
+/// the `m × m`, `p × p` and `n × n` matrices `U`, `V` and `Q`.
+pub(super) struct GsvdOutput<T: ComplexFloat> {
+    pub k: usize,
+    pub l: usize,
+    pub alpha: Vec<T::Real>,
+    pub beta: Vec<T::Real>,
+    pub a: Vec<T>,
+    pub b: Vec<T>,
+    pub u: Vec<T>,
+    pub v: Vec<T>,
+    pub q: Vec<T>,
+}
+
+/// Copies a matrix into a column-major vector.
+fn to_column_major<T: Copy, D0: Dim, D1: Dim, L: Layout>(m: &Slice<T, (D0, D1), L>) -> Vec<T> {
+    let (rows, cols) = (m.shape().dim(0), m.shape().dim(1));
+    (0..rows * cols).map(|k| m[[k % rows, k / rows]]).collect()
+}
+
+fn check_info(info: i32) -> Result<(), GSVDError> {
+    if info < 0 {
+        Err(GSVDError::BackendError(-info))
+    } else if info > 0 {
+        Err(GSVDError::BackendDidNotConverge { cycles: info })
+    } else {
+        Ok(())
+    }
+}
+
+/// Computes the generalized singular value decomposition of the `m × n`
+/// matrix `a` and the `p × n` matrix `b` with `ggsvd3`.
+pub(super) fn ggsvd3<
+    La: Layout,
+    Lb: Layout,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork<Elem = T>,
+    D0: Dim,
+    D1: Dim,
+>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<GsvdOutput<T>, GSVDError> {
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    let p = b.shape().dim(0);
+    if b.shape().dim(1) != n {
+        return Err(GSVDError::ShapeMismatch);
+    }
+
+    let mut ac = to_column_major(a);
+    let mut bc = to_column_major(b);
+    ac.resize(ac.len().max(1), T::default());
+    bc.resize(bc.len().max(1), T::default());
+    let (lda, ldb, ldq) = (into_i32(m.max(1)), into_i32(p.max(1)), into_i32(n.max(1)));
+
+    let (mut k, mut l) = (0, 0);
+    let mut alpha = vec![T::Real::zero(); n.max(1)];
+    let mut beta = vec![T::Real::zero(); n.max(1)];
+    let mut u = vec![T::default(); (m * m).max(1)];
+    let mut v = vec![T::default(); (p * p).max(1)];
+    let mut q = vec![T::default(); (n * n).max(1)];
+    let mut rwork = vec![T::Real::zero(); T::rwork_len(into_i32(n))];
+    let mut iwork = vec![0i32; n.max(1)];
+    let mut info = 0;
+
+    // First call to query optimal workspace size
+    let mut work = T::allocate(1);
+    unsafe {
+        T::lapack_ggsvd3(
+            b'U' as i8,
+            b'V' as i8,
+            b'Q' as i8,
+            into_i32(m),
+            into_i32(n),
+            into_i32(p),
+            &mut k,
+            &mut l,
+            ac.as_mut_ptr(),
+            lda,
+            bc.as_mut_ptr(),
+            ldb,
+            alpha.as_mut_ptr().cast::<c_void>(),
+            beta.as_mut_ptr().cast::<c_void>(),
+            u.as_mut_ptr(),
+            lda,
+            v.as_mut_ptr(),
+            ldb,
+            q.as_mut_ptr(),
+            ldq,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    // Second call with optimal workspace
+    let lwork = T::lwork_from_query(&work[0]).max(1);
+    let mut work = T::allocate(lwork);
+    unsafe {
+        T::lapack_ggsvd3(
+            b'U' as i8,
+            b'V' as i8,
+            b'Q' as i8,
+            into_i32(m),
+            into_i32(n),
+            into_i32(p),
+            &mut k,
+            &mut l,
+            ac.as_mut_ptr(),
+            lda,
+            bc.as_mut_ptr(),
+            ldb,
+            alpha.as_mut_ptr().cast::<c_void>(),
+            beta.as_mut_ptr().cast::<c_void>(),
+            u.as_mut_ptr(),
+            lda,
+            v.as_mut_ptr(),
+            ldb,
+            q.as_mut_ptr(),
+            ldq,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr().cast::<c_void>(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+    check_info(info)?;
+
+    Ok(GsvdOutput {
+        k: k as usize,
+        l: l as usize,
+        alpha,
+        beta,
+        a: ac,
+        b: bc,
+        u,
+        v,
+        q,
+    })
+}
@@ -13,9 +13,10 @@
 //!
 //! The LAPACK backend covers:
 //!
-//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eigh`
+//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eig_condition`, `eigh`, `eigh_values`, `eigh_range`
 //! - **Schur decomposition** — `schur`, `schur_complex`
-//! - **SVD** — `svd`, `svd_thin`, `svd_s`
+//! - **Generalized eigenvalue and QZ decomposition** — `geig`, `geig_full`, `geig_values`, `qz`, `qz_reorder`
+//! - **SVD** — `svd`, `svd_thin`, `svd_s`, `svd_partial`, `svd_range`
 //! - **LU decomposition** — `lu`, `det`, `inv`
 //! - **Cholesky decomposition** — `cholesky`
 //! - **QR decomposition** — `qr`
@@ -122,11 +123,18 @@
 extern crate lapack_src as _;
 
 mod eig;
+mod geig;
+mod gsvd;
 mod lu;
 mod qr;
 mod solve;
 mod svd;
 
+use mdarray_linalg::{
+    eig::{EigConfig, EighConfig},
+    gauge::Gauge,
+};
+
 /// Configuration for the SVD algorithm.
 ///
 /// This is hidden while backend configuration is being reconsidered.
@@ -163,6 +171,9 @@ pub enum QRConfig {
 pub struct Lapack {
     svd_config: SVDConfig,
     qr_config: QRConfig,
+    eig_config: EigConfig,
+    eigh_config: EighConfig,
+    gauge: Option<Gauge>,
 }
 
 impl Lapack {
@@ -171,6 +182,9 @@ impl Lapack {
         Self {
             svd_config: SVDConfig::default(),
             qr_config: QRConfig::default(),
+            eig_config: EigConfig::default(),
+            eigh_config: EighConfig::default(),
+            gauge: None,
         }
     }
 
@@ -189,4 +203,27 @@ impl Lapack {
         self.qr_config = config;
         self
     }
+
+    /// Configures the general eigenvalue decompositions (`geevx`).
+    #[must_use]
+    pub fn config_eig(mut self, config: EigConfig) -> Self {
+        self.eig_config = config;
+        self
+    }
+
+    /// Configures the self-adjoint eigenvalue decomposition: `syev` (the
+    /// default), `syevd`, `syevr` or Jacobi rotations.
+    #[must_use]
+    pub fn config_eigh(mut self, config: EighConfig) -> Self {
+        self.eigh_config = config;
+        self
+    }
+
+    /// Canonicalizes the results of the eigenvalue decompositions and SVDs,
+    /// see [`mdarray_linalg::gauge`].
+    #[must_use]
+    pub fn config_gauge(mut self, gauge: Gauge) -> Self {
+        self.gauge = Some(gauge);
+        self
+    }
 }
@@ -7,20 +7,25 @@
 //!     - V^T is n × n       (transpose of right singular vectors, orthogonal)
 //!     - s (Σ) contains min(m, n) singular values (non-negative, sorted in descending order) in the first row
 
-use mdarray::{Array, Dense, Dim, Layout, Shape, Slice};
-use mdarray_linalg::svd::{SVD, SVDDecomp, SVDError};
+use mdarray::{Array, Dense, Dim, Dyn, Layout, Shape, Slice};
+use mdarray_linalg::{
+    gauge::gauge_svd,
+    structured::Uplo,
+    svd::{SVD, SVDDecomp, SVDError},
+};
 use num_complex::ComplexFloat;
+use num_traits::Zero;
 
 use super::{
-    scalar::{LapackScalar, NeedsRwork},
-    simple::gsvd,
+    scalar::{LapackRealScalar, LapackScalar, NeedsRwork},
+    simple::{bdsdc, gsvd, gsvdx},
 };
 use crate::Lapack;
 
 impl<T, D> SVD<T, D> for Lapack
 where
     T: ComplexFloat + Default + LapackScalar + NeedsRwork,
-    T::Real: Into<T>,
+    T::Real: Into<T> + ComplexFloat<Real = T::Real> + LapackRealScalar,
     D: Dim,
 {
     type SingularValue = T;
@@ -50,7 +55,13 @@ where
             self.svd_config,
             true,
         ) {
-            Ok(_) => Ok(SVDDecomp { s, u, vt }),
+            Ok(_) => {
+                let mut decomp = SVDDecomp { s, u, vt };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
+            }
             Err(e) => Err(e),
         }
     }
@@ -80,11 +91,55 @@ where
             self.svd_config,
             false,
         ) {
-            Ok(_) => Ok(SVDDecomp { s, u, vt }),
+            Ok(_) => {
+                let mut decomp = SVDDecomp { s, u, vt };
+                if let Some(gauge) = &self.gauge {
+                    decomp.canonicalize(gauge);
+                }
+                Ok(decomp)
+            }
             Err(e) => Err(e),
         }
     }
 
+    // Computes the k largest singular triplets with new allocated matrices
+    fn svd_partial<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        k: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        self.svd_range(a, 0, k)
+    }
+
+    // Computes the singular triplets lo..hi with new allocated matrices
+    fn svd_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        lo: usize,
+        hi: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let k = hi.saturating_sub(lo);
+
+        let mut s = vec![T::Real::zero(); m.min(n)];
+        let mut u = vec![T::default(); m * k];
+        let mut vt = vec![T::default(); k * n];
+
+        gsvdx(a, lo, hi, &mut s, &mut u, &mut vt)?;
+
+        // LAPACK returns U (ldu = m) and VT (ldvt = k) column-major.
+        let mut decomp = SVDDecomp {
+            s: Array::from_fn((k,), |i| s[i[0]].into()),
+            u: Array::from_fn((m, k), |i| u[i[0] + i[1] * m]),
+            vt: Array::from_fn((k, n), |i| vt[i[0] + i[1] * k]),
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
     // Computes only singular values with new allocated matrix
     fn svd_s<L: Layout>(
         &self,
@@ -116,12 +171,16 @@ where
         let compute_full_svd_vectors = u.shape().0 == u.shape().1;
         gsvd(
             a,
-            s,
-            Some(u),
-            Some(vt),
+            &mut *s,
+            Some(&mut *u),
+            Some(&mut *vt),
             self.svd_config,
             compute_full_svd_vectors,
-        )
+        )?;
+        if let Some(gauge) = &self.gauge {
+            gauge_svd(gauge, s, u, vt);
+        }
+        Ok(())
     }
 
     // Computes only singular values, overwriting existing matrix
@@ -132,4 +191,46 @@ where
     ) -> Result<(), SVDError> {
         gsvd::<T, D, L, Ls, Dense, Dense>(a, s, None, None, self.svd_config, false)
     }
+
+    // Computes the SVD of a bidiagonal matrix with bdsdc
+    fn svd_bidiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<SVDDecomp<T::Real, T::Real, D>, SVDError> {
+        let n = d.len();
+        let mut s: Vec<T::Real> = d.iter().copied().collect();
+        let e: Vec<T::Real> = e.iter().copied().collect();
+        let mut u = vec![T::Real::zero(); n * n];
+        let mut vt = vec![T::Real::zero(); n * n];
+
+        bdsdc(uplo, &mut s, &e, Some((&mut u, &mut vt)))?;
+
+        // LAPACK returns U and VT column-major with leading dimension n.
+        let mut decomp = SVDDecomp {
+            s: Array::from_fn(<(D,) as Shape>::from_dims(&[n]), |i| s[i[0]]),
+            u: Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| u[i[0] + i[1] * n]),
+            vt: Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| vt[i[0] + i[1] * n]),
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    // Computes only the singular values of a bidiagonal matrix with bdsdc
+    fn svd_bidiagonal_s<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<Array<T::Real, (D,)>, SVDError> {
+        let mut s: Vec<T::Real> = d.iter().copied().collect();
+        let e: Vec<T::Real> = e.iter().copied().collect();
+
+        bdsdc(uplo, &mut s, &e, None)?;
+
+        Ok(Array::from_fn(<(D,) as Shape>::from_dims(&[s.len()]), |i| s[i[0]]))
+    }
 }
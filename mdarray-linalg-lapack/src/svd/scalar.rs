@@ -1,3 +1,5 @@
+use std::ffi::c_void;
+
 use num_complex::Complex;
 use paste::paste;
 
@@ -38,6 +40,31 @@ pub(super) trait LapackScalar {
         rwork: *mut Self,
         info: *mut i32,
     );
+
+    unsafe fn lapack_gesvdx(
+        jobu: i8,
+        jobvt: i8,
+        range: i8,
+        m: i32,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        vl: *const c_void,
+        vu: *const c_void,
+        il: i32,
+        iu: i32,
+        ns: *mut i32,
+        s: *mut c_void,
+        u: *mut Self,
+        ldu: i32,
+        vt: *mut Self,
+        ldvt: i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut c_void,
+        iwork: *mut i32,
+        info: *mut i32,
+    );
 }
 
 macro_rules! impl_lapack_scalar_real {
@@ -122,6 +149,60 @@ macro_rules! impl_lapack_scalar_real {
                     }
                 }
             }
+
+            #[inline]
+            unsafe fn lapack_gesvdx(
+                jobu: i8,
+                jobvt: i8,
+                range: i8,
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: *const c_void,
+                vu: *const c_void,
+                il: i32,
+                iu: i32,
+                ns: *mut i32,
+                s: *mut c_void,
+                u: *mut Self,
+                ldu: i32,
+                vt: *mut Self,
+                ldvt: i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut c_void, // unused for real types
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gesvdx_>](
+                            &jobu as *const i8,
+                            &jobvt as *const i8,
+                            &range as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            vl as *const _,
+                            vu as *const _,
+                            &il as *const i32,
+                            &iu as *const i32,
+                            ns,
+                            s as *mut _,
+                            u as *mut _,
+                            &ldu as *const i32,
+                            vt as *mut _,
+                            &ldvt as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork,
+                            info,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -229,6 +310,61 @@ macro_rules! impl_lapack_scalar_cplx {
                     }
                 }
             }
+
+            #[inline]
+            unsafe fn lapack_gesvdx(
+                jobu: i8,
+                jobvt: i8,
+                range: i8,
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: *const c_void,
+                vu: *const c_void,
+                il: i32,
+                iu: i32,
+                ns: *mut i32,
+                s: *mut c_void,
+                u: *mut Self,
+                ldu: i32,
+                vt: *mut Self,
+                ldvt: i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut c_void,
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gesvdx_>](
+                            &jobu as *const i8,
+                            &jobvt as *const i8,
+                            &range as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            vl as *const sv_cast!($prefix),
+                            vu as *const sv_cast!($prefix),
+                            &il as *const i32,
+                            &iu as *const i32,
+                            ns,
+                            s as *mut sv_cast!($prefix),
+                            u as *mut lapack_sys_cast!($prefix),
+                            &ldu as *const i32,
+                            vt as *mut lapack_sys_cast!($prefix),
+                            &ldvt as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork as *mut sv_cast!($prefix),
+                            iwork,
+                            info,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -238,10 +374,81 @@ impl_lapack_scalar_real!(f64, d);
 impl_lapack_scalar_cplx!(Complex<f32>, c);
 impl_lapack_scalar_cplx!(Complex<f64>, z);
 
+/// LAPACK routines that only exist for real scalars.
+#[allow(clippy::too_many_arguments)]
+pub(super) trait LapackRealScalar: Sized {
+    // Bidiagonal SVD, divide and conquer (BDSDC)
+    unsafe fn lapack_bdsdc(
+        uplo: i8,
+        compq: i8,
+        n: i32,
+        d: *mut Self,
+        e: *mut Self,
+        u: *mut Self,
+        ldu: i32,
+        vt: *mut Self,
+        ldvt: i32,
+        q: *mut Self,
+        iq: *mut i32,
+        work: *mut Self,
+        iwork: *mut i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_real_scalar {
+    ($t:ty, $prefix:ident) => {
+        impl LapackRealScalar for $t {
+            #[inline]
+            unsafe fn lapack_bdsdc(
+                uplo: i8,
+                compq: i8,
+                n: i32,
+                d: *mut Self,
+                e: *mut Self,
+                u: *mut Self,
+                ldu: i32,
+                vt: *mut Self,
+                ldvt: i32,
+                q: *mut Self,
+                iq: *mut i32,
+                work: *mut Self,
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix bdsdc_>](
+                            &uplo as *const i8,
+                            &compq as *const i8,
+                            &n as *const i32,
+                            d,
+                            e,
+                            u,
+                            &ldu as *const i32,
+                            vt,
+                            &ldvt as *const i32,
+                            q,
+                            iq,
+                            work,
+                            iwork,
+                            info,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_real_scalar!(f32, s);
+impl_lapack_real_scalar!(f64, d);
+
 pub(super) trait NeedsRwork {
     type RworkType;
     type Elem;
     fn rwork_len(m: i32, n: i32) -> usize;
+    fn rwork_len_gesvdx(m: i32, n: i32) -> usize;
     fn lwork_from_query(query: &Self::Elem) -> i32;
     fn allocate(lwork: i32) -> Vec<Self::Elem>;
 }
@@ -256,6 +463,10 @@ macro_rules! impl_needs_rwork {
                 0
             }
 
+            fn rwork_len_gesvdx(_: i32, _: i32) -> usize {
+                0
+            }
+
             fn lwork_from_query(query: &Self::Elem) -> i32 {
                 *query as i32
             }
@@ -275,6 +486,11 @@ macro_rules! impl_needs_rwork {
                 2 * (m + n) as usize
             }
 
+            fn rwork_len_gesvdx(m: i32, n: i32) -> usize {
+                let min_mn = m.min(n) as usize;
+                min_mn * (min_mn * 2 + 15 * min_mn)
+            }
+
             fn lwork_from_query(query: &Self::Elem) -> i32 {
                 query.re as i32
             }
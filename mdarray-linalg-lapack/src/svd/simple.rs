@@ -1,13 +1,15 @@
-use std::ptr::null_mut;
+use std::{ffi::c_void, ptr::null_mut};
 
 use mdarray::{DArray, Dim, Layout, Shape, Slice};
 use mdarray_linalg::{
+    structured::Uplo,
     svd::SVDError,
     utils::{into_i32, transpose_in_place},
 };
 use num_complex::ComplexFloat;
+use num_traits::{Float, Zero};
 
-use super::scalar::{LapackScalar, NeedsRwork};
+use super::scalar::{LapackRealScalar, LapackScalar, NeedsRwork};
 use crate::SVDConfig;
 
 pub(super) fn gsvd<
@@ -157,6 +159,131 @@ where
     }
 }
 
+/// Computes the singular triplets `lo..hi` with gesvdx.
+///
+/// `s` receives the `k = hi - lo` singular values, `u` the m × k left singular
+/// vectors and `vt` the k × n right singular vectors, both column-major.
+pub(super) fn gsvdx<T: ComplexFloat + Default + LapackScalar + NeedsRwork, D: Dim, La: Layout>(
+    a: &mut Slice<T, (D, D), La>,
+    lo: usize,
+    hi: usize,
+    s: &mut [T::Real],
+    u: &mut [T],
+    vt: &mut [T],
+) -> Result<(), SVDError>
+where
+    T::Real: Into<T>,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let min_mn = m.min(n);
+
+    if lo > hi || hi > min_mn {
+        return Err(SVDError::InvalidRange);
+    }
+    if lo == hi {
+        return Ok(());
+    }
+
+    let k = hi - lo;
+    assert_eq!(s.len(), min_mn, "s must have min(m, n) elements");
+    assert_eq!(u.len(), m * k, "U must have m × k elements");
+    assert_eq!(vt.len(), k * n, "VT must have k × n elements");
+
+    let row_major = a.stride(1) == 1;
+    assert!(
+        row_major || a.stride(0) == 1,
+        "a must be contiguous in one dimension"
+    );
+
+    if row_major {
+        transpose_in_place(a)
+    };
+
+    let (m, n, k) = (into_i32(m), into_i32(n), into_i32(k));
+    // LAPACK uses 1-based inclusive bounds.
+    let (il, iu) = (into_i32(lo + 1), into_i32(hi));
+    let zero = T::Real::zero();
+    let mut ns = 0;
+    let mut info = 0;
+
+    let mut work = T::allocate(1);
+    let mut iwork = vec![0i32; 12 * min_mn];
+    let mut rwork = vec![zero; T::rwork_len_gesvdx(m, n)];
+
+    // First call to query optimal workspace size
+    unsafe {
+        T::lapack_gesvdx(
+            'V' as i8,
+            'V' as i8,
+            'I' as i8,
+            m,
+            n,
+            a.as_mut_ptr(),
+            m,
+            &zero as *const _ as *const c_void,
+            &zero as *const _ as *const c_void,
+            il,
+            iu,
+            &mut ns,
+            s.as_mut_ptr() as *mut c_void,
+            u.as_mut_ptr(),
+            m,
+            vt.as_mut_ptr(),
+            k,
+            work.as_mut_ptr() as *mut _,
+            -1,
+            rwork.as_mut_ptr() as *mut c_void,
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    let lwork = T::lwork_from_query(&work[0]);
+    let mut work = T::allocate(lwork);
+
+    // Actual call
+    unsafe {
+        T::lapack_gesvdx(
+            'V' as i8,
+            'V' as i8,
+            'I' as i8,
+            m,
+            n,
+            a.as_mut_ptr(),
+            m,
+            &zero as *const _ as *const c_void,
+            &zero as *const _ as *const c_void,
+            il,
+            iu,
+            &mut ns,
+            s.as_mut_ptr() as *mut c_void,
+            u.as_mut_ptr(),
+            m,
+            vt.as_mut_ptr(),
+            k,
+            work.as_mut_ptr() as *mut _,
+            lwork,
+            rwork.as_mut_ptr() as *mut c_void,
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        panic!(
+            "Invalid argument to SVD: the {}-th parameter had an illegal value.",
+            -info
+        );
+    } else if info > 0 {
+        Err(SVDError::BackendDidNotConverge {
+            superdiagonals: info,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 fn call_gesdd<T: ComplexFloat + Default + LapackScalar + NeedsRwork, D0: Dim, D1: Dim, La: Layout>(
     a: &mut Slice<T, (D0, D1), La>,
     m: i32,
@@ -314,3 +441,72 @@ where
 
     info
 }
+
+/// Computes the SVD of the n × n bidiagonal matrix with diagonal `d` and
+/// off-diagonal `e` with bdsdc, overwriting `d` with the singular values in
+/// descending order.
+///
+/// With `vectors`, the two slices receive the n × n matrices U and VT,
+/// column-major.
+pub(super) fn bdsdc<R: Float + LapackRealScalar>(
+    uplo: Uplo,
+    d: &mut [R],
+    e: &[R],
+    vectors: Option<(&mut [R], &mut [R])>,
+) -> Result<(), SVDError> {
+    let n = d.len();
+    assert_eq!(e.len(), n.saturating_sub(1), "Off-diagonal must have n - 1 elements");
+    if n == 0 {
+        return Ok(());
+    }
+
+    // bdsdc overwrites the off-diagonal, which needs at least one entry.
+    let mut e: Vec<R> = e.iter().copied().chain(std::iter::once(R::zero())).collect();
+    let uplo = match uplo {
+        Uplo::Upper => b'U',
+        Uplo::Lower => b'L',
+    };
+    let (compq, lwork, u, vt, ld) = match vectors {
+        Some((u, vt)) => {
+            assert_eq!(u.len(), n * n, "U must be n × n");
+            assert_eq!(vt.len(), n * n, "VT must be n × n");
+            (b'I', 3 * n * n + 4 * n, u.as_mut_ptr(), vt.as_mut_ptr(), n)
+        }
+        None => (b'N', 4 * n, null_mut(), null_mut(), 1),
+    };
+    let mut work = vec![R::zero(); lwork];
+    let mut iwork = vec![0i32; 8 * n];
+    let mut info = 0;
+
+    unsafe {
+        R::lapack_bdsdc(
+            uplo as i8,
+            compq as i8,
+            into_i32(n),
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            u,
+            into_i32(ld),
+            vt,
+            into_i32(ld),
+            null_mut(),
+            null_mut(),
+            work.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        panic!(
+            "Invalid argument to BDSDC: the {}-th parameter had an illegal value.",
+            -info
+        );
+    } else if info > 0 {
+        Err(SVDError::BackendDidNotConverge {
+            superdiagonals: info,
+        })
+    } else {
+        Ok(())
+    }
+}
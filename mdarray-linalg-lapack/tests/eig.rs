@@ -1,5 +1,9 @@
 extern crate lapack_src as _;
-use mdarray_linalg::testing::eig::*;
+use mdarray_linalg::{
+    eig::{EigBalance, EigConfig, EighAlgorithm, EighConfig},
+    gauge::Gauge,
+    testing::eig::*,
+};
 use mdarray_linalg_lapack::Lapack;
 
 #[test]
@@ -53,6 +57,46 @@ fn eig_full_complex_singleton() {
     test_eig_full_complex_singleton(&Lapack::default());
 }
 
+#[test]
+fn eigh_values() {
+    test_eigh_values(&Lapack::default());
+}
+
+#[test]
+fn eigh_values_complex_hermitian() {
+    test_eigh_values_complex_hermitian(&Lapack::default());
+}
+
+#[test]
+fn eigh_write() {
+    test_eigh_write(&Lapack::default());
+}
+
+#[test]
+fn eigh_range_index() {
+    test_eigh_range_index(&Lapack::default());
+}
+
+#[test]
+fn eigh_range_value() {
+    test_eigh_range_value(&Lapack::default());
+}
+
+#[test]
+fn eigh_range_complex_hermitian() {
+    test_eigh_range_complex_hermitian(&Lapack::default());
+}
+
+#[test]
+fn eigh_range_invalid() {
+    test_eigh_range_invalid(&Lapack::default());
+}
+
+#[test]
+fn eigh_range_const() {
+    test_eigh_range_const(&Lapack::default());
+}
+
 #[test]
 #[should_panic]
 fn eig_full_non_square() {
@@ -74,3 +118,119 @@ fn schur_decomp() {
 fn schur_decomp_cplx() {
     test_schur_cplx(&Lapack::default());
 }
+
+#[test]
+fn schur_reorder() {
+    test_schur_reorder(&Lapack::default());
+}
+
+#[test]
+fn schur_reorder_cplx() {
+    test_schur_reorder_cplx(&Lapack::default());
+}
+
+#[test]
+fn schur_reorder_invalid() {
+    test_schur_reorder_invalid(&Lapack::default());
+}
+
+#[test]
+fn schur_eigenvectors() {
+    test_schur_eigenvectors(&Lapack::default());
+}
+
+#[test]
+fn schur_eigenvectors_cplx() {
+    test_schur_eigenvectors_cplx(&Lapack::default());
+}
+
+#[test]
+fn eig_condition() {
+    test_eig_condition(&Lapack::default());
+}
+
+#[test]
+fn eig_condition_cplx() {
+    test_eig_condition_cplx(&Lapack::default());
+}
+
+#[test]
+fn eig_badly_scaled() {
+    test_eig_badly_scaled(&Lapack::default());
+}
+
+#[test]
+fn eig_full_without_balancing() {
+    test_eig_full(&Lapack::default().config_eig(EigConfig { balance: EigBalance::None }));
+    test_eig_full_real_complex_pair(&Lapack::default().config_eig(EigConfig { balance: EigBalance::None }));
+}
+
+#[test]
+fn eig_full_permute_only() {
+    test_eig_full(&Lapack::default().config_eig(EigConfig { balance: EigBalance::Permute }));
+    test_eig_full_complex(&Lapack::default().config_eig(EigConfig { balance: EigBalance::Permute }));
+}
+
+#[test]
+fn eig_full_scale_only() {
+    test_eig_full(&Lapack::default().config_eig(EigConfig { balance: EigBalance::Scale }));
+    test_eig_full_complex(&Lapack::default().config_eig(EigConfig { balance: EigBalance::Scale }));
+}
+
+#[test]
+fn eigh_algorithms() {
+    for algorithm in [
+        EighAlgorithm::Qr,
+        EighAlgorithm::DivideAndConquer,
+        EighAlgorithm::Mrrr,
+        EighAlgorithm::Jacobi,
+    ] {
+        let bd = Lapack::default().config_eigh(EighConfig { algorithm });
+        test_eigh_symmetric(&bd);
+        test_eigh_complex_hermitian(&bd);
+        test_eigh_values(&bd);
+        test_eigh_write(&bd);
+        test_eigh_upper_triangle(&bd);
+    }
+}
+
+#[test]
+fn eigh_jacobi_graded() {
+    test_eigh_graded(&Lapack::default().config_eigh(EighConfig { algorithm: EighAlgorithm::Jacobi }));
+}
+
+#[test]
+fn eigh_gauge() {
+    let bd = Lapack::default().config_gauge(Gauge::default());
+    test_eigh_gauge(&bd);
+    test_eigh_gauge_complex(&bd);
+    test_eigh_gauge(&bd.config_eigh(EighConfig { algorithm: EighAlgorithm::Jacobi }));
+}
+
+#[test]
+fn eig_gauge() {
+    test_eig_gauge(&Lapack::default().config_gauge(Gauge::default()));
+}
+
+#[test]
+fn eig_suites_gauge() {
+    let bd = Lapack::default().config_gauge(Gauge::default());
+    test_square_matrix(&bd);
+    test_eig_cplx_square_matrix(&bd);
+    test_eig_full(&bd);
+    test_eig_full_complex(&bd);
+    test_eig_full_real_complex_pair(&bd);
+    test_eig_full_complex_singleton(&bd);
+    test_eigh_symmetric(&bd);
+    test_eigh_complex_hermitian(&bd);
+}
+
+#[test]
+fn eigh_tridiagonal() {
+    test_eigh_tridiagonal(&Lapack::default());
+}
+
+#[test]
+fn eigh_tridiagonal_const() {
+    test_eigh_tridiagonal_const(&Lapack::default());
+}
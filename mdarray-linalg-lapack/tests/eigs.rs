@@ -0,0 +1,59 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::eigs::*;
+use mdarray_linalg::Naive;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn lanczos_largest() {
+    test_lanczos_largest(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lanczos_smallest() {
+    test_lanczos_smallest(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lanczos_complex_hermitian() {
+    test_lanczos_complex_hermitian(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lanczos_shift_invert() {
+    test_lanczos_shift_invert(&Lapack::default(), &Lapack::default());
+}
+
+#[test]
+fn arnoldi_largest_magnitude() {
+    test_arnoldi_largest_magnitude(&Naive, &Lapack::default());
+}
+
+#[test]
+fn arnoldi_smallest_real() {
+    test_arnoldi_smallest_real(&Naive, &Lapack::default());
+}
+
+#[test]
+fn arnoldi_complex() {
+    test_arnoldi_complex(&Naive, &Lapack::default());
+}
+
+#[test]
+fn shift_invert_invalid() {
+    test_shift_invert_invalid(&Lapack::default());
+}
+
+#[test]
+fn arnoldi_shift_invert() {
+    test_arnoldi_shift_invert(&Lapack::default(), &Lapack::default());
+}
+
+#[test]
+fn eigs_not_converged() {
+    test_eigs_not_converged(&Naive, &Lapack::default());
+}
+
+#[test]
+fn eigs_invalid_dimensions() {
+    test_eigs_invalid_dimensions(&Lapack::default());
+}
@@ -0,0 +1,43 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::geig::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn geig() {
+    test_geig(&Lapack::default());
+}
+
+#[test]
+fn geig_cplx() {
+    test_geig_cplx(&Lapack::default());
+}
+
+#[test]
+fn geig_values() {
+    test_geig_values(&Lapack::default());
+}
+
+#[test]
+fn qz() {
+    test_qz(&Lapack::default());
+}
+
+#[test]
+fn qz_cplx() {
+    test_qz_cplx(&Lapack::default());
+}
+
+#[test]
+fn qz_reorder() {
+    test_qz_reorder(&Lapack::default());
+}
+
+#[test]
+fn qz_reorder_cplx() {
+    test_qz_reorder_cplx(&Lapack::default());
+}
+
+#[test]
+fn geig_invalid() {
+    test_geig_invalid(&Lapack::default());
+}
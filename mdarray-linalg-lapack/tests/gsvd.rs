@@ -0,0 +1,33 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::gsvd::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn gsvd() {
+    test_gsvd(&Lapack::default());
+}
+
+#[test]
+fn gsvd_wide() {
+    test_gsvd_wide(&Lapack::default());
+}
+
+#[test]
+fn gsvd_rank_deficient() {
+    test_gsvd_rank_deficient(&Lapack::default());
+}
+
+#[test]
+fn gsvd_identity() {
+    test_gsvd_identity(&Lapack::default());
+}
+
+#[test]
+fn gsvd_complex() {
+    test_gsvd_complex(&Lapack::default());
+}
+
+#[test]
+fn gsvd_shape_mismatch() {
+    test_gsvd_shape_mismatch(&Lapack::default());
+}
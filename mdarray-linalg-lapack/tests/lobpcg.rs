@@ -0,0 +1,34 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::lobpcg::*;
+use mdarray_linalg::Naive;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn lobpcg_smallest() {
+    test_lobpcg_smallest(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lobpcg_largest() {
+    test_lobpcg_largest(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lobpcg_generalized() {
+    test_lobpcg_generalized(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lobpcg_clustered() {
+    test_lobpcg_clustered(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lobpcg_complex() {
+    test_lobpcg_complex(&Naive, &Lapack::default());
+}
+
+#[test]
+fn lobpcg_errors() {
+    test_lobpcg_errors(&Naive, &Lapack::default());
+}
@@ -0,0 +1,48 @@
+extern crate lapack_src as _;
+use mdarray_linalg::{Naive, testing::polar::*};
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn polar_svd() {
+    test_polar_svd(&Lapack::default(), &Naive);
+}
+
+#[test]
+fn polar_svd_complex() {
+    test_polar_svd_complex(&Lapack::default(), &Naive);
+}
+
+#[test]
+fn polar_svd_rank_deficient() {
+    test_polar_svd_rank_deficient(&Lapack::default(), &Naive);
+}
+
+#[test]
+fn polar_newton() {
+    test_polar_newton(&Naive, &Lapack::default());
+}
+
+#[test]
+fn polar_newton_complex() {
+    test_polar_newton_complex(&Naive, &Lapack::default());
+}
+
+#[test]
+fn polar_newton_not_square() {
+    test_polar_newton_not_square(&Naive, &Lapack::default());
+}
+
+#[test]
+fn polar_qdwh() {
+    test_polar_qdwh(&Naive, &Lapack::default());
+}
+
+#[test]
+fn polar_qdwh_complex() {
+    test_polar_qdwh_complex(&Naive, &Lapack::default());
+}
+
+#[test]
+fn polar_qdwh_rank_deficient() {
+    test_polar_qdwh_rank_deficient(&Naive, &Lapack::default());
+}
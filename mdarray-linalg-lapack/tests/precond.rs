@@ -0,0 +1,14 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::precond::*;
+use mdarray_linalg::Naive;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn block_jacobi() {
+    test_block_jacobi(&Lapack::default());
+}
+
+#[test]
+fn block_jacobi_cg() {
+    test_block_jacobi_cg(&Naive, &Naive, &Lapack::default());
+}
@@ -0,0 +1,28 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::riccati::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn care() {
+    test_care(&Lapack::default());
+}
+
+#[test]
+fn care_complex() {
+    test_care_complex(&Lapack::default());
+}
+
+#[test]
+fn dare() {
+    test_dare(&Lapack::default());
+}
+
+#[test]
+fn dare_complex() {
+    test_dare_complex(&Lapack::default());
+}
+
+#[test]
+fn riccati_errors() {
+    test_riccati_errors(&Lapack::default());
+}
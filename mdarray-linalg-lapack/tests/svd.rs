@@ -1,5 +1,5 @@
 extern crate lapack_src as _;
-use mdarray_linalg::testing::svd::*;
+use mdarray_linalg::{gauge::Gauge, testing::svd::*};
 use mdarray_linalg_lapack::{Lapack, SVDConfig};
 
 #[test]
@@ -31,3 +31,64 @@ fn test_backend_svd_random_matrix() {
 fn test_backend_svd_cplx_square_matrix() {
     test_svd_cplx_square_matrix(&Lapack::default());
 }
+
+#[test]
+fn test_backend_svd_partial() {
+    test_svd_partial(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_range() {
+    test_svd_range(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_partial_const() {
+    test_svd_partial_const(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_range_invalid() {
+    test_svd_range_invalid(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_max_rank() {
+    test_svd_truncate_max_rank(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_cutoffs() {
+    test_svd_truncate_cutoffs(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_renormalize_absorb() {
+    test_svd_truncate_renormalize_absorb(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_gauge() {
+    test_svd_gauge(&Lapack::default().config_gauge(Gauge::default()));
+}
+
+#[test]
+fn test_backend_svd_suites_gauge() {
+    let bd = Lapack::default().config_gauge(Gauge::default());
+    test_svd_square_matrix(&bd);
+    test_svd_rectangular_m_gt_n(&bd);
+    test_svd_rectangular_n_gt_m(&bd);
+    test_svd_random_matrix(&bd);
+    test_svd_cplx_square_matrix(&bd);
+    test_svd_cplx_random_matrix(&bd);
+}
+
+#[test]
+fn test_backend_svd_bidiagonal() {
+    test_svd_bidiagonal(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_bidiagonal_const() {
+    test_svd_bidiagonal_const(&Lapack::default());
+}
@@ -0,0 +1,33 @@
+extern crate lapack_src as _;
+use mdarray_linalg::testing::sylvester::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn sylvester() {
+    test_sylvester(&Lapack::default());
+}
+
+#[test]
+fn sylvester_complex() {
+    test_sylvester_complex(&Lapack::default());
+}
+
+#[test]
+fn lyapunov() {
+    test_lyapunov(&Lapack::default());
+}
+
+#[test]
+fn lyapunov_complex() {
+    test_lyapunov_complex(&Lapack::default());
+}
+
+#[test]
+fn sylvester_scale() {
+    test_sylvester_scale(&Lapack::default());
+}
+
+#[test]
+fn sylvester_errors() {
+    test_sylvester_errors(&Lapack::default());
+}
@@ -1,15 +1,23 @@
-use mdarray::{Array, Dense, Dim, Layout, Shape, Slice};
-use mdarray_linalg::eig::{Eig, EigDecomp, EigError, EighDecomp, SchurDecomp, SchurError};
+use mdarray::{Array, Dense, Dim, Dyn, Layout, Shape, Slice};
+use mdarray_linalg::{
+    eig::{
+        Eig, EigCondition, EigDecomp, EigError, EigSide, EighAlgorithm, EighDecomp, EighRange,
+        SchurCondition, SchurDecomp, SchurError, SchurReorder, SchurSelect, balance,
+        eig_condition, jacobi_eigh, reorder_schur, schur_eigenvectors, select_eigh,
+        tridiagonal_eigh,
+    },
+    gauge::{gauge_eig, gauge_eig_condition, gauge_eigh},
+};
 use num_complex::{Complex, ComplexFloat};
 use num_traits::Zero;
 
-use super::simple::{eig_values_from_complex_matrix, eig_vectors_from_complex_matrix, eigendecomp, schur, schur_complex};
-use crate::{Nalgebra, to_complex_dmatrix, to_dmatrix, write_dmatrix};
+use super::simple::{complex_schur, eig_values_from_complex_matrix, eig_vectors_from_complex_matrix, eigendecomp, schur, schur_complex, schur_to_eig_error};
+use crate::{Nalgebra, to_dmatrix, write_dmatrix};
 
 impl<T, D0, D1> Eig<T, D0, D1> for Nalgebra
 where
     T: ComplexFloat + Copy + Zero + nalgebra::ComplexField<RealField = T::Real>,
-    T::Real: nalgebra::RealField + Copy,
+    T::Real: nalgebra::RealField + ComplexFloat<Real = T::Real> + Copy,
     D0: Dim,
     D1: Dim,
 {
@@ -22,15 +30,20 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
+        let balancing = balance(a, self.eig_config.balance);
         let (eigenvalues_nalgebra, right_nalgebra) = eigendecomp(a)?;
+        let mut right = eig_vectors_from_complex_matrix::<T::Real, D0, D1>(&right_nalgebra);
+        balancing.unbalance(&mut right, EigSide::Right);
 
-        Ok(EigDecomp {
+        let mut decomp = EigDecomp {
             eigenvalues: eig_values_from_complex_matrix::<T::Real, D0>(&eigenvalues_nalgebra),
             left_eigenvectors: None,
-            right_eigenvectors: Some(eig_vectors_from_complex_matrix::<T::Real, D0, D1>(
-                &right_nalgebra,
-            )),
-        })
+            right_eigenvectors: Some(right),
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
     }
 
     fn eig_full<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, EigError> {
@@ -39,22 +52,27 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
+        let balancing = balance(a, self.eig_config.balance);
         let (eigenvalues_nalgebra, right_nalgebra) = eigendecomp(a)?;
         let left_nalgebra = right_nalgebra
             .clone()
             .try_inverse()
             .ok_or(EigError::BackendError(-1))?
             .adjoint();
+        let mut left = eig_vectors_from_complex_matrix::<T::Real, D0, D1>(&left_nalgebra);
+        let mut right = eig_vectors_from_complex_matrix::<T::Real, D0, D1>(&right_nalgebra);
+        balancing.unbalance(&mut left, EigSide::Left);
+        balancing.unbalance(&mut right, EigSide::Right);
 
-        Ok(EigDecomp {
+        let mut decomp = EigDecomp {
             eigenvalues: eig_values_from_complex_matrix::<T::Real, D0>(&eigenvalues_nalgebra),
-            left_eigenvectors: Some(eig_vectors_from_complex_matrix::<T::Real, D0, D1>(
-                &left_nalgebra,
-            )),
-            right_eigenvectors: Some(eig_vectors_from_complex_matrix::<T::Real, D0, D1>(
-                &right_nalgebra,
-            )),
-        })
+            left_eigenvectors: Some(left),
+            right_eigenvectors: Some(right),
+        };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
     }
 
     fn eig_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::SpectralScalar, (D0,)>, EigError> {
@@ -63,11 +81,26 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
-        let schur = to_complex_dmatrix(a).schur();
-        let (_, t_nalgebra) = schur.unpack();
+        balance(a, self.eig_config.balance);
+        let (_, t_nalgebra) = complex_schur(a).map_err(schur_to_eig_error)?;
         let eigenvalues_nalgebra = t_nalgebra.diagonal();
 
-        Ok(eig_values_from_complex_matrix::<T::Real, D0>(&eigenvalues_nalgebra))
+        let mut eigenvalues = eig_values_from_complex_matrix::<T::Real, D0>(&eigenvalues_nalgebra);
+        if let Some(gauge) = &self.gauge {
+            gauge_eig::<_, D0, D1>(gauge, &mut eigenvalues, None, None);
+        }
+        Ok(eigenvalues)
+    }
+
+    fn eig_condition<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(EigDecomp<Self::SpectralScalar, D0, D1>, EigCondition<Self::RealScalar, D0>), EigError> {
+        let (mut decomp, mut condition) = eig_condition(self, a, self.eig_config.balance)?;
+        if let Some(gauge) = &self.gauge {
+            gauge_eig_condition(gauge, &mut decomp, &mut condition);
+        }
+        Ok((decomp, condition))
     }
 
     fn eigh<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<EighDecomp<T, Self::RealScalar, D0, D1>, EigError> {
@@ -76,24 +109,138 @@ where
             return Err(EigError::NotSquareMatrix);
         }
 
-        let eig = to_dmatrix(a).symmetric_eigen();
         let mut eigenvalues = Array::from_elem(<(D0,) as Shape>::from_dims(&[n]), T::Real::zero());
         let mut eigenvectors = Array::from_elem(<(D0, D1) as Shape>::from_dims(&[m, n]), T::zero());
+        self.eigh_write(a, &mut eigenvalues, &mut eigenvectors)?;
 
-        for (dst, src) in eigenvalues.iter_mut().zip(eig.eigenvalues.iter()) {
-            *dst = *src;
+        Ok(EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
+    fn eigh_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+        eigenvectors: &mut Slice<T, (D0, D1), Dense>,
+    ) -> Result<(), EigError> {
+        let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
         }
+        assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+
+        // nalgebra only offers QR iteration; Jacobi rotations are provided
+        // by mdarray_linalg.
+        if self.eigh_config.algorithm == EighAlgorithm::Jacobi {
+            let decomp = jacobi_eigh(a)?;
+            for j in 0..n {
+                eigenvalues[j] = decomp.eigenvalues[j];
+                for i in 0..n {
+                    eigenvectors[[i, j]] = decomp.eigenvectors[[i, j]];
+                }
+            }
+        } else {
+            // nalgebra reads the lower triangle, which for A^H holds the
+            // upper triangle of A that the other algorithms and LAPACK read.
+            let eig = to_dmatrix(a).adjoint().symmetric_eigen();
 
-        for i in 0..eig.eigenvectors.nrows() {
-            for j in 0..eig.eigenvectors.ncols() {
-                eigenvectors[[i, j]] = eig.eigenvectors[(i, j)];
+            for (dst, src) in eigenvalues.iter_mut().zip(eig.eigenvalues.iter()) {
+                *dst = *src;
+            }
+
+            for i in 0..eig.eigenvectors.nrows() {
+                for j in 0..eig.eigenvectors.ncols() {
+                    eigenvectors[[i, j]] = eig.eigenvectors[(i, j)];
+                }
             }
         }
 
-        Ok(EighDecomp {
-            eigenvalues,
-            eigenvectors,
-        })
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh(gauge, eigenvalues, Some(eigenvectors));
+        }
+
+        Ok(())
+    }
+
+    fn eigh_values<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<Array<Self::RealScalar, (D0,)>, EigError> {
+        let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+
+        let mut eigenvalues = Array::from_elem(<(D0,) as Shape>::from_dims(&[n]), T::Real::zero());
+        self.eigh_values_write(a, &mut eigenvalues)?;
+
+        Ok(eigenvalues)
+    }
+
+    fn eigh_values_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+    ) -> Result<(), EigError> {
+        let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+        assert_eq!(eigenvalues.len(), n, "Eigenvalues must have n elements");
+
+        if self.eigh_config.algorithm == EighAlgorithm::Jacobi {
+            let decomp = jacobi_eigh(a)?;
+            for i in 0..n {
+                eigenvalues[i] = decomp.eigenvalues[i];
+            }
+        } else {
+            for (dst, src) in eigenvalues.iter_mut().zip(to_dmatrix(a).adjoint().symmetric_eigenvalues().iter()) {
+                *dst = *src;
+            }
+        }
+
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, D0, D1, Dense>(gauge, eigenvalues, None);
+        }
+
+        Ok(())
+    }
+
+    fn eigh_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<T, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let mut decomp = select_eigh(self.eigh(a)?, &range)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    fn eigh_tridiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<Self::RealScalar, Self::RealScalar, Dyn, Dyn>, EigError> {
+        let mut decomp = tridiagonal_eigh(d, e, &range, true)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    fn eigh_tridiagonal_values<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<Array<Self::RealScalar, (Dyn,)>, EigError> {
+        let mut eigenvalues = tridiagonal_eigh(d, e, &range, false)?.eigenvalues;
+        if let Some(gauge) = &self.gauge {
+            gauge_eigh::<T, Dyn, Dyn, Dense>(gauge, &mut eigenvalues, None);
+        }
+        Ok(eigenvalues)
     }
 
     fn schur<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<SchurDecomp<T, D0, D1>, SchurError> {
@@ -147,4 +294,44 @@ where
         }
         Ok(())
     }
+
+    fn schur_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        reorder_schur(t, z, &select, condition)
+    }
+
+    fn schur_complex_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &mut Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError> {
+        reorder_schur(t, z, &select, condition)
+    }
+
+    fn schur_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<T, (D0, D1), Lt>,
+        z: &Slice<T, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        schur_eigenvectors(t, z, side, select.as_ref())
+    }
+
+    fn schur_complex_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError> {
+        schur_eigenvectors(t, z, side, select.as_ref())
+    }
 }
@@ -1,16 +1,63 @@
 use mdarray::{Array, Dim, Layout, Shape, Slice};
 use mdarray_linalg::eig::{EigError, SchurDecomp, SchurError};
+use nalgebra::{DMatrix, DVector};
 use num_complex::{Complex, ComplexFloat};
-use num_traits::Zero;
+use num_traits::{Float, Zero};
 
 use crate::{
     to_complex_dmatrix, to_dmatrix, write_complex_dmatrix, write_complex_dvector, write_dmatrix,
 };
 
+/// Schur vectors and complex Schur form `(Z, T)`.
+type SchurFactors<R> = (DMatrix<Complex<R>>, DMatrix<Complex<R>>);
+
+/// Eigenvalues and right eigenvectors.
+type Eigenpairs<R> = (DVector<Complex<R>>, DMatrix<Complex<R>>);
+
+/// Computes the complex Schur form `A = Z T Z^H`, returning `(Z, T)`.
+///
+/// nalgebra's default deflation threshold of one machine epsilon can stall the
+/// QR iteration forever, so a slightly looser threshold is used and the
+/// iterations are bounded so that failures surface as errors.
+pub(super) fn complex_schur<T, D0, D1, L>(
+    a: &Slice<T, (D0, D1), L>,
+) -> Result<SchurFactors<T::Real>, SchurError>
+where
+    T: ComplexFloat,
+    T::Real: nalgebra::RealField + Copy,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let n = a.shape().dim(0);
+    let eps = <T::Real as Float>::epsilon() * nalgebra::convert(4.0);
+    let max_iterations = 100 * n.max(10);
+    let schur = to_complex_dmatrix(a).try_schur(eps, max_iterations).ok_or(
+        SchurError::BackendDidNotConverge {
+            iterations: max_iterations as i32,
+        },
+    )?;
+    Ok(schur.unpack())
+}
+
+/// Converts a failure of [`complex_schur`], keeping the iteration count.
+pub(super) fn schur_to_eig_error(e: SchurError) -> EigError {
+    match e {
+        SchurError::BackendDidNotConverge { iterations } => {
+            EigError::BackendDidNotConverge { iterations }
+        }
+        SchurError::BackendError(code) => EigError::BackendError(code),
+        SchurError::NotSquareMatrix => EigError::NotSquareMatrix,
+        SchurError::InvalidSelection | SchurError::ReorderFailed => {
+            unreachable!("complex_schur neither selects nor reorders eigenvalues")
+        }
+    }
+}
+
 /// Compute a complex eigendecomposition from the complex Schur form.
 pub(super) fn eigendecomp<T, D0, D1, L>(
     a: &Slice<T, (D0, D1), L>,
-) -> Result<(nalgebra::DVector<Complex<T::Real>>, nalgebra::DMatrix<Complex<T::Real>>), EigError>
+) -> Result<Eigenpairs<T::Real>, EigError>
 where
     T: ComplexFloat,
     T::Real: nalgebra::RealField + Copy,
@@ -19,8 +66,8 @@ where
     L: Layout,
 {
     let dim = a.shape().dim(0);
-    let schur = to_complex_dmatrix(a).schur();
-    let (mut eigenvectors, mut schur_form) = schur.unpack();
+    let (mut eigenvectors, mut schur_form) =
+        complex_schur(a).map_err(schur_to_eig_error)?;
 
     for j in 1..dim {
         for i in 0..j {
@@ -110,8 +157,7 @@ where
     D1: Dim,
     L: Layout,
 {
-    let schur = to_complex_dmatrix(a).schur();
-    let (z_nalgebra, t_nalgebra) = schur.unpack();
+    let (z_nalgebra, t_nalgebra) = complex_schur(a)?;
     let shape = *a.shape();
 
     let mut t = Array::from_elem(shape, Complex::new(T::Real::zero(), T::Real::zero()));
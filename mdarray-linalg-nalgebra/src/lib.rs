@@ -18,7 +18,7 @@
 //! - **Level 2** — matrix-vector & outer product: `matvec`, `outer`
 //! - **Level 3** — matrix multiplication: `matmul`
 //! - **Tensor contraction** — `contract_all`, `contract_n`, `contract_pairs`, `contract`
-//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eigh`
+//! - **Eigenvalue decomposition** — `eig`, `eig_full`, `eig_values`, `eig_condition`, `eigh`, `eigh_values`
 //! - **Schur decomposition** — `schur`, `schur_complex`
 //! - **SVD** — `svd`, `svd_thin`, `svd_s`
 //! - **LU decomposition** — `lu`, `det`, `inv`
@@ -112,10 +112,44 @@ mod svd;
 /// to the pure-Rust `nalgebra` library.  Nalgebra is particularly well-suited
 /// for **small matrices** where its compile-time dimension optimizations
 /// provide excellent performance without the overhead of system BLAS/LAPACK.
-#[derive(Default)]
-pub struct Nalgebra;
+#[derive(Debug, Default, Clone)]
+pub struct Nalgebra {
+    eig_config: EigConfig,
+    eigh_config: EighConfig,
+    gauge: Option<Gauge>,
+}
+
+impl Nalgebra {
+    /// Configures the general eigenvalue decompositions.
+    #[must_use]
+    pub fn config_eig(mut self, config: EigConfig) -> Self {
+        self.eig_config = config;
+        self
+    }
+
+    /// Configures the self-adjoint eigenvalue decomposition.  nalgebra only
+    /// offers QR iteration, and Jacobi rotations are provided by
+    /// `mdarray_linalg`; other algorithms fall back to QR iteration.
+    #[must_use]
+    pub fn config_eigh(mut self, config: EighConfig) -> Self {
+        self.eigh_config = config;
+        self
+    }
+
+    /// Canonicalizes the results of the eigenvalue decompositions and SVDs,
+    /// see [`mdarray_linalg::gauge`].
+    #[must_use]
+    pub fn config_gauge(mut self, gauge: Gauge) -> Self {
+        self.gauge = Some(gauge);
+        self
+    }
+}
 
 use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    eig::{EigConfig, EighConfig},
+    gauge::Gauge,
+};
 use num_complex::{Complex, ComplexFloat};
 
 /// Copy an mdarray matrix into a dense nalgebra matrix.
@@ -1,5 +1,9 @@
-use mdarray::{Array, Dim, Layout, Shape, Slice};
-use mdarray_linalg::svd::{SVD, SVDDecomp, SVDError};
+use mdarray::{Array, Dim, Dyn, Layout, Shape, Slice};
+use mdarray_linalg::{
+    gauge::gauge_svd,
+    structured::Uplo,
+    svd::{SVD, SVDDecomp, SVDError, jacobi_svd_bidiagonal, select_svd},
+};
 use num_complex::ComplexFloat;
 use num_traits::Zero;
 
@@ -38,6 +42,27 @@ where
     Ok(())
 }
 
+/// Extends the orthonormal columns of `q` to `cols` orthonormal columns with
+/// a basis of their orthogonal complement.
+fn complete_columns<T>(q: &nalgebra::DMatrix<T>, cols: usize) -> nalgebra::DMatrix<T>
+where
+    T: nalgebra::ComplexField + Copy,
+{
+    let (m, k) = q.shape();
+    if cols <= k {
+        return q.clone();
+    }
+
+    // The trailing columns of the complete Q factor of q span the
+    // orthogonal complement of its columns.
+    let qr = q.clone().qr();
+    let mut qh = nalgebra::DMatrix::identity(m, m);
+    qr.q_tr_mul(&mut qh);
+    let mut full = qh.adjoint().columns(0, cols).into_owned();
+    full.columns_mut(0, k).copy_from(q);
+    full
+}
+
 /// Copy a possibly thin nalgebra factor into a larger mdarray output.
 fn write_dmatrix_padded<T, D0, D1, L>(src: &nalgebra::DMatrix<T>, dst: &mut Slice<T, (D0, D1), L>)
 where
@@ -78,6 +103,10 @@ where
     let u_nalgebra = svd.u.ok_or(SVDError::BackendError(-1))?;
     let vt_nalgebra = svd.v_t.ok_or(SVDError::BackendError(-1))?;
 
+    // nalgebra only computes the thin factors; complete them to the
+    // requested shapes so that U and V^H are unitary.
+    let u_nalgebra = complete_columns(&u_nalgebra, u.shape().dim(1));
+    let vt_nalgebra = complete_columns(&vt_nalgebra.adjoint(), vt.shape().dim(0)).adjoint();
     write_dmatrix_padded(&u_nalgebra, u);
     write_dmatrix_padded(&vt_nalgebra, vt);
     Ok(())
@@ -86,7 +115,7 @@ where
 impl<T, D> SVD<T, D> for Nalgebra
 where
     T: ComplexFloat + Copy + Zero + nalgebra::ComplexField<RealField = T::Real>,
-    T::Real: nalgebra::RealField + Copy,
+    T::Real: nalgebra::RealField + ComplexFloat<Real = T::Real> + Copy,
     D: Dim,
 {
     type SingularValue = T;
@@ -107,7 +136,11 @@ where
 
         svd_full_impl(a, &mut s, &mut u, &mut vt)?;
 
-        Ok(SVDDecomp { s, u, vt })
+        let mut decomp = SVDDecomp { s, u, vt };
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
     }
 
     fn svd_thin<L: Layout>(
@@ -117,6 +150,27 @@ where
         unimplemented!()
     }
 
+    fn svd_partial<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        k: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        self.svd_range(a, 0, k)
+    }
+
+    fn svd_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        lo: usize,
+        hi: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError> {
+        let mut decomp = select_svd(self.svd(a)?, lo, hi)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
     fn svd_s<L: Layout>(
         &self,
         a: &mut Slice<T, (D, D), L>,
@@ -136,7 +190,11 @@ where
         u: &mut Slice<T, (D, D), Lu>,
         vt: &mut Slice<T, (D, D), Lvt>,
     ) -> Result<(), SVDError> {
-        svd_full_impl(a, s, u, vt)
+        svd_full_impl(a, &mut *s, &mut *u, &mut *vt)?;
+        if let Some(gauge) = &self.gauge {
+            gauge_svd(gauge, s, u, vt);
+        }
+        Ok(())
     }
 
     fn svd_write_s<L: Layout, Ls: Layout>(
@@ -146,4 +204,26 @@ where
     ) -> Result<(), SVDError> {
         svd_values_impl(a, s)
     }
+
+    fn svd_bidiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<SVDDecomp<T::Real, T::Real, D>, SVDError> {
+        let mut decomp = jacobi_svd_bidiagonal(d, e, uplo)?;
+        if let Some(gauge) = &self.gauge {
+            decomp.canonicalize(gauge);
+        }
+        Ok(decomp)
+    }
+
+    fn svd_bidiagonal_s<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<Array<T::Real, (D,)>, SVDError> {
+        Ok(jacobi_svd_bidiagonal(d, e, uplo)?.s)
+    }
 }
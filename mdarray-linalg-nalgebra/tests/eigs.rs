@@ -0,0 +1,57 @@
+use mdarray_linalg::testing::eigs::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn lanczos_largest() {
+    test_lanczos_largest(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lanczos_smallest() {
+    test_lanczos_smallest(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lanczos_complex_hermitian() {
+    test_lanczos_complex_hermitian(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lanczos_shift_invert() {
+    test_lanczos_shift_invert(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn arnoldi_largest_magnitude() {
+    test_arnoldi_largest_magnitude(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn arnoldi_smallest_real() {
+    test_arnoldi_smallest_real(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn arnoldi_complex() {
+    test_arnoldi_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn shift_invert_invalid() {
+    test_shift_invert_invalid(&Nalgebra::default());
+}
+
+#[test]
+fn arnoldi_shift_invert() {
+    test_arnoldi_shift_invert(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn eigs_not_converged() {
+    test_eigs_not_converged(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn eigs_invalid_dimensions() {
+    test_eigs_invalid_dimensions(&Nalgebra::default());
+}
@@ -0,0 +1,62 @@
+use mdarray_linalg::testing::krylov::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn cg() {
+    test_cg(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn cg_preconditioned() {
+    test_cg_preconditioned(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn cg_complex() {
+    test_cg_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn cg_breakdown() {
+    test_cg_breakdown(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn minres_indefinite() {
+    test_minres_indefinite(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn minres_preconditioned() {
+    test_minres_preconditioned(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn gmres() {
+    test_gmres(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn gmres_complex() {
+    test_gmres_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn bicgstab() {
+    test_bicgstab(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn bicgstab_complex() {
+    test_bicgstab_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn krylov_not_converged() {
+    test_krylov_not_converged(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn krylov_trivial() {
+    test_krylov_trivial(&Nalgebra::default(), &Nalgebra::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::lobpcg::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn lobpcg_smallest() {
+    test_lobpcg_smallest(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lobpcg_largest() {
+    test_lobpcg_largest(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lobpcg_generalized() {
+    test_lobpcg_generalized(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lobpcg_clustered() {
+    test_lobpcg_clustered(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lobpcg_complex() {
+    test_lobpcg_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn lobpcg_errors() {
+    test_lobpcg_errors(&Nalgebra::default(), &Nalgebra::default());
+}
@@ -0,0 +1,52 @@
+use mdarray_linalg::testing::polar::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn polar_svd() {
+    test_polar_svd(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_svd_complex() {
+    test_polar_svd_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_svd_rank_deficient() {
+    test_polar_svd_rank_deficient(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_newton() {
+    test_polar_newton(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_newton_complex() {
+    test_polar_newton_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_newton_not_square() {
+    test_polar_newton_not_square(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_qdwh() {
+    test_polar_qdwh(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_qdwh_complex() {
+    test_polar_qdwh_complex(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_qdwh_rank_deficient() {
+    test_polar_qdwh_rank_deficient(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn polar_methods_agree() {
+    test_polar_methods_agree(&Nalgebra::default());
+}
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::precond::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn block_jacobi() {
+    test_block_jacobi(&Nalgebra::default());
+}
+
+#[test]
+fn block_jacobi_cg() {
+    test_block_jacobi_cg(&Nalgebra::default(), &Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn ssor() {
+    test_ssor(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn ilu0() {
+    test_ilu0(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn ic0() {
+    test_ic0(&Nalgebra::default(), &Nalgebra::default());
+}
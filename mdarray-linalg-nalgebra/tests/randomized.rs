@@ -0,0 +1,52 @@
+use mdarray_linalg::testing::randomized::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn randomized_range_finder() {
+    test_randomized_range_finder(&Nalgebra::default(), &Nalgebra::default());
+}
+
+#[test]
+fn randomized_svd() {
+    test_randomized_svd(
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+    );
+}
+
+#[test]
+fn randomized_svd_seeded() {
+    test_randomized_svd_seeded(
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+    );
+}
+
+#[test]
+fn randomized_svd_complex() {
+    test_randomized_svd_complex(
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+    );
+}
+
+#[test]
+fn randomized_svd_invalid_rank() {
+    test_randomized_svd_invalid_rank(
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+    );
+}
+
+#[test]
+fn randomized_svd_const() {
+    test_randomized_svd_const(
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+        &Nalgebra::default(),
+    );
+}
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::riccati::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn care() {
+    test_care(&Nalgebra::default());
+}
+
+#[test]
+fn care_complex() {
+    test_care_complex(&Nalgebra::default());
+}
+
+#[test]
+fn dare() {
+    test_dare(&Nalgebra::default());
+}
+
+#[test]
+fn dare_complex() {
+    test_dare_complex(&Nalgebra::default());
+}
+
+#[test]
+fn riccati_errors() {
+    test_riccati_errors(&Nalgebra::default());
+}
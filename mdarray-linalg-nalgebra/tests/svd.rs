@@ -1,4 +1,4 @@
-use mdarray_linalg::testing::svd::*;
+use mdarray_linalg::{gauge::Gauge, testing::svd::*};
 use mdarray_linalg_nalgebra::Nalgebra;
 
 #[test]
@@ -35,3 +35,64 @@ fn test_backend_svd_cplx_square_matrix() {
 fn test_backend_svd_cplx_random_matrix() {
     test_svd_cplx_random_matrix(&Nalgebra::default());
 }
+
+#[test]
+fn test_backend_svd_partial() {
+    test_svd_partial(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_range() {
+    test_svd_range(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_partial_const() {
+    test_svd_partial_const(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_range_invalid() {
+    test_svd_range_invalid(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_max_rank() {
+    test_svd_truncate_max_rank(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_cutoffs() {
+    test_svd_truncate_cutoffs(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_truncate_renormalize_absorb() {
+    test_svd_truncate_renormalize_absorb(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_gauge() {
+    test_svd_gauge(&Nalgebra::default().config_gauge(Gauge::default()));
+}
+
+#[test]
+fn test_backend_svd_suites_gauge() {
+    let bd = Nalgebra::default().config_gauge(Gauge::default());
+    test_svd_square_matrix(&bd);
+    test_svd_rectangular_m_gt_n(&bd);
+    test_svd_rectangular_n_gt_m(&bd);
+    test_svd_random_matrix(&bd);
+    test_svd_cplx_square_matrix(&bd);
+    test_svd_cplx_random_matrix(&bd);
+}
+
+#[test]
+fn test_backend_svd_bidiagonal() {
+    test_svd_bidiagonal(&Nalgebra::default());
+}
+
+#[test]
+fn test_backend_svd_bidiagonal_const() {
+    test_svd_bidiagonal_const(&Nalgebra::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::sylvester::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn sylvester() {
+    test_sylvester(&Nalgebra::default());
+}
+
+#[test]
+fn sylvester_complex() {
+    test_sylvester_complex(&Nalgebra::default());
+}
+
+#[test]
+fn lyapunov() {
+    test_lyapunov(&Nalgebra::default());
+}
+
+#[test]
+fn lyapunov_complex() {
+    test_lyapunov_complex(&Nalgebra::default());
+}
+
+#[test]
+fn sylvester_scale() {
+    test_sylvester_scale(&Nalgebra::default());
+}
+
+#[test]
+fn sylvester_errors() {
+    test_sylvester_errors(&Nalgebra::default());
+}
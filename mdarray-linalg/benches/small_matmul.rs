@@ -77,7 +77,7 @@ pub fn matmul4x4_backend_naive(a: &Slice4x4, b: &Slice4x4, c: &mut Slice4x4) {
 
 #[inline(never)]
 pub fn matmul4x4_backend_faer(a: &Slice4x4, b: &Slice4x4, c: &mut Slice4x4) {
-    Faer.matmul(a, b).write(c);
+    Faer::default().matmul(a, b).write(c);
 }
 
 #[inline(never)]
@@ -92,7 +92,7 @@ pub fn matmuln_backend_naive(a: &SliceN, b: &SliceN, c: &mut SliceN) {
 
 #[inline(never)]
 pub fn matmuln_backend_faer(a: &SliceN, b: &SliceN, c: &mut SliceN) {
-    Faer.matmul(a, b).write(c);
+    Faer::default().matmul(a, b).write(c);
 }
 
 #[inline(never)]
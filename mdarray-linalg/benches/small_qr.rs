@@ -49,7 +49,7 @@ pub fn qr_4x4_dyn_backend_lapack(a: &Slice4x4Dyn) -> (DArray<f64, 2>, DArray<f64
 #[inline(never)]
 pub fn qr_4x4_dyn_backend_faer(a: &Slice4x4Dyn) -> (DArray<f64, 2>, DArray<f64, 2>) {
     let mut a_copy = a.to_owned();
-    let bd = Faer;
+    let bd = Faer::default();
     bd.qr(&mut a_copy)
 }
 
@@ -80,7 +80,7 @@ pub fn qr_n_dyn_backend_lapack(a: &SliceNDyn) -> (DArray<f64, 2>, DArray<f64, 2>
 #[inline(never)]
 pub fn qr_n_dyn_backend_faer(a: &SliceNDyn) -> (DArray<f64, 2>, DArray<f64, 2>) {
     let mut a_copy = a.to_owned();
-    let bd = Faer;
+    let bd = Faer::default();
     bd.qr(&mut a_copy)
 }
 
@@ -111,7 +111,7 @@ pub fn qr_n_nalgebra(data: &[f64]) -> nalgebra::linalg::QR<f64, nalgebra::Dyn, n
 // #[inline(never)]
 // pub fn qr_4x4_const_backend_faer(a: &Slice4x4Const) -> (DArray<f64, 2>, DArray<f64, 2>) {
 //     let mut a_copy = a.to_owned();
-//     let bd = Faer;
+//     let bd = Faer::default();
 //     bd.qr(&mut a_copy)
 // }
 
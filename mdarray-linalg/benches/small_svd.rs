@@ -44,14 +44,14 @@ pub fn svd_4x4_dyn_backend_lapack(a: &Slice4x4Dyn) -> SVDDecomp<f64, f64, usize>
 #[inline(never)]
 pub fn svd_4x4_dyn_backend_faer(a: &Slice4x4Dyn) -> SVDDecomp<f64, f64, usize> {
     let mut a_copy = a.to_owned();
-    let bd = Faer;
+    let bd = Faer::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
 #[inline(never)]
 pub fn svd_4x4_dyn_backend_nalgebra(a: &Slice4x4Dyn) -> SVDDecomp<f64, f64, usize> {
     let mut a_copy = a.to_owned();
-    let bd = Nalgebra;
+    let bd = Nalgebra::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
@@ -75,14 +75,14 @@ pub fn svd_n_dyn_backend_lapack(a: &SliceNDyn) -> SVDDecomp<f64, f64, usize> {
 #[inline(never)]
 pub fn svd_n_dyn_backend_faer(a: &SliceNDyn) -> SVDDecomp<f64, f64, usize> {
     let mut a_copy = a.to_owned();
-    let bd = Faer;
+    let bd = Faer::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
 #[inline(never)]
 pub fn svd_n_dyn_backend_nalgebra(a: &SliceNDyn) -> SVDDecomp<f64, f64, usize> {
     let mut a_copy = a.to_owned();
-    let bd = Nalgebra;
+    let bd = Nalgebra::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
@@ -106,14 +106,14 @@ pub fn svd_4x4_const_backend_lapack(a: &Slice4x4Const) -> SVDDecomp<f64, f64, Co
 #[inline(never)]
 pub fn svd_4x4_const_backend_faer(a: &Slice4x4Const) -> SVDDecomp<f64, f64, Const<4>> {
     let mut a_copy = a.to_owned();
-    let bd = Faer;
+    let bd = Faer::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
 #[inline(never)]
 pub fn svd_4x4_const_backend_nalgebra(a: &Slice4x4Const) -> SVDDecomp<f64, f64, Const<4>> {
     let mut a_copy = a.to_owned();
-    let bd = Nalgebra;
+    let bd = Nalgebra::default();
     bd.svd(&mut a_copy).expect("SVD failed")
 }
 
@@ -16,6 +16,32 @@
 //!     .eig(&mut a.clone())
 //!     .expect("Eigenvalue decomposition failed");
 //!
+//! // Only the lowest ten eigenpairs of a self-adjoint matrix, or those in (-1, 1].
+//! let EighDecomp { eigenvalues, eigenvectors } = bd
+//!     .eigh_range(&mut h.clone(), EighRange::Index(0..10))
+//!     .expect("Partial self-adjoint eigenvalue decomposition failed");
+//! let EighDecomp { eigenvalues, eigenvectors } = bd
+//!     .eigh_range(&mut h.clone(), EighRange::Value(-1., 1.))
+//!     .expect("Partial self-adjoint eigenvalue decomposition failed");
+//!
+//! // Eigenvalues only, into a buffer reused across calls.
+//! let mut w = Array::from_elem([n], 0.);
+//! bd.eigh_values_write(&mut h.clone(), &mut w)
+//!     .expect("Self-adjoint eigenvalue decomposition failed");
+//!
+//! // Tridiagonal matrix from a Lanczos recurrence, given by its diagonal
+//! // `alpha` and off-diagonal `beta`: the five lowest Ritz pairs.
+//! let EighDecomp { eigenvalues, eigenvectors } = bd
+//!     .eigh_tridiagonal(&alpha, &beta, EighRange::Index(0..5))
+//!     .expect("Tridiagonal eigenvalue decomposition failed");
+//!
+//! // Self-adjoint decomposition by divide and conquer instead of the
+//! // backend's default algorithm.
+//! let bd = Backend::default().config_eigh(EighConfig { algorithm: EighAlgorithm::DivideAndConquer });
+//! let EighDecomp { eigenvalues, eigenvectors } = bd
+//!     .eigh(&mut h.clone())
+//!     .expect("Self-adjoint eigenvalue decomposition failed");
+//!
 //! // Full decomposition with left and right eigenvectors.
 //! let EigDecomp { eigenvalues, left_eigenvectors, right_eigenvectors } = bd
 //!     .eig_full(&mut a.clone())
@@ -23,6 +49,14 @@
 //! let left = left_eigenvectors.expect("Left eigenvectors were not computed");
 //! let right = right_eigenvectors.expect("Right eigenvectors were not computed");
 //!
+//! // Eigenvalues of a badly scaled matrix with their reciprocal condition
+//! // numbers; backends balance by permuting and scaling unless configured
+//! // otherwise.
+//! let bd = Backend::default().config_eig(EigConfig { balance: EigBalance::Both });
+//! let (EigDecomp { eigenvalues, .. }, EigCondition { eigenvalues: rcond, .. }) = bd
+//!     .eig_condition(&mut a.clone())
+//!     .expect("Eigenvalue decomposition failed");
+//!
 //! // ----- Schur decomposition -----
 //! // A = Z * T * Z^H, with `Z^H` reducing to `Z^T` for real Schur decompositions.
 //! let SchurDecomp { t, z } = bd
@@ -31,11 +65,22 @@
 //!
 //! // Reconstruct A from the decomposition with the conjugate transpose Z^H:
 //! // A ≈ Z * T * Z^H
+//!
+//! // Eigenvectors from the Schur decomposition, without new QR iterations.
+//! let EigDecomp { eigenvalues, right_eigenvectors, .. } = bd
+//!     .schur_eigenvectors(&t, &z, EigSide::Right, None)
+//!     .expect("Eigenvectors from the Schur form failed");
 //! ```
 
-use mdarray::{Array, Dense, Dim, Layout, Slice};
+use std::{cmp::Ordering, ops::Range};
+
+use mdarray::{Array, DArray, Dense, Dim, Dyn, Layout, Shape, Slice};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, One, Zero};
 use thiserror::Error;
 
+use crate::utils::imaginary_unit;
+
 /// Error types related to eigenvalue decomposition
 #[derive(Debug, Error)]
 pub enum EigError {
@@ -47,6 +92,9 @@ pub enum EigError {
 
     #[error("Matrix must be square for eigenvalue decomposition")]
     NotSquareMatrix,
+
+    #[error("Invalid eigenvalue selection range")]
+    InvalidRange,
 }
 
 /// Holds the results of a general eigenvalue decomposition.
@@ -69,6 +117,17 @@ pub struct EighDecomp<T, R, D0: Dim, D1: Dim> {
     pub eigenvectors: Array<T, (D0, D1)>,
 }
 
+/// Selects a subset of the eigenpairs of a self-adjoint matrix.
+///
+/// Eigenvalues are counted in ascending order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EighRange<R> {
+    /// Eigenpairs with zero-based indices in `lo..hi`.
+    Index(Range<usize>),
+    /// Eigenpairs whose eigenvalues lie in the half-open interval `(vl, vu]`.
+    Value(R, R),
+}
+
 /// Error types related to Schur decomposition
 #[derive(Debug, Error)]
 pub enum SchurError {
@@ -80,6 +139,12 @@ pub enum SchurError {
 
     #[error("Matrix must be square for Schur decomposition")]
     NotSquareMatrix,
+
+    #[error("Selection mask must have one entry per eigenvalue")]
+    InvalidSelection,
+
+    #[error("Schur reordering failed: eigenvalues are too close to swap")]
+    ReorderFailed,
 }
 
 /// Holds the results of a Schur decomposition: A = Z * T * Z^H
@@ -91,6 +156,127 @@ pub struct SchurDecomp<T, D0: Dim, D1: Dim> {
     pub z: Array<T, (D0, D1)>,
 }
 
+/// Selects the eigenvalues moved to the leading block of a Schur form by
+/// [`Eig::schur_reorder`] and [`Eig::schur_complex_reorder`], or of a
+/// generalized Schur form by [`GEig::qz_reorder`](crate::geig::GEig::qz_reorder).
+///
+/// In a real Schur form, a complex conjugate pair (a `2 × 2` diagonal block)
+/// is selected if either of its eigenvalues is.
+pub enum SchurSelect<'a, S> {
+    /// `mask[i]` selects the eigenvalue at diagonal position `i` of `T`.
+    Mask(&'a [bool]),
+    /// Selects the eigenvalues for which the predicate returns `true`.
+    Predicate(&'a dyn Fn(S) -> bool),
+}
+
+/// Condition numbers computed by a Schur reordering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchurCondition {
+    /// No condition numbers.
+    #[default]
+    None,
+    /// Condition number of the average of the selected eigenvalues.
+    Eigenvalues,
+    /// Condition number of the invariant subspace.
+    Subspace,
+    /// Both condition numbers.
+    Both,
+}
+
+/// Result of a Schur reordering: the leading `dim` columns of `Z` span the
+/// invariant subspace of the selected eigenvalues.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SchurReorder<R> {
+    /// Number of selected eigenvalues
+    pub dim: usize,
+    /// Reciprocal condition number of the average of the selected eigenvalues
+    pub s: Option<R>,
+    /// Estimated reciprocal condition number of the invariant subspace, the
+    /// separation of the selected and remaining blocks of `T`
+    pub sep: Option<R>,
+}
+
+/// Selects the eigenvectors computed from a Schur decomposition by
+/// [`Eig::schur_eigenvectors`] and [`Eig::schur_complex_eigenvectors`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EigSide {
+    /// Right eigenvectors, `A v = λ v`.
+    #[default]
+    Right,
+    /// Left eigenvectors, `u^H A = λ u^H`.
+    Left,
+    /// Both left and right eigenvectors.
+    Both,
+}
+
+/// Balancing of a general matrix before its eigenvalue decomposition, as in
+/// LAPACK's `gebal`.
+///
+/// Permuting isolates eigenvalues that can be read off the diagonal, and
+/// scaling by powers of two brings the norms of each row and the
+/// corresponding column closer, which improves the accuracy of badly scaled
+/// matrices.  Eigenvectors always refer to the original matrix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EigBalance {
+    /// No balancing.
+    None,
+    /// Permute only.
+    Permute,
+    /// Scale only.
+    Scale,
+    /// Permute and scale.
+    #[default]
+    Both,
+}
+
+/// Configuration of the general eigenvalue decompositions of a backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EigConfig {
+    /// Balancing applied by [`Eig::eig`], [`Eig::eig_full`],
+    /// [`Eig::eig_values`] and [`Eig::eig_condition`]
+    pub balance: EigBalance,
+}
+
+/// Algorithm of the self-adjoint eigenvalue decomposition.
+///
+/// Backends whose library lacks an algorithm use their default instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EighAlgorithm {
+    /// The backend's default algorithm.
+    #[default]
+    Auto,
+    /// Implicit QR iteration on the tridiagonal form, as LAPACK's `syev`.
+    Qr,
+    /// Divide and conquer on the tridiagonal form, as LAPACK's `syevd`.
+    /// Much faster for large matrices, at the cost of more workspace.
+    DivideAndConquer,
+    /// Multiple relatively robust representations on the tridiagonal form,
+    /// as LAPACK's `syevr`.  Usually the fastest, with the least workspace.
+    Mrrr,
+    /// Cyclic Jacobi rotations on the full matrix.  Slower, but the
+    /// eigenvalues of positive definite matrices, including the smallest
+    /// ones, are computed to high relative accuracy.
+    Jacobi,
+}
+
+/// Configuration of the self-adjoint eigenvalue decompositions of a backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EighConfig {
+    /// Algorithm used by [`Eig::eigh`]
+    pub algorithm: EighAlgorithm,
+}
+
+/// Reciprocal condition numbers computed by [`Eig::eig_condition`], one per
+/// eigenvalue.  Small values flag inaccurate results.
+pub struct EigCondition<R, D0: Dim> {
+    /// Reciprocal condition numbers of the eigenvalues, `|u^H v|` for the
+    /// unit left and right eigenvectors `u` and `v`
+    pub eigenvalues: Array<R, (D0,)>,
+    /// Estimated reciprocal condition numbers of the right eigenvectors, the
+    /// separation of each eigenvalue from the others
+    pub eigenvectors: Array<R, (D0,)>,
+}
+
 /// Eigenvalue decomposition operations of general and self-adjoint matrices.
 ///
 /// Backends choose the spectral scalar model through associated types.
@@ -125,12 +311,95 @@ pub trait Eig<T, D0: Dim, D1: Dim> {
         a: &mut Slice<T, (D0, D1), L>,
     ) -> Result<Array<Self::SpectralScalar, (D0,)>, EigError>;
 
+    /// Compute eigenvalues, left/right eigenvectors and the reciprocal
+    /// condition numbers of the eigenvalues and right eigenvectors.
+    ///
+    /// The error of eigenvalue `i` is about `ε ‖A‖ / eigenvalues[i]` and the
+    /// angle between the computed and exact eigenvectors about
+    /// `ε ‖A‖ / eigenvectors[i]`.  As in LAPACK, the condition numbers refer to
+    /// the balanced matrix.
+    #[allow(clippy::type_complexity)]
+    fn eig_condition<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<
+        (
+            EigDecomp<Self::SpectralScalar, D0, D1>,
+            EigCondition<Self::RealScalar, D0>,
+        ),
+        EigError,
+    >;
+
     /// Compute eigenvalues and eigenvectors of a self-adjoint matrix.
+    ///
+    /// Only the upper triangle of `a` is read, whatever the
+    /// [`EighAlgorithm`].
     fn eigh<L: Layout>(
         &self,
         a: &mut Slice<T, (D0, D1), L>,
     ) -> Result<EighDecomp<T, Self::RealScalar, D0, D1>, EigError>;
 
+    /// Compute eigenvalues and eigenvectors of a self-adjoint matrix,
+    /// overwriting existing buffers.
+    fn eigh_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+        eigenvectors: &mut Slice<T, (D0, D1), Dense>,
+    ) -> Result<(), EigError>;
+
+    /// Compute only the eigenvalues of a self-adjoint matrix.
+    ///
+    /// This skips the eigenvectors entirely and is several times faster than
+    /// [`Self::eigh`].
+    fn eigh_values<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<Array<Self::RealScalar, (D0,)>, EigError>;
+
+    /// Compute only the eigenvalues of a self-adjoint matrix, overwriting an
+    /// existing buffer.
+    fn eigh_values_write<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        eigenvalues: &mut Slice<Self::RealScalar, (D0,), Dense>,
+    ) -> Result<(), EigError>;
+
+    /// Compute a subset of the eigenvalues and eigenvectors of a self-adjoint matrix.
+    ///
+    /// Eigenvalues are returned in ascending order, or in the order of the
+    /// backend's [`Gauge`](crate::gauge::Gauge).  For `n × n` input and `k`
+    /// selected eigenpairs, the eigenvectors are returned as the columns of an
+    /// `n × k` matrix.  As `k` is only known at runtime, the result has
+    /// dynamic dimensions.
+    fn eigh_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<T, Self::RealScalar, Dyn, Dyn>, EigError>;
+
+    /// Compute the eigenvalues and selected eigenvectors of a real symmetric
+    /// tridiagonal matrix, given by its diagonal `d` and off-diagonal `e`.
+    ///
+    /// For `n` diagonal entries, `e` must have `n - 1` entries.  The selected
+    /// eigenpairs are returned in ascending order, with the eigenvectors as
+    /// the columns of an `n × k` matrix.
+    fn eigh_tridiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<EighDecomp<Self::RealScalar, Self::RealScalar, Dyn, Dyn>, EigError>;
+
+    /// Compute only the selected eigenvalues of a real symmetric tridiagonal
+    /// matrix, in ascending order.
+    fn eigh_tridiagonal_values<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<Self::RealScalar, (D0,), Ld>,
+        e: &Slice<Self::RealScalar, (De,), Le>,
+        range: EighRange<Self::RealScalar>,
+    ) -> Result<Array<Self::RealScalar, (Dyn,)>, EigError>;
+
     /// Compute Schur decomposition over the input scalar field.
     fn schur<L: Layout>(
         &self,
@@ -158,4 +427,1408 @@ pub trait Eig<T, D0: Dim, D1: Dim> {
         t: &mut Slice<Self::SpectralScalar, (D0, D1), Dense>,
         z: &mut Slice<Self::SpectralScalar, (D0, D1), Dense>,
     ) -> Result<(), SchurError>;
+
+    /// Reorders a Schur decomposition over the input scalar field (as
+    /// returned by [`Self::schur`]) so that the selected eigenvalues lead the
+    /// diagonal of `T`, updating `T` and `Z` in place.
+    fn schur_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError>;
+
+    /// Reorders a Schur decomposition over the spectral scalar field (as
+    /// returned by [`Self::schur_complex`]) so that the selected eigenvalues
+    /// lead the diagonal of `T`, updating `T` and `Z` in place.
+    fn schur_complex_reorder<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &mut Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &mut Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+        condition: SchurCondition,
+    ) -> Result<SchurReorder<Self::RealScalar>, SchurError>;
+
+    /// Computes eigenvectors of `A = Z T Z^H` from its Schur decomposition
+    /// over the input scalar field (as returned by [`Self::schur`]), without
+    /// repeating the QR iterations.
+    ///
+    /// `select` picks eigenvalues by their diagonal position in `T`, or all
+    /// of them if `None`; unlike in [`Self::schur_reorder`], selecting one
+    /// eigenvalue of a complex conjugate pair does not select the other.  The
+    /// selected eigenvalues are returned in diagonal order, with the
+    /// corresponding eigenvectors as columns normalized to unit Euclidean
+    /// norm and largest component real.
+    fn schur_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<T, (D0, D1), Lt>,
+        z: &Slice<T, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError>;
+
+    /// Computes eigenvectors of `A = Z T Z^H` from its Schur decomposition
+    /// over the spectral scalar field (as returned by
+    /// [`Self::schur_complex`]), as [`Self::schur_eigenvectors`].
+    fn schur_complex_eigenvectors<Lt: Layout, Lz: Layout>(
+        &self,
+        t: &Slice<Self::SpectralScalar, (D0, D1), Lt>,
+        z: &Slice<Self::SpectralScalar, (D0, D1), Lz>,
+        side: EigSide,
+        select: Option<SchurSelect<'_, Self::SpectralScalar>>,
+    ) -> Result<EigDecomp<Self::SpectralScalar, D0, D1>, SchurError>;
+}
+
+// The following exported items are unstable backend-implementation helpers.
+// They are hidden from generated documentation and may be redesigned before
+// the public API stabilizes.
+
+/// Selects eigenpairs from a full self-adjoint decomposition.
+///
+/// This is the fallback for backends without native subset selection.  The
+/// input eigenpairs may be in any order; the result is sorted in ascending
+/// order of eigenvalues, with dynamic dimensions.
+#[doc(hidden)]
+pub fn select_eigh<T, R, D0, D1>(
+    decomp: EighDecomp<T, R, D0, D1>,
+    range: &EighRange<R>,
+) -> Result<EighDecomp<T, R, Dyn, Dyn>, EigError>
+where
+    T: Copy,
+    R: Copy + PartialOrd,
+    D0: Dim,
+    D1: Dim,
+{
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = decomp;
+    let n = eigenvalues.len();
+    let rows = eigenvectors.shape().dim(0);
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        eigenvalues[i]
+            .partial_cmp(&eigenvalues[j])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let selected: Vec<usize> = match range {
+        EighRange::Index(r) => {
+            if r.start > r.end || r.end > n {
+                return Err(EigError::InvalidRange);
+            }
+            order[r.clone()].to_vec()
+        }
+        EighRange::Value(vl, vu) => {
+            if vl.partial_cmp(vu) != Some(Ordering::Less) {
+                return Err(EigError::InvalidRange);
+            }
+            order
+                .into_iter()
+                .filter(|&i| *vl < eigenvalues[i] && eigenvalues[i] <= *vu)
+                .collect()
+        }
+    };
+
+    let k = selected.len();
+
+    Ok(EighDecomp {
+        eigenvalues: Array::from_fn((k,), |idx| eigenvalues[selected[idx[0]]]),
+        eigenvectors: Array::from_fn((rows, k), |idx| {
+            eigenvectors[[idx[0], selected[idx[1]]]]
+        }),
+    })
+}
+
+/// Computes the eigenvalues and, if `vectors` is set, the eigenvectors of the
+/// symmetric tridiagonal matrix with diagonal `d` and off-diagonal `e`, and
+/// selects those in `range`.
+///
+/// This is the fallback for backends without a native tridiagonal
+/// eigensolver.  It uses implicit QL iterations with Wilkinson shifts, in
+/// `O(n²)` operations for the eigenvalues and `O(n³)` with the eigenvectors.
+/// Without eigenvectors, the result has `0 × k` eigenvectors.
+#[doc(hidden)]
+pub fn tridiagonal_eigh<R, D, De, Ld, Le>(
+    d: &Slice<R, (D,), Ld>,
+    e: &Slice<R, (De,), Le>,
+    range: &EighRange<R>,
+    vectors: bool,
+) -> Result<EighDecomp<R, R, Dyn, Dyn>, EigError>
+where
+    R: Float,
+    D: Dim,
+    De: Dim,
+    Ld: Layout,
+    Le: Layout,
+{
+    const MAX_ITERATIONS: i32 = 30;
+
+    let n = d.len();
+    assert_eq!(e.len(), n.saturating_sub(1), "Off-diagonal must have n - 1 elements");
+
+    let zero = R::zero();
+    let one = R::one();
+    let two = one + one;
+    let eps = R::epsilon();
+    let mut d: Vec<R> = d.iter().copied().collect();
+    // e[l] couples rows l and l + 1; the trailing zero ends the last block.
+    let mut e: Vec<R> = e.iter().copied().chain(std::iter::once(zero)).collect();
+    let rows = if vectors { n } else { 0 };
+    let mut z = DArray::<R, 2>::from_fn([rows, rows], |i| if i[0] == i[1] { one } else { zero });
+
+    for l in 0..n {
+        let mut iterations = 0;
+        loop {
+            // The first negligible off-diagonal entry at or after l ends the
+            // unreduced block l..=m.
+            let m = (l..n)
+                .find(|&m| m + 1 == n || e[m].abs() <= eps * (d[m].abs() + d[m + 1].abs()))
+                .unwrap_or(n - 1);
+            if m == l {
+                break;
+            }
+            iterations += 1;
+            if iterations > MAX_ITERATIONS {
+                return Err(EigError::BackendDidNotConverge { iterations });
+            }
+
+            // Wilkinson shift from the leading 2 × 2 block, then chase the
+            // bulge from the bottom of the block back to l.
+            let g = (d[l + 1] - d[l]) / (two * e[l]);
+            let r = g.hypot(one);
+            let mut g = d[m] - d[l] + e[l] / (g + if g < zero { -r } else { r });
+            let (mut s, mut c, mut p) = (one, one, zero);
+            let mut deflated = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                let r = f.hypot(g);
+                e[i + 1] = r;
+                if r == zero {
+                    // Underflow: the block splits at i + 1.
+                    d[i + 1] = d[i + 1] - p;
+                    e[m] = zero;
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                let g1 = d[i + 1] - p;
+                let r = (d[i] - g1) * s + two * c * b;
+                p = s * r;
+                d[i + 1] = g1 + p;
+                g = c * r - b;
+
+                for k in 0..rows {
+                    let f = z[[k, i + 1]];
+                    z[[k, i + 1]] = s * z[[k, i]] + c * f;
+                    z[[k, i]] = c * z[[k, i]] - s * f;
+                }
+            }
+            if !deflated {
+                d[l] = d[l] - p;
+                e[l] = g;
+                e[m] = zero;
+            }
+        }
+    }
+
+    let decomp = EighDecomp {
+        eigenvalues: Array::from_fn((n,), |idx| d[idx[0]]),
+        eigenvectors: Array::from_fn((rows, n), |idx| z[[idx[0], idx[1]]]),
+    };
+    select_eigh(decomp, range)
+}
+
+/// Computes the eigendecomposition of the self-adjoint matrix `a`, given by
+/// its upper triangle, with cyclic two-sided Jacobi rotations.
+///
+/// This is the fallback for backends without a native Jacobi eigensolver.
+/// An off-diagonal entry is only considered negligible relative to the
+/// geometric mean of the corresponding diagonal entries, which computes the
+/// eigenvalues of positive definite matrices to high relative accuracy.  The
+/// eigenpairs are sorted in ascending order of eigenvalues.
+#[doc(hidden)]
+pub fn jacobi_eigh<T, D0, D1, L>(
+    a: &Slice<T, (D0, D1), L>,
+) -> Result<EighDecomp<T, T::Real, D0, D1>, EigError>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    const MAX_SWEEPS: i32 = 100;
+
+    let n = a.shape().dim(0);
+    if a.shape().dim(1) != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+    let two = one + one;
+    let eps = T::Real::epsilon();
+    let mut h = DArray::<T, 2>::from_fn([n, n], |i| match i[0].cmp(&i[1]) {
+        Ordering::Less => a[[i[0], i[1]]],
+        Ordering::Greater => a[[i[1], i[0]]].conj(),
+        Ordering::Equal => scalar::<T>(a[[i[0], i[0]]].re()),
+    });
+    let mut v =
+        DArray::<T, 2>::from_fn([n, n], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+
+    let mut sweeps = 0;
+    loop {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (app, aqq) = (h[[p, p]].re(), h[[q, q]].re());
+                let g = h[[p, q]].abs();
+                if g <= T::Real::min_positive_value()
+                    || g <= eps * Float::sqrt(Float::abs(app) * Float::abs(aqq))
+                {
+                    continue;
+                }
+                rotated = true;
+
+                // The phase of a_pq makes the 2 × 2 block real symmetric, which
+                // the rotation [c, s e; -s conj(e), c] then diagonalizes.
+                let e = h[[p, q]] * scalar::<T>(one / g);
+                let theta = (aqq - app) / (two * g);
+                let t = one / (Float::abs(theta) + Float::hypot(theta, one));
+                let t = if theta < zero { -t } else { t };
+                let c = one / Float::hypot(t, one);
+                let (c, se) = (scalar::<T>(c), e * scalar::<T>(t * c));
+
+                for k in 0..n {
+                    let (xp, xq) = (h[[k, p]], h[[k, q]]);
+                    h[[k, p]] = c * xp - se.conj() * xq;
+                    h[[k, q]] = se * xp + c * xq;
+                    let (xp, xq) = (v[[k, p]], v[[k, q]]);
+                    v[[k, p]] = c * xp - se.conj() * xq;
+                    v[[k, q]] = se * xp + c * xq;
+                }
+                for k in 0..n {
+                    let (xp, xq) = (h[[p, k]], h[[q, k]]);
+                    h[[p, k]] = c * xp - se * xq;
+                    h[[q, k]] = se.conj() * xp + c * xq;
+                }
+
+                // Set the rotated 2 × 2 block exactly to avoid rounding drift.
+                h[[p, q]] = T::zero();
+                h[[q, p]] = T::zero();
+                h[[p, p]] = scalar::<T>(app - t * g);
+                h[[q, q]] = scalar::<T>(aqq + t * g);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+        sweeps += 1;
+        if sweeps == MAX_SWEEPS {
+            return Err(EigError::BackendDidNotConverge { iterations: sweeps });
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        h[[i, i]]
+            .re()
+            .partial_cmp(&h[[j, j]].re())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    Ok(EighDecomp {
+        eigenvalues: Array::from_fn(<(D0,) as Shape>::from_dims(&[n]), |idx| {
+            h[[order[idx[0]], order[idx[0]]]].re()
+        }),
+        eigenvectors: Array::from_fn(<(D0, D1) as Shape>::from_dims(&[n, n]), |idx| {
+            v[[idx[0], order[idx[1]]]]
+        }),
+    })
+}
+
+/// Lists the eigenvalues of the diagonal blocks of a Schur form in diagonal
+/// order, a complex conjugate pair with positive imaginary part first.
+#[doc(hidden)]
+pub fn schur_eigenvalues<T, S, D0, D1, L>(t: &Slice<T, (D0, D1), L>) -> Vec<S>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    schur_blocks(t)
+        .into_iter()
+        .flat_map(|(k, size)| block_eigenvalues(t, k, size))
+        .map(spectral)
+        .collect()
+}
+
+/// Computes the selection mask of the eigenvalues of a Schur form, selecting
+/// all of them if `select` is `None`.
+#[doc(hidden)]
+pub fn schur_eigenvalue_mask<T, S, D0, D1, L>(
+    t: &Slice<T, (D0, D1), L>,
+    select: Option<&SchurSelect<'_, S>>,
+) -> Result<Vec<bool>, SchurError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let n = t.shape().dim(0);
+    match select {
+        None => Ok(vec![true; n]),
+        Some(SchurSelect::Mask(mask)) if mask.len() != n => Err(SchurError::InvalidSelection),
+        Some(SchurSelect::Mask(mask)) => Ok(mask.to_vec()),
+        Some(SchurSelect::Predicate(predicate)) => Ok(schur_eigenvalues::<T, S, D0, D1, L>(t)
+            .into_iter()
+            .map(predicate)
+            .collect()),
+    }
+}
+
+/// Computes the selection mask of a Schur form, extending the selection of
+/// one eigenvalue of a `2 × 2` block to its conjugate.
+#[doc(hidden)]
+pub fn schur_select_mask<T, S, D0, D1, L>(
+    t: &Slice<T, (D0, D1), L>,
+    select: &SchurSelect<'_, S>,
+) -> Result<Vec<bool>, SchurError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let mut mask = schur_eigenvalue_mask(t, Some(select))?;
+    for (k, size) in schur_blocks(t) {
+        if size == 2 {
+            let selected = mask[k] || mask[k + 1];
+            mask[k] = selected;
+            mask[k + 1] = selected;
+        }
+    }
+    Ok(mask)
+}
+
+/// Computes the selected left and right eigenvectors of `A = Z T Z^H` from
+/// its upper (quasi-)triangular Schur form by back substitution, as LAPACK's
+/// `trevc`, followed by the back-transformation with `Z`.
+///
+/// This is the fallback for backends without a native `trevc`.
+#[doc(hidden)]
+pub fn schur_eigenvectors<T, S, D0, D1, Lt, Lz>(
+    t: &Slice<T, (D0, D1), Lt>,
+    z: &Slice<T, (D0, D1), Lz>,
+    side: EigSide,
+    select: Option<&SchurSelect<'_, S>>,
+) -> Result<EigDecomp<S, D0, D1>, SchurError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Lt: Layout,
+    Lz: Layout,
+{
+    let n = t.shape().dim(0);
+    if t.shape().dim(1) != n || z.shape().dim(0) != n || z.shape().dim(1) != n {
+        return Err(SchurError::NotSquareMatrix);
+    }
+    let mask = schur_eigenvalue_mask(t, select)?;
+    let blocks = schur_blocks(t);
+    let eigenvalues: Vec<_> = blocks
+        .iter()
+        .flat_map(|&(k, size)| block_eigenvalues(t, k, size))
+        .collect();
+    let selected: Vec<usize> = (0..n).filter(|&j| mask[j]).collect();
+    let block_of = |j: usize| blocks.iter().position(|&(k, size)| j < k + size).unwrap();
+
+    let c = |x: T| Complex::new(x.re(), x.im());
+    let tc = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| c(t[[i[0], i[1]]]));
+    // Left eigenvectors of T are conjugated right eigenvectors of T^T, which
+    // is upper quasi-triangular after reversing the order of rows and columns.
+    let tr = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| c(t[[n - 1 - i[1], n - 1 - i[0]]]));
+    let blocks_r: Vec<_> = blocks
+        .iter()
+        .rev()
+        .map(|&(k, size)| (n - k - size, size))
+        .collect();
+    let back_transform = |x: &[Complex<T::Real>]| {
+        let mut v: Vec<_> = (0..n)
+            .map(|i| (0..n).fold(Complex::zero(), |acc, l| acc + c(z[[i, l]]) * x[l]))
+            .collect();
+        normalize_eigenvector(&mut v);
+        v
+    };
+
+    let right = matches!(side, EigSide::Right | EigSide::Both).then(|| {
+        selected
+            .iter()
+            .map(|&j| {
+                back_transform(&quasi_triangular_eigenvector(
+                    &tc,
+                    &blocks,
+                    block_of(j),
+                    eigenvalues[j],
+                ))
+            })
+            .collect::<Vec<_>>()
+    });
+    let left = matches!(side, EigSide::Left | EigSide::Both).then(|| {
+        selected
+            .iter()
+            .map(|&j| {
+                let b = blocks.len() - 1 - block_of(j);
+                let w = quasi_triangular_eigenvector(&tr, &blocks_r, b, eigenvalues[j]);
+                let y: Vec<_> = (0..n).map(|i| w[n - 1 - i].conj()).collect();
+                back_transform(&y)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let m = selected.len();
+    let vectors_shape = <(D0, D1) as Shape>::from_dims(&[n, m]);
+    let to_array = |columns: Vec<Vec<Complex<T::Real>>>| {
+        Array::from_fn(vectors_shape, |idx| spectral(columns[idx[1]][idx[0]]))
+    };
+    Ok(EigDecomp {
+        eigenvalues: Array::from_fn(<(D0,) as Shape>::from_dims(&[m]), |idx| {
+            spectral(eigenvalues[selected[idx[0]]])
+        }),
+        left_eigenvectors: left.map(to_array),
+        right_eigenvectors: right.map(to_array),
+    })
+}
+
+/// Scales an eigenvector to unit Euclidean norm with its largest component
+/// real and positive.
+#[doc(hidden)]
+pub fn normalize_eigenvector<S: ComplexFloat>(v: &mut [S]) {
+    let norm = Float::sqrt(
+        v.iter()
+            .fold(S::Real::zero(), |acc, x| acc + x.abs() * x.abs()),
+    );
+    let Some(largest) = v
+        .iter()
+        .copied()
+        .max_by(|x, y| x.abs().partial_cmp(&y.abs()).unwrap_or(Ordering::Equal))
+    else {
+        return;
+    };
+    if norm == S::Real::zero() {
+        return;
+    }
+    let phase = largest.conj() * scalar::<S>(S::Real::one() / (largest.abs() * norm));
+    for x in v.iter_mut() {
+        *x = *x * phase;
+    }
+}
+
+/// Similarity transformation `B = D^-1 P^T A P D` computed by [`balance`].
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Balancing<R> {
+    /// Symmetric row and column interchanges, in the order they were applied
+    pub swaps: Vec<(usize, usize)>,
+    /// Diagonal of the scaling `D`
+    pub scale: Vec<R>,
+}
+
+impl<R: Float> Balancing<R> {
+    /// Transforms eigenvectors of the balanced matrix, stored as the columns
+    /// of `v`, into eigenvectors of the original matrix and normalizes them
+    /// as [`normalize_eigenvector`].
+    pub fn unbalance<S, D0, D1, L>(&self, v: &mut Slice<S, (D0, D1), L>, side: EigSide)
+    where
+        S: ComplexFloat,
+        D0: Dim,
+        D1: Dim,
+        L: Layout,
+    {
+        let (n, m) = (v.shape().dim(0), v.shape().dim(1));
+        // Right eigenvectors are x = P D y, left eigenvectors x = P D^-1 y.
+        let factor = |i: usize| {
+            let d = <S::Real as NumCast>::from(self.scale[i]).unwrap();
+            scalar::<S>(if side == EigSide::Left {
+                S::Real::one() / d
+            } else {
+                d
+            })
+        };
+        for j in 0..m {
+            let mut col: Vec<S> = (0..n).map(|i| v[[i, j]] * factor(i)).collect();
+            for &(k, l) in self.swaps.iter().rev() {
+                col.swap(k, l);
+            }
+            normalize_eigenvector(&mut col);
+            for (i, x) in col.into_iter().enumerate() {
+                v[[i, j]] = x;
+            }
+        }
+    }
+}
+
+/// Balances the square matrix `a` in place, as LAPACK's `gebal`: rows and
+/// columns isolating an eigenvalue are permuted to the bottom or the top,
+/// and the remaining ones are scaled by powers of two.
+///
+/// This is the fallback for backends without a native `gebal`.
+#[doc(hidden)]
+pub fn balance<T, D0, D1, L>(
+    a: &mut Slice<T, (D0, D1), L>,
+    balance: EigBalance,
+) -> Balancing<T::Real>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let n = a.shape().dim(0);
+    let mut swaps = Vec::new();
+    let mut scale = vec![T::Real::one(); n];
+    let (mut lo, mut hi) = (0, n);
+    let zero = T::zero();
+
+    if n > 0 && matches!(balance, EigBalance::Permute | EigBalance::Both) {
+        let mut swap = |a: &mut Slice<T, (D0, D1), L>, k: usize, l: usize| {
+            if k != l {
+                for i in 0..n {
+                    let x = a[[i, k]];
+                    a[[i, k]] = a[[i, l]];
+                    a[[i, l]] = x;
+                }
+                for j in 0..n {
+                    let x = a[[k, j]];
+                    a[[k, j]] = a[[l, j]];
+                    a[[l, j]] = x;
+                }
+                swaps.push((k, l));
+            }
+        };
+        // A row without off-diagonal entries in the active block isolates
+        // its diagonal entry at the bottom.
+        while let Some(i) = (lo..hi)
+            .rev()
+            .find(|&i| (lo..hi).all(|j| j == i || a[[i, j]] == zero))
+        {
+            swap(a, i, hi - 1);
+            hi -= 1;
+        }
+        // Likewise, such a column isolates its diagonal entry at the top.
+        while let Some(j) = (lo..hi).find(|&j| (lo..hi).all(|i| i == j || a[[i, j]] == zero)) {
+            swap(a, j, lo);
+            lo += 1;
+        }
+    }
+
+    if matches!(balance, EigBalance::Scale | EigBalance::Both) {
+        let two = T::Real::one() + T::Real::one();
+        let sfmin1 = T::Real::min_positive_value() / T::Real::epsilon();
+        let sfmax1 = T::Real::one() / sfmin1;
+        let sfmin2 = sfmin1 * two;
+        let sfmax2 = T::Real::one() / sfmin2;
+        let factor = <T::Real as NumCast>::from(0.95).unwrap();
+        let norm2 = |v: &mut dyn Iterator<Item = T>| {
+            Float::sqrt(v.fold(T::Real::zero(), |acc, x| acc + x.abs() * x.abs()))
+        };
+        let max_abs =
+            |v: &mut dyn Iterator<Item = T>| v.fold(T::Real::zero(), |acc, x| acc.max(x.abs()));
+
+        let mut converged = false;
+        while !converged {
+            converged = true;
+            for i in lo..hi {
+                let mut c = norm2(&mut (lo..hi).map(|k| a[[k, i]]));
+                let mut r = norm2(&mut (lo..hi).map(|k| a[[i, k]]));
+                let mut ca = max_abs(&mut (0..hi).map(|k| a[[k, i]]));
+                let mut ra = max_abs(&mut (lo..n).map(|k| a[[i, k]]));
+                if c == T::Real::zero() || r == T::Real::zero() {
+                    continue;
+                }
+                if Float::is_nan(c + ca + r + ra) {
+                    return Balancing { swaps, scale };
+                }
+
+                let sum = c + r;
+                let mut f = T::Real::one();
+                let mut g = r / two;
+                while c < g && f.max(c).max(ca) < sfmax2 && r.min(g).min(ra) > sfmin2 {
+                    f = f * two;
+                    c = c * two;
+                    ca = ca * two;
+                    r = r / two;
+                    g = g / two;
+                    ra = ra / two;
+                }
+                g = c / two;
+                while g >= r && r.max(ra) < sfmax2 && f.min(c).min(g).min(ca) > sfmin2 {
+                    f = f / two;
+                    c = c / two;
+                    g = g / two;
+                    ca = ca / two;
+                    r = r * two;
+                    ra = ra * two;
+                }
+
+                // Only accept scalings that reduce the norm noticeably and
+                // keep the accumulated factor representable.
+                if c + r >= factor * sum
+                    || (f < T::Real::one() && scale[i] < T::Real::one() && f * scale[i] <= sfmin1)
+                    || (f > T::Real::one() && scale[i] > T::Real::one() && scale[i] >= sfmax1 / f)
+                {
+                    continue;
+                }
+                scale[i] = scale[i] * f;
+                converged = false;
+                let (fs, gs) = (scalar::<T>(f), scalar::<T>(T::Real::one() / f));
+                for k in lo..n {
+                    a[[i, k]] = a[[i, k]] * gs;
+                }
+                for k in 0..hi {
+                    a[[k, i]] = a[[k, i]] * fs;
+                }
+            }
+        }
+    }
+
+    Balancing { swaps, scale }
+}
+
+/// Computes the eigendecomposition of `a` with both eigenvectors and their
+/// reciprocal condition numbers from the complex Schur form of the balanced
+/// matrix, as LAPACK's `geevx` and `trsna`.
+///
+/// This is the fallback for backends without a native `geevx`.  The
+/// eigenvalues are returned in the diagonal order of the Schur form.  An
+/// eigenvalue that cannot be moved to the top of the Schur form gets an
+/// eigenvector condition number of zero.
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub fn eig_condition<B, T, D0, D1, L>(
+    bd: &B,
+    a: &mut Slice<T, (D0, D1), L>,
+    balancing: EigBalance,
+) -> Result<
+    (
+        EigDecomp<B::SpectralScalar, D0, D1>,
+        EigCondition<B::RealScalar, D0>,
+    ),
+    EigError,
+>
+where
+    B: Eig<T, D0, D1>,
+    B::SpectralScalar: ComplexFloat,
+    B::RealScalar: Float,
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let n = a.shape().dim(0);
+    if a.shape().dim(1) != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+    let schur_error = |e: SchurError| match e {
+        SchurError::BackendError(code) => EigError::BackendError(code),
+        SchurError::BackendDidNotConverge { iterations } => {
+            EigError::BackendDidNotConverge { iterations }
+        }
+        SchurError::NotSquareMatrix => EigError::NotSquareMatrix,
+        SchurError::InvalidSelection | SchurError::ReorderFailed => {
+            unreachable!("no eigenvalues are selected or reordered")
+        }
+    };
+
+    let balancing = balance(a, balancing);
+    let SchurDecomp { t, z } = bd.schur_complex(a).map_err(schur_error)?;
+    let mut decomp = bd
+        .schur_complex_eigenvectors(&t, &z, EigSide::Both, None)
+        .map_err(schur_error)?;
+
+    let to_r =
+        |x: <B::SpectralScalar as ComplexFloat>::Real| <B::RealScalar as NumCast>::from(x).unwrap();
+    let shape = <(D0,) as Shape>::from_dims(&[n]);
+    let (Some(left), Some(right)) = (
+        &mut decomp.left_eigenvectors,
+        &mut decomp.right_eigenvectors,
+    ) else {
+        unreachable!("both eigenvectors were requested");
+    };
+    let eigenvalues = Array::from_fn(shape, |idx| {
+        let j = idx[0];
+        let dot = (0..n).fold(B::SpectralScalar::zero(), |acc, i| {
+            acc + left[[i, j]].conj() * right[[i, j]]
+        });
+        to_r(dot.abs())
+    });
+    let eigenvectors = Array::from_fn(shape, |idx| {
+        let (mut tj, mut zj) = (t.clone(), z.clone());
+        let mask: Vec<bool> = (0..n).map(|i| i == idx[0]).collect();
+        reorder_schur(
+            &mut tj,
+            &mut zj,
+            &SchurSelect::<B::SpectralScalar>::Mask(&mask),
+            SchurCondition::Subspace,
+        )
+        .ok()
+        .and_then(|r| r.sep)
+        .unwrap_or(B::RealScalar::zero())
+    });
+
+    balancing.unbalance(left, EigSide::Left);
+    balancing.unbalance(right, EigSide::Right);
+    Ok((
+        decomp,
+        EigCondition {
+            eigenvalues,
+            eigenvectors,
+        },
+    ))
+}
+
+/// Reorders the upper (quasi-)triangular Schur form `t` so that the selected
+/// eigenvalues lead its diagonal, replacing `T` by `Q^H T Q` and `Z` by `Z Q`.
+///
+/// This is the fallback for backends without a native `trsen`.  Adjacent
+/// diagonal blocks are swapped as in LAPACK's `trexc`, and real `2 × 2`
+/// blocks are kept in standard form.
+#[doc(hidden)]
+pub fn reorder_schur<T, S, R, D0, D1, Lt, Lz>(
+    t: &mut Slice<T, (D0, D1), Lt>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    select: &SchurSelect<'_, S>,
+    condition: SchurCondition,
+) -> Result<SchurReorder<R>, SchurError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    R: Float,
+    D0: Dim,
+    D1: Dim,
+    Lt: Layout,
+    Lz: Layout,
+{
+    let (n, cols) = (t.shape().dim(0), t.shape().dim(1));
+    if n != cols {
+        return Err(SchurError::NotSquareMatrix);
+    }
+    let mask = schur_select_mask(t, select)?;
+
+    // Blocks at and after `k` have not moved yet, so the mask still applies.
+    let mut dim = 0;
+    let mut k = 0;
+    while k < n {
+        let size = block_size(t, k);
+        if mask[k] {
+            let mut pos = k;
+            while pos > dim {
+                let prev = if pos >= 2 && t[[pos - 1, pos - 2]] != T::zero() {
+                    2
+                } else {
+                    1
+                };
+                swap_blocks(t, z, pos - prev, prev, size)?;
+                pos -= prev;
+            }
+            dim += size;
+        }
+        k += size;
+    }
+
+    let (want_s, want_sep) = match condition {
+        SchurCondition::None => (false, false),
+        SchurCondition::Eigenvalues => (true, false),
+        SchurCondition::Subspace => (false, true),
+        SchurCondition::Both => (true, true),
+    };
+    let one = T::Real::one();
+    let (mut s, mut sep) = (None, None);
+    if dim == 0 || dim == n {
+        // As LAPACK: the whole spectrum is perfectly conditioned, and the
+        // separation from an empty block is the norm of T.
+        let norm = (0..n)
+            .map(|j| (0..n).fold(T::Real::zero(), |acc, i| acc + t[[i, j]].abs()))
+            .fold(T::Real::zero(), Float::max);
+        s = want_s.then_some(one);
+        sep = want_sep.then_some(norm);
+    } else {
+        let sub = |r0: usize, c0: usize, rows: usize, cols: usize| {
+            DArray::<T, 2>::from_fn([rows, cols], |i| t[[r0 + i[0], c0 + i[1]]])
+        };
+        let (t11, t12, t22) = (
+            sub(0, 0, dim, dim),
+            sub(0, dim, dim, n - dim),
+            sub(dim, dim, n - dim, n - dim),
+        );
+        if want_s {
+            // X with T11 X - X T22 = T12 decouples the selected block.
+            let x = quasi_sylvester(&t11, &t22, &t12);
+            let rnorm = Float::sqrt(
+                x.iter()
+                    .fold(T::Real::zero(), |acc, v| acc + v.abs() * v.abs()),
+            );
+            s = Some(one / Float::sqrt(one + rnorm * rnorm));
+        }
+        if want_sep {
+            let est = estimate_inverse_norm(
+                (dim, n - dim),
+                |c| quasi_sylvester(&t11, &t22, c),
+                // The adjoint T11^H Y - Y T22^H = C reads T22 W - W T11 = -C^H
+                // for W = Y^H.
+                |c| adjoint(&quasi_sylvester(&t22, &t11, &adjoint(c).map(|v| -v))),
+            );
+            sep = Some(one / est);
+        }
+    }
+    let to_r = |x: Option<T::Real>| x.map(|x| R::from(x).unwrap());
+    Ok(SchurReorder {
+        dim,
+        s: to_r(s),
+        sep: to_r(sep),
+    })
+}
+
+pub(crate) fn block_size<T, D0, D1, L>(t: &Slice<T, (D0, D1), L>, k: usize) -> usize
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    if k + 1 < t.shape().dim(0) && t[[k + 1, k]] != T::zero() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Start and size of the diagonal blocks of a quasi-triangular matrix.
+pub(crate) fn schur_blocks<T, D0, D1, L>(t: &Slice<T, (D0, D1), L>) -> Vec<(usize, usize)>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let n = t.shape().dim(0);
+    let mut blocks = Vec::new();
+    let mut k = 0;
+    while k < n {
+        let size = block_size(t, k);
+        blocks.push((k, size));
+        k += size;
+    }
+    blocks
+}
+
+fn block_eigenvalues<T, D0, D1, L>(
+    t: &Slice<T, (D0, D1), L>,
+    k: usize,
+    size: usize,
+) -> Vec<Complex<T::Real>>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let c = |i: usize, j: usize| Complex::new(t[[i, j]].re(), t[[i, j]].im());
+    if size == 1 {
+        return vec![c(k, k)];
+    }
+    let (a, b, cc, d) = (c(k, k), c(k, k + 1), c(k + 1, k), c(k + 1, k + 1));
+    let two = T::Real::one() + T::Real::one();
+    let mean = (a + d) / two;
+    let half_diff = (a - d) / two;
+    let root = (half_diff * half_diff + b * cc).sqrt();
+    let (l1, l2) = (mean + root, mean - root);
+    // List the eigenvalue with positive imaginary part first, as LAPACK.
+    if l1.im >= l2.im {
+        vec![l1, l2]
+    } else {
+        vec![l2, l1]
+    }
+}
+
+/// Swaps the adjacent diagonal blocks of sizes `p` and `q` starting at `k`.
+fn swap_blocks<T, D0, D1, Lt, Lz>(
+    t: &mut Slice<T, (D0, D1), Lt>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    k: usize,
+    p: usize,
+    q: usize,
+) -> Result<(), SchurError>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Lt: Layout,
+    Lz: Layout,
+{
+    let n = t.shape().dim(0);
+    let r = p + q;
+    let d = DArray::<T, 2>::from_fn([r, r], |i| t[[k + i[0], k + i[1]]]);
+    let block = |r0: usize, c0: usize, rows: usize, cols: usize| {
+        DArray::<T, 2>::from_fn([rows, cols], |i| d[[r0 + i[0], c0 + i[1]]])
+    };
+
+    // [-X; I] with T11 X - X T22 = T12 spans the invariant subspace of T22.
+    let x = small_sylvester(&block(0, 0, p, p), &block(p, p, q, q), &block(0, p, p, q));
+    let v = DArray::<T, 2>::from_fn([r, q], |i| {
+        if i[0] < p {
+            -x[[i[0], i[1]]]
+        } else if i[0] - p == i[1] {
+            T::one()
+        } else {
+            T::zero()
+        }
+    });
+    let qm = householder_q(v);
+
+    // Reject the swap if Q^H D Q is not block triangular to working precision.
+    let eps = T::Real::epsilon();
+    let dnorm = d.iter().fold(T::Real::zero(), |acc, v| acc.max(v.abs()));
+    let ten = <T::Real as NumCast>::from(10).unwrap();
+    let thresh = (ten * eps * dnorm).max(T::Real::min_positive_value());
+    let swapped = DArray::<T, 2>::from_fn([r, r], |i| {
+        (0..r).fold(T::zero(), |acc, a| {
+            acc + qm[[a, i[0]]].conj()
+                * (0..r).fold(T::zero(), |acc, b| acc + d[[a, b]] * qm[[b, i[1]]])
+        })
+    });
+    for i in q..r {
+        for j in 0..q {
+            if swapped[[i, j]].abs() > thresh {
+                return Err(SchurError::ReorderFailed);
+            }
+        }
+    }
+
+    for j in 0..n {
+        let col: Vec<T> = (0..r).map(|a| t[[k + a, j]]).collect();
+        for i in 0..r {
+            t[[k + i, j]] = (0..r).fold(T::zero(), |acc, a| acc + qm[[a, i]].conj() * col[a]);
+        }
+    }
+    rotate_columns(t, k, &qm);
+    rotate_columns(z, k, &qm);
+    for i in q..r {
+        for j in 0..q {
+            t[[k + i, k + j]] = T::zero();
+        }
+    }
+
+    if imaginary_unit::<T>().is_none() {
+        if q == 2 {
+            standardize(t, z, k);
+        }
+        if p == 2 {
+            standardize(t, z, k + q);
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the columns `k..k + r` of `m` by their product with the
+/// `r × r` matrix `qm`.
+pub(crate) fn rotate_columns<T, D0, D1, L>(
+    m: &mut Slice<T, (D0, D1), L>,
+    k: usize,
+    qm: &DArray<T, 2>,
+) where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let r = qm.shape().0;
+    for i in 0..m.shape().dim(0) {
+        let row: Vec<T> = (0..r).map(|a| m[[i, k + a]]).collect();
+        for j in 0..r {
+            m[[i, k + j]] = (0..r).fold(T::zero(), |acc, a| acc + row[a] * qm[[a, j]]);
+        }
+    }
+}
+
+/// Unitary `Q` whose leading columns span those of `v`, from Householder
+/// reflections.
+pub(crate) fn householder_q<T: ComplexFloat>(mut v: DArray<T, 2>) -> DArray<T, 2> {
+    let (r, q) = *v.shape();
+    let two = scalar::<T>(T::Real::one() + T::Real::one());
+    let mut qm =
+        DArray::<T, 2>::from_fn([r, r], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+    for j in 0..q {
+        let mut u: Vec<T> = (j..r).map(|i| v[[i, j]]).collect();
+        let alpha = Float::sqrt(
+            u.iter()
+                .fold(T::Real::zero(), |acc, x| acc + x.abs() * x.abs()),
+        );
+        if alpha == T::Real::zero() {
+            continue;
+        }
+        let phase = if u[0] == T::zero() {
+            T::one()
+        } else {
+            u[0] * scalar::<T>(T::Real::one() / u[0].abs())
+        };
+        u[0] = u[0] + phase * scalar::<T>(alpha);
+        let unorm2 = scalar::<T>(
+            u.iter()
+                .fold(T::Real::zero(), |acc, x| acc + x.abs() * x.abs()),
+        );
+
+        // H = I - 2 u u^H / (u^H u), applied as V <- H V and Q <- Q H.
+        for c in j..q {
+            let dot = (0..u.len()).fold(T::zero(), |acc, l| acc + u[l].conj() * v[[j + l, c]]);
+            for (l, ul) in u.iter().enumerate() {
+                v[[j + l, c]] = v[[j + l, c]] - two * *ul * dot / unorm2;
+            }
+        }
+        for i in 0..r {
+            let dot = (0..u.len()).fold(T::zero(), |acc, l| acc + qm[[i, j + l]] * u[l]);
+            for (l, ul) in u.iter().enumerate() {
+                qm[[i, j + l]] = qm[[i, j + l]] - two * dot * ul.conj() / unorm2;
+            }
+        }
+    }
+    qm
+}
+
+/// Puts the real `2 × 2` block at `k` in standard form: equal diagonal
+/// entries for a complex conjugate pair, upper triangular otherwise.
+fn standardize<T, D0, D1, Lt, Lz>(
+    t: &mut Slice<T, (D0, D1), Lt>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    k: usize,
+) where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Lt: Layout,
+    Lz: Layout,
+{
+    let n = t.shape().dim(0);
+    let (a, b, c, d) = (
+        t[[k, k]].re(),
+        t[[k, k + 1]].re(),
+        t[[k + 1, k]].re(),
+        t[[k + 1, k + 1]].re(),
+    );
+    if c == T::Real::zero() {
+        return;
+    }
+    let two = T::Real::one() + T::Real::one();
+    let p = (a - d) / two;
+    let disc = p * p + b * c;
+    let real_pair = disc >= T::Real::zero();
+    let (cs, sn) = if real_pair {
+        // Rotate the eigenvector (λ - d, c) onto e_1 to split the block.
+        let lambda = d + p + Float::sqrt(disc).copysign(p);
+        let (x, y) = (lambda - d, c);
+        let r = x.hypot(y);
+        (x / r, y / r)
+    } else {
+        // (a' - d') = (a - d) cos 2θ + (b + c) sin 2θ vanishes.
+        let theta = Float::atan2(d - a, b + c) / two;
+        (Float::cos(theta), Float::sin(theta))
+    };
+    let (cs, sn) = (scalar::<T>(cs), scalar::<T>(sn));
+    for j in 0..n {
+        let (x, y) = (t[[k, j]], t[[k + 1, j]]);
+        t[[k, j]] = cs * x + sn * y;
+        t[[k + 1, j]] = cs * y - sn * x;
+    }
+    for i in 0..n {
+        let (x, y) = (t[[i, k]], t[[i, k + 1]]);
+        t[[i, k]] = cs * x + sn * y;
+        t[[i, k + 1]] = cs * y - sn * x;
+        let (x, y) = (z[[i, k]], z[[i, k + 1]]);
+        z[[i, k]] = cs * x + sn * y;
+        z[[i, k + 1]] = cs * y - sn * x;
+    }
+    if real_pair {
+        t[[k + 1, k]] = T::zero();
+    } else {
+        let mean = (t[[k, k]] + t[[k + 1, k + 1]]) * scalar::<T>(T::Real::one() / two);
+        t[[k, k]] = mean;
+        t[[k + 1, k + 1]] = mean;
+    }
+}
+
+/// Solves `A X - X B = C` for upper quasi-triangular `A` and `B`, by
+/// substitution over their diagonal blocks as LAPACK's `trsyl`.
+fn quasi_sylvester<T>(a: &DArray<T, 2>, b: &DArray<T, 2>, c: &DArray<T, 2>) -> DArray<T, 2>
+where
+    T: ComplexFloat,
+{
+    let (m, n) = *c.shape();
+    let mut x = DArray::<T, 2>::from_elem([m, n], T::zero());
+    let a_blocks = schur_blocks(a);
+    for &(j0, q) in &schur_blocks(b) {
+        for &(i0, p) in a_blocks.iter().rev() {
+            let rhs = DArray::<T, 2>::from_fn([p, q], |idx| {
+                let (i, j) = (i0 + idx[0], j0 + idx[1]);
+                let below = (i0 + p..m).fold(T::zero(), |acc, k| acc + a[[i, k]] * x[[k, j]]);
+                let left = (0..j0).fold(T::zero(), |acc, l| acc + x[[i, l]] * b[[l, j]]);
+                c[[i, j]] - below + left
+            });
+            let aii = DArray::<T, 2>::from_fn([p, p], |idx| a[[i0 + idx[0], i0 + idx[1]]]);
+            let bjj = DArray::<T, 2>::from_fn([q, q], |idx| b[[j0 + idx[0], j0 + idx[1]]]);
+            let xij = small_sylvester(&aii, &bjj, &rhs);
+            for i in 0..p {
+                for j in 0..q {
+                    x[[i0 + i, j0 + j]] = xij[[i, j]];
+                }
+            }
+        }
+    }
+    x
+}
+
+/// Solves `A X - X B = C` for `p × p` `A` and `q × q` `B` with `p, q <= 2`
+/// as a linear system of order `p q`, perturbing tiny pivots as LAPACK.
+fn small_sylvester<T>(a: &DArray<T, 2>, b: &DArray<T, 2>, c: &DArray<T, 2>) -> DArray<T, 2>
+where
+    T: ComplexFloat,
+{
+    let (p, q) = *c.shape();
+    let size = p * q;
+    // Unknown X[i, j] is entry i + p j.
+    let mut m = vec![vec![T::zero(); size + 1]; size];
+    for j in 0..q {
+        for i in 0..p {
+            let row = &mut m[i + p * j];
+            for l in 0..p {
+                row[l + p * j] = row[l + p * j] + a[[i, l]];
+            }
+            for l in 0..q {
+                row[i + p * l] = row[i + p * l] - b[[l, j]];
+            }
+            row[size] = c[[i, j]];
+        }
+    }
+    let norm = a
+        .iter()
+        .chain(b.iter())
+        .fold(T::Real::zero(), |acc, v| acc.max(v.abs()));
+    let sol = solve_small(m, norm);
+    DArray::<T, 2>::from_fn([p, q], |i| sol[i[0] + p * i[1]])
+}
+
+/// Solves the small dense system whose augmented matrix has the rows `m` by
+/// Gaussian elimination with partial pivoting, replacing pivots tiny relative
+/// to `norm` by `eps * norm` as LAPACK.
+pub(crate) fn solve_small<T: ComplexFloat>(mut m: Vec<Vec<T>>, norm: T::Real) -> Vec<T> {
+    let size = m.len();
+    let smin = (T::Real::epsilon() * norm).max(T::Real::min_positive_value());
+
+    for col in 0..size {
+        let pivot = (col..size)
+            .max_by(|&i, &j| {
+                m[i][col]
+                    .abs()
+                    .partial_cmp(&m[j][col].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(col);
+        m.swap(col, pivot);
+        if m[col][col].abs() < smin {
+            m[col][col] = scalar::<T>(smin);
+        }
+        for row in col + 1..size {
+            let f = m[row][col] / m[col][col];
+            let (upper, lower) = m.split_at_mut(row);
+            for (x, &v) in lower[0][col..].iter_mut().zip(&upper[col][col..]) {
+                *x = *x - f * v;
+            }
+        }
+    }
+    let mut sol = vec![T::zero(); size];
+    for row in (0..size).rev() {
+        let acc = (row + 1..size).fold(m[row][size], |acc, k| acc - m[row][k] * sol[k]);
+        sol[row] = acc / m[row][row];
+    }
+    sol
+}
+
+fn adjoint<T: ComplexFloat>(a: &DArray<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+/// Estimates the 1-norm of a linear operator on `shape` matrices from
+/// products with the operator (`apply`) and its adjoint, with Hager's method
+/// as refined by Higham (LAPACK's `lacn2`).
+fn estimate_inverse_norm<T>(
+    shape: (usize, usize),
+    apply: impl Fn(&DArray<T, 2>) -> DArray<T, 2>,
+    apply_adjoint: impl Fn(&DArray<T, 2>) -> DArray<T, 2>,
+) -> T::Real
+where
+    T: ComplexFloat,
+{
+    let len = shape.0 * shape.1;
+    let real = |v: usize| <T::Real as NumCast>::from(v).unwrap();
+    let norm1 = |y: &DArray<T, 2>| y.iter().fold(T::Real::zero(), |acc, v| acc + v.abs());
+    let sign = |y: &DArray<T, 2>| {
+        y.to_array().map(|v| {
+            if v.abs() == T::Real::zero() {
+                T::one()
+            } else {
+                v * scalar::<T>(T::Real::one() / v.abs())
+            }
+        })
+    };
+
+    let mut x =
+        DArray::<T, 2>::from_elem([shape.0, shape.1], scalar::<T>(T::Real::one() / real(len)));
+    let mut y = apply(&x);
+    let mut est = norm1(&y);
+    for _ in 0..5 {
+        let g = apply_adjoint(&sign(&y));
+        let (j, gmax) = g
+            .iter()
+            .enumerate()
+            .fold((0, T::Real::zero()), |(j, m), (i, v)| {
+                if v.abs() > m { (i, v.abs()) } else { (j, m) }
+            });
+        let gx = g
+            .iter()
+            .zip(x.iter())
+            .fold(T::Real::zero(), |acc, (g, x)| acc + (g.conj() * *x).re());
+        if gmax <= gx {
+            break;
+        }
+        x = DArray::<T, 2>::from_fn([shape.0, shape.1], |i| {
+            if i[0] * shape.1 + i[1] == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+        y = apply(&x);
+        let next = norm1(&y);
+        if next <= est {
+            break;
+        }
+        est = next;
+    }
+
+    // Alternating vector guarding against the worst cases of the iteration.
+    let denom = real(len.max(2) - 1);
+    let alt = DArray::<T, 2>::from_fn([shape.0, shape.1], |i| {
+        let k = i[0] * shape.1 + i[1];
+        let v = T::Real::one() + real(k) / denom;
+        scalar::<T>(if k.is_multiple_of(2) { v } else { -v })
+    });
+    let three = real(3);
+    let alt_est = (T::Real::one() + T::Real::one()) * norm1(&apply(&alt)) / (three * real(len));
+    est.max(alt_est)
+}
+
+pub(crate) fn scalar<T: ComplexFloat>(x: T::Real) -> T {
+    T::from(x).unwrap()
+}
+
+pub(crate) fn spectral<S: ComplexFloat, R: Float>(z: Complex<R>) -> S {
+    let re = S::from(z.re).unwrap();
+    match imaginary_unit::<S>() {
+        Some(i) => re + i * S::from(z.im).unwrap(),
+        None => re,
+    }
+}
+
+/// Right eigenvector of the upper quasi-triangular `t` for the eigenvalue
+/// `lambda` of its diagonal block `blocks[b]`: a null vector of that block,
+/// extended upwards by block back substitution.  Near-singular diagonal
+/// blocks are perturbed as in LAPACK's `trevc`.
+fn quasi_triangular_eigenvector<R: Float>(
+    t: &DArray<Complex<R>, 2>,
+    blocks: &[(usize, usize)],
+    b: usize,
+    lambda: Complex<R>,
+) -> Vec<Complex<R>> {
+    let n = t.shape().0;
+    let smin = Float::max(
+        R::epsilon() * (lambda.re.abs() + lambda.im.abs()),
+        R::min_positive_value() / R::epsilon(),
+    );
+    let mut x = vec![Complex::zero(); n];
+    let (k, size) = blocks[b];
+    if size == 1 {
+        x[k] = Complex::one();
+    } else {
+        // (b, λ - a) and (λ - d, c) both span the null space of the block.
+        let (a, bb, c, d) = (t[[k, k]], t[[k, k + 1]], t[[k + 1, k]], t[[k + 1, k + 1]]);
+        let (u, v) = ((bb, lambda - a), (lambda - d, c));
+        (x[k], x[k + 1]) = if u.0.norm().hypot(u.1.norm()) >= v.0.norm().hypot(v.1.norm()) {
+            u
+        } else {
+            v
+        };
+    }
+    let end = k + size;
+    for &(j, size) in blocks[..b].iter().rev() {
+        let rhs =
+            |r: usize| -(j + size..end).fold(Complex::zero(), |acc, l| acc + t[[r, l]] * x[l]);
+        if size == 1 {
+            let mut p = t[[j, j]] - lambda;
+            if p.norm() < smin {
+                p = Complex::new(smin, R::zero());
+            }
+            x[j] = rhs(j) / p;
+        } else {
+            let m = [
+                [t[[j, j]] - lambda, t[[j, j + 1]]],
+                [t[[j + 1, j]], t[[j + 1, j + 1]] - lambda],
+            ];
+            (x[j], x[j + 1]) = solve_2x2(m, (rhs(j), rhs(j + 1)), smin);
+        }
+    }
+    x
+}
+
+/// Solves a `2 × 2` system by Gaussian elimination with complete pivoting,
+/// perturbing pivots smaller than `smin`.
+fn solve_2x2<R: Float>(
+    m: [[Complex<R>; 2]; 2],
+    rhs: (Complex<R>, Complex<R>),
+    smin: R,
+) -> (Complex<R>, Complex<R>) {
+    let (mut pi, mut pj) = (0, 0);
+    for i in 0..2 {
+        for j in 0..2 {
+            if m[i][j].norm() > m[pi][pj].norm() {
+                (pi, pj) = (i, j);
+            }
+        }
+    }
+    let (qi, qj) = (1 - pi, 1 - pj);
+    let r = [rhs.0, rhs.1];
+    let mut pivot = m[pi][pj];
+    if pivot.norm() < smin {
+        pivot = Complex::new(smin, R::zero());
+    }
+    let f = m[qi][pj] / pivot;
+    let mut second = m[qi][qj] - f * m[pi][qj];
+    if second.norm() < smin {
+        second = Complex::new(smin, R::zero());
+    }
+    let mut x = [Complex::zero(); 2];
+    x[qj] = (r[qi] - f * r[pi]) / second;
+    x[pj] = (r[pi] - m[pi][qj] * x[qj]) / pivot;
+    (x[0], x[1])
 }
@@ -0,0 +1,632 @@
+//! Iterative eigensolvers for a few eigenpairs of large operators
+//!
+//! [`lanczos`] (self-adjoint operators) and [`arnoldi`] (general operators)
+//! compute `k` eigenpairs at one end of the spectrum, selected by [`Which`].
+//! The operator is only accessed through products `y = A x`, supplied as a
+//! closure, so it can be a dense matrix driven by [`MatVec`](crate::MatVec)
+//! or any matrix-free operator; a
+//! [`LinearOperator`](crate::operator::LinearOperator) `op` is passed as
+//! `|x, y| op.apply(x, y)`.
+//!
+//! Both solvers build a Krylov basis of dimension
+//! [`EigsConfig::krylov_dim`] and restart it with the Krylov–Schur (thick
+//! restart) scheme, which is mathematically equivalent to implicit restarting
+//! with exact shifts: the wanted Ritz vectors are kept and the others are
+//! purged.  The small projected problems are solved through an [`Eig`]
+//! backend.
+//!
+//! For interior eigenvalues close to a shift `σ`, [`ShiftInvert`] factors
+//! `A - σI` once with an [`LU`] backend and applies `(A - σI)^{-1}`;
+//! [`lanczos_shift_invert`] and [`arnoldi_shift_invert`] map the eigenvalues
+//! back to those of `A`.
+//!```rust,ignore
+//!use mdarray::DArray;
+//!use mdarray_linalg::eigs::{EigsConfig, Which, lanczos};
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg_faer::Faer;
+//!
+//!let n = 1000;
+//!let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+//!    if i[0] == i[1] { 2.0 } else if i[0].abs_diff(i[1]) == 1 { -1.0 } else { 0.0 }
+//!});
+//!
+//!let bd = Faer::default();
+//!let config = EigsConfig { which: Which::LargestReal, ..Default::default() };
+//!let decomp = lanczos(&bd, n, |x, y| bd.matvec(&a, x).write(y), 4, &config)
+//!    .expect("Lanczos did not converge");
+//!// decomp.eigenvalues holds the 4 largest eigenvalues, decomp.eigenvectors is n × 4
+//!```
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    eig::{Eig, EigDecomp, EigError, EighDecomp},
+    lu::LU,
+    utils::{from_complex, imaginary_unit, to_complex},
+};
+
+/// Error types related to iterative eigensolvers
+#[derive(Debug, Error)]
+pub enum EigsError {
+    /// The requested number of eigenpairs or the Krylov dimension does not
+    /// fit the operator size
+    #[error("Invalid dimensions: {k} eigenpairs with Krylov dimension {krylov_dim} for size {n}")]
+    InvalidDimensions { n: usize, k: usize, krylov_dim: usize },
+
+    /// Not all requested eigenpairs reached the tolerance
+    #[error("Only {converged} of {requested} eigenpairs converged after {restarts} restarts")]
+    NotConverged {
+        converged: usize,
+        requested: usize,
+        restarts: usize,
+    },
+
+    /// The matrix of a shift-invert transformation is not square
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// The shifted matrix of a shift-invert transformation is singular to
+    /// working precision
+    #[error("Shifted matrix is singular: negligible pivot at position {pivot}")]
+    SingularShift { pivot: usize },
+
+    /// The projected eigenproblem failed in the backend
+    #[error("Projected eigenproblem failed: {0}")]
+    Eig(#[from] EigError),
+}
+
+/// End of the spectrum targeted by an iterative eigensolver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Which {
+    /// Eigenvalues of largest magnitude.
+    LargestMagnitude,
+    /// Eigenvalues of smallest magnitude.
+    SmallestMagnitude,
+    /// Eigenvalues of largest real part.
+    LargestReal,
+    /// Eigenvalues of smallest real part.
+    SmallestReal,
+}
+
+/// Settings for [`lanczos`] and [`arnoldi`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EigsConfig<R> {
+    /// Targeted end of the spectrum.
+    pub which: Which,
+    /// Relative tolerance: a Ritz pair `(θ, x)` is accepted once
+    /// `‖A x - θ x‖ <= tol * max(|θ|, ε^{2/3})`.
+    pub tol: R,
+    /// Maximum number of restarts.
+    pub max_restarts: usize,
+    /// Dimension of the Krylov basis.  Defaults to `min(n, max(2k + 1, 20))`.
+    pub krylov_dim: Option<usize>,
+}
+
+impl<R: Float> Default for EigsConfig<R> {
+    fn default() -> Self {
+        Self {
+            which: Which::LargestMagnitude,
+            tol: eps23(),
+            max_restarts: 300,
+            krylov_dim: None,
+        }
+    }
+}
+
+/// Computes `k` eigenpairs of a self-adjoint operator of size `n` with the
+/// restarted Lanczos method.
+///
+/// `op(x, y)` must overwrite `y` with `A x`.  Eigenvalues are returned in the
+/// order given by `config.which`, most wanted first, and the eigenvectors are
+/// the orthonormal columns of an `n × k` matrix.
+pub fn lanczos<T, B>(
+    bd: &B,
+    n: usize,
+    op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    k: usize,
+    config: &EigsConfig<T::Real>,
+) -> Result<EighDecomp<T, T::Real, Dyn, Dyn>, EigsError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, RealScalar = T::Real>,
+{
+    let ritz = krylov_schur(n, op, k, config, |h| {
+        let m = h.len();
+        let mut hs = DArray::<T, 2>::from_fn([m, m], |i| {
+            (h[i[0]][i[1]] + h[i[1]][i[0]].conj()) / (T::one() + T::one())
+        });
+        let EighDecomp {
+            eigenvalues,
+            eigenvectors,
+        } = bd.eigh(&mut hs)?;
+        Ok((0..m)
+            .map(|j| RitzPair {
+                value: Complex::new(eigenvalues[j], T::Real::zero()),
+                vector: (0..m).map(|i| to_complex(eigenvectors[[i, j]])).collect(),
+            })
+            .collect())
+    })?;
+
+    let k = ritz.values.len();
+    Ok(EighDecomp {
+        eigenvalues: DArray::<T::Real, 1>::from_fn([k], |i| ritz.values[i[0]].re),
+        eigenvectors: DArray::<T, 2>::from_fn([n, k], |i| {
+            from_complex(ritz.vectors[i[1]][i[0]], ritz.imaginary_unit)
+        }),
+    })
+}
+
+/// Computes `k` eigenpairs of a general operator of size `n` with the
+/// restarted Arnoldi method.
+///
+/// `op(x, y)` must overwrite `y` with `A x`.  Eigenvalues are returned in the
+/// order given by `config.which`, most wanted first, together with the
+/// unit-norm right eigenvectors as the columns of an `n × k` matrix.
+pub fn arnoldi<T, B>(
+    bd: &B,
+    n: usize,
+    op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    k: usize,
+    config: &EigsConfig<T::Real>,
+) -> Result<EigDecomp<Complex<T::Real>, Dyn, Dyn>, EigsError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let ritz = krylov_schur(n, op, k, config, |h| {
+        let m = h.len();
+        let mut hm = DArray::<T, 2>::from_fn([m, m], |i| h[i[0]][i[1]]);
+        let decomp = bd.eig(&mut hm)?;
+        let vectors = decomp.right_eigenvectors.ok_or(EigError::BackendError(0))?;
+        Ok((0..m)
+            .map(|j| RitzPair {
+                value: decomp.eigenvalues[j],
+                vector: (0..m).map(|i| vectors[[i, j]]).collect(),
+            })
+            .collect())
+    })?;
+
+    let k = ritz.values.len();
+    Ok(EigDecomp {
+        eigenvalues: DArray::<Complex<T::Real>, 1>::from_fn([k], |i| ritz.values[i[0]]),
+        left_eigenvectors: None,
+        right_eigenvectors: Some(DArray::<Complex<T::Real>, 2>::from_fn([n, k], |i| {
+            ritz.vectors[i[1]][i[0]]
+        })),
+    })
+}
+
+/// Shift-invert transformation `x ↦ (A - σI)^{-1} x` backed by a single LU
+/// factorization of `A - σI`.
+///
+/// The factorization is computed once in [`ShiftInvert::new`] and reused by
+/// every application, so the same value can drive several solves.
+pub struct ShiftInvert<T> {
+    sigma: T,
+    l: DArray<T, 2>,
+    u: DArray<T, 2>,
+    p: DArray<T, 2>,
+}
+
+impl<T> ShiftInvert<T>
+where
+    T: ComplexFloat + Default,
+{
+    /// Factors `A - σI` with the LU backend `bd`.
+    ///
+    /// Returns [`EigsError::SingularShift`] if a pivot of `U` does not exceed
+    /// `n ε ‖A - σI‖₁`, i.e. if `σ` is an eigenvalue to working precision.
+    pub fn new(bd: &impl LU<T, Dyn, Dyn>, a: &DSlice<T, 2>, sigma: T) -> Result<Self, EigsError> {
+        let (n, cols) = *a.shape();
+        if cols != n {
+            return Err(EigsError::NotSquare { rows: n, cols });
+        }
+
+        let mut shifted = DArray::<T, 2>::from_fn([n, n], |i| {
+            if i[0] == i[1] {
+                a[[i[0], i[1]]] - sigma
+            } else {
+                a[[i[0], i[1]]]
+            }
+        });
+        let norm = (0..n)
+            .map(|j| (0..n).fold(T::Real::zero(), |acc, i| acc + shifted[[i, j]].abs()))
+            .fold(T::Real::zero(), Float::max);
+        let tol = <T::Real as Float>::epsilon() * <T::Real as NumCast>::from(n).unwrap() * norm;
+        let (l, u, p) = bd.lu(&mut shifted);
+
+        if let Some(pivot) = (0..n).find(|&i| u[[i, i]].abs() <= tol) {
+            return Err(EigsError::SingularShift { pivot });
+        }
+
+        Ok(Self { sigma, l, u, p })
+    }
+
+    /// The shift `σ`.
+    pub fn sigma(&self) -> T {
+        self.sigma
+    }
+
+    /// Overwrites `y` with `(A - σI)^{-1} x`.
+    pub fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        let n = self.u.shape().0;
+
+        // P (A - σI) = L U, so (A - σI)^{-1} x = U^{-1} L^{-1} P x.
+        for i in 0..n {
+            let mut acc = (0..n).fold(T::zero(), |acc, j| acc + self.p[[i, j]] * x[j]);
+            for j in 0..i {
+                acc = acc - self.l[[i, j]] * y[j];
+            }
+            y[i] = acc / self.l[[i, i]];
+        }
+        for i in (0..n).rev() {
+            let mut acc = y[i];
+            for j in i + 1..n {
+                acc = acc - self.u[[i, j]] * y[j];
+            }
+            y[i] = acc / self.u[[i, i]];
+        }
+    }
+}
+
+/// Computes the `k` eigenpairs of a self-adjoint matrix closest to the shift
+/// of `si`, which must be real.
+///
+/// `config.which` applies to the eigenvalues `ν = 1 / (λ - σ)` of the
+/// transformed operator, so [`Which::LargestMagnitude`] selects the
+/// eigenvalues `λ` nearest to `σ`.
+pub fn lanczos_shift_invert<T, B>(
+    bd: &B,
+    si: &ShiftInvert<T>,
+    k: usize,
+    config: &EigsConfig<T::Real>,
+) -> Result<EighDecomp<T, T::Real, Dyn, Dyn>, EigsError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, RealScalar = T::Real>,
+{
+    let n = si.u.shape().0;
+    let mut decomp = lanczos(bd, n, |x, y| si.apply(x, y), k, config)?;
+    let sigma = si.sigma.re();
+    for nu in decomp.eigenvalues.iter_mut() {
+        *nu = sigma + T::Real::one() / *nu;
+    }
+    Ok(decomp)
+}
+
+/// Computes the `k` eigenpairs of a general matrix closest to the shift of
+/// `si`.
+///
+/// `config.which` applies to the eigenvalues `ν = 1 / (λ - σ)` of the
+/// transformed operator, so [`Which::LargestMagnitude`] selects the
+/// eigenvalues `λ` nearest to `σ`.
+pub fn arnoldi_shift_invert<T, B>(
+    bd: &B,
+    si: &ShiftInvert<T>,
+    k: usize,
+    config: &EigsConfig<T::Real>,
+) -> Result<EigDecomp<Complex<T::Real>, Dyn, Dyn>, EigsError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let n = si.u.shape().0;
+    let mut decomp = arnoldi(bd, n, |x, y| si.apply(x, y), k, config)?;
+    let sigma = to_complex(si.sigma);
+    for nu in decomp.eigenvalues.iter_mut() {
+        *nu = sigma + Complex::new(T::Real::one(), T::Real::zero()) / *nu;
+    }
+    Ok(decomp)
+}
+
+/// Eigenpair of the projected matrix.
+struct RitzPair<R> {
+    value: Complex<R>,
+    vector: Vec<Complex<R>>,
+}
+
+/// Converged Ritz values and vectors of the operator.
+struct Ritz<T, R> {
+    values: Vec<Complex<R>>,
+    vectors: Vec<Vec<Complex<R>>>,
+    imaginary_unit: Option<T>,
+}
+
+/// Krylov–Schur iteration shared by [`lanczos`] and [`arnoldi`].
+///
+/// `project` returns the eigenpairs of the projected matrix, given as rows.
+fn krylov_schur<T, F>(
+    n: usize,
+    mut op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    k: usize,
+    config: &EigsConfig<T::Real>,
+    mut project: F,
+) -> Result<Ritz<T, T::Real>, EigsError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    F: FnMut(&[Vec<T>]) -> Result<Vec<RitzPair<T::Real>>, EigError>,
+{
+    let m = config.krylov_dim.unwrap_or((2 * k + 1).max(20)).min(n);
+    if k > m || (k == m && m < n) {
+        return Err(EigsError::InvalidDimensions {
+            n,
+            k,
+            krylov_dim: m,
+        });
+    }
+
+    let imaginary_unit = imaginary_unit::<T>();
+    if k == 0 {
+        return Ok(Ritz {
+            values: vec![],
+            vectors: vec![],
+            imaginary_unit,
+        });
+    }
+
+    let zero = T::Real::zero();
+    let mut rng = StartVector::new();
+
+    // Basis vectors v_0 .. v_m and the (m + 1) × m projected matrix H with
+    // A V_m = V_m H_m + h_{m, m-1} v_m e_m^T.
+    let mut v: Vec<DArray<T, 1>> = vec![rng.orthonormal(n, &[])];
+    let mut h = vec![vec![T::zero(); m]; m + 1];
+    let mut w = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut start = 0;
+
+    for restart in 0..=config.max_restarts {
+        for j in start..m {
+            op(&v[j], &mut w);
+            let coeffs = orthogonalize(&v, &mut w);
+            for (i, c) in coeffs.into_iter().enumerate() {
+                h[i][j] = c;
+            }
+
+            let beta = norm(&w);
+            let scale = h.iter().take(j + 1).map(|row| row[j].abs()).fold(zero, Float::max);
+            let next = if beta > <T::Real as Float>::epsilon() * scale {
+                h[j + 1][j] = beta.into();
+                let inv: T = (T::Real::one() / beta).into();
+                DArray::<T, 1>::from_fn([n], |i| w[i] * inv)
+            } else {
+                // Invariant subspace: continue with a fresh direction.
+                h[j + 1][j] = T::zero();
+                rng.orthonormal(n, &v)
+            };
+            v.truncate(j + 1);
+            v.push(next);
+        }
+
+        let mut pairs = project(&h[..m])?;
+        for pair in pairs.iter_mut() {
+            let ny = Float::sqrt(pair.vector.iter().fold(zero, |acc, z| acc + z.norm_sqr()));
+            pair.vector.iter_mut().for_each(|z| *z = *z / ny);
+        }
+        sort_by_which(&mut pairs, config.which);
+
+        // ‖A x - θ x‖ = |h_m^T y| for unit-norm y.
+        let residuals: Vec<T::Real> = pairs
+            .iter()
+            .map(|p| {
+                (0..m)
+                    .fold(Complex::new(zero, zero), |acc, c| acc + to_complex(h[m][c]) * p.vector[c])
+                    .norm()
+            })
+            .collect();
+        let converged = (0..k)
+            .take_while(|&i| residuals[i] <= config.tol * pairs[i].value.norm().max(eps23()))
+            .count();
+
+        if converged == k {
+            let vectors = pairs[..k]
+                .iter()
+                .map(|p| {
+                    let mut x = vec![Complex::new(zero, zero); n];
+                    for (c, vc) in v.iter().take(m).enumerate() {
+                        for (xi, &vi) in x.iter_mut().zip(vc.iter()) {
+                            *xi = *xi + to_complex(vi) * p.vector[c];
+                        }
+                    }
+                    let nx = Float::sqrt(x.iter().fold(zero, |acc, z| acc + z.norm_sqr()));
+                    x.iter().map(|z| z / nx).collect()
+                })
+                .collect();
+            return Ok(Ritz {
+                values: pairs[..k].iter().map(|p| p.value).collect(),
+                vectors,
+                imaginary_unit,
+            });
+        }
+
+        if restart == config.max_restarts {
+            return Err(EigsError::NotConverged {
+                converged,
+                requested: k,
+                restarts: restart,
+            });
+        }
+
+        // Keep an orthonormal basis Y of the wanted invariant subspace of
+        // H_m, so that A (V_m Y) = (V_m Y) (Y^H H_m Y) + v_m (h_m^T Y).
+        let keep = (k + (m - k) / 2).min(m - 1).max(k.min(m - 1));
+        let y = restart_basis(&pairs, keep, m - 1, imaginary_unit);
+        let p = y.len();
+
+        let hy: Vec<Vec<T>> = y
+            .iter()
+            .map(|yc| (0..=m).map(|r| (0..m).fold(T::zero(), |acc, c| acc + h[r][c] * yc[c])).collect())
+            .collect();
+        let mut h_new = vec![vec![T::zero(); m]; m + 1];
+        for (a, ya) in y.iter().enumerate() {
+            for (b, hyb) in hy.iter().enumerate() {
+                h_new[a][b] = (0..m).fold(T::zero(), |acc, r| acc + ya[r].conj() * hyb[r]);
+            }
+        }
+        for (b, hyb) in hy.iter().enumerate() {
+            h_new[p][b] = hyb[m];
+        }
+
+        let mut v_new: Vec<DArray<T, 1>> = y
+            .iter()
+            .map(|yc| {
+                DArray::<T, 1>::from_fn([n], |i| {
+                    (0..m).fold(T::zero(), |acc, c| acc + v[c][i] * yc[c])
+                })
+            })
+            .collect();
+        v_new.push(v.swap_remove(m));
+
+        v = v_new;
+        h = h_new;
+        start = p;
+    }
+
+    unreachable!("the restart loop always returns")
+}
+
+/// Orders Ritz pairs by `which`, most wanted first.
+fn sort_by_which<R: Float>(pairs: &mut [RitzPair<R>], which: Which) {
+    let key = |z: &Complex<R>| match which {
+        Which::LargestMagnitude => -z.norm(),
+        Which::SmallestMagnitude => z.norm(),
+        Which::LargestReal => -z.re,
+        Which::SmallestReal => z.re,
+    };
+    pairs.sort_by(|a, b| {
+        key(&a.value)
+            .partial_cmp(&key(&b.value))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+/// Orthonormal basis (in the scalar field of `T`) of the span of the first
+/// `keep` Ritz vectors, with at most `max_len` columns.
+///
+/// For real `T`, a complex Ritz vector contributes its real and imaginary
+/// parts, which also span its conjugate partner.
+fn restart_basis<T>(
+    pairs: &[RitzPair<T::Real>],
+    keep: usize,
+    max_len: usize,
+    imaginary_unit: Option<T>,
+) -> Vec<Vec<T>>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    let zero = T::Real::zero();
+    let tol = Float::sqrt(<T::Real as Float>::epsilon());
+    let mut columns: Vec<Vec<T>> = Vec::new();
+
+    let push = |mut col: Vec<T>, columns: &mut Vec<Vec<T>>| {
+        for _ in 0..2 {
+            for q in columns.iter() {
+                let dot = q.iter().zip(&col).fold(T::zero(), |acc, (&a, &b)| acc + a.conj() * b);
+                for (c, &qi) in col.iter_mut().zip(q) {
+                    *c = *c - dot * qi;
+                }
+            }
+        }
+        let nc = Float::sqrt(col.iter().fold(zero, |acc, x| acc + (x.conj() * *x).re()));
+        if nc > tol {
+            let inv: T = (T::Real::one() / nc).into();
+            columns.push(col.into_iter().map(|x| x * inv).collect());
+        }
+    };
+
+    for pair in pairs.iter().take(keep) {
+        if columns.len() >= max_len {
+            break;
+        }
+        match imaginary_unit {
+            Some(i) => push(pair.vector.iter().map(|&z| from_complex(z, Some(i))).collect(), &mut columns),
+            None => {
+                push(pair.vector.iter().map(|z| z.re.into()).collect(), &mut columns);
+                if pair.value.im != zero && columns.len() < max_len {
+                    push(pair.vector.iter().map(|z| z.im.into()).collect(), &mut columns);
+                }
+            }
+        }
+    }
+
+    columns
+}
+
+/// Orthogonalizes `w` against `basis` with classical Gram–Schmidt and one
+/// reorthogonalization, returning the projection coefficients.
+fn orthogonalize<T: ComplexFloat>(basis: &[DArray<T, 1>], w: &mut DArray<T, 1>) -> Vec<T> {
+    let mut coeffs = vec![T::zero(); basis.len()];
+    for _ in 0..2 {
+        let dots: Vec<T> = basis
+            .iter()
+            .map(|q| q.iter().zip(w.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a.conj() * b))
+            .collect();
+        for (q, &d) in basis.iter().zip(&dots) {
+            for (wi, &qi) in w.iter_mut().zip(q.iter()) {
+                *wi = *wi - d * qi;
+            }
+        }
+        for (c, d) in coeffs.iter_mut().zip(dots) {
+            *c = *c + d;
+        }
+    }
+    coeffs
+}
+
+fn norm<T: ComplexFloat>(x: &DArray<T, 1>) -> T::Real {
+    Float::sqrt(x.iter().fold(T::Real::zero(), |acc, &a| acc + (a.conj() * a).re()))
+}
+
+fn eps23<R: Float>() -> R {
+    let two_thirds = <R as NumCast>::from(2.0 / 3.0).unwrap();
+    R::epsilon().powf(two_thirds)
+}
+
+/// Deterministic pseudo-random starting vectors (xorshift), so that runs are
+/// reproducible without a caller-provided generator.
+struct StartVector(u64);
+
+impl StartVector {
+    fn new() -> Self {
+        Self(0x9e37_79b9_7f4a_7c15)
+    }
+
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+    }
+
+    /// A unit vector orthogonal to `basis`, or zero if `basis` spans the
+    /// whole space.
+    fn orthonormal<T>(&mut self, n: usize, basis: &[DArray<T, 1>]) -> DArray<T, 1>
+    where
+        T: ComplexFloat,
+        T::Real: Into<T>,
+    {
+        let mut x = DArray::<T, 1>::from_fn([n], |_| {
+            <T::Real as NumCast>::from(self.next()).unwrap().into()
+        });
+        orthogonalize(basis, &mut x);
+        let nx = norm(&x);
+        if nx > <T::Real as Float>::epsilon() * <T::Real as NumCast>::from(n).unwrap() {
+            let inv: T = (T::Real::one() / nx).into();
+            for xi in x.iter_mut() {
+                *xi = *xi * inv;
+            }
+        } else {
+            for xi in x.iter_mut() {
+                *xi = T::zero();
+            }
+        }
+        x
+    }
+}
@@ -0,0 +1,542 @@
+//! Canonical gauge for eigenvalue and singular value decompositions
+//!
+//! Eigenvectors and singular vectors are only determined up to a phase, and up
+//! to a unitary transformation within a degenerate subspace, and backends
+//! return eigenvalues in different orders.  A [`Gauge`] fixes all of these
+//! choices, so that the results of different backends can be compared
+//! directly:
+//!
+//! - Eigenvalues are sorted by [`Gauge::order`], singular values by
+//!   [`Gauge::singular_order`] (descending by default).
+//! - The vectors of a degenerate eigenvalue or singular value are replaced by
+//!   a basis that only depends on the subspace they span.  Values are
+//!   degenerate when they differ by at most `100 n ε max|λ|`.
+//! - Each vector is multiplied by the phase that makes its largest component
+//!   real and positive.  Components within a relative `√ε` of the largest
+//!   count as ties, of which the first is chosen.
+//!
+//! Backends apply the gauge to their eigenvalue decompositions and SVDs when
+//! configured with `config_gauge`, and the `canonicalize` methods of
+//! [`EigDecomp`], [`EighDecomp`] and [`SVDDecomp`] apply it to an existing
+//! result.
+//!
+//! The singular vectors of zero singular values are only canonical when they
+//! span the whole null space, that is for full decompositions and thin
+//! decompositions of square matrices.
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::gauge::{Gauge, SpectrumOrder};
+//! use mdarray_linalg::prelude::*;
+//! use mdarray_linalg_backend::Backend;
+//!
+//! let bd = Backend::default().config_gauge(Gauge {
+//!     order: SpectrumOrder::DescendingAbs,
+//!     ..Default::default()
+//! });
+//! let EighDecomp { eigenvalues, eigenvectors } = bd
+//!     .eigh(&mut h.clone())
+//!     .expect("Self-adjoint eigenvalue decomposition failed");
+//!
+//! // Or on a result computed without a gauge.
+//! let mut decomp = Backend::default().svd(&mut a.clone()).expect("SVD failed");
+//! decomp.canonicalize(&Gauge::default());
+//! ```
+
+use std::{cmp::Ordering, ops::Range};
+
+use mdarray::{Dense, Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::{
+    eig::{EigCondition, EigDecomp, EighDecomp, scalar},
+    svd::SVDDecomp,
+};
+
+/// Order of the eigenvalues in a canonical gauge.
+///
+/// Real eigenvalues have a zero imaginary part.  Keys that differ by at most
+/// the degeneracy tolerance compare equal, so that rounding errors do not
+/// decide the order of, for example, complex conjugate pairs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpectrumOrder {
+    /// Ascending real part, then ascending imaginary part.
+    #[default]
+    Ascending,
+    /// Descending real part, then descending imaginary part.
+    Descending,
+    /// Ascending magnitude, then as [`Self::Ascending`].
+    AscendingAbs,
+    /// Descending magnitude, then as [`Self::Descending`].
+    DescendingAbs,
+}
+
+/// Canonical gauge of eigenvalue and singular value decompositions, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gauge {
+    /// Order of the eigenvalues
+    pub order: SpectrumOrder,
+    /// Order of the singular values.  As singular values are nonnegative,
+    /// the `Abs` variants sort as the plain ones.
+    pub singular_order: SpectrumOrder,
+}
+
+impl Default for Gauge {
+    fn default() -> Self {
+        Self {
+            order: SpectrumOrder::Ascending,
+            singular_order: SpectrumOrder::Descending,
+        }
+    }
+}
+
+impl<T, D0: Dim, D1: Dim> EighDecomp<T, T::Real, D0, D1>
+where
+    T: ComplexFloat,
+{
+    /// Sorts the eigenpairs and fixes the eigenvectors as `gauge` specifies.
+    ///
+    /// Returns the permutation of the eigenpairs: pair `k` of the result was
+    /// pair `perm[k]` of the input.
+    pub fn canonicalize(&mut self, gauge: &Gauge) -> Vec<usize> {
+        gauge_eigh(gauge, &mut *self.eigenvalues, Some(&mut *self.eigenvectors))
+    }
+}
+
+impl<S, D0: Dim, D1: Dim> EigDecomp<S, D0, D1>
+where
+    S: ComplexFloat,
+{
+    /// Sorts the eigenvalues and fixes the left and right eigenvectors as
+    /// `gauge` specifies.
+    ///
+    /// Returns the permutation of the eigenvalues: eigenvalue `k` of the
+    /// result was eigenvalue `perm[k]` of the input, which also applies to
+    /// condition numbers computed alongside.
+    pub fn canonicalize(&mut self, gauge: &Gauge) -> Vec<usize> {
+        gauge_eig(
+            gauge,
+            &mut *self.eigenvalues,
+            self.left_eigenvectors.as_deref_mut(),
+            self.right_eigenvectors.as_deref_mut(),
+        )
+    }
+}
+
+impl<T, S, D: Dim> SVDDecomp<T, S, D>
+where
+    T: ComplexFloat,
+    S: ComplexFloat<Real = T::Real>,
+{
+    /// Sorts the singular triplets and fixes the singular vectors as `gauge`
+    /// specifies.
+    ///
+    /// Returns the permutation of the singular triplets: triplet `k` of the
+    /// result was triplet `perm[k]` of the input.
+    pub fn canonicalize(&mut self, gauge: &Gauge) -> Vec<usize> {
+        gauge_svd(gauge, &mut *self.s, &mut *self.u, &mut *self.vt)
+    }
+}
+
+// The following exported items are unstable backend-implementation helpers.
+// They are hidden from generated documentation and may be redesigned before
+// the public API stabilizes.
+
+/// Applies `gauge` to the eigenvalues of a self-adjoint matrix and, if given,
+/// to its eigenvectors, and returns the permutation of the eigenpairs.
+#[doc(hidden)]
+pub fn gauge_eigh<T, D0, D1, L>(
+    gauge: &Gauge,
+    eigenvalues: &mut Slice<T::Real, (D0,), L>,
+    eigenvectors: Option<&mut Slice<T, (D0, D1), Dense>>,
+) -> Vec<usize>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let zero = T::Real::zero();
+    let keys: Vec<_> = eigenvalues.iter().map(|&x| (x, zero)).collect();
+    let n = keys.len();
+    let (perm, keys) = sort_spectrum(&keys, gauge.order, n);
+    for (k, &(x, _)) in keys.iter().enumerate() {
+        eigenvalues[k] = x;
+    }
+
+    if let Some(v) = eigenvectors {
+        permute_columns(v, &perm);
+        canonicalize_columns(v, &clusters(&keys, n));
+    }
+
+    perm
+}
+
+/// Applies `gauge` to the eigenvalues of a general matrix and to the given
+/// left and right eigenvectors, and returns the permutation of the
+/// eigenvalues.
+#[doc(hidden)]
+pub fn gauge_eig<S, D0, D1>(
+    gauge: &Gauge,
+    eigenvalues: &mut Slice<S, (D0,), Dense>,
+    left: Option<&mut Slice<S, (D0, D1), Dense>>,
+    right: Option<&mut Slice<S, (D0, D1), Dense>>,
+) -> Vec<usize>
+where
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+{
+    let values: Vec<S> = eigenvalues.iter().copied().collect();
+    let keys: Vec<_> = values.iter().map(|x| (x.re(), x.im())).collect();
+    let n = keys.len();
+    let (perm, keys) = sort_spectrum(&keys, gauge.order, n);
+    for (k, &i) in perm.iter().enumerate() {
+        eigenvalues[k] = values[i];
+    }
+
+    let clusters = clusters(&keys, n);
+    for v in [left, right].into_iter().flatten() {
+        permute_columns(v, &perm);
+        canonicalize_columns(v, &clusters);
+    }
+
+    perm
+}
+
+/// Applies `gauge` to an eigenvalue decomposition and reorders its condition
+/// numbers to match.
+#[doc(hidden)]
+pub fn gauge_eig_condition<S, R, D0, D1>(
+    gauge: &Gauge,
+    decomp: &mut EigDecomp<S, D0, D1>,
+    condition: &mut EigCondition<R, D0>,
+) where
+    S: ComplexFloat,
+    R: Copy,
+    D0: Dim,
+    D1: Dim,
+{
+    let perm = decomp.canonicalize(gauge);
+    for x in [&mut condition.eigenvalues, &mut condition.eigenvectors] {
+        let values: Vec<R> = x.iter().copied().collect();
+        for (k, &i) in perm.iter().enumerate() {
+            x[k] = values[i];
+        }
+    }
+}
+
+/// Applies `gauge` to a full, thin or partial SVD, and returns the
+/// permutation of the singular triplets.
+#[doc(hidden)]
+pub fn gauge_svd<T, S, D, Ls, Lu, Lvt>(
+    gauge: &Gauge,
+    s: &mut Slice<S, (D,), Ls>,
+    u: &mut Slice<T, (D, D), Lu>,
+    vt: &mut Slice<T, (D, D), Lvt>,
+) -> Vec<usize>
+where
+    T: ComplexFloat,
+    S: ComplexFloat<Real = T::Real>,
+    D: Dim,
+    Ls: Layout,
+    Lu: Layout,
+    Lvt: Layout,
+{
+    let zero = T::Real::zero();
+    let (m, n) = (u.shape().dim(0), vt.shape().dim(1));
+    let values: Vec<S> = s.iter().copied().collect();
+    let keys: Vec<_> = values.iter().map(|x| (x.re(), zero)).collect();
+    let (perm, keys) = sort_spectrum(&keys, SpectrumOrder::Descending, m.max(n));
+    for (k, &i) in perm.iter().enumerate() {
+        s[k] = values[i];
+    }
+    permute_columns(u, &perm);
+    let rows: Vec<Vec<T>> = perm.iter().map(|&i| row(vt, i)).collect();
+    for (k, x) in rows.iter().enumerate() {
+        set_row(vt, k, x);
+    }
+
+    let max = keys.first().map_or(zero, |x| x.0);
+    let null = keys
+        .iter()
+        .position(|x| x.0 <= tolerance(max, m.max(n)))
+        .unwrap_or(keys.len());
+
+    // Nonzero singular values: rotate U within each cluster into its
+    // canonical basis and apply the same rotation to the rows of V^H.
+    for c in clusters(&keys[..null], m.max(n)) {
+        let old: Vec<Vec<T>> = c.clone().map(|j| column(u, j)).collect();
+        let new = canonicalize(old.clone());
+        let old_rows: Vec<Vec<T>> = c.clone().map(|j| row(vt, j)).collect();
+        for (b, j) in c.enumerate() {
+            // Row b of Q^H V^H with Q = U_old^H U_new.
+            let x: Vec<T> = (0..n)
+                .map(|l| {
+                    old.iter()
+                        .zip(&old_rows)
+                        .fold(T::zero(), |acc, (ua, ra)| acc + dot(&new[b], ua) * ra[l])
+                })
+                .collect();
+            set_row(vt, j, &x);
+            set_column(u, j, &new[b]);
+        }
+    }
+
+    // Zero singular values: the remaining columns of U and rows of V^H span
+    // the null spaces of A^H and A, which are canonicalized independently.
+    let null_space = null..u.shape().dim(1);
+    canonicalize_columns(u, std::slice::from_ref(&null_space));
+    let conj = |x: Vec<T>| x.into_iter().map(|y| y.conj()).collect::<Vec<T>>();
+    let v: Vec<Vec<T>> = (null..vt.shape().dim(0)).map(|j| conj(row(vt, j))).collect();
+    for (j, x) in (null..).zip(canonicalize(v)) {
+        set_row(vt, j, &conj(x));
+    }
+
+    // The gauge is built in descending order; an ascending order reverses
+    // the singular triplets, and the remaining null vectors stay last.
+    if matches!(
+        gauge.singular_order,
+        SpectrumOrder::Ascending | SpectrumOrder::AscendingAbs
+    ) {
+        let k = values.len();
+        let reversed: Vec<usize> = (0..k).rev().collect();
+        for (j, &i) in reversed.iter().enumerate() {
+            s[j] = values[perm[i]];
+        }
+        let columns: Vec<Vec<T>> = reversed.iter().map(|&i| column(u, i)).collect();
+        let rows: Vec<Vec<T>> = reversed.iter().map(|&i| row(vt, i)).collect();
+        for (j, (x, y)) in columns.iter().zip(&rows).enumerate() {
+            set_column(u, j, x);
+            set_row(vt, j, y);
+        }
+        return reversed.iter().map(|&i| perm[i]).collect();
+    }
+
+    perm
+}
+
+/// Sorts the eigenvalues `keys` of an `n × n` problem, given as `(re, im)`;
+/// returns the permutation and the sorted keys.
+fn sort_spectrum<R: Float>(
+    keys: &[(R, R)],
+    order: SpectrumOrder,
+    n: usize,
+) -> (Vec<usize>, Vec<(R, R)>) {
+    let max = keys
+        .iter()
+        .fold(R::zero(), |acc, z| acc.max(z.0.hypot(z.1)));
+    let sign = match order {
+        SpectrumOrder::Ascending | SpectrumOrder::AscendingAbs => R::one(),
+        SpectrumOrder::Descending | SpectrumOrder::DescendingAbs => -R::one(),
+    };
+    let components: Vec<Vec<R>> = keys
+        .iter()
+        .map(|&(re, im)| match order {
+            SpectrumOrder::Ascending | SpectrumOrder::Descending => vec![sign * re, sign * im],
+            SpectrumOrder::AscendingAbs | SpectrumOrder::DescendingAbs => {
+                vec![sign * re.hypot(im), sign * re, sign * im]
+            }
+        })
+        .collect();
+
+    let mut perm: Vec<usize> = (0..keys.len()).collect();
+    sort_runs(&mut perm, &components, 0, tolerance(max, n));
+    let sorted = perm.iter().map(|&i| keys[i]).collect();
+    (perm, sorted)
+}
+
+/// Stable sort of `perm` by component `level`, then of each run of
+/// components within `tol` of each other by the next components.
+fn sort_runs<R: Float>(perm: &mut [usize], components: &[Vec<R>], level: usize, tol: R) {
+    if perm.len() < 2 || level == components[perm[0]].len() {
+        return;
+    }
+
+    let key = |i: usize| components[i][level];
+    perm.sort_by(|&i, &j| key(i).partial_cmp(&key(j)).unwrap_or(Ordering::Equal));
+    let mut start = 0;
+    for k in 1..=perm.len() {
+        if k == perm.len() || key(perm[k]) - key(perm[k - 1]) > tol {
+            sort_runs(&mut perm[start..k], components, level + 1, tol);
+            start = k;
+        }
+    }
+}
+
+/// Largest difference of degenerate values of an `n × n` problem whose
+/// largest value has magnitude `max`.
+fn tolerance<R: Float>(max: R, n: usize) -> R {
+    <R as NumCast>::from(100 * n.max(1)).unwrap() * R::epsilon() * max
+}
+
+/// Splits the sorted spectrum `keys` of an `n × n` problem into ranges of
+/// degenerate values.
+fn clusters<R: Float>(keys: &[(R, R)], n: usize) -> Vec<Range<usize>> {
+    let max = keys
+        .iter()
+        .fold(R::zero(), |acc, z| acc.max(z.0.hypot(z.1)));
+    let tol = tolerance(max, n);
+
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    for k in 1..=keys.len() {
+        if k == keys.len() || (keys[k].0 - keys[k - 1].0).hypot(keys[k].1 - keys[k - 1].1) > tol {
+            clusters.push(start..k);
+            start = k;
+        }
+    }
+    clusters
+}
+
+/// Replaces the columns of `v` in each cluster by their canonical basis.
+fn canonicalize_columns<S, D0, D1, L>(v: &mut Slice<S, (D0, D1), L>, clusters: &[Range<usize>])
+where
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    for c in clusters {
+        let basis = canonicalize(c.clone().map(|j| column(v, j)).collect());
+        for (j, x) in c.clone().zip(&basis) {
+            set_column(v, j, x);
+        }
+    }
+}
+
+/// Returns the canonical basis of the span of `basis`, or only fixes the
+/// phases if the vectors are numerically dependent.
+fn canonicalize<S: ComplexFloat>(mut basis: Vec<Vec<S>>) -> Vec<Vec<S>> {
+    if let Some(canonical) = canonical_basis(&basis) {
+        return canonical;
+    }
+    for x in &mut basis {
+        fix_phase(x);
+    }
+    basis
+}
+
+/// Returns an orthonormal basis of the span of `basis` that only depends on
+/// the span, or `None` if the vectors are numerically dependent.
+///
+/// The basis vectors are the projections of standard basis vectors onto the
+/// span, orthonormalized in turn.  Each step takes the first standard basis
+/// vector whose remaining projection is at least half the largest, so that
+/// rounding errors rarely change the choice.  The phases are fixed as
+/// [`fix_phase`] does.
+fn canonical_basis<S: ComplexFloat>(basis: &[Vec<S>]) -> Option<Vec<Vec<S>>> {
+    let zero = S::Real::zero();
+    let one = S::Real::one();
+    let n = basis.first().map_or(0, Vec::len);
+    let norm = |x: &[S]| Float::sqrt(x.iter().fold(zero, |acc, y| acc + y.abs() * y.abs()));
+    let orthogonalize = |x: &mut [S], q: &[Vec<S>]| {
+        // Twice is enough.
+        for _ in 0..2 {
+            for qk in q {
+                let c = dot(qk, x);
+                for (xi, &qi) in x.iter_mut().zip(qk) {
+                    *xi = *xi - qi * c;
+                }
+            }
+        }
+    };
+    let normalize = |x: &mut [S], nx: S::Real| {
+        for xi in x.iter_mut() {
+            *xi = *xi * scalar::<S>(one / nx);
+        }
+    };
+
+    // Any orthonormal basis of the span
+    let mut w: Vec<Vec<S>> = Vec::with_capacity(basis.len());
+    for b in basis {
+        let mut x = b.clone();
+        orthogonalize(&mut x, &w);
+        let nx = norm(&x);
+        if nx == zero || nx <= Float::sqrt(S::Real::epsilon()) * norm(b) {
+            return None;
+        }
+        normalize(&mut x, nx);
+        w.push(x);
+    }
+
+    let mut q: Vec<Vec<S>> = Vec::with_capacity(w.len());
+    for _ in 0..w.len() {
+        // Squared norms of the projections of the standard basis vectors onto
+        // the part of the span not covered by `q` yet.
+        let weight = |x: &[Vec<S>], i: usize| x.iter().fold(zero, |acc, y| acc + y[i].abs() * y[i].abs());
+        let d: Vec<S::Real> = (0..n).map(|i| weight(&w, i) - weight(&q, i)).collect();
+        let max = d.iter().fold(zero, |acc, &x| acc.max(x));
+        let i = d.iter().position(|&x| x >= max / (one + one))?;
+
+        let mut x: Vec<S> = (0..n)
+            .map(|l| w.iter().fold(S::zero(), |acc, y| acc + y[l] * y[i].conj()))
+            .collect();
+        orthogonalize(&mut x, &q);
+        let nx = norm(&x);
+        if nx == zero {
+            return None;
+        }
+        normalize(&mut x, nx);
+        fix_phase(&mut x);
+        q.push(x);
+    }
+
+    Some(q)
+}
+
+/// Multiplies `v` by the phase that makes its largest component real and
+/// positive, and returns that phase.
+///
+/// Components within a relative `√ε` of the largest count as ties, of which
+/// the first is chosen.
+pub(crate) fn fix_phase<S: ComplexFloat>(v: &mut [S]) -> S {
+    let max = v.iter().fold(S::Real::zero(), |acc, x| acc.max(x.abs()));
+    let tie = max * (S::Real::one() - Float::sqrt(S::Real::epsilon()));
+    let Some(&pivot) = v.iter().find(|x| max > S::Real::zero() && x.abs() >= tie) else {
+        return S::one();
+    };
+
+    let phase = pivot.conj() * scalar::<S>(S::Real::one() / pivot.abs());
+    for x in v.iter_mut() {
+        *x = *x * phase;
+    }
+    phase
+}
+
+/// `x^H y`
+fn dot<S: ComplexFloat>(x: &[S], y: &[S]) -> S {
+    x.iter()
+        .zip(y)
+        .fold(S::zero(), |acc, (&a, &b)| acc + a.conj() * b)
+}
+
+fn column<S: Copy, D0: Dim, D1: Dim, L: Layout>(v: &Slice<S, (D0, D1), L>, j: usize) -> Vec<S> {
+    (0..v.shape().dim(0)).map(|i| v[[i, j]]).collect()
+}
+
+fn set_column<S: Copy, D0: Dim, D1: Dim, L: Layout>(v: &mut Slice<S, (D0, D1), L>, j: usize, x: &[S]) {
+    for (i, &y) in x.iter().enumerate() {
+        v[[i, j]] = y;
+    }
+}
+
+fn row<S: Copy, D0: Dim, D1: Dim, L: Layout>(v: &Slice<S, (D0, D1), L>, i: usize) -> Vec<S> {
+    (0..v.shape().dim(1)).map(|j| v[[i, j]]).collect()
+}
+
+fn set_row<S: Copy, D0: Dim, D1: Dim, L: Layout>(v: &mut Slice<S, (D0, D1), L>, i: usize, x: &[S]) {
+    for (j, &y) in x.iter().enumerate() {
+        v[[i, j]] = y;
+    }
+}
+
+/// Moves column `perm[k]` of `v` to column `k`; the columns beyond
+/// `perm.len()` are left in place.
+fn permute_columns<S: Copy, D0: Dim, D1: Dim, L: Layout>(v: &mut Slice<S, (D0, D1), L>, perm: &[usize]) {
+    let columns: Vec<Vec<S>> = perm.iter().map(|&j| column(v, j)).collect();
+    for (k, x) in columns.iter().enumerate() {
+        set_column(v, k, x);
+    }
+}
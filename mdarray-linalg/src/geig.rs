@@ -0,0 +1,503 @@
+//! Generalized eigenvalue problems and QZ decomposition of matrix pencils
+//!
+//! The generalized eigenvalues of a square pencil `(A, B)` are the roots of
+//! `det(A - λ B) = 0`.  They are returned as pairs `(α, β)` with `λ = α / β`,
+//! so that a singular `B` yields infinite eigenvalues (`β = 0`) instead of
+//! overflow, and a singular pencil shows up as `α = β = 0`.
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::prelude::*;
+//! use mdarray_linalg::eig::SchurSelect;
+//! use mdarray_linalg::geig::{GEigDecomp, QzDecomp};
+//! use mdarray_linalg_backend::Backend;
+//!
+//! // ----- Generalized eigenvalue decomposition -----
+//! // A * v = λ * B * v, with λ = α / β.
+//! let bd = Backend::default();
+//! let GEigDecomp { alpha, beta, right_eigenvectors, .. } = bd
+//!     .geig(&mut a.clone(), &mut b.clone())
+//!     .expect("Generalized eigenvalue decomposition failed");
+//!
+//! // ----- Generalized Schur (QZ) decomposition -----
+//! // A = Q * S * Z^H and B = Q * T * Z^H.
+//! let QzDecomp { mut s, mut t, mut q, mut z } = bd
+//!     .qz(&mut a.clone(), &mut b.clone())
+//!     .expect("QZ decomposition failed");
+//!
+//! // Move the finite eigenvalues of the open left half-plane to the leading
+//! // block, whose deflating subspace is then spanned by the leading columns of Z.
+//! let stable = |lambda: Complex<f64>| lambda.re < 0.;
+//! let dim = bd
+//!     .qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Predicate(&stable))
+//!     .expect("QZ reordering failed");
+//! ```
+
+use mdarray::{Array, DArray, Dim, Layout, Shape, Slice};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::eig::{
+    Eig, SchurSelect, householder_q, rotate_columns, schur_blocks, solve_small, spectral,
+};
+use crate::utils::imaginary_unit;
+
+/// Error types related to generalized eigenvalue and QZ decompositions
+#[derive(Debug, Error)]
+pub enum GEigError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Backend failed to converge: {iterations} iterations exceeded")]
+    BackendDidNotConverge { iterations: i32 },
+
+    #[error("Matrices must be square for generalized eigenvalue decomposition")]
+    NotSquareMatrix,
+
+    #[error("Matrices of a pencil must have the same shape")]
+    ShapeMismatch,
+
+    #[error("Selection mask must have one entry per eigenvalue")]
+    InvalidSelection,
+
+    #[error("QZ reordering failed: eigenvalues are too close to swap")]
+    ReorderFailed,
+}
+
+/// Holds the results of a generalized eigenvalue decomposition.
+///
+/// The `i`-th eigenvalue is `alpha[i] / beta[i]`.  `alpha` is in the
+/// backend's spectral scalar `S`, while `beta` is in the input scalar `T`:
+/// for a real pencil it is real and non-negative.  Eigenvectors are columns
+/// normalized to unit Euclidean norm with largest component real.
+pub struct GEigDecomp<S, T, D0: Dim, D1: Dim> {
+    pub alpha: Array<S, (D0,)>,
+    pub beta: Array<T, (D0,)>,
+    /// Left eigenvectors, `β u^H A = α u^H B`.
+    pub left_eigenvectors: Option<Array<S, (D0, D1)>>,
+    /// Right eigenvectors, `β A v = α B v`.
+    pub right_eigenvectors: Option<Array<S, (D0, D1)>>,
+}
+
+/// Holds the results of a generalized Schur (QZ) decomposition:
+/// A = Q * S * Z^H and B = Q * T * Z^H
+/// where Q and Z are unitary, T is upper-triangular and S is upper-triangular
+/// (complex) or quasi-upper triangular (real)
+pub struct QzDecomp<T, D0: Dim, D1: Dim> {
+    /// Generalized Schur form of A
+    pub s: Array<T, (D0, D1)>,
+    /// Upper triangular generalized Schur form of B
+    pub t: Array<T, (D0, D1)>,
+    /// Unitary left Schur vectors
+    pub q: Array<T, (D0, D1)>,
+    /// Unitary right Schur vectors
+    pub z: Array<T, (D0, D1)>,
+}
+
+/// Generalized eigenvalue and QZ decompositions of square matrix pencils
+/// `(A, B)`.
+///
+/// Eigenvalues and eigenvectors use the spectral scalar of [`Eig`].
+pub trait GEig<T, D0: Dim, D1: Dim>: Eig<T, D0, D1> {
+    /// Compute generalized eigenvalues and right eigenvectors with new
+    /// allocated matrices: `β A v = α B v`.
+    fn geig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError>;
+
+    /// Compute generalized eigenvalues and both left/right eigenvectors with
+    /// new allocated matrices: `β A v = α B v` and `β u^H A = α u^H B`.
+    fn geig_full<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError>;
+
+    /// Compute only the generalized eigenvalues; the eigenvectors of the
+    /// result are `None`.
+    fn geig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<GEigDecomp<Self::SpectralScalar, T, D0, D1>, GEigError>;
+
+    /// Compute the generalized Schur decomposition over the input scalar
+    /// field.
+    fn qz<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<QzDecomp<T, D0, D1>, GEigError>;
+
+    /// Reorders a generalized Schur decomposition (as returned by
+    /// [`Self::qz`]) so that the selected eigenvalues lead the diagonals of
+    /// `S` and `T`, updating all four matrices in place.  Returns the number
+    /// of selected eigenvalues: the leading columns of `Q` and `Z` span the
+    /// corresponding left and right deflating subspaces.
+    ///
+    /// A predicate is passed `λ = α / β`, which is infinite for `β = 0`.
+    fn qz_reorder<Ls: Layout, Lt: Layout, Lq: Layout, Lz: Layout>(
+        &self,
+        s: &mut Slice<T, (D0, D1), Ls>,
+        t: &mut Slice<T, (D0, D1), Lt>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        z: &mut Slice<T, (D0, D1), Lz>,
+        select: SchurSelect<'_, Self::SpectralScalar>,
+    ) -> Result<usize, GEigError>;
+}
+
+// The following exported items are unstable backend-implementation helpers.
+// They are hidden from generated documentation and may be redesigned before
+// the public API stabilizes.
+
+/// Computes the eigenvalues `λ = α / β` of a generalized Schur form in
+/// diagonal order, infinite ones as a real infinity.
+#[doc(hidden)]
+pub fn qz_eigenvalues<T, S, D0, D1, Ls, Lt>(
+    s: &Slice<T, (D0, D1), Ls>,
+    t: &Slice<T, (D0, D1), Lt>,
+) -> Vec<S>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+{
+    schur_blocks(s)
+        .into_iter()
+        .flat_map(|(k, size)| pencil_block_eigenvalues(s, t, k, size))
+        .map(spectral)
+        .collect()
+}
+
+/// Computes the selection mask of a generalized Schur form, extending the
+/// selection of one eigenvalue of a `2 × 2` block to its conjugate.
+#[doc(hidden)]
+pub fn qz_select_mask<T, S, D0, D1, Ls, Lt>(
+    s: &Slice<T, (D0, D1), Ls>,
+    t: &Slice<T, (D0, D1), Lt>,
+    select: &SchurSelect<'_, S>,
+) -> Result<Vec<bool>, GEigError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+{
+    let n = s.shape().dim(0);
+    let mut mask = match select {
+        SchurSelect::Mask(mask) if mask.len() != n => return Err(GEigError::InvalidSelection),
+        SchurSelect::Mask(mask) => mask.to_vec(),
+        SchurSelect::Predicate(predicate) => qz_eigenvalues::<T, S, D0, D1, Ls, Lt>(s, t)
+            .into_iter()
+            .map(predicate)
+            .collect(),
+    };
+    for (k, size) in schur_blocks(s) {
+        if size == 2 {
+            let selected = mask[k] || mask[k + 1];
+            mask[k] = selected;
+            mask[k + 1] = selected;
+        }
+    }
+    Ok(mask)
+}
+
+/// Reorders the generalized Schur form `(s, t)` so that the selected
+/// eigenvalues lead its diagonal, replacing `S`, `T` by `Q'^H S Z'`,
+/// `Q'^H T Z'` and `Q`, `Z` by `Q Q'`, `Z Z'`.
+///
+/// This is the fallback for backends without a native `tgsen`.  Adjacent
+/// diagonal blocks are swapped as in LAPACK's `tgexc`.
+#[doc(hidden)]
+pub fn reorder_qz<T, S, D0, D1, Ls, Lt, Lq, Lz>(
+    s: &mut Slice<T, (D0, D1), Ls>,
+    t: &mut Slice<T, (D0, D1), Lt>,
+    q: &mut Slice<T, (D0, D1), Lq>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    select: &SchurSelect<'_, S>,
+) -> Result<usize, GEigError>
+where
+    T: ComplexFloat,
+    S: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+    Lq: Layout,
+    Lz: Layout,
+{
+    let n = s.shape().dim(0);
+    if s.shape().dim(1) != n {
+        return Err(GEigError::NotSquareMatrix);
+    }
+    if [t.shape(), q.shape(), z.shape()]
+        .iter()
+        .any(|sh| sh.dim(0) != n || sh.dim(1) != n)
+    {
+        return Err(GEigError::ShapeMismatch);
+    }
+    let mask = qz_select_mask(s, t, select)?;
+
+    // Blocks at and after `k` have not moved yet, so the mask still applies.
+    let mut dim = 0;
+    let mut k = 0;
+    while k < n {
+        let size = if k + 1 < n && s[[k + 1, k]] != T::zero() {
+            2
+        } else {
+            1
+        };
+        if mask[k] {
+            let mut pos = k;
+            while pos > dim {
+                let prev = if pos >= 2 && s[[pos - 1, pos - 2]] != T::zero() {
+                    2
+                } else {
+                    1
+                };
+                swap_pencil_blocks(s, t, q, z, pos - prev, prev, size)?;
+                pos -= prev;
+            }
+            dim += size;
+        }
+        k += size;
+    }
+    Ok(dim)
+}
+
+/// Eigenvalues of the diagonal block of size `size` at `k` of a generalized
+/// Schur form, listing the one with positive imaginary part first.
+fn pencil_block_eigenvalues<T, D0, D1, Ls, Lt>(
+    s: &Slice<T, (D0, D1), Ls>,
+    t: &Slice<T, (D0, D1), Lt>,
+    k: usize,
+    size: usize,
+) -> Vec<Complex<T::Real>>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+{
+    let cs = |i: usize, j: usize| Complex::new(s[[i, j]].re(), s[[i, j]].im());
+    let ct = |i: usize, j: usize| Complex::new(t[[i, j]].re(), t[[i, j]].im());
+    if size == 1 {
+        let (alpha, beta) = (cs(k, k), ct(k, k));
+        return vec![if beta == Complex::zero() {
+            Complex::new(T::Real::infinity(), T::Real::zero())
+        } else {
+            alpha / beta
+        }];
+    }
+    // det(S - λ T) = a λ^2 + b λ + c on the block.
+    let (s11, s12, s21, s22) = (cs(k, k), cs(k, k + 1), cs(k + 1, k), cs(k + 1, k + 1));
+    let (t11, t12, t21, t22) = (ct(k, k), ct(k, k + 1), ct(k + 1, k), ct(k + 1, k + 1));
+    let a = t11 * t22 - t12 * t21;
+    let b = -(s11 * t22 + t11 * s22 - s12 * t21 - t12 * s21);
+    let c = s11 * s22 - s12 * s21;
+    let two = T::Real::one() + T::Real::one();
+    let four = two * two;
+    let root = (b * b - a * c * four).sqrt();
+    let (l1, l2) = ((-b + root) / (a * two), (-b - root) / (a * two));
+    if l1.im >= l2.im {
+        vec![l1, l2]
+    } else {
+        vec![l2, l1]
+    }
+}
+
+/// Swaps the adjacent diagonal blocks of sizes `p` and `r - p` starting at
+/// `k` of the generalized Schur form `(s, t)`.
+#[allow(clippy::too_many_arguments)]
+fn swap_pencil_blocks<T, D0, D1, Ls, Lt, Lq, Lz>(
+    s: &mut Slice<T, (D0, D1), Ls>,
+    t: &mut Slice<T, (D0, D1), Lt>,
+    q: &mut Slice<T, (D0, D1), Lq>,
+    z: &mut Slice<T, (D0, D1), Lz>,
+    k: usize,
+    p: usize,
+    p2: usize,
+) -> Result<(), GEigError>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+    Lq: Layout,
+    Lz: Layout,
+{
+    let r = p + p2;
+    let ds = DArray::<T, 2>::from_fn([r, r], |i| s[[k + i[0], k + i[1]]]);
+    let dt = DArray::<T, 2>::from_fn([r, r], |i| t[[k + i[0], k + i[1]]]);
+
+    // With S11 R - L S22 = -S12 and T11 R - L T22 = -T12, [L; I] and [R; I]
+    // span the left and right deflating subspaces of the trailing block.
+    let (rm, lm) = small_generalized_sylvester(&ds, &dt, p, p2);
+    let basis = |x: &DArray<T, 2>| {
+        DArray::<T, 2>::from_fn([r, p2], |i| {
+            if i[0] < p {
+                x[[i[0], i[1]]]
+            } else if i[0] - p == i[1] {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    };
+    let qm = householder_q(basis(&lm));
+    let zm = householder_q(basis(&rm));
+
+    // Reject the swap if the transformed pencil is not block triangular to
+    // working precision.
+    let eps = T::Real::epsilon();
+    let ten = <T::Real as NumCast>::from(10).unwrap();
+    for d in [&ds, &dt] {
+        let dnorm = d.iter().fold(T::Real::zero(), |acc, v| acc.max(v.abs()));
+        let thresh = (ten * eps * dnorm).max(T::Real::min_positive_value());
+        for i in p2..r {
+            for j in 0..p2 {
+                let x = (0..r).fold(T::zero(), |acc, a| {
+                    acc + qm[[a, i]].conj()
+                        * (0..r).fold(T::zero(), |acc, b| acc + d[[a, b]] * zm[[b, j]])
+                });
+                if x.abs() > thresh {
+                    return Err(GEigError::ReorderFailed);
+                }
+            }
+        }
+    }
+
+    rotate_rows(s, k, &qm);
+    rotate_rows(t, k, &qm);
+    rotate_columns(s, k, &zm);
+    rotate_columns(t, k, &zm);
+    rotate_columns(q, k, &qm);
+    rotate_columns(z, k, &zm);
+    for i in p2..r {
+        for j in 0..p2 {
+            s[[k + i, k + j]] = T::zero();
+            t[[k + i, k + j]] = T::zero();
+        }
+    }
+
+    if imaginary_unit::<T>().is_none() {
+        if p2 == 2 {
+            triangularize_block(s, t, q, k);
+        }
+        if p == 2 {
+            triangularize_block(s, t, q, k + p2);
+        }
+    } else {
+        for i in 1..r {
+            for j in 0..i {
+                t[[k + i, k + j]] = T::zero();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the rows `k..k + r` of `m` by their product with the adjoint of
+/// the `r × r` matrix `qm`.
+fn rotate_rows<T, D0, D1, L>(m: &mut Slice<T, (D0, D1), L>, k: usize, qm: &DArray<T, 2>)
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let r = qm.shape().0;
+    for j in 0..m.shape().dim(1) {
+        let col: Vec<T> = (0..r).map(|a| m[[k + a, j]]).collect();
+        for i in 0..r {
+            m[[k + i, j]] = (0..r).fold(T::zero(), |acc, a| acc + qm[[a, i]].conj() * col[a]);
+        }
+    }
+}
+
+/// Restores the upper triangular `2 × 2` block of the real `T` at `k` with a
+/// rotation of rows `k` and `k + 1`.
+fn triangularize_block<T, D0, D1, Ls, Lt, Lq>(
+    s: &mut Slice<T, (D0, D1), Ls>,
+    t: &mut Slice<T, (D0, D1), Lt>,
+    q: &mut Slice<T, (D0, D1), Lq>,
+    k: usize,
+) where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    Ls: Layout,
+    Lt: Layout,
+    Lq: Layout,
+{
+    let (x, y) = (t[[k, k]], t[[k + 1, k]]);
+    if y == T::zero() {
+        return;
+    }
+    let norm = Float::hypot(x.abs(), y.abs());
+    let (cs, sn) = (x / T::from(norm).unwrap(), y / T::from(norm).unwrap());
+    let n = s.shape().dim(0);
+    for j in 0..n {
+        let (a, b) = (s[[k, j]], s[[k + 1, j]]);
+        s[[k, j]] = cs * a + sn * b;
+        s[[k + 1, j]] = cs * b - sn * a;
+        let (a, b) = (t[[k, j]], t[[k + 1, j]]);
+        t[[k, j]] = cs * a + sn * b;
+        t[[k + 1, j]] = cs * b - sn * a;
+    }
+    for i in 0..n {
+        let (a, b) = (q[[i, k]], q[[i, k + 1]]);
+        q[[i, k]] = cs * a + sn * b;
+        q[[i, k + 1]] = cs * b - sn * a;
+    }
+    t[[k + 1, k]] = T::zero();
+}
+
+/// Solves `S11 R - L S22 = -S12`, `T11 R - L T22 = -T12` for the `p × p2`
+/// matrices `R` and `L`, where the blocks split the `r × r` pencil `(s, t)`
+/// at `p`, as a linear system of order `2 p p2`.
+fn small_generalized_sylvester<T>(
+    s: &DArray<T, 2>,
+    t: &DArray<T, 2>,
+    p: usize,
+    p2: usize,
+) -> (DArray<T, 2>, DArray<T, 2>)
+where
+    T: ComplexFloat,
+{
+    let size = p * p2;
+    // Unknown R[i, j] is entry i + p j and L[i, j] entry size + i + p j.
+    let mut m = vec![vec![T::zero(); 2 * size + 1]; 2 * size];
+    for (e, d) in [s, t].into_iter().enumerate() {
+        for j in 0..p2 {
+            for i in 0..p {
+                let row = &mut m[e * size + i + p * j];
+                for l in 0..p {
+                    row[l + p * j] = row[l + p * j] + d[[i, l]];
+                }
+                for l in 0..p2 {
+                    row[size + i + p * l] = row[size + i + p * l] - d[[p + l, p + j]];
+                }
+                row[2 * size] = -d[[i, p + j]];
+            }
+        }
+    }
+    let norm = s
+        .iter()
+        .chain(t.iter())
+        .fold(T::Real::zero(), |acc, v| acc.max(v.abs()));
+    let sol = solve_small(m, norm);
+    (
+        DArray::<T, 2>::from_fn([p, p2], |i| sol[i[0] + p * i[1]]),
+        DArray::<T, 2>::from_fn([p, p2], |i| sol[size + i[0] + p * i[1]]),
+    )
+}
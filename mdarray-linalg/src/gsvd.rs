@@ -0,0 +1,131 @@
+//! Generalized Singular Value Decomposition (GSVD) of matrix pairs
+//!
+//! The GSVD of an `m × n` matrix `A` and a `p × n` matrix `B` is
+//! ```text
+//! A = U * Σa * [0 R] * Q^H
+//! B = V * Σb * [0 R] * Q^H
+//! ```
+//! where `U`, `V` and `Q` are unitary, `R` is a nonsingular upper triangular
+//! `r × r` matrix, `r = k + l` is the rank of `[A; B]`, and `Σa` (`m × r`)
+//! and `Σb` (`p × r`) are diagonal blocks built from the generalized singular
+//! value pairs `(α, β)` with `α² + β² = 1`.  The generalized singular values
+//! are the ratios `α / β`, infinite for the first `k` pairs.
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::prelude::*;
+//! use mdarray_linalg::gsvd::GSVDDecomp;
+//! use mdarray_linalg_backend::Backend;
+//!
+//! let bd = Backend::default();
+//! let decomp = bd
+//!     .gsvd(&mut a.clone(), &mut b.clone())
+//!     .expect("GSVD failed");
+//!
+//! // The generalized singular values of the regular part of the pair.
+//! let (k, l) = (decomp.k, decomp.l);
+//! let sigma: Vec<f64> = (k..k + l).map(|i| decomp.alpha[i] / decomp.beta[i]).collect();
+//!
+//! // Reconstruct A and B.
+//! let zero_r = decomp.zero_r();
+//! let a_rec = bd.matmul(&bd.matmul(&decomp.u, &decomp.sigma_a()).eval(), &zero_r).eval();
+//! ```
+
+use mdarray::{Array, Dim, Layout, Shape, Slice};
+use num_traits::Zero;
+use thiserror::Error;
+
+/// Error types related to the generalized singular value decomposition
+#[derive(Debug, Error)]
+pub enum GSVDError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Backend failed to converge: {cycles} Jacobi cycles exceeded")]
+    BackendDidNotConverge { cycles: i32 },
+
+    #[error("Matrices of a pair must have the same number of columns")]
+    ShapeMismatch,
+}
+
+/// Holds the results of a generalized singular value decomposition of an
+/// `m × n` matrix `A` and a `p × n` matrix `B`.
+///
+/// `alpha` and `beta` have `n` entries: `alpha[i] = 1`, `beta[i] = 0` for
+/// `i < k`, `alpha[i]² + beta[i]² = 1` for `k ≤ i < k + l`, and the
+/// remaining entries are zero.
+pub struct GSVDDecomp<T, S, D: Dim> {
+    pub alpha: Array<S, (D,)>,
+    pub beta: Array<S, (D,)>,
+    /// Unitary `m × m` matrix
+    pub u: Array<T, (D, D)>,
+    /// Unitary `p × p` matrix
+    pub v: Array<T, (D, D)>,
+    /// Unitary `n × n` matrix
+    pub q: Array<T, (D, D)>,
+    /// Nonsingular upper triangular `(k + l) × (k + l)` matrix
+    pub r: Array<T, (D, D)>,
+    /// Number of pairs with `beta = 0`
+    pub k: usize,
+    /// Number of remaining pairs of the regular part
+    pub l: usize,
+}
+
+impl<T, S, D> GSVDDecomp<T, S, D>
+where
+    T: Copy + Zero,
+    S: Copy + Into<T>,
+    D: Dim,
+{
+    /// Returns the `m × (k + l)` matrix `Σa` with `A = U * Σa * [0 R] * Q^H`.
+    pub fn sigma_a(&self) -> Array<T, (D, D)> {
+        let m = self.u.shape().dim(0);
+        let r = self.k + self.l;
+        Array::from_fn(<(D, D) as Shape>::from_dims(&[m, r]), |i| {
+            if i[0] == i[1] {
+                self.alpha[i[0]].into()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the `p × (k + l)` matrix `Σb` with `B = V * Σb * [0 R] * Q^H`.
+    pub fn sigma_b(&self) -> Array<T, (D, D)> {
+        let p = self.v.shape().dim(0);
+        let r = self.k + self.l;
+        Array::from_fn(<(D, D) as Shape>::from_dims(&[p, r]), |i| {
+            if i[0] + self.k == i[1] {
+                self.beta[i[1]].into()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the `(k + l) × n` matrix `[0 R]`.
+    pub fn zero_r(&self) -> Array<T, (D, D)> {
+        let n = self.q.shape().dim(0);
+        let r = self.k + self.l;
+        Array::from_fn(<(D, D) as Shape>::from_dims(&[r, n]), |i| {
+            if i[1] + r >= n {
+                self.r[[i[0], i[1] + r - n]]
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+/// Generalized singular value decomposition of matrix pairs `(A, B)` with
+/// the same number of columns.
+pub trait GSVD<T, D: Dim> {
+    type SingularValue;
+
+    /// Compute the GSVD with new allocated matrices.  `a` and `b` may be
+    /// overwritten.
+    fn gsvd<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), La>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<GSVDDecomp<T, Self::SingularValue, D>, GSVDError>;
+}
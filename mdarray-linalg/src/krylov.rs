@@ -0,0 +1,614 @@
+//! Krylov subspace solvers for large linear systems `A x = b`
+//!
+//! The solvers only access `A` through products `y = A x`, supplied as a
+//! closure, so it can be a dense matrix driven by [`MatVec`](crate::MatVec)
+//! or any matrix-free operator; a
+//! [`LinearOperator`](crate::operator::LinearOperator) `op` is passed as
+//! `|x, y| op.apply(x, y)`.  Vector updates, inner products and norms go
+//! through a [`VecOps`] backend.
+//!
+//! | Solver       | Matrix                          | Preconditioner        |
+//! |--------------|---------------------------------|-----------------------|
+//! | [`cg`]       | Hermitian positive definite     | Hermitian pos. def.   |
+//! | [`minres`]   | Hermitian, possibly indefinite  | Hermitian pos. def.   |
+//! | [`gmres`]    | general (restarted)             | any, applied right    |
+//! | [`bicgstab`] | general                         | any, applied right    |
+//!
+//! `x` holds the initial guess on entry and the last iterate on return, also
+//! when the solver fails.  Each solver records the relative residual
+//! `‖r‖ / ‖b‖` of every iteration in [`KrylovInfo::history`]; [`minres`]
+//! measures residuals in the norm induced by the preconditioner, and
+//! [`gmres`] uses the residual estimate of its least-squares problem.
+//!```rust
+//!use mdarray::{DArray, tensor};
+//!use mdarray_linalg::krylov::{KrylovConfig, cg};
+//!use mdarray_linalg::precond::Identity;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!
+//!let n = 50;
+//!let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+//!    if i[0] == i[1] { 2.0 } else if i[0].abs_diff(i[1]) == 1 { -1.0 } else { 0.0 }
+//!});
+//!let b = DArray::<f64, 1>::from_elem([n], 1.0);
+//!let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+//!
+//!let info = cg(&Naive, |x, y| Naive.matvec(&a, x).write(y), &b, &mut x, &Identity,
+//!              &KrylovConfig::default())
+//!    .expect("CG did not converge");
+//!assert!(info.residual < 1e-8);
+//!```
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{matvec::VecOps, precond::Preconditioner};
+
+/// Error types related to iterative linear solvers
+#[derive(Debug, Error)]
+pub enum KrylovError<R> {
+    /// The right-hand side and the solution vector have different lengths
+    #[error("Dimension mismatch: right-hand side of length {b}, solution of length {x}")]
+    DimensionMismatch { b: usize, x: usize },
+
+    /// The tolerance was not reached within the iteration limit
+    #[error("No convergence after {iterations} iterations (relative residual {residual})")]
+    NotConverged {
+        iterations: usize,
+        residual: R,
+        history: Vec<R>,
+    },
+
+    /// A scalar the recurrence divides by vanished, or a definiteness
+    /// assumption of the method was violated
+    #[error("Breakdown at iteration {iteration}")]
+    Breakdown { iteration: usize, history: Vec<R> },
+}
+
+/// Settings shared by the Krylov solvers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KrylovConfig<R> {
+    /// Relative tolerance on `‖r‖ / ‖b‖`.
+    pub tol: R,
+    /// Absolute tolerance on `‖r‖`; the iteration stops once
+    /// `‖r‖ <= max(tol ‖b‖, atol)`.
+    pub atol: R,
+    /// Maximum number of iterations.  Defaults to `10 n`.
+    pub max_iterations: Option<usize>,
+    /// Krylov dimension between restarts of [`gmres`].
+    pub restart: usize,
+}
+
+impl<R: Float> Default for KrylovConfig<R> {
+    fn default() -> Self {
+        Self {
+            tol: <R as NumCast>::from(1e-10).unwrap(),
+            atol: R::zero(),
+            max_iterations: None,
+            restart: 30,
+        }
+    }
+}
+
+/// Convergence report of a successful solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KrylovInfo<R> {
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Final relative residual `‖r‖ / ‖b‖`.
+    pub residual: R,
+    /// Relative residual before the first and after every iteration.
+    pub history: Vec<R>,
+}
+
+/// Solves a Hermitian positive definite system with the preconditioned
+/// conjugate gradient method.
+///
+/// `op(x, y)` must overwrite `y` with `A x`.  Returns
+/// [`KrylovError::Breakdown`] if `A` or `M` turns out not to be positive
+/// definite.
+pub fn cg<T, B>(
+    bd: &B,
+    mut op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    b: &DSlice<T, 1>,
+    x: &mut DSlice<T, 1>,
+    precond: &impl Preconditioner<T>,
+    config: &KrylovConfig<T::Real>,
+) -> Result<KrylovInfo<T::Real>, KrylovError<T::Real>>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: VecOps<T, Dyn, Real = T::Real>,
+{
+    let mut state = match Monitor::start(bd, &mut op, b, x, config)? {
+        Start::Done(info) => return Ok(info),
+        Start::Running(state) => state,
+    };
+    let n = b.len();
+    let zero = T::Real::zero();
+
+    let mut r = state.residual.take().unwrap();
+    let mut z = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut q = DArray::<T, 1>::from_elem([n], T::zero());
+    precond.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut rz = bd.dotc(&r, &z).re();
+
+    for it in 1..=state.max_iterations {
+        op(&p, &mut q);
+        let pq = bd.dotc(&p, &q).re();
+        if pq <= zero || rz <= zero {
+            return Err(state.breakdown(it));
+        }
+
+        let alpha = rz / pq;
+        bd.add_to_scaled(alpha.into(), &p, x);
+        bd.add_to_scaled((-alpha).into(), &q, &mut r);
+
+        if state.record(bd.norm2(&r)) {
+            return Ok(state.finish(it));
+        }
+
+        precond.apply(&r, &mut z);
+        let rz_next = bd.dotc(&r, &z).re();
+        let beta: T = (rz_next / rz).into();
+        for (pi, &zi) in p.iter_mut().zip(z.iter()) {
+            *pi = zi + beta * *pi;
+        }
+        rz = rz_next;
+    }
+
+    let iterations = state.max_iterations;
+    Err(state.not_converged(iterations))
+}
+
+/// Solves a Hermitian, possibly indefinite system with the preconditioned
+/// MINRES method of Paige and Saunders.
+///
+/// `op(x, y)` must overwrite `y` with `A x`, and the preconditioner must be
+/// Hermitian positive definite.  Residuals are measured in the
+/// `M^{-1}`-norm, which is the Euclidean norm without preconditioning.
+pub fn minres<T, B>(
+    bd: &B,
+    mut op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    b: &DSlice<T, 1>,
+    x: &mut DSlice<T, 1>,
+    precond: &impl Preconditioner<T>,
+    config: &KrylovConfig<T::Real>,
+) -> Result<KrylovInfo<T::Real>, KrylovError<T::Real>>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: VecOps<T, Dyn, Real = T::Real>,
+{
+    let mut state = match Monitor::start(bd, &mut op, b, x, config)? {
+        Start::Done(info) => return Ok(info),
+        Start::Running(state) => state,
+    };
+    let n = b.len();
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+
+    // Lanczos vectors r1, r2 of the preconditioned operator, with y = M^{-1} r2.
+    let mut r2 = state.residual.take().unwrap();
+    let mut y = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut v = DArray::<T, 1>::from_elem([n], T::zero());
+    precond.apply(&r2, &mut y);
+    let ry = bd.dotc(&r2, &y).re();
+    if ry < zero {
+        return Err(state.breakdown(0));
+    }
+
+    // Switch the history to the M^{-1}-norm.
+    let beta1 = Float::sqrt(ry);
+    precond.apply(b, &mut v);
+    if state.rescale(Float::sqrt(Float::abs(bd.dotc(b, &v).re())), beta1) {
+        return Ok(state.finish(0));
+    }
+
+    let mut r1 = r2.clone();
+    let mut w = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut w1 = w.clone();
+    let mut w2 = w.clone();
+
+    let (mut beta, mut old_beta) = (beta1, zero);
+    let (mut dbar, mut epsilon) = (zero, zero);
+    let mut phibar = beta1;
+    let (mut cs, mut sn) = (-one, zero);
+
+    for it in 1..=state.max_iterations {
+        let s: T = (one / beta).into();
+        for (vi, &yi) in v.iter_mut().zip(y.iter()) {
+            *vi = s * yi;
+        }
+
+        op(&v, &mut y);
+        if it >= 2 {
+            bd.add_to_scaled((-beta / old_beta).into(), &r1, &mut y);
+        }
+        let alpha = bd.dotc(&v, &y).re();
+        bd.add_to_scaled((-alpha / beta).into(), &r2, &mut y);
+        std::mem::swap(&mut r1, &mut r2);
+        std::mem::swap(&mut r2, &mut y);
+        precond.apply(&r2, &mut y);
+
+        old_beta = beta;
+        let ry = bd.dotc(&r2, &y).re();
+        if ry < zero {
+            return Err(state.breakdown(it));
+        }
+        beta = Float::sqrt(ry);
+
+        // Apply the previous rotation, then compute the next one.
+        let old_epsilon = epsilon;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsilon = sn * beta;
+        dbar = -cs * beta;
+
+        let gamma = Float::max(Float::hypot(gbar, beta), <T::Real as Float>::epsilon());
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        // w = (v - ε w1 - δ w2) / γ
+        std::mem::swap(&mut w1, &mut w2);
+        std::mem::swap(&mut w2, &mut w);
+        let inv_gamma = one / gamma;
+        for ((wi, &vi), (&w1i, &w2i)) in w.iter_mut().zip(v.iter()).zip(w1.iter().zip(w2.iter())) {
+            *wi = (vi - w1i * old_epsilon.into() - w2i * delta.into()) * inv_gamma.into();
+        }
+        bd.add_to_scaled(phi.into(), &w, x);
+
+        if state.record(phibar) {
+            return Ok(state.finish(it));
+        }
+        if beta == zero {
+            // Exact invariant subspace without reaching the tolerance.
+            return Err(state.breakdown(it));
+        }
+    }
+
+    let iterations = state.max_iterations;
+    Err(state.not_converged(iterations))
+}
+
+/// Solves a general system with the restarted GMRES(m) method, `m` being
+/// [`KrylovConfig::restart`].
+///
+/// `op(x, y)` must overwrite `y` with `A x`.  The preconditioner is applied
+/// from the right, so the recorded residuals are those of the original
+/// system.  The iteration limit counts inner iterations.
+pub fn gmres<T, B>(
+    bd: &B,
+    mut op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    b: &DSlice<T, 1>,
+    x: &mut DSlice<T, 1>,
+    precond: &impl Preconditioner<T>,
+    config: &KrylovConfig<T::Real>,
+) -> Result<KrylovInfo<T::Real>, KrylovError<T::Real>>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: VecOps<T, Dyn, Real = T::Real>,
+{
+    let mut state = match Monitor::start(bd, &mut op, b, x, config)? {
+        Start::Done(info) => return Ok(info),
+        Start::Running(state) => state,
+    };
+    let n = b.len();
+    let m = config.restart.clamp(1, n);
+    let zero = T::Real::zero();
+
+    let mut r = state.residual.take().unwrap();
+    let mut z = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut w = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut it = 0;
+
+    loop {
+        let beta = bd.norm2(&r);
+        let mut basis = vec![scaled(&r, (T::Real::one() / beta).into())];
+        let mut h = vec![vec![T::zero(); m]; m + 1];
+        let mut rotations: Vec<(T::Real, T)> = Vec::with_capacity(m);
+        let mut g = vec![T::zero(); m + 1];
+        g[0] = beta.into();
+
+        let mut converged = false;
+        let mut cols = 0;
+        while cols < m && it < state.max_iterations {
+            let j = cols;
+            precond.apply(&basis[j], &mut z);
+            op(&z, &mut w);
+
+            // Modified Gram–Schmidt
+            for (i, vi) in basis.iter().enumerate() {
+                h[i][j] = bd.dotc(vi, &w);
+                bd.add_to_scaled(-h[i][j], vi, &mut w);
+            }
+            let h_next = bd.norm2(&w);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (hi, hk) = (h[i][j], h[i + 1][j]);
+                h[i][j] = c.into() * hi + s * hk;
+                h[i + 1][j] = c.into() * hk - s.conj() * hi;
+            }
+            let (c, s, rho) = givens(h[j][j], h_next.into());
+            h[j][j] = rho;
+            rotations.push((c, s));
+            g[j + 1] = -s.conj() * g[j];
+            g[j] = c.into() * g[j];
+
+            it += 1;
+            cols += 1;
+            converged = state.record(g[j + 1].abs());
+            if converged || h_next == zero {
+                break;
+            }
+            basis.push(scaled(&w, (T::Real::one() / h_next).into()));
+        }
+
+        // Solve the triangular least-squares system and update x = x + M^{-1} V y.
+        let mut coeffs = vec![T::zero(); cols];
+        for i in (0..cols).rev() {
+            let acc = (i + 1..cols).fold(g[i], |acc, l| acc - h[i][l] * coeffs[l]);
+            if h[i][i] == T::zero() {
+                return Err(state.breakdown(it));
+            }
+            coeffs[i] = acc / h[i][i];
+        }
+        let mut update = DArray::<T, 1>::from_elem([n], T::zero());
+        for (vi, &ci) in basis.iter().zip(&coeffs) {
+            bd.add_to_scaled(ci, vi, &mut update);
+        }
+        precond.apply(&update, &mut z);
+        bd.add_to_scaled(T::one(), &z, x);
+
+        if converged {
+            return Ok(state.finish(it));
+        }
+        if it >= state.max_iterations {
+            return Err(state.not_converged(it));
+        }
+
+        // Restart from the true residual.
+        state.residual_of(&mut op, b, x, &mut r);
+    }
+}
+
+/// Solves a general system with the stabilized biconjugate gradient method
+/// (BiCGSTAB).
+///
+/// `op(x, y)` must overwrite `y` with `A x`.  The preconditioner is applied
+/// from the right, so the recorded residuals are those of the original
+/// system.
+pub fn bicgstab<T, B>(
+    bd: &B,
+    mut op: impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    b: &DSlice<T, 1>,
+    x: &mut DSlice<T, 1>,
+    precond: &impl Preconditioner<T>,
+    config: &KrylovConfig<T::Real>,
+) -> Result<KrylovInfo<T::Real>, KrylovError<T::Real>>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: VecOps<T, Dyn, Real = T::Real>,
+{
+    let mut state = match Monitor::start(bd, &mut op, b, x, config)? {
+        Start::Done(info) => return Ok(info),
+        Start::Running(state) => state,
+    };
+    let n = b.len();
+
+    let mut r = state.residual.take().unwrap();
+    let r_hat = r.clone();
+    let mut p = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut v = p.clone();
+    let mut p_hat = p.clone();
+    let mut s_hat = p.clone();
+    let mut t = p.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+
+    for it in 1..=state.max_iterations {
+        let rho_next = bd.dotc(&r_hat, &r);
+        if rho_next == T::zero() {
+            return Err(state.breakdown(it));
+        }
+
+        // p = r + β (p - ω v)
+        let beta = (rho_next / rho) * (alpha / omega);
+        for ((pi, &ri), &vi) in p.iter_mut().zip(r.iter()).zip(v.iter()) {
+            *pi = ri + beta * (*pi - omega * vi);
+        }
+
+        precond.apply(&p, &mut p_hat);
+        op(&p_hat, &mut v);
+        let rv = bd.dotc(&r_hat, &v);
+        if rv == T::zero() {
+            return Err(state.breakdown(it));
+        }
+        alpha = rho_next / rv;
+
+        // s = r - α v is stored in r.
+        bd.add_to_scaled(-alpha, &v, &mut r);
+        bd.add_to_scaled(alpha, &p_hat, x);
+        let s_norm = bd.norm2(&r);
+        if state.satisfied(s_norm) {
+            state.record(s_norm);
+            return Ok(state.finish(it));
+        }
+
+        precond.apply(&r, &mut s_hat);
+        op(&s_hat, &mut t);
+        let tt = bd.dotc(&t, &t);
+        if tt == T::zero() {
+            return Err(state.breakdown(it));
+        }
+        omega = bd.dotc(&t, &r) / tt;
+
+        bd.add_to_scaled(omega, &s_hat, x);
+        bd.add_to_scaled(-omega, &t, &mut r);
+
+        if state.record(bd.norm2(&r)) {
+            return Ok(state.finish(it));
+        }
+        if omega == T::zero() {
+            return Err(state.breakdown(it));
+        }
+        rho = rho_next;
+    }
+
+    let iterations = state.max_iterations;
+    Err(state.not_converged(iterations))
+}
+
+/// Outcome of [`Monitor::start`].
+enum Start<T, R> {
+    /// The initial guess already satisfies the tolerance.
+    Done(KrylovInfo<R>),
+    Running(Monitor<T, R>),
+}
+
+/// Residual history and stopping test shared by the solvers.
+struct Monitor<T, R> {
+    /// Initial residual `b - A x`, handed over to the solver.
+    residual: Option<DArray<T, 1>>,
+    /// Norm of `b` relative to which the history is recorded.
+    reference: R,
+    /// Absolute stopping threshold.
+    threshold: R,
+    tol: R,
+    atol: R,
+    max_iterations: usize,
+    history: Vec<R>,
+}
+
+impl<T, R> Monitor<T, R>
+where
+    T: ComplexFloat<Real = R>,
+    R: Float,
+{
+    fn start<B>(
+        bd: &B,
+        op: &mut impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+        b: &DSlice<T, 1>,
+        x: &DSlice<T, 1>,
+        config: &KrylovConfig<R>,
+    ) -> Result<Start<T, R>, KrylovError<R>>
+    where
+        B: VecOps<T, Dyn, Real = R>,
+    {
+        let n = b.len();
+        if x.len() != n {
+            return Err(KrylovError::DimensionMismatch { b: n, x: x.len() });
+        }
+
+        let b_norm = bd.norm2(b);
+        let mut state = Self {
+            residual: None,
+            reference: R::one(),
+            threshold: R::zero(),
+            tol: config.tol,
+            atol: config.atol,
+            max_iterations: config.max_iterations.unwrap_or(10 * n),
+            history: Vec::new(),
+        };
+
+        state.set_reference(b_norm);
+
+        let mut r = DArray::<T, 1>::from_elem([n], T::zero());
+        state.residual_of(op, b, x, &mut r);
+        if state.record(bd.norm2(&r)) {
+            return Ok(Start::Done(state.finish(0)));
+        }
+        state.residual = Some(r);
+        Ok(Start::Running(state))
+    }
+
+    /// Overwrites `r` with `b - A x`.
+    fn residual_of(
+        &self,
+        op: &mut impl FnMut(&DSlice<T, 1>, &mut DSlice<T, 1>),
+        b: &DSlice<T, 1>,
+        x: &DSlice<T, 1>,
+        r: &mut DSlice<T, 1>,
+    ) {
+        op(x, r);
+        for (ri, &bi) in r.iter_mut().zip(b.iter()) {
+            *ri = bi - *ri;
+        }
+    }
+
+    fn set_reference(&mut self, b_norm: R) {
+        self.reference = if b_norm > R::zero() { b_norm } else { R::one() };
+        self.threshold = Float::max(self.tol * b_norm, self.atol);
+    }
+
+    /// Restarts the history in another norm, e.g. a preconditioned one,
+    /// given the norms of `b` and of the initial residual.  Returns whether
+    /// the initial residual satisfies the tolerance in that norm.
+    fn rescale(&mut self, b_norm: R, initial: R) -> bool {
+        self.set_reference(b_norm);
+        self.history.clear();
+        self.record(initial)
+    }
+
+    fn satisfied(&self, residual: R) -> bool {
+        residual <= self.threshold
+    }
+
+    /// Records an absolute residual norm and returns whether it satisfies
+    /// the tolerance.
+    fn record(&mut self, residual: R) -> bool {
+        self.history.push(residual / self.reference);
+        self.satisfied(residual)
+    }
+
+    fn finish(self, iterations: usize) -> KrylovInfo<R> {
+        KrylovInfo {
+            iterations,
+            residual: *self.history.last().unwrap(),
+            history: self.history,
+        }
+    }
+
+    fn not_converged(self, iterations: usize) -> KrylovError<R> {
+        KrylovError::NotConverged {
+            iterations,
+            residual: *self.history.last().unwrap(),
+            history: self.history,
+        }
+    }
+
+    fn breakdown(self, iteration: usize) -> KrylovError<R> {
+        KrylovError::Breakdown {
+            iteration,
+            history: self.history,
+        }
+    }
+}
+
+fn scaled<T: ComplexFloat>(x: &DSlice<T, 1>, alpha: T) -> DArray<T, 1> {
+    DArray::<T, 1>::from_fn([x.len()], |i| alpha * x[i[0]])
+}
+
+/// Complex Givens rotation `(c, s)` with real `c`, such that
+/// `[c s; -s̄ c] [a; b] = [ρ; 0]`.  Returns `(c, s, ρ)`.
+fn givens<T>(a: T, b: T) -> (T::Real, T, T)
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    let (abs_a, abs_b) = (a.abs(), b.abs());
+    if abs_b == T::Real::zero() {
+        return (T::Real::one(), T::zero(), a);
+    }
+    if abs_a == T::Real::zero() {
+        return (T::Real::zero(), b.conj() / abs_b.into(), abs_b.into());
+    }
+    let norm = Float::hypot(abs_a, abs_b);
+    let phase = a / abs_a.into();
+    (abs_a / norm, phase * b.conj() / norm.into(), phase * norm.into())
+}
@@ -54,6 +54,8 @@
 //! | [Matrix-vector multiplications](crate::matvec#matrix-vector-operations) | ✅ | ⬜ | ✅ | ✅ | ✅ | ⬜ |
 //! | [Operations on vectors](crate::matvec#vector-operations)     | ✅ | ⬜ | ✅ | ✅ | ✅ | ⬜ |
 //! | [Matrix multiplication](mod@crate::contract)     | ✅ | ⬜ | ✅ | ✅ | ✅ | ✅ |
+//! | [Sparse-dense products](crate::sparse)           | ⬜ | ⬜ | ✅ | ✅ | 🔧 | ⬜ |
+//! | [Structured matrices](crate::structured)         | ✅ | ⬜ | ✅ | ✅ | 🔧 | ⬜ |
 //! | [Argmax](crate::matvec#argmax)                    | ✅ | ⬜ | ✅ | ⬜ | ✅ | ⬜ |
 //! | **▶︎ Decomposition and solving**                              |||||||
 //! | [Eigen decomposition](crate::eig)             | ⬜ | ✅ | ⬜ | ✅ | ✅ | ⬜ |
@@ -63,6 +65,8 @@
 //! | [QR decomposition](crate::qr)                  | ⬜ | ✅ | ✅ | ✅ | ✅ | ⬜ |
 //! | [Cholesky decomposition](crate::lu)| ⬜ | ✅ | ⬜ | ✅ |✅ | ⬜ |
 //! | [Schur decomposition](crate::eig)         | ⬜ | ✅ | ⬜ | ✅ | ✅ | ⬜ |
+//! | [Generalized eigen and QZ decomposition](crate::geig) | ⬜ | ✅ | ⬜ | ✅ | ⬜ | ⬜ |
+//! | [Generalized SVD](crate::gsvd)                | ⬜ | ✅ | ⬜ | 🔧 | 🔧 | ⬜ |
 //! | **▶︎ Advanced**                                   |||||||
 //! | [Tensor contraction](mod@crate::contract)        | ✅ | ⬜ | ✅ | ✅ | ✅ | ✅ |
 //!
@@ -70,6 +74,18 @@
 //! 🔧 = not implemented yet / partially implemented
 //! ⬜ = not applicable / not part of the backend’s scope
 //!
+//! Algorithms written against these traits, such as the
+//! [randomized SVD](crate::randomized), the restarted
+//! [Lanczos and Arnoldi eigensolvers](crate::eigs), [LOBPCG](crate::lobpcg) or
+//! the [Krylov linear solvers](crate::krylov), the
+//! [Sylvester and Lyapunov solvers](crate::sylvester), the
+//! [Riccati solvers](crate::riccati) and the
+//! [polar decomposition](crate::polar), work with any backend (or combination
+//! of backends) providing the operations they need.  The
+//! [Toeplitz, Hankel and circulant](crate::toeplitz) solvers need no backend at all.
+//! Eigenvalue decompositions and SVDs can be brought to a
+//! [canonical gauge](crate::gauge) to compare the results of different backends.
+//!
 // </details>
 //!
 //! # Example
@@ -139,21 +155,42 @@
 pub mod prelude;
 
 pub mod eig;
+pub mod eigs;
+pub mod gauge;
+pub mod geig;
+pub mod gsvd;
+pub mod krylov;
+pub mod lobpcg;
 pub mod lu;
 pub mod contract;
 pub mod matvec;
+pub mod operator;
+pub mod polar;
+pub mod precond;
 pub mod qr;
+pub mod randomized;
+pub mod riccati;
 pub mod solve;
+pub mod sparse;
+pub mod sparse_solve;
+pub mod structured;
 pub mod svd;
+pub mod sylvester;
+pub mod toeplitz;
 
 pub mod utils;
 
 pub use contract::Contract;
 pub use eig::Eig;
+pub use geig::GEig;
+pub use gsvd::GSVD;
 pub use lu::LU;
 pub use matvec::{Argmax, MatVec, Outer, VecOps};
 pub use qr::QR;
 pub use solve::Solve;
+pub use sparse::{SparseMatVec, SparseMatmul};
+pub use sparse_solve::SparseSolve;
+pub use structured::Structured;
 pub use svd::SVD;
 
 mod naive;
@@ -0,0 +1,412 @@
+//! Locally optimal block preconditioned conjugate gradient (LOBPCG)
+//!
+//! [`lobpcg`] computes the `k` smallest (or largest) eigenpairs of the
+//! generalized Hermitian problem `A x = λ B x`, with `B` Hermitian positive
+//! definite (`B = I` if omitted).  Every iteration performs a Rayleigh–Ritz
+//! projection on the span of the current block `X`, the preconditioned
+//! residuals `W` and the previous search directions `P`:
+//!
+//! - block products and Gram matrices go through a [`Contract`] backend,
+//! - the basis `[X, W, P]` is made `B`-orthonormal with the Cholesky
+//!   factorization of its Gram matrix from an [`LU`] backend,
+//! - the projected problem is solved with [`Eig::eigh`].
+//!
+//! Converged vectors are soft-locked: they stay in the Rayleigh–Ritz basis,
+//! which keeps the block orthogonal, but no longer contribute residual and
+//! search directions.  This is what makes LOBPCG effective for clustered
+//! spectra, where single-vector Lanczos converges slowly.
+//!
+//! `A`, `B` are any [`LinearOperator`] and the preconditioner any
+//! [`Preconditioner`], which should approximate `A^{-1}` (or
+//! `(A - σB)^{-1}` for a shift `σ` near the wanted eigenvalues).
+//!```rust,ignore
+//!use mdarray::DArray;
+//!use mdarray_linalg::lobpcg::{LobpcgConfig, lobpcg};
+//!use mdarray_linalg::operator::DenseOperator;
+//!use mdarray_linalg::precond::Jacobi;
+//!use mdarray_linalg_faer::Faer;
+//!
+//!let n = 400;
+//!let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+//!    if i[0] == i[1] { 2.0 + (i[0] % 4) as f64 } else if i[0].abs_diff(i[1]) == 1 { -1.0 } else { 0.0 }
+//!});
+//!let x0 = DArray::<f64, 2>::from_fn([n, 4], |i| ((i[0] * 7 + i[1] * 3) as f64).sin());
+//!
+//!let bd = Faer::default();
+//!let decomp = lobpcg(&bd, &bd, &bd, &DenseOperator::new(&bd, &a), None,
+//!                    &Jacobi::new(&a).unwrap(), &x0, &LobpcgConfig::default())
+//!    .expect("LOBPCG did not converge");
+//!// decomp.eigenvalues holds the 4 smallest eigenvalues in ascending order
+//!```
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::ComplexFloat;
+use num_traits::{Float, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    contract::{Contract, MatmulBuilder},
+    eig::{Eig, EigError, EighDecomp},
+    lu::LU,
+    operator::LinearOperator,
+    precond::Preconditioner,
+};
+
+/// Error types related to LOBPCG
+#[derive(Debug, Error)]
+pub enum LobpcgError {
+    /// The block size does not fit the operator size
+    #[error("Invalid dimensions: block of {k} vectors for size {n}")]
+    InvalidDimensions { n: usize, k: usize },
+
+    /// Not all eigenpairs reached the tolerance
+    #[error("Only {converged} of {requested} eigenpairs converged after {iterations} iterations")]
+    NotConverged {
+        converged: usize,
+        requested: usize,
+        iterations: usize,
+    },
+
+    /// The initial block, or the block of preconditioned residuals, is
+    /// numerically rank deficient in the `B`-inner product, or `B` is not
+    /// positive definite
+    #[error("Basis is not B-orthonormalizable at iteration {iteration}")]
+    Breakdown { iteration: usize },
+
+    /// The Rayleigh–Ritz problem failed in the backend
+    #[error("Rayleigh-Ritz eigenproblem failed: {0}")]
+    Eig(#[from] EigError),
+}
+
+/// Settings for [`lobpcg`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LobpcgConfig<R> {
+    /// An eigenpair `(θ, x)` with `B`-normalized `x` is converged once
+    /// `‖A x - θ B x‖ <= tol * max(|θ|, 1)`.
+    pub tol: R,
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+    /// Compute the largest instead of the smallest eigenvalues.
+    pub largest: bool,
+}
+
+impl<R: Float> Default for LobpcgConfig<R> {
+    fn default() -> Self {
+        Self {
+            tol: R::epsilon().sqrt(),
+            max_iterations: 500,
+            largest: false,
+        }
+    }
+}
+
+/// Computes the `k` smallest (or largest) eigenpairs of `A x = λ B x`,
+/// starting from the `n × k` block `x0`.
+///
+/// Eigenvalues are returned in ascending order for the smallest ones and in
+/// descending order for the largest ones; the eigenvectors are the
+/// `B`-orthonormal columns of an `n × k` matrix.  `3 k <= n` is required.
+#[allow(clippy::too_many_arguments)]
+pub fn lobpcg<T>(
+    mm: &impl Contract<T>,
+    eig: &impl Eig<T, Dyn, Dyn, RealScalar = T::Real>,
+    chol: &impl LU<T, Dyn, Dyn>,
+    a: &impl LinearOperator<T>,
+    b: Option<&dyn LinearOperator<T>>,
+    precond: &impl Preconditioner<T>,
+    x0: &DSlice<T, 2>,
+    config: &LobpcgConfig<T::Real>,
+) -> Result<EighDecomp<T, T::Real, Dyn, Dyn>, LobpcgError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+{
+    let (n, k) = *x0.shape();
+    if k == 0 || 3 * k > n || a.shape() != (n, n) || b.is_some_and(|b| b.shape() != (n, n)) {
+        return Err(LobpcgError::InvalidDimensions { n, k });
+    }
+
+    let ctx = Context { mm, chol, a, b };
+    let one = T::Real::one();
+
+    let mut x = ctx.block(x0.to_tensor());
+    if !x.b_orthonormalize(&ctx) {
+        return Err(LobpcgError::Breakdown { iteration: 0 });
+    }
+    let (mut theta, c) = rayleigh_ritz(eig, &ctx, &x, k, config.largest)?;
+    x = x.combine(&ctx, &c, 0);
+    let mut p: Option<Block<T>> = None;
+    let mut converged = 0;
+
+    for iteration in 1..=config.max_iterations {
+        // Residuals R = A X - B X Θ
+        let r = DArray::<T, 2>::from_fn([n, k], |i| {
+            x.a[[i[0], i[1]]] - x.b[[i[0], i[1]]] * theta[i[1]].into()
+        });
+        let active: Vec<usize> = (0..k)
+            .filter(|&j| {
+                let norm = Float::sqrt((0..n).fold(T::Real::zero(), |acc, i| acc + sqr(r[[i, j]])));
+                norm > config.tol * Float::max(Float::abs(theta[j]), one)
+            })
+            .collect();
+        converged = k - active.len();
+
+        if active.is_empty() {
+            return Ok(EighDecomp {
+                eigenvalues: DArray::<T::Real, 1>::from_fn([k], |i| theta[i[0]]),
+                eigenvectors: x.x,
+            });
+        }
+
+        // Preconditioned residuals, B-orthogonalized against X
+        let mut w = DArray::<T, 2>::from_elem([n, active.len()], T::zero());
+        let mut rj = DArray::<T, 1>::from_elem([n], T::zero());
+        let mut wj = rj.clone();
+        for (col, &j) in active.iter().enumerate() {
+            for i in 0..n {
+                rj[i] = r[[i, j]];
+            }
+            precond.apply(&rj, &mut wj);
+            for i in 0..n {
+                w[[i, col]] = wj[i];
+            }
+        }
+        let xbw = ctx.gram(&x.b, &w);
+        let xxbw = mm.matmul(&x.x, &xbw).eval();
+        let w = DArray::<T, 2>::from_fn([n, active.len()], |i| w[[i[0], i[1]]] - xxbw[[i[0], i[1]]]);
+        let mut w = ctx.block(w);
+        if !w.b_orthonormalize(&ctx) {
+            return Err(LobpcgError::Breakdown { iteration });
+        }
+
+        // Rayleigh–Ritz on [X, W, P], falling back to [X, W] if the search
+        // directions have become dependent.
+        let p_active = p.as_ref().map(|p| p.columns(&active));
+        let mut basis = match &p_active {
+            Some(p_active) => Block::concat(&[&x, &w, p_active]),
+            None => Block::concat(&[&x, &w]),
+        };
+        if !basis.b_orthonormalize(&ctx) {
+            if p_active.is_none() {
+                return Err(LobpcgError::Breakdown { iteration });
+            }
+            basis = Block::concat(&[&x, &w]);
+            if !basis.b_orthonormalize(&ctx) {
+                return Err(LobpcgError::Breakdown { iteration });
+            }
+        }
+
+        let (values, c) = rayleigh_ritz(eig, &ctx, &basis, k, config.largest)?;
+        theta = values;
+        x = basis.combine(&ctx, &c, 0);
+        p = Some(basis.combine(&ctx, &c, k));
+    }
+
+    Err(LobpcgError::NotConverged {
+        converged,
+        requested: k,
+        iterations: config.max_iterations,
+    })
+}
+
+/// Backends and operators shared by the helpers.
+struct Context<'a, MM, C, A, T> {
+    mm: &'a MM,
+    chol: &'a C,
+    a: &'a A,
+    b: Option<&'a dyn LinearOperator<T>>,
+}
+
+impl<MM, C, A, T> Context<'_, MM, C, A, T>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    MM: Contract<T>,
+    C: LU<T, Dyn, Dyn>,
+    A: LinearOperator<T>,
+{
+    /// Wraps `x` with its images under `A` and `B`.
+    fn block(&self, x: DArray<T, 2>) -> Block<T> {
+        let a = apply_block(self.a, &x);
+        let b = match self.b {
+            Some(b) => apply_block(b, &x),
+            None => x.clone(),
+        };
+        Block { x, a, b }
+    }
+
+    /// `u^H v`
+    fn gram(&self, u: &DArray<T, 2>, v: &DArray<T, 2>) -> DArray<T, 2> {
+        let (n, m) = *u.shape();
+        let uh = DArray::<T, 2>::from_fn([m, n], |i| u[[i[1], i[0]]].conj());
+        self.mm.matmul(&uh, v).eval()
+    }
+}
+
+/// Block of vectors `X` together with `A X` and `B X`.
+struct Block<T> {
+    x: DArray<T, 2>,
+    a: DArray<T, 2>,
+    b: DArray<T, 2>,
+}
+
+impl<T: ComplexFloat + Default> Block<T>
+where
+    T::Real: Into<T>,
+{
+    fn width(&self) -> usize {
+        self.x.shape().1
+    }
+
+    fn columns(&self, idx: &[usize]) -> Block<T> {
+        let pick = |m: &DArray<T, 2>| DArray::<T, 2>::from_fn([m.shape().0, idx.len()], |i| m[[i[0], idx[i[1]]]]);
+        Block {
+            x: pick(&self.x),
+            a: pick(&self.a),
+            b: pick(&self.b),
+        }
+    }
+
+    fn concat(blocks: &[&Block<T>]) -> Block<T> {
+        let n = blocks[0].x.shape().0;
+        let widths: Vec<usize> = blocks.iter().map(|b| b.width()).collect();
+        let total = widths.iter().sum();
+        let cat = |get: fn(&Block<T>) -> &DArray<T, 2>| {
+            let mut out = DArray::<T, 2>::from_elem([n, total], T::zero());
+            let mut offset = 0;
+            for (block, &w) in blocks.iter().zip(&widths) {
+                let m = get(block);
+                for i in 0..n {
+                    for j in 0..w {
+                        out[[i, offset + j]] = m[[i, j]];
+                    }
+                }
+                offset += w;
+            }
+            out
+        };
+        Block {
+            x: cat(|b| &b.x),
+            a: cat(|b| &b.a),
+            b: cat(|b| &b.b),
+        }
+    }
+
+    /// Makes the columns `B`-orthonormal with the Cholesky factor of the
+    /// Gram matrix, `X ← X L^{-H}`.  Returns `false` if the columns are
+    /// numerically dependent.
+    fn b_orthonormalize<MM, C, A>(&mut self, ctx: &Context<'_, MM, C, A, T>) -> bool
+    where
+        MM: Contract<T>,
+        C: LU<T, Dyn, Dyn>,
+        A: LinearOperator<T>,
+    {
+        let m = self.width();
+        let g = ctx.gram(&self.x, &self.b);
+        let two: T = (T::Real::one() + T::Real::one()).into();
+        let mut g = DArray::<T, 2>::from_fn([m, m], |i| (g[[i[0], i[1]]] + g[[i[1], i[0]]].conj()) / two);
+
+        let Ok(l) = ctx.chol.cholesky(&mut g) else {
+            return false;
+        };
+
+        let diag: Vec<T::Real> = (0..m).map(|i| l[[i, i]].re()).collect();
+        let max = diag.iter().copied().fold(T::Real::zero(), Float::max);
+        let threshold = Float::sqrt(<T::Real as Float>::epsilon()) * max;
+        // Also rejects NaN pivots.
+        if diag.iter().any(|&d| Float::is_nan(d) || d <= threshold) {
+            return false;
+        }
+
+        // L^{-H} is upper triangular: solve L^H Y = I column by column.
+        let mut y = DArray::<T, 2>::from_elem([m, m], T::zero());
+        for c in 0..m {
+            for i in (0..=c).rev() {
+                let rhs = if i == c { T::one() } else { T::zero() };
+                let acc = (i + 1..=c).fold(rhs, |acc, j| acc - l[[j, i]].conj() * y[[j, c]]);
+                y[[i, c]] = acc / l[[i, i]].conj();
+            }
+        }
+
+        self.x = ctx.mm.matmul(&self.x, &y).eval();
+        self.a = ctx.mm.matmul(&self.a, &y).eval();
+        self.b = ctx.mm.matmul(&self.b, &y).eval();
+        true
+    }
+
+    /// `[X, A X, B X] C[from.., :]` restricted to the columns `from..` of
+    /// the block.
+    fn combine<MM, C, A>(&self, ctx: &Context<'_, MM, C, A, T>, c: &DArray<T, 2>, from: usize) -> Block<T>
+    where
+        MM: Contract<T>,
+        C: LU<T, Dyn, Dyn>,
+        A: LinearOperator<T>,
+    {
+        let (m, k) = *c.shape();
+        let c = DArray::<T, 2>::from_fn([m - from, k], |i| c[[from + i[0], i[1]]]);
+        let tail = self.columns(&(from..m).collect::<Vec<_>>());
+        Block {
+            x: ctx.mm.matmul(&tail.x, &c).eval(),
+            a: ctx.mm.matmul(&tail.a, &c).eval(),
+            b: ctx.mm.matmul(&tail.b, &c).eval(),
+        }
+    }
+}
+
+/// Solves the projected problem of a `B`-orthonormal block and returns the
+/// `k` wanted Ritz values and the corresponding coefficient vectors.
+fn rayleigh_ritz<T, MM, C, A>(
+    eig: &impl Eig<T, Dyn, Dyn, RealScalar = T::Real>,
+    ctx: &Context<'_, MM, C, A, T>,
+    basis: &Block<T>,
+    k: usize,
+    largest: bool,
+) -> Result<(Vec<T::Real>, DArray<T, 2>), EigError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    MM: Contract<T>,
+    C: LU<T, Dyn, Dyn>,
+    A: LinearOperator<T>,
+{
+    let m = basis.width();
+    let g = ctx.gram(&basis.x, &basis.a);
+    let two: T = (T::Real::one() + T::Real::one()).into();
+    let mut g = DArray::<T, 2>::from_fn([m, m], |i| (g[[i[0], i[1]]] + g[[i[1], i[0]]].conj()) / two);
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = eig.eigh(&mut g)?;
+
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&i, &j| eigenvalues[i].partial_cmp(&eigenvalues[j]).unwrap_or(std::cmp::Ordering::Equal));
+    if largest {
+        order.reverse();
+    }
+    order.truncate(k);
+
+    Ok((
+        order.iter().map(|&j| eigenvalues[j]).collect(),
+        DArray::<T, 2>::from_fn([m, k], |i| eigenvectors[[i[0], order[i[1]]]]),
+    ))
+}
+
+fn apply_block<T: ComplexFloat, O: LinearOperator<T> + ?Sized>(op: &O, x: &DArray<T, 2>) -> DArray<T, 2> {
+    let (n, m) = *x.shape();
+    let mut out = DArray::<T, 2>::from_elem([n, m], T::zero());
+    let mut xj = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut yj = xj.clone();
+    for j in 0..m {
+        for i in 0..n {
+            xj[i] = x[[i, j]];
+        }
+        op.apply(&xj, &mut yj);
+        for i in 0..n {
+            out[[i, j]] = yj[i];
+        }
+    }
+    out
+}
+
+fn sqr<T: ComplexFloat>(x: T) -> T::Real {
+    (x.conj() * x).re()
+}
@@ -1,6 +1,8 @@
 pub mod contract;
 pub mod matvec;
 pub mod qr;
+pub mod sparse;
+pub mod structured;
 
 /// Simple backend, mostly for demonstratration purposes
 #[derive(Default)]
@@ -0,0 +1,124 @@
+use mdarray::{DArray, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use super::simple::{naive_spmm, naive_spmv};
+use crate::{
+    Naive,
+    sparse::{SparseMatVec, SparseMatVecBuilder, SparseMatmul, SparseMatmulBuilder, SparseMatrix},
+};
+
+struct NaiveSparseMatVecBuilder<'a, T, Lx: Layout> {
+    alpha: T,
+    a: &'a SparseMatrix<T>,
+    x: &'a Slice<T, (Dyn,), Lx>,
+}
+
+impl<'a, T, Lx> SparseMatVecBuilder<'a, T, Lx> for NaiveSparseMatVecBuilder<'a, T, Lx>
+where
+    Lx: Layout,
+    T: ComplexFloat,
+{
+    fn scale(mut self, alpha: T) -> Self {
+        self.alpha = alpha * self.alpha;
+        self
+    }
+
+    fn eval(self) -> DArray<T, 1> {
+        let mut y = DArray::<T, 1>::from_elem([self.a.shape().0], T::zero());
+        naive_spmv(self.alpha, self.a, self.x, &mut y);
+        y
+    }
+
+    fn write<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>) {
+        for yi in y.iter_mut() {
+            *yi = T::zero();
+        }
+        naive_spmv(self.alpha, self.a, self.x, y);
+    }
+
+    fn add_to_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>) {
+        naive_spmv(self.alpha, self.a, self.x, y);
+    }
+
+    fn add_to_scaled_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>, beta: T) {
+        for yi in y.iter_mut() {
+            *yi = beta * *yi;
+        }
+        naive_spmv(self.alpha, self.a, self.x, y);
+    }
+}
+
+impl<T: ComplexFloat> SparseMatVec<T> for Naive {
+    fn spmv<'a, Lx>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        x: &'a Slice<T, (Dyn,), Lx>,
+    ) -> impl SparseMatVecBuilder<'a, T, Lx>
+    where
+        Lx: Layout,
+    {
+        NaiveSparseMatVecBuilder {
+            alpha: T::one(),
+            a,
+            x,
+        }
+    }
+}
+
+struct NaiveSparseMatmulBuilder<'a, T, Lb: Layout> {
+    alpha: T,
+    a: &'a SparseMatrix<T>,
+    b: &'a Slice<T, (Dyn, Dyn), Lb>,
+}
+
+impl<'a, T, Lb> SparseMatmulBuilder<'a, T, Lb> for NaiveSparseMatmulBuilder<'a, T, Lb>
+where
+    Lb: Layout,
+    T: ComplexFloat,
+{
+    fn scale(mut self, factor: T) -> Self {
+        self.alpha = factor * self.alpha;
+        self
+    }
+
+    fn eval(self) -> DArray<T, 2> {
+        let mut c = DArray::<T, 2>::from_elem([self.a.shape().0, self.b.shape().1], T::zero());
+        naive_spmm(self.alpha, self.a, self.b, &mut c);
+        c
+    }
+
+    fn write<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>) {
+        for ci in c.iter_mut() {
+            *ci = T::zero();
+        }
+        naive_spmm(self.alpha, self.a, self.b, c);
+    }
+
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>) {
+        naive_spmm(self.alpha, self.a, self.b, c);
+    }
+
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>, beta: T) {
+        for ci in c.iter_mut() {
+            *ci = beta * *ci;
+        }
+        naive_spmm(self.alpha, self.a, self.b, c);
+    }
+}
+
+impl<T: ComplexFloat> SparseMatmul<T> for Naive {
+    fn spmm<'a, Lb>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        b: &'a Slice<T, (Dyn, Dyn), Lb>,
+    ) -> impl SparseMatmulBuilder<'a, T, Lb>
+    where
+        Lb: Layout,
+    {
+        NaiveSparseMatmulBuilder {
+            alpha: T::one(),
+            a,
+            b,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use mdarray::{Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use crate::sparse::SparseMatrix;
+
+/// Performs naively y := α·A·x + y
+pub fn naive_spmv<T: ComplexFloat, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &SparseMatrix<T>,
+    x: &Slice<T, (Dyn,), Lx>,
+    y: &mut Slice<T, (Dyn,), Ly>,
+) {
+    let (m, n) = a.shape();
+    assert!(n == x.shape().0, "Matrix columns must match x vector length");
+    assert!(m == y.shape().0, "Matrix rows must match y vector length");
+
+    for (i, j, &v) in a.iter() {
+        y[[i]] = y[[i]] + alpha * v * x[[j]];
+    }
+}
+
+/// Performs naively C := α·A·B + C
+pub fn naive_spmm<T: ComplexFloat, Lb: Layout, Lc: Layout>(
+    alpha: T,
+    a: &SparseMatrix<T>,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+    c: &mut Slice<T, (Dyn, Dyn), Lc>,
+) {
+    let (m, k) = a.shape();
+    let n = b.shape().1;
+    assert!(k == b.shape().0, "Matrix columns must match rows of B");
+    assert!(*c.shape() == (m, n), "Output shape must be {m}x{n}");
+
+    for (i, l, &v) in a.iter() {
+        let av = alpha * v;
+        for j in 0..n {
+            c[[i, j]] = c[[i, j]] + av * b[[l, j]];
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use mdarray::{DArray, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use super::simple::{
+    naive_diag_scale, naive_matmul_entries, naive_matvec_entries, naive_trmm, naive_trsm,
+};
+use crate::{
+    Naive,
+    solve::SolveError,
+    structured::{
+        Diagonal, Hermitian, PackedHermitian, PackedSymmetric, Structured, Symmetric, Triangular,
+        check_tri_nonsingular,
+    },
+};
+
+impl<T: ComplexFloat> Structured<T> for Naive {
+    fn diag_scale<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mut c = b.to_array();
+        naive_diag_scale(d, &mut c);
+        c
+    }
+
+    fn diag_scale_write<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        naive_diag_scale(d, b);
+    }
+
+    fn tri_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        let mut c = b.to_array();
+        naive_trmm(a, &mut c);
+        c
+    }
+
+    fn tri_matmul_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) {
+        naive_trmm(a, b);
+    }
+
+    fn tri_solve<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<DArray<T, 2>, SolveError> {
+        let mut x = b.to_array();
+        self.tri_solve_write(a, &mut x)?;
+        Ok(x)
+    }
+
+    fn tri_solve_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<(), SolveError> {
+        check_tri_nonsingular(a)?;
+        naive_trsm(a, b);
+        Ok(())
+    }
+
+    fn sym_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Symmetric<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        naive_matmul_entries(a.size(), |i, j| a.get(i, j), b)
+    }
+
+    fn herm_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Hermitian<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2> {
+        naive_matmul_entries(a.size(), |i, j| a.get(i, j), b)
+    }
+
+    fn packed_sym_matvec<Lx: Layout>(
+        &self,
+        a: &PackedSymmetric<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        naive_matvec_entries(a.size(), |i, j| a.get(i, j), x)
+    }
+
+    fn packed_herm_matvec<Lx: Layout>(
+        &self,
+        a: &PackedHermitian<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1> {
+        naive_matvec_entries(a.size(), |i, j| a.get(i, j), x)
+    }
+}
@@ -0,0 +1,104 @@
+use mdarray::{DArray, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use crate::structured::{Diagonal, Triangular, Uplo};
+
+/// Performs naively B := D·B
+pub fn naive_diag_scale<T: ComplexFloat, Ld: Layout, Lb: Layout>(
+    d: &Diagonal<'_, T, Ld>,
+    b: &mut Slice<T, (Dyn, Dyn), Lb>,
+) {
+    let (m, n) = *b.shape();
+    assert!(d.size() == m, "Diagonal size must match rows of B");
+
+    for i in 0..m {
+        let di = d.diag()[[i]];
+        for j in 0..n {
+            b[[i, j]] = di * b[[i, j]];
+        }
+    }
+}
+
+/// Performs naively B := A·B for a triangular A
+pub fn naive_trmm<T: ComplexFloat, La: Layout, Lb: Layout>(
+    a: &Triangular<'_, T, La>,
+    b: &mut Slice<T, (Dyn, Dyn), Lb>,
+) {
+    let (m, n) = *b.shape();
+    assert!(a.size() == m, "Matrix columns must match rows of B");
+
+    // Row i of A·B only depends on the rows of B on the stored side of i,
+    // which are still unmodified in this order.
+    let rows: Box<dyn Iterator<Item = usize>> = match a.uplo() {
+        Uplo::Upper => Box::new(0..m),
+        Uplo::Lower => Box::new((0..m).rev()),
+    };
+    for i in rows {
+        let ks = match a.uplo() {
+            Uplo::Upper => i..m,
+            Uplo::Lower => 0..i + 1,
+        };
+        for j in 0..n {
+            b[[i, j]] = ks
+                .clone()
+                .fold(T::zero(), |acc, k| acc + a.get(i, k) * b[[k, j]]);
+        }
+    }
+}
+
+/// Solves naively A·X = B for a triangular A, overwriting B with X
+pub fn naive_trsm<T: ComplexFloat, La: Layout, Lb: Layout>(
+    a: &Triangular<'_, T, La>,
+    b: &mut Slice<T, (Dyn, Dyn), Lb>,
+) {
+    let (m, n) = *b.shape();
+    assert!(a.size() == m, "Matrix columns must match rows of B");
+
+    let rows: Box<dyn Iterator<Item = usize>> = match a.uplo() {
+        Uplo::Upper => Box::new((0..m).rev()),
+        Uplo::Lower => Box::new(0..m),
+    };
+    for i in rows {
+        let ks = match a.uplo() {
+            Uplo::Upper => i + 1..m,
+            Uplo::Lower => 0..i,
+        };
+        let aii = a.get(i, i);
+        for j in 0..n {
+            let s = ks
+                .clone()
+                .fold(b[[i, j]], |acc, k| acc - a.get(i, k) * b[[k, j]]);
+            b[[i, j]] = s / aii;
+        }
+    }
+}
+
+/// Computes naively A·B for any A given entrywise
+pub fn naive_matmul_entries<T: ComplexFloat, Lb: Layout>(
+    n: usize,
+    a: impl Fn(usize, usize) -> T,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+) -> DArray<T, 2> {
+    let (m, k) = *b.shape();
+    assert!(n == m, "Matrix columns must match rows of B");
+
+    DArray::<T, 2>::from_fn([n, k], |i| {
+        (0..n).fold(T::zero(), |acc, l| acc + a(i[0], l) * b[[l, i[1]]])
+    })
+}
+
+/// Computes naively A·x for any A given entrywise
+pub fn naive_matvec_entries<T: ComplexFloat, Lx: Layout>(
+    n: usize,
+    a: impl Fn(usize, usize) -> T,
+    x: &Slice<T, (Dyn,), Lx>,
+) -> DArray<T, 1> {
+    assert!(
+        n == x.shape().0,
+        "Matrix columns must match x vector length"
+    );
+
+    DArray::<T, 1>::from_fn([n], |i| {
+        (0..n).fold(T::zero(), |acc, l| acc + a(i[0], l) * x[[l]])
+    })
+}
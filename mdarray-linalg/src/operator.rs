@@ -0,0 +1,380 @@
+//! Linear operators that are only accessed through their action on vectors
+//!
+//! A [`LinearOperator`] provides `y = A x`, `y = A^H x`, its shape and, when
+//! cheaply available, its diagonal.  Implementations are provided for
+//!
+//! - dense matrices through any [`MatVec`] backend ([`DenseOperator`]),
+//! - closures ([`FnOperator`]),
+//! - the combinations [`Scaled`] (`α A`), [`Sum`] (`A + B`), [`Composed`]
+//!   (`A B`), [`Transpose`] (`A^T`) and [`Adjoint`] (`A^H`), which never
+//!   materialize the combined matrix.
+//!
+//! The iterative solvers of [`crate::eigs`] and [`crate::krylov`] take the
+//! operator as a closure, which is written `|x, y| op.apply(x, y)`.
+//!```rust
+//!use mdarray::{DArray, tensor};
+//!use mdarray_linalg::Naive;
+//!use mdarray_linalg::operator::{Composed, DenseOperator, LinearOperator, Scaled, Transpose};
+//!
+//!let a = DArray::<f64, 2>::from_fn([3, 2], |i| (i[0] + 2 * i[1]) as f64);
+//!let dense = DenseOperator::new(&Naive, &a);
+//!
+//!// 2 A^T A, without forming the product
+//!let op = Scaled::new(2.0, Composed::new(Transpose::new(&dense), &dense));
+//!assert_eq!(op.shape(), (2, 2));
+//!
+//!let x = tensor![1.0, 1.0];
+//!let mut y = DArray::<f64, 1>::from_elem([2], 0.0);
+//!op.apply(&x, &mut y);
+//!// A x = [2, 4, 6], A^T A x = [16, 40]
+//!assert_eq!(y, tensor![32.0, 80.0]);
+//!```
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use crate::matvec::{MatVec, MatVecBuilder};
+
+/// Linear map `A: T^cols → T^rows` given by its action on vectors.
+pub trait LinearOperator<T> {
+    /// Number of rows and columns of `A`.
+    fn shape(&self) -> (usize, usize);
+
+    /// Overwrites `y` with `A x`.
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>);
+
+    /// Overwrites `y` with `A^H x`.
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>);
+
+    /// Diagonal of `A`, if it is available without materializing `A`.
+    fn diagonal(&self) -> Option<DArray<T, 1>>;
+}
+
+impl<T, O: LinearOperator<T> + ?Sized> LinearOperator<T> for &O {
+    fn shape(&self) -> (usize, usize) {
+        (**self).shape()
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        (**self).apply(x, y)
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        (**self).apply_adjoint(x, y)
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        (**self).diagonal()
+    }
+}
+
+/// Dense matrix applied through a [`MatVec`] backend.
+pub struct DenseOperator<'a, T, L: Layout, B> {
+    bd: &'a B,
+    a: &'a Slice<T, (Dyn, Dyn), L>,
+}
+
+impl<'a, T, L: Layout, B> DenseOperator<'a, T, L, B> {
+    pub fn new(bd: &'a B, a: &'a Slice<T, (Dyn, Dyn), L>) -> Self {
+        Self { bd, a }
+    }
+}
+
+impl<T, L, B> LinearOperator<T> for DenseOperator<'_, T, L, B>
+where
+    T: ComplexFloat,
+    L: Layout,
+    B: MatVec<T, Dyn, Dyn>,
+{
+    fn shape(&self) -> (usize, usize) {
+        *self.a.shape()
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.bd.matvec(self.a, x).write(y);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        // A^H x = conj(A^T conj(x))
+        let xc = conjugated(x);
+        let at = self.a.transpose();
+        self.bd.matvec(&at, &xc).write(y);
+        conjugate(y);
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        let (m, n) = *self.a.shape();
+        Some(DArray::<T, 1>::from_fn([m.min(n)], |i| self.a[[i[0], i[0]]]))
+    }
+}
+
+/// Operator given by closures for `A x` and `A^H x`.
+pub struct FnOperator<T, F, G> {
+    rows: usize,
+    cols: usize,
+    apply: F,
+    apply_adjoint: G,
+    diagonal: Option<DArray<T, 1>>,
+}
+
+impl<T, F, G> FnOperator<T, F, G>
+where
+    F: Fn(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    G: Fn(&DSlice<T, 1>, &mut DSlice<T, 1>),
+{
+    /// `apply(x, y)` must overwrite `y` with `A x` and `apply_adjoint(x, y)`
+    /// with `A^H x`; for a self-adjoint operator both can be the same
+    /// closure.
+    pub fn new(rows: usize, cols: usize, apply: F, apply_adjoint: G) -> Self {
+        Self {
+            rows,
+            cols,
+            apply,
+            apply_adjoint,
+            diagonal: None,
+        }
+    }
+
+    /// Attaches the known diagonal of the operator.
+    pub fn with_diagonal(mut self, diagonal: DArray<T, 1>) -> Self {
+        assert_eq!(
+            diagonal.len(),
+            self.rows.min(self.cols),
+            "Diagonal length must be min(rows, cols)"
+        );
+        self.diagonal = Some(diagonal);
+        self
+    }
+}
+
+impl<T, F, G> LinearOperator<T> for FnOperator<T, F, G>
+where
+    T: Clone,
+    F: Fn(&DSlice<T, 1>, &mut DSlice<T, 1>),
+    G: Fn(&DSlice<T, 1>, &mut DSlice<T, 1>),
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        (self.apply)(x, y)
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        (self.apply_adjoint)(x, y)
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        self.diagonal.clone()
+    }
+}
+
+/// `α A`
+pub struct Scaled<T, O> {
+    alpha: T,
+    op: O,
+}
+
+impl<T, O> Scaled<T, O> {
+    pub fn new(alpha: T, op: O) -> Self {
+        Self { alpha, op }
+    }
+}
+
+impl<T: ComplexFloat, O: LinearOperator<T>> LinearOperator<T> for Scaled<T, O> {
+    fn shape(&self) -> (usize, usize) {
+        self.op.shape()
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.op.apply(x, y);
+        y.iter_mut().for_each(|yi| *yi = self.alpha * *yi);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.op.apply_adjoint(x, y);
+        let alpha = self.alpha.conj();
+        y.iter_mut().for_each(|yi| *yi = alpha * *yi);
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        let mut d = self.op.diagonal()?;
+        d.iter_mut().for_each(|di| *di = self.alpha * *di);
+        Some(d)
+    }
+}
+
+/// `A + B`
+pub struct Sum<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Sum<A, B> {
+    /// Panics if the shapes of `a` and `b` differ.
+    pub fn new<T>(a: A, b: B) -> Self
+    where
+        A: LinearOperator<T>,
+        B: LinearOperator<T>,
+    {
+        assert_eq!(a.shape(), b.shape(), "Operators must have the same shape");
+        Self { a, b }
+    }
+}
+
+impl<T, A, B> LinearOperator<T> for Sum<A, B>
+where
+    T: ComplexFloat,
+    A: LinearOperator<T>,
+    B: LinearOperator<T>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.a.shape()
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.a.apply(x, y);
+        let mut tmp = DArray::<T, 1>::from_elem([y.len()], T::zero());
+        self.b.apply(x, &mut tmp);
+        y.iter_mut().zip(tmp.iter()).for_each(|(yi, &ti)| *yi = *yi + ti);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.a.apply_adjoint(x, y);
+        let mut tmp = DArray::<T, 1>::from_elem([y.len()], T::zero());
+        self.b.apply_adjoint(x, &mut tmp);
+        y.iter_mut().zip(tmp.iter()).for_each(|(yi, &ti)| *yi = *yi + ti);
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        let mut d = self.a.diagonal()?;
+        let e = self.b.diagonal()?;
+        d.iter_mut().zip(e.iter()).for_each(|(di, &ei)| *di = *di + ei);
+        Some(d)
+    }
+}
+
+/// `A B`, applying `B` first.
+pub struct Composed<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Composed<A, B> {
+    /// Panics if the number of columns of `a` differs from the number of
+    /// rows of `b`.
+    pub fn new<T>(a: A, b: B) -> Self
+    where
+        A: LinearOperator<T>,
+        B: LinearOperator<T>,
+    {
+        assert_eq!(
+            a.shape().1,
+            b.shape().0,
+            "Inner dimensions of composed operators must match"
+        );
+        Self { a, b }
+    }
+}
+
+impl<T, A, B> LinearOperator<T> for Composed<A, B>
+where
+    T: ComplexFloat,
+    A: LinearOperator<T>,
+    B: LinearOperator<T>,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.a.shape().0, self.b.shape().1)
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        let mut tmp = DArray::<T, 1>::from_elem([self.b.shape().0], T::zero());
+        self.b.apply(x, &mut tmp);
+        self.a.apply(&tmp, y);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        let mut tmp = DArray::<T, 1>::from_elem([self.a.shape().1], T::zero());
+        self.a.apply_adjoint(x, &mut tmp);
+        self.b.apply_adjoint(&tmp, y);
+    }
+
+    /// The diagonal of a product is not available without materializing it.
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        None
+    }
+}
+
+/// `A^T`, the transpose without conjugation.
+pub struct Transpose<O> {
+    op: O,
+}
+
+impl<O> Transpose<O> {
+    pub fn new(op: O) -> Self {
+        Self { op }
+    }
+}
+
+impl<T: ComplexFloat, O: LinearOperator<T>> LinearOperator<T> for Transpose<O> {
+    fn shape(&self) -> (usize, usize) {
+        let (m, n) = self.op.shape();
+        (n, m)
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        // A^T x = conj(A^H conj(x))
+        self.op.apply_adjoint(&conjugated(x), y);
+        conjugate(y);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        // (A^T)^H x = conj(A conj(x))
+        self.op.apply(&conjugated(x), y);
+        conjugate(y);
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        self.op.diagonal()
+    }
+}
+
+/// `A^H`, the conjugate transpose.
+pub struct Adjoint<O> {
+    op: O,
+}
+
+impl<O> Adjoint<O> {
+    pub fn new(op: O) -> Self {
+        Self { op }
+    }
+}
+
+impl<T: ComplexFloat, O: LinearOperator<T>> LinearOperator<T> for Adjoint<O> {
+    fn shape(&self) -> (usize, usize) {
+        let (m, n) = self.op.shape();
+        (n, m)
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.op.apply_adjoint(x, y)
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.op.apply(x, y)
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        let mut d = self.op.diagonal()?;
+        d.iter_mut().for_each(|di| *di = di.conj());
+        Some(d)
+    }
+}
+
+fn conjugated<T: ComplexFloat>(x: &DSlice<T, 1>) -> DArray<T, 1> {
+    DArray::<T, 1>::from_fn([x.len()], |i| x[i[0]].conj())
+}
+
+fn conjugate<T: ComplexFloat>(y: &mut DSlice<T, 1>) {
+    y.iter_mut().for_each(|yi| *yi = yi.conj());
+}
@@ -0,0 +1,423 @@
+//! Polar decomposition
+//!
+//! Every `m × n` matrix `A` factors as
+//!
+//! | Form                 | Factorization | Hermitian positive semidefinite factor |
+//! |----------------------|---------------|----------------------------------------|
+//! | [`PolarSide::Right`] | `A = U P`     | `P = (A^H A)^½`, `n × n`               |
+//! | [`PolarSide::Left`]  | `A = P U`     | `P = (A A^H)^½`, `m × m`               |
+//!
+//! where the `m × n` factor `U` has orthonormal columns (`m ≥ n`) or rows
+//! (`m < n`).  `U` is the nearest matrix with orthonormal columns (rows) to
+//! `A` in every unitarily invariant norm; for a square real `A` with positive
+//! determinant it is the nearest rotation.  Both forms share `U`, which is
+//! unique when `A` has full rank.
+//!
+//! | Function         | Method                                       | Backends           |
+//! |------------------|----------------------------------------------|--------------------|
+//! | [`polar`]        | SVD `A = W Σ V^H`, `U = W V^H`               | [`SVD`], [`Contract`]  |
+//! | [`polar_newton`] | scaled Newton iteration `X ← (ζ X + (ζ X)^-H) / 2` | [`Solve`], [`Contract`] |
+//! | [`polar_qdwh`]   | QR-based dynamically weighted Halley (QDWH)  | [`QR`], [`Contract`]   |
+//!
+//! The Newton iteration needs a square nonsingular `A` and the QDWH
+//! iteration a full-rank `A`.  Both stop once the relative change
+//! `‖X_{k+1} - X_k‖_F / ‖X_{k+1}‖_F` of the iterate drops below
+//! [`PolarConfig::tol`]; as they converge at least quadratically, `U` is then
+//! accurate to about `tol²`.  `P` is computed as the Hermitian part of
+//! `U^H A` (respectively `A U^H`).
+//!
+//! ```rust
+//! use mdarray::DArray;
+//! use mdarray_linalg::Naive;
+//! use mdarray_linalg::polar::{PolarConfig, PolarDecomp, PolarSide, polar_qdwh};
+//!
+//! let a = DArray::<f64, 2>::from_fn([3, 2], |i| [[2., 1.], [0., 1.], [1., 3.]][i[0]][i[1]]);
+//! let PolarDecomp { u, p } = polar_qdwh(&Naive, &Naive, &a, PolarSide::Right, &PolarConfig::default())
+//!     .expect("Polar decomposition failed");
+//! assert_eq!(*u.shape(), (3, 2));
+//! assert_eq!(*p.shape(), (2, 2));
+//! ```
+use mdarray::{Array, Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    contract::{Contract, MatmulBuilder},
+    qr::QR,
+    solve::{Solve, SolveError},
+    svd::{SVD, SVDDecomp, SVDError},
+};
+
+/// Error types related to the polar decomposition
+#[derive(Debug, Error)]
+pub enum PolarError {
+    /// The Newton iteration requires a square matrix
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// The QDWH iteration requires a matrix of full rank
+    #[error("Matrix is rank deficient")]
+    RankDeficient,
+
+    /// The tolerance was not reached within the iteration limit
+    #[error("No convergence after {iterations} iterations")]
+    NotConverged { iterations: usize },
+
+    #[error(transparent)]
+    SVD(#[from] SVDError),
+
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+}
+
+/// Form of the polar decomposition
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PolarSide {
+    /// `A = U P` with `P = (A^H A)^½`
+    #[default]
+    Right,
+    /// `A = P U` with `P = (A A^H)^½`
+    Left,
+}
+
+/// Holds the results of a polar decomposition
+pub struct PolarDecomp<T, D: Dim> {
+    /// `m × n` factor with orthonormal columns or rows
+    pub u: Array<T, (D, D)>,
+    /// Hermitian positive semidefinite factor, `n × n` for
+    /// [`PolarSide::Right`] and `m × m` for [`PolarSide::Left`]
+    pub p: Array<T, (D, D)>,
+}
+
+/// Settings for [`polar_newton`] and [`polar_qdwh`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarConfig<R> {
+    /// Tolerance on the relative change of the iterate.  Defaults to the
+    /// square root of the machine epsilon.
+    pub tol: R,
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl<R: Float> Default for PolarConfig<R> {
+    fn default() -> Self {
+        Self {
+            tol: R::epsilon().sqrt(),
+            max_iterations: 100,
+        }
+    }
+}
+
+/// Computes the polar decomposition of `a` from its SVD.
+///
+/// Works for any `a`; if `a` is rank deficient, `U` is one of the
+/// nearest matrices with orthonormal columns (rows).
+pub fn polar<T, D, L>(
+    svd: &impl SVD<T, D, SingularValue = T>,
+    mm: &impl Contract<T>,
+    a: &Slice<T, (D, D), L>,
+    side: PolarSide,
+) -> Result<PolarDecomp<T, D>, PolarError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    let SVDDecomp { s, u: w, vt } = svd.svd(&mut a.to_tensor())?;
+
+    // Only the leading min(m, n) singular vectors enter the factors.
+    let k = s.len();
+    let w = Array::from_fn(<(D, D) as Shape>::from_dims(&[m, k]), |i| w[[i[0], i[1]]]);
+    let vt = Array::from_fn(<(D, D) as Shape>::from_dims(&[k, n]), |i| vt[[i[0], i[1]]]);
+    let u = mm.matmul(&w, &vt).eval();
+
+    // P = V Σ V^H or W Σ W^H
+    let p = match side {
+        PolarSide::Right => {
+            let svt = Array::from_fn(*vt.shape(), |i| s[i[0]] * vt[[i[0], i[1]]]);
+            mm.matmul(&adjoint(&vt), &svt).eval()
+        }
+        PolarSide::Left => {
+            let ws = Array::from_fn(*w.shape(), |i| w[[i[0], i[1]]] * s[i[1]]);
+            mm.matmul(&ws, &adjoint(&w)).eval()
+        }
+    };
+
+    Ok(PolarDecomp {
+        u,
+        p: hermitian_part(&p),
+    })
+}
+
+/// Computes the polar decomposition of a square nonsingular `a` with the
+/// Newton iteration `X ← (ζ X + (ζ X)^-H) / 2` and Frobenius-norm scaling
+/// `ζ = (‖X^-1‖_F / ‖X‖_F)^½`.
+///
+/// Each step solves one linear system with `n` right-hand sides.  Returns
+/// [`PolarError::NotSquare`] for a rectangular `a` and the error of the
+/// solver if an iterate is singular.
+pub fn polar_newton<T, D, L>(
+    mm: &impl Contract<T>,
+    solve: &impl Solve<T, D>,
+    a: &Slice<T, (D, D), L>,
+    side: PolarSide,
+    config: &PolarConfig<T::Real>,
+) -> Result<PolarDecomp<T, D>, PolarError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m != n {
+        return Err(PolarError::NotSquare { rows: m, cols: n });
+    }
+
+    let half: T = <T::Real as NumCast>::from(0.5).unwrap().into();
+    let identity = identity::<T, D>(n);
+    let mut x = a.to_tensor();
+
+    for _ in 0..config.max_iterations {
+        let x_inv = solve.solve(&mut x.to_tensor(), &identity)?;
+        let zeta = Float::sqrt(norm(&x_inv) / norm(&x));
+        let (zeta, zeta_inv): (T, T) = (zeta.into(), Float::recip(zeta).into());
+
+        let next = Array::from_fn(*x.shape(), |i| {
+            (zeta * x[[i[0], i[1]]] + zeta_inv * x_inv[[i[1], i[0]]].conj()) * half
+        });
+        let change = relative_change(&next, &x);
+        x = next;
+        if change <= config.tol {
+            return Ok(factors(mm, a, x, side));
+        }
+    }
+
+    Err(PolarError::NotConverged {
+        iterations: config.max_iterations,
+    })
+}
+
+/// Computes the polar decomposition of a full-rank `a` with the QR-based
+/// dynamically weighted Halley iteration (QDWH) of Nakatsukasa, Bai and
+/// Gygi,
+/// `X ← (b/c) X + (a - b/c) / √c · Q1 Q2^H` with `[√c X; I] = [Q1; Q2] R`.
+///
+/// The weights `(a, b, c)` are computed from a lower bound on the smallest
+/// singular value, so that the iteration converges within six steps for
+/// any condition number up to `1/ε`.  A wide `a` is handled through its
+/// adjoint.  Returns [`PolarError::RankDeficient`] if `a` does not have full
+/// rank.
+pub fn polar_qdwh<T, D, L>(
+    mm: &impl Contract<T>,
+    qr: &impl QR<T, D, D>,
+    a: &Slice<T, (D, D), L>,
+    side: PolarSide,
+    config: &PolarConfig<T::Real>,
+) -> Result<PolarDecomp<T, D>, PolarError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    let u = if m >= n {
+        qdwh(mm, qr, &a.to_tensor(), config)?
+    } else {
+        adjoint(&*qdwh(mm, qr, &adjoint(a), config)?)
+    };
+    Ok(factors(mm, a, u, side))
+}
+
+/// Returns the orthonormal polar factor of a tall full-rank `a`.
+fn qdwh<T, D>(
+    mm: &impl Contract<T>,
+    qr: &impl QR<T, D, D>,
+    a: &Array<T, (D, D)>,
+    config: &PolarConfig<T::Real>,
+) -> Result<Array<T, (D, D)>, PolarError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    D: Dim,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+    let real = |x: f64| <T::Real as NumCast>::from(x).unwrap();
+
+    let alpha = norm(a);
+    if n == 0 {
+        return Ok(a.clone());
+    }
+    if alpha == zero {
+        return Err(PolarError::RankDeficient);
+    }
+
+    // X_0 = A / ‖A‖_F has ‖X_0‖_2 <= 1, and σ_min(X_0) = σ_min(R) is
+    // bounded below by 1 / ‖R^-1‖_F.
+    let mut x = Array::from_fn(*a.shape(), |i| a[[i[0], i[1]]] / alpha.into());
+    let (_, r) = qr_factors(qr, &mut x.clone(), m, n);
+    let mut l = match inverse_norm(&r) {
+        Some(r_inv) => Float::max(Float::recip(r_inv), T::Real::epsilon()),
+        None => return Err(PolarError::RankDeficient),
+    };
+
+    for _ in 0..config.max_iterations {
+        // Dynamical weights h(l) of the Halley iteration
+        let l2 = l * l;
+        let gamma = Float::cbrt(real(4.0) * (one - l2) / (l2 * l2));
+        let sq = Float::sqrt(one + gamma);
+        let wa = sq
+            + real(0.5)
+                * Float::sqrt(
+                    real(8.0) - real(4.0) * gamma + real(8.0) * (real(2.0) - l2) / (l2 * sq),
+                );
+        let wb = (wa - one) * (wa - one) / real(4.0);
+        let wc = wa + wb - one;
+
+        let sqrt_c = Float::sqrt(wc);
+        let mut stacked = Array::from_fn(<(D, D) as Shape>::from_dims(&[m + n, n]), |i| {
+            if i[0] < m {
+                x[[i[0], i[1]]] * sqrt_c.into()
+            } else if i[0] - m == i[1] {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+        let (q, _) = qr_factors(qr, &mut stacked, m + n, n);
+        let q1 = Array::from_fn(<(D, D) as Shape>::from_dims(&[m, n]), |i| q[[i[0], i[1]]]);
+        let q2h = Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+            q[[m + i[1], i[0]]].conj()
+        });
+        let q1q2h = mm.matmul(&q1, &q2h).eval();
+
+        let (b_c, weight) = (wb / wc, (wa - wb / wc) / sqrt_c);
+        let next = Array::from_fn(*x.shape(), |i| {
+            x[[i[0], i[1]]] * b_c.into() + q1q2h[[i[0], i[1]]] * weight.into()
+        });
+        let change = relative_change(&next, &x);
+        x = next;
+        l = Float::min(l * (wa + wb * l2) / (one + wc * l2), one);
+        if change <= config.tol {
+            return Ok(x);
+        }
+    }
+
+    Err(PolarError::NotConverged {
+        iterations: config.max_iterations,
+    })
+}
+
+/// Completes the decomposition of `a` from its orthonormal factor `u`.
+fn factors<T, D, L>(
+    mm: &impl Contract<T>,
+    a: &Slice<T, (D, D), L>,
+    u: Array<T, (D, D)>,
+    side: PolarSide,
+) -> PolarDecomp<T, D>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+{
+    let p = match side {
+        PolarSide::Right => mm.matmul(&adjoint(&u), a).eval(),
+        PolarSide::Left => mm.matmul(a, &adjoint(&u)).eval(),
+    };
+    PolarDecomp {
+        u,
+        p: hermitian_part(&p),
+    }
+}
+
+/// `Q` and `R` factors of a reduced QR decomposition
+type QRFactors<T, D> = (Array<T, (D, D)>, Array<T, (D, D)>);
+
+/// Returns `Q` (`rows × cols`) and `R` (`cols × cols`) of the reduced QR
+/// decomposition of `y`, which is overwritten.
+fn qr_factors<T, D>(
+    qr: &impl QR<T, D, D>,
+    y: &mut Array<T, (D, D)>,
+    rows: usize,
+    cols: usize,
+) -> QRFactors<T, D>
+where
+    T: ComplexFloat + Default,
+    D: Dim,
+{
+    let mut q = Array::from_elem(<(D, D) as Shape>::from_dims(&[rows, cols]), T::default());
+    let mut r = Array::from_elem(<(D, D) as Shape>::from_dims(&[cols, cols]), T::default());
+    qr.qr_write(y, &mut q, &mut r);
+    (q, r)
+}
+
+/// Returns `‖R^-1‖_F` for an upper triangular `r`, or `None` if `r` is
+/// singular.
+fn inverse_norm<T: ComplexFloat, D: Dim>(r: &Array<T, (D, D)>) -> Option<T::Real> {
+    let n = r.shape().dim(0);
+    let mut sum = T::Real::zero();
+    let mut x = vec![T::zero(); n];
+    for j in 0..n {
+        // Back substitution for column j of R^-1
+        for i in (0..=j).rev() {
+            let rhs = if i == j { T::one() } else { T::zero() };
+            let acc = (i + 1..=j).fold(rhs, |acc, k| acc - r[[i, k]] * x[k]);
+            if r[[i, i]] == T::zero() {
+                return None;
+            }
+            x[i] = acc / r[[i, i]];
+            sum = sum + x[i].abs() * x[i].abs();
+        }
+    }
+    let norm = Float::sqrt(sum);
+    Float::is_finite(norm).then_some(norm)
+}
+
+fn identity<T: ComplexFloat, D: Dim>(n: usize) -> Array<T, (D, D)> {
+    Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        if i[0] == i[1] { T::one() } else { T::zero() }
+    })
+}
+
+fn adjoint<T: ComplexFloat, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> Array<T, (D, D)> {
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    Array::from_fn(<(D, D) as Shape>::from_dims(&[n, m]), |i| {
+        a[[i[1], i[0]]].conj()
+    })
+}
+
+/// Returns `(P + P^H) / 2`.
+fn hermitian_part<T, D>(p: &Array<T, (D, D)>) -> Array<T, (D, D)>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    D: Dim,
+{
+    let half: T = <T::Real as NumCast>::from(0.5).unwrap().into();
+    Array::from_fn(*p.shape(), |i| {
+        (p[[i[0], i[1]]] + p[[i[1], i[0]]].conj()) * half
+    })
+}
+
+/// Frobenius norm
+fn norm<T: ComplexFloat, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> T::Real {
+    Float::sqrt(
+        a.iter()
+            .fold(T::Real::zero(), |acc, x| acc + x.abs() * x.abs()),
+    )
+}
+
+/// Returns `‖x - y‖_F / ‖x‖_F`.
+fn relative_change<T: ComplexFloat, D: Dim>(x: &Array<T, (D, D)>, y: &Array<T, (D, D)>) -> T::Real {
+    let diff = x.iter().zip(y.iter()).fold(T::Real::zero(), |acc, (a, b)| {
+        acc + (*a - *b).abs() * (*a - *b).abs()
+    });
+    Float::sqrt(diff) / norm(x)
+}
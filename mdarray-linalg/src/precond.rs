@@ -0,0 +1,509 @@
+//! Preconditioners for the iterative solvers of [`crate::krylov`]
+//!
+//! A preconditioner approximates the inverse of the system matrix `A` by an
+//! operator `M^{-1}` that is cheap to apply.  The solvers only access it
+//! through [`Preconditioner::apply`], so any closure
+//! `Fn(&DSlice<T, 1>, &mut DSlice<T, 1>)` computing `z = M^{-1} r` can be
+//! used as well.
+//!
+//! The following preconditioners are built from a matrix `A`:
+//!
+//! | Preconditioner  | `M`                                             | Hermitian `M` for Hermitian `A` |
+//! |-----------------|-------------------------------------------------|---------------------------------|
+//! | [`Jacobi`]      | `diag(A)`                                       | yes                             |
+//! | [`BlockJacobi`] | block diagonal of `A`, inverted with [`Solve`]  | yes                             |
+//! | [`Ssor`]        | symmetric successive over-relaxation            | yes                             |
+//! | [`Ilu0`]        | incomplete LU without fill-in                   | no                              |
+//! | [`Ic0`]         | incomplete Cholesky without fill-in             | yes (requires Hermitian `A`)    |
+//!
+//! The incomplete factorizations keep the sparsity pattern of `A`: its
+//! nonzero entries for a dense `A`, or its stored entries for a
+//! [`SparseMatrix`] passed to `from_sparse`.  The factors are stored with that
+//! pattern only.  [`Chain`] applies two preconditioners one after the
+//! other and [`Sum`] adds their results.
+//!```rust
+//!use mdarray::DArray;
+//!use mdarray_linalg::krylov::{KrylovConfig, cg};
+//!use mdarray_linalg::precond::Ic0;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!
+//!let n = 50;
+//!let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+//!    if i[0] == i[1] { 2.5 } else if i[0].abs_diff(i[1]) == 1 { -1.0 } else { 0.0 }
+//!});
+//!let b = DArray::<f64, 1>::from_elem([n], 1.0);
+//!let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+//!
+//!// For a tridiagonal matrix, IC(0) is the exact Cholesky factorization.
+//!let ic = Ic0::new(&a).expect("matrix is not positive definite");
+//!let info = cg(&Naive, |x, y| Naive.matvec(&a, x).write(y), &b, &mut x, &ic,
+//!              &KrylovConfig::default())
+//!    .expect("CG did not converge");
+//!assert!(info.iterations <= 2);
+//!```
+use std::cmp::Ordering;
+
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{Float, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    solve::{Solve, SolveError},
+    sparse::{SparseFormat, SparseMatrix},
+};
+
+/// Error types related to building preconditioners
+#[derive(Debug, Error)]
+pub enum PrecondError {
+    /// The input matrix is not square
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// A diagonal entry or pivot the preconditioner divides by is zero
+    #[error("Zero pivot at position {index}")]
+    ZeroPivot { index: usize },
+
+    /// A pivot of an incomplete Cholesky factorization is not positive
+    #[error("Matrix is not positive definite: non-positive pivot at position {index}")]
+    NotPositiveDefinite { index: usize },
+
+    /// The block size of a block-Jacobi preconditioner is zero
+    #[error("Block size must be positive")]
+    InvalidBlockSize,
+
+    /// The SSOR relaxation parameter is outside `(0, 2)`
+    #[error("Relaxation parameter must lie in (0, 2)")]
+    InvalidRelaxation,
+
+    /// A diagonal block could not be inverted
+    #[error("Diagonal block solve failed: {0}")]
+    Solve(#[from] SolveError),
+}
+
+/// Application of an approximate inverse `M^{-1}`.
+pub trait Preconditioner<T> {
+    /// Overwrites `z` with `M^{-1} r`.
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>);
+}
+
+/// The identity `M = I`, i.e. no preconditioning.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Identity;
+
+impl<T: Copy> Preconditioner<T> for Identity {
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        for (zi, &ri) in z.iter_mut().zip(r.iter()) {
+            *zi = ri;
+        }
+    }
+}
+
+impl<T, F> Preconditioner<T> for F
+where
+    F: Fn(&DSlice<T, 1>, &mut DSlice<T, 1>),
+{
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        self(r, z)
+    }
+}
+
+/// Jacobi (diagonal) preconditioner `M = diag(A)`.
+#[derive(Debug, Clone)]
+pub struct Jacobi<T> {
+    inv_diagonal: DArray<T, 1>,
+}
+
+impl<T: ComplexFloat> Jacobi<T> {
+    /// Takes the diagonal of the square `a`, which must have no zero entry;
+    /// returns an error rather than panicking otherwise.
+    pub fn new<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> Result<Self, PrecondError> {
+        let n = square_size(a)?;
+        Self::from_diagonal(&DArray::<T, 1>::from_fn([n], |i| a[[i[0], i[0]]]))
+    }
+
+    /// Builds the preconditioner from a known diagonal, e.g. the one of a
+    /// [`LinearOperator`](crate::operator::LinearOperator).
+    pub fn from_diagonal(diagonal: &DSlice<T, 1>) -> Result<Self, PrecondError> {
+        if let Some(index) = diagonal.iter().position(|&d| d == T::zero()) {
+            return Err(PrecondError::ZeroPivot { index });
+        }
+        Ok(Self {
+            inv_diagonal: DArray::<T, 1>::from_fn([diagonal.len()], |i| T::one() / diagonal[i[0]]),
+        })
+    }
+}
+
+impl<T: ComplexFloat> Preconditioner<T> for Jacobi<T> {
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        for ((zi, &ri), &di) in z.iter_mut().zip(r.iter()).zip(self.inv_diagonal.iter()) {
+            *zi = di * ri;
+        }
+    }
+}
+
+/// Block-Jacobi preconditioner: `M` is the block diagonal part of `A`.
+///
+/// The diagonal blocks are inverted once with a [`Solve`] backend.
+#[derive(Debug, Clone)]
+pub struct BlockJacobi<T> {
+    /// First index and inverse of every diagonal block.
+    blocks: Vec<(usize, DArray<T, 2>)>,
+}
+
+impl<T: ComplexFloat> BlockJacobi<T> {
+    /// Splits `A` into diagonal blocks of `block_size` rows and columns; the
+    /// last block is smaller if `block_size` does not divide the size of `A`.
+    pub fn new<L: Layout>(
+        bd: &impl Solve<T, Dyn>,
+        a: &Slice<T, (Dyn, Dyn), L>,
+        block_size: usize,
+    ) -> Result<Self, PrecondError> {
+        let n = square_size(a)?;
+        if block_size == 0 {
+            return Err(PrecondError::InvalidBlockSize);
+        }
+
+        let mut blocks = Vec::with_capacity(n.div_ceil(block_size));
+        for start in (0..n).step_by(block_size) {
+            let size = block_size.min(n - start);
+            let mut block = DArray::<T, 2>::from_fn([size, size], |i| a[[start + i[0], start + i[1]]]);
+            let identity = DArray::<T, 2>::from_fn([size, size], |i| {
+                if i[0] == i[1] { T::one() } else { T::zero() }
+            });
+            blocks.push((start, bd.solve(&mut block, &identity)?));
+        }
+
+        Ok(Self { blocks })
+    }
+}
+
+impl<T: ComplexFloat> Preconditioner<T> for BlockJacobi<T> {
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        for (start, inv) in &self.blocks {
+            let size = inv.shape().0;
+            for i in 0..size {
+                z[start + i] = (0..size).fold(T::zero(), |acc, j| acc + inv[[i, j]] * r[start + j]);
+            }
+        }
+    }
+}
+
+/// Symmetric successive over-relaxation preconditioner
+///
+/// With `A = L + D + U` split into its strictly lower, diagonal and strictly
+/// upper parts,
+/// `M = ω / (2 - ω) (D / ω + L) (D / ω)^{-1} (D / ω + U)`.
+/// `ω = 1` gives the symmetric Gauss–Seidel preconditioner.
+#[derive(Debug, Clone)]
+pub struct Ssor<T: ComplexFloat> {
+    a: DArray<T, 2>,
+    omega: T::Real,
+}
+
+impl<T: ComplexFloat> Ssor<T> {
+    /// Copies the square `a`, which must have no zero diagonal entry, with
+    /// `omega` in `(0, 2)`; returns an error rather than panicking otherwise.
+    pub fn new<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>, omega: T::Real) -> Result<Self, PrecondError> {
+        let n = square_size(a)?;
+        if !(omega > T::Real::zero() && omega < T::Real::one() + T::Real::one()) {
+            return Err(PrecondError::InvalidRelaxation);
+        }
+        if let Some(index) = (0..n).find(|&i| a[[i, i]] == T::zero()) {
+            return Err(PrecondError::ZeroPivot { index });
+        }
+        Ok(Self {
+            a: DArray::<T, 2>::from_fn([n, n], |i| a[[i[0], i[1]]]),
+            omega,
+        })
+    }
+}
+
+impl<T> Preconditioner<T> for Ssor<T>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        let n = self.a.shape().0;
+        let omega: T = self.omega.into();
+        let two = T::one() + T::one();
+        let a = &self.a;
+
+        // (D / ω + L) y = r, then y ← (D / ω) y
+        let mut y = vec![T::zero(); n];
+        for i in 0..n {
+            let acc = (0..i).fold(r[i], |acc, j| acc - a[[i, j]] * y[j]);
+            y[i] = acc * omega / a[[i, i]];
+        }
+        for (i, yi) in y.iter_mut().enumerate() {
+            *yi = *yi * a[[i, i]] / omega;
+        }
+
+        // (D / ω + U) z = y, scaled by (2 - ω) / ω
+        for i in (0..n).rev() {
+            let acc = (i + 1..n).fold(y[i], |acc, j| acc - a[[i, j]] * z[j]);
+            z[i] = acc * omega / a[[i, i]];
+        }
+        let scale = (two - omega) / omega;
+        z.iter_mut().for_each(|zi| *zi = *zi * scale);
+    }
+}
+
+/// Incomplete LU factorization without fill-in, `M = L U`.
+///
+/// `L` (unit lower triangular) and `U` keep the sparsity pattern of `A`, and
+/// no pivoting is performed.  The factors are stored in CSR format with that
+/// pattern, so memory and application cost scale with the number of stored
+/// entries.
+#[derive(Debug, Clone)]
+pub struct Ilu0<T> {
+    /// Strictly lower part of `L` and upper part of `U`.
+    lu: SparseMatrix<T>,
+    /// Position of every diagonal entry in the values of `lu`.
+    diagonal: Vec<usize>,
+}
+
+impl<T: ComplexFloat> Ilu0<T> {
+    /// Factorizes the dense `a`, whose nonzero entries give the pattern.
+    pub fn new<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> Result<Self, PrecondError> {
+        square_size(a)?;
+        Self::from_sparse(&SparseMatrix::from_dense(a, SparseFormat::Csr))
+    }
+
+    /// Factorizes the sparse `a`, whose stored entries give the pattern.
+    pub fn from_sparse(a: &SparseMatrix<T>) -> Result<Self, PrecondError> {
+        let n = sparse_square_size(a)?;
+        let mut lu = a.to_csr();
+        let (indptr, indices) = (lu.indptr().to_owned(), lu.indices().to_owned());
+        let values = lu.values_mut();
+
+        // Position of the entries of the current row, by column.
+        let mut position = vec![None; n];
+        let mut diagonal = Vec::with_capacity(n);
+        for i in 0..n {
+            let row = indptr[i]..indptr[i + 1];
+            for p in row.clone() {
+                position[indices[p]] = Some(p);
+            }
+
+            for p in row.clone().take_while(|&p| indices[p] < i) {
+                let k = indices[p];
+                let factor = values[p] / values[diagonal[k]];
+                values[p] = factor;
+                for q in diagonal[k] + 1..indptr[k + 1] {
+                    if let Some(r) = position[indices[q]] {
+                        values[r] = values[r] - factor * values[q];
+                    }
+                }
+            }
+
+            match position[i] {
+                Some(d) if values[d] != T::zero() => diagonal.push(d),
+                _ => return Err(PrecondError::ZeroPivot { index: i }),
+            }
+            for p in row {
+                position[indices[p]] = None;
+            }
+        }
+
+        Ok(Self { lu, diagonal })
+    }
+}
+
+impl<T: ComplexFloat> Preconditioner<T> for Ilu0<T> {
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        let (indptr, indices, values) = (self.lu.indptr(), self.lu.indices(), self.lu.values());
+        let n = self.diagonal.len();
+        for i in 0..n {
+            z[i] = (indptr[i]..self.diagonal[i]).fold(r[i], |acc, p| acc - values[p] * z[indices[p]]);
+        }
+        for i in (0..n).rev() {
+            let d = self.diagonal[i];
+            let acc = (d + 1..indptr[i + 1]).fold(z[i], |acc, p| acc - values[p] * z[indices[p]]);
+            z[i] = acc / values[d];
+        }
+    }
+}
+
+/// Incomplete Cholesky factorization without fill-in, `M = L L^H`.
+///
+/// Only the lower triangle of the Hermitian matrix `A` is read, and `L`
+/// keeps its sparsity pattern.  `L` is stored in CSR format with that
+/// pattern.
+#[derive(Debug, Clone)]
+pub struct Ic0<T> {
+    /// Lower triangle of `L`; the diagonal entry ends every row.
+    l: SparseMatrix<T>,
+}
+
+impl<T> Ic0<T>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    /// Factorizes the dense `a`, whose nonzero entries give the pattern.
+    pub fn new<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> Result<Self, PrecondError> {
+        square_size(a)?;
+        Self::from_sparse(&SparseMatrix::from_dense(a, SparseFormat::Csr))
+    }
+
+    /// Factorizes the sparse `a`, whose stored entries give the pattern.
+    pub fn from_sparse(a: &SparseMatrix<T>) -> Result<Self, PrecondError> {
+        let n = sparse_square_size(a)?;
+        let a = a.to_csr();
+
+        let mut indptr = Vec::with_capacity(n + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+
+        for i in 0..n {
+            let start = indices.len();
+            let mut diagonal = None;
+            for p in a.indptr()[i]..a.indptr()[i + 1] {
+                let (j, aij) = (a.indices()[p], a.values()[p]);
+                if j > i {
+                    break;
+                }
+                if j == i {
+                    diagonal = Some(aij);
+                    break;
+                }
+                // l_ij = (a_ij - Σ_{k<j} l_ik conj(l_jk)) / l_jj, with row j
+                // of L complete and ending with l_jj.
+                let row_j = indptr[j]..indptr[j + 1] - 1;
+                let acc = sparse_dot(&indices[start..], &values[start..], &indices[row_j.clone()], &values[row_j]);
+                indices.push(j);
+                values.push((aij - acc) / values[indptr[j + 1] - 1]);
+            }
+
+            let d = (start..indices.len()).fold(diagonal.map_or(T::Real::zero(), |x| x.re()), |acc, p| {
+                acc - (values[p].conj() * values[p]).re()
+            });
+            if d <= T::Real::zero() {
+                return Err(PrecondError::NotPositiveDefinite { index: i });
+            }
+            indices.push(i);
+            values.push(Float::sqrt(d).into());
+            indptr.push(indices.len());
+        }
+
+        let l = SparseMatrix::new(n, n, SparseFormat::Csr, indptr.into(), indices.into(), values.into())
+            .expect("rows of L are sorted");
+        Ok(Self { l })
+    }
+}
+
+impl<T: ComplexFloat> Preconditioner<T> for Ic0<T> {
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        let (indptr, indices, values) = (self.l.indptr(), self.l.indices(), self.l.values());
+        let n = indptr.len() - 1;
+        for i in 0..n {
+            let d = indptr[i + 1] - 1;
+            let acc = (indptr[i]..d).fold(r[i], |acc, p| acc - values[p] * z[indices[p]]);
+            z[i] = acc / values[d];
+        }
+        // L^H z = y, sweeping over the rows of L, i.e. the columns of L^H.
+        for i in (0..n).rev() {
+            let d = indptr[i + 1] - 1;
+            z[i] = z[i] / values[d].conj();
+            for p in indptr[i]..d {
+                z[indices[p]] = z[indices[p]] - values[p].conj() * z[i];
+            }
+        }
+    }
+}
+
+/// Applies `first`, then `second`: `M^{-1} = M_2^{-1} M_1^{-1}`.
+#[derive(Debug, Clone)]
+pub struct Chain<P, Q> {
+    first: P,
+    second: Q,
+}
+
+impl<P, Q> Chain<P, Q> {
+    /// Combines `first` and `second`, which must act on vectors of the same
+    /// length; never panics.
+    pub fn new(first: P, second: Q) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, P, Q> Preconditioner<T> for Chain<P, Q>
+where
+    T: ComplexFloat,
+    P: Preconditioner<T>,
+    Q: Preconditioner<T>,
+{
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        let mut tmp = DArray::<T, 1>::from_elem([r.len()], T::zero());
+        self.first.apply(r, &mut tmp);
+        self.second.apply(&tmp, z);
+    }
+}
+
+/// Additive combination `M^{-1} = M_1^{-1} + M_2^{-1}`.
+#[derive(Debug, Clone)]
+pub struct Sum<P, Q> {
+    first: P,
+    second: Q,
+}
+
+impl<P, Q> Sum<P, Q> {
+    /// Combines `first` and `second`, which must act on vectors of the same
+    /// length; never panics.
+    pub fn new(first: P, second: Q) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, P, Q> Preconditioner<T> for Sum<P, Q>
+where
+    T: ComplexFloat,
+    P: Preconditioner<T>,
+    Q: Preconditioner<T>,
+{
+    fn apply(&self, r: &DSlice<T, 1>, z: &mut DSlice<T, 1>) {
+        let mut tmp = DArray::<T, 1>::from_elem([r.len()], T::zero());
+        self.first.apply(r, z);
+        self.second.apply(r, &mut tmp);
+        z.iter_mut().zip(tmp.iter()).for_each(|(zi, &ti)| *zi = *zi + ti);
+    }
+}
+
+/// Conjugated dot product `Σ x_k conj(y_k)` of two sparse vectors with sorted
+/// indices.
+fn sparse_dot<T: ComplexFloat>(xi: &[usize], xv: &[T], yi: &[usize], yv: &[T]) -> T {
+    let (mut p, mut q) = (0, 0);
+    let mut acc = T::zero();
+    while p < xi.len() && q < yi.len() {
+        match xi[p].cmp(&yi[q]) {
+            Ordering::Less => p += 1,
+            Ordering::Greater => q += 1,
+            Ordering::Equal => {
+                acc = acc + xv[p] * yv[q].conj();
+                p += 1;
+                q += 1;
+            }
+        }
+    }
+    acc
+}
+
+fn square_size<T, L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> Result<usize, PrecondError> {
+    let (rows, cols) = *a.shape();
+    if rows != cols {
+        return Err(PrecondError::NotSquare { rows, cols });
+    }
+    Ok(rows)
+}
+
+fn sparse_square_size<T>(a: &SparseMatrix<T>) -> Result<usize, PrecondError> {
+    let (rows, cols) = a.shape();
+    if rows != cols {
+        return Err(PrecondError::NotSquare { rows, cols });
+    }
+    Ok(rows)
+}
@@ -2,8 +2,10 @@
 //! namespace.
 
 pub use super::{
-    Argmax as _, Contract as _, Eig as _, LU as _, MatVec as _, Outer as _, QR as _, SVD as _,
-    Solve as _, VecOps as _,
+    Argmax as _, Contract as _, Eig as _, GEig as _, GSVD as _, LU as _, MatVec as _, Outer as _,
+    QR as _, SVD as _, Solve as _, SparseMatVec as _, SparseMatmul as _, SparseSolve as _,
+    Structured as _, VecOps as _,
     contract::{ContractBuilder as _, MatmulBuilder as _},
     matvec::{MatVecBuilder as _, OuterBuilder as _},
+    sparse::{SparseMatVecBuilder as _, SparseMatmulBuilder as _},
 };
@@ -0,0 +1,175 @@
+//! Randomized low-rank approximation (Halko–Martinsson–Tropp)
+//!
+//! For a large m × n matrix `A` whose numerical rank `k` is much smaller than
+//! `min(m, n)`, these routines compute an approximate basis of the range of `A`
+//! and a rank-`k` SVD at the cost of a few products with `A` and `A^H`:
+//!
+//! 1. Sample `Y = A Ω` with a Gaussian test matrix `Ω` of `k + p` columns,
+//!    where `p` is the oversampling; `Ω` is complex Gaussian for complex `A`.
+//! 2. Orthonormalize `Y = Q R`, optionally refining `Q` with `q` power
+//!    iterations `Q ← orth(A orth(A^H Q))`.
+//! 3. Compute the SVD of the small matrix `B = Q^H A` and lift its left
+//!    singular vectors with `U = Q U_B`.
+//!
+//! Matrix products go through any [`Contract`] backend, orthonormalization
+//! through any [`QR`] backend and the small SVD through any [`SVD`] backend,
+//! so they can come from different crates.
+//!```rust
+//!use mdarray::DArray;
+//!use mdarray_linalg::Naive;
+//!use mdarray_linalg::randomized::{RandomizedSVDConfig, randomized_svd};
+//!use mdarray_linalg_faer::Faer;
+//!use rand::{SeedableRng, rngs::StdRng};
+//!
+//!// Rank-2 matrix 8 × 6
+//!let a = DArray::<f64, 2>::from_fn([8, 6], |i| {
+//!    (i[0] + 1) as f64 * (i[1] + 1) as f64 + if i[0] == i[1] { 0.5 } else { 0.0 }
+//!});
+//!
+//!let mut rng = StdRng::seed_from_u64(42);
+//!let config = RandomizedSVDConfig::default();
+//!let svd = randomized_svd(&Naive, &Naive, &Faer::default(), &a, 2, &config, &mut rng)
+//!    .expect("SVD failed");
+//!assert_eq!(*svd.u.shape(), (8, 2));
+//!assert!(svd.s[0] >= svd.s[1]);
+//!```
+use mdarray::{Array, DArray, Dim, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+use num_traits::NumCast;
+use rand::Rng;
+
+use crate::{
+    contract::{Contract, MatmulBuilder},
+    qr::QR,
+    svd::{SVD, SVDDecomp, SVDError},
+    utils::imaginary_unit,
+};
+
+/// Settings for [`randomized_range_finder`] and [`randomized_svd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomizedSVDConfig {
+    /// Number of extra samples drawn beyond the target rank.
+    pub oversampling: usize,
+    /// Number of power iterations used to sharpen the spectrum decay.
+    pub power_iterations: usize,
+}
+
+impl Default for RandomizedSVDConfig {
+    fn default() -> Self {
+        Self {
+            oversampling: 10,
+            power_iterations: 2,
+        }
+    }
+}
+
+/// Computes an orthonormal basis `Q` (m × l) approximating the range of `a`,
+/// with `l = min(rank + oversampling, m, n)`.
+///
+/// Returns [`SVDError::InvalidRange`] if `rank > min(m, n)`.
+pub fn randomized_range_finder<T, D, L, R>(
+    mm: &impl Contract<T>,
+    qr: &impl QR<T, Dyn, Dyn>,
+    a: &Slice<T, (D, D), L>,
+    rank: usize,
+    config: &RandomizedSVDConfig,
+    rng: &mut R,
+) -> Result<DArray<T, 2>, SVDError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+    R: Rng + ?Sized,
+{
+    // The sample size is only known at runtime.
+    let a = a.remap::<(Dyn, Dyn), L>();
+    let (m, n) = *a.shape();
+
+    if rank > m.min(n) {
+        return Err(SVDError::InvalidRange);
+    }
+
+    let l = (rank + config.oversampling).min(m).min(n);
+
+    // Complex scalars get independent real and imaginary parts.
+    let i = imaginary_unit::<T>();
+    let omega = DArray::<T, 2>::from_fn([n, l], |_| {
+        let re: T = gaussian::<T::Real, R>(rng).into();
+        match i {
+            Some(i) => re + i * gaussian::<T::Real, R>(rng).into(),
+            None => re,
+        }
+    });
+    let mut q = orthonormalize(qr, &mut mm.matmul(&a, &omega).eval());
+
+    if config.power_iterations > 0 {
+        let ah = adjoint(&a);
+        for _ in 0..config.power_iterations {
+            let z = orthonormalize(qr, &mut mm.matmul(&ah, &q).eval());
+            q = orthonormalize(qr, &mut mm.matmul(&a, &z).eval());
+        }
+    }
+
+    Ok(q)
+}
+
+/// Computes an approximate rank-`rank` SVD of `a`.
+///
+/// `s` has length `rank` in descending order, `u` is m × `rank` and `vt` is
+/// `rank` × n.  The SVD of the projected matrix is computed by `svd`, whose
+/// configuration (e.g. gauge) applies to the result.  Returns
+/// [`SVDError::InvalidRange`] if `rank > min(m, n)`.
+pub fn randomized_svd<T, D, L, S, R>(
+    mm: &impl Contract<T>,
+    qr: &impl QR<T, Dyn, Dyn>,
+    svd: &S,
+    a: &Slice<T, (D, D), L>,
+    rank: usize,
+    config: &RandomizedSVDConfig,
+    rng: &mut R,
+) -> Result<SVDDecomp<T, S::SingularValue, Dyn>, SVDError>
+where
+    T: ComplexFloat + Default,
+    T::Real: Into<T>,
+    D: Dim,
+    L: Layout,
+    S: SVD<T, Dyn>,
+    R: Rng + ?Sized,
+{
+    let q = randomized_range_finder(mm, qr, a, rank, config, rng)?;
+    let a = a.remap::<(Dyn, Dyn), L>();
+
+    // B = Q^H A is l × n, and B = U_B Σ V^H gives A ≈ (Q U_B) Σ V^H.
+    let mut b = mm.matmul(&adjoint(&q), &a).eval();
+    let SVDDecomp { s, u: ub, vt } = svd.svd_partial(&mut b, rank)?;
+    let u = mm.matmul(&q, &ub).eval();
+
+    Ok(SVDDecomp { s, u, vt })
+}
+
+/// Draws a standard normal sample with the Box–Muller transform.
+fn gaussian<F: NumCast, R: Rng + ?Sized>(rng: &mut R) -> F {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random::<f64>();
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    <F as NumCast>::from(z).unwrap()
+}
+
+fn adjoint<T: ComplexFloat, L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+/// Returns the Q factor of the reduced QR decomposition of `y`, which is
+/// overwritten.
+fn orthonormalize<T>(qr: &impl QR<T, Dyn, Dyn>, y: &mut DArray<T, 2>) -> DArray<T, 2>
+where
+    T: ComplexFloat + Default,
+{
+    let (m, l) = *y.shape();
+    let mut q = Array::from_elem((m, l), T::default());
+    let mut r = Array::from_elem((l, l), T::default());
+    qr.qr_write(y, &mut q, &mut r);
+    q
+}
@@ -0,0 +1,407 @@
+//! Algebraic Riccati equations
+//!
+//! | Function | Equation                                                  | Gain `K`                          |
+//! |----------|-----------------------------------------------------------|-----------------------------------|
+//! | [`care`] | `A^H X + X A - X B R^-1 B^H X + Q = 0`                    | `R^-1 B^H X`                      |
+//! | [`dare`] | `A^H X A - X - A^H X B (R + B^H X B)^-1 B^H X A + Q = 0`  | `(R + B^H X B)^-1 B^H X A`        |
+//!
+//! Both solvers return the stabilizing solution: the closed-loop matrix
+//! `A - B K` is stable (all eigenvalues in the open left half-plane for
+//! [`care`], inside the unit circle for [`dare`]).  For LQR, `u = -K x` is
+//! the optimal state feedback; for Kalman filtering, solve the dual problem
+//! with `A^H` and `C^H` in place of `A` and `B`.
+//!
+//! The solution is read off the stable invariant subspace `[U1; U2]` of the
+//! Hamiltonian matrix (continuous case) or of the symplectic pencil (discrete
+//! case) as `X = U2 U1^-1`.  The subspace is obtained by reordering a complex
+//! Schur form from [`Eig::schur_complex`].  The discrete pencil is first
+//! mapped to a matrix with the Cayley transform, which is well-defined
+//! whenever a stabilizing solution exists, so `A` may be singular.  The
+//! solution is then refined with Newton–Kleinman (respectively Hewer)
+//! iterations, which solve a [Lyapunov equation](crate::sylvester) per step,
+//! as long as the residual decreases.
+//!
+//! An error is returned if the equation has no stabilizing solution, or if
+//! the final residual
+//! `‖Res(X)‖_F / (‖Q‖_F + ‖X‖_F + Σ ‖terms of Res(X)‖_F)` exceeds the square
+//! root of the machine epsilon.
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::riccati::care;
+//! use mdarray_linalg_backend::Backend;
+//!
+//! let bd = Backend::default();
+//! let sol = care(&bd, &a, &b, &q, &r)?;
+//! // Optimal feedback u = -K x
+//! let (x, k) = (sol.x, sol.k);
+//! ```
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, One, ToPrimitive, Zero};
+use thiserror::Error;
+
+use crate::{
+    eig::{Eig, EigError, SchurCondition, SchurDecomp, SchurError, SchurSelect},
+    solve::{Solve, SolveError},
+    sylvester::{SylvesterError, adjoint, discrete_lyapunov, from_spectral, lyapunov, matmul},
+    utils::to_complex,
+};
+
+/// Error types related to algebraic Riccati equations
+#[derive(Debug, Error)]
+pub enum RiccatiError {
+    /// A square coefficient matrix is required
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// A coefficient does not match the dimensions of `A` and `R`
+    #[error("Matrix must be {m}x{n}, got {rows}x{cols}")]
+    DimensionMismatch {
+        m: usize,
+        n: usize,
+        rows: usize,
+        cols: usize,
+    },
+
+    /// The pair `(A, B)` is not stabilizable, or the Hamiltonian (symplectic)
+    /// spectrum has eigenvalues on the imaginary axis (unit circle)
+    #[error("No stabilizing solution exists")]
+    NoStabilizingSolution,
+
+    /// The relative residual of the computed solution is too large
+    #[error("Solution is inaccurate: relative residual {residual:e}")]
+    InaccurateSolution { residual: f64 },
+
+    #[error(transparent)]
+    Schur(#[from] SchurError),
+
+    #[error(transparent)]
+    Eig(#[from] EigError),
+
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+
+    #[error(transparent)]
+    Sylvester(#[from] SylvesterError),
+}
+
+/// Stabilizing solution of an algebraic Riccati equation
+#[derive(Debug, Clone)]
+pub struct RiccatiSolution<T: ComplexFloat> {
+    /// Hermitian solution `X`
+    pub x: DArray<T, 2>,
+    /// Feedback gain `K`, such that `A - B K` is stable
+    pub k: DArray<T, 2>,
+    /// Relative residual of `X`
+    pub residual: T::Real,
+}
+
+/// Maximum number of Newton refinement steps after the Schur method.
+const MAX_REFINEMENTS: usize = 5;
+
+/// Solves the continuous algebraic Riccati equation
+/// `A^H X + X A - X B R^-1 B^H X + Q = 0` for `n × n` `A` and Hermitian `Q`,
+/// `n × m` `B` and Hermitian positive definite `m × m` `R`.
+pub fn care<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    b: &DSlice<T, 2>,
+    q: &DSlice<T, 2>,
+    r: &DSlice<T, 2>,
+) -> Result<RiccatiSolution<T>, RiccatiError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>> + Solve<Complex<T::Real>, Dyn>,
+{
+    let Coefficients { a, b, q, r, g } = coefficients(bd, a, b, q, r)?;
+    let n = a.shape().0;
+
+    // H = [A, -G; -Q, -A^H] and H [I; X] = [I; X] (A - G X)
+    let ah = adjoint(&a);
+    let h = block(&a, &neg(&g), &neg(&q), &neg(&ah));
+    let mut x = stable_subspace(bd, &from_spectral::<T>(&h), n, |z| z.re < T::Real::zero())?;
+
+    let residual_of = |x: &DArray<Complex<T::Real>, 2>| {
+        let axh = matmul(&ah, x);
+        let xgx = matmul(&matmul(x, &g), x);
+        let res = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| {
+            let (i, j) = (i[0], i[1]);
+            axh[[i, j]] + axh[[j, i]].conj() - xgx[[i, j]] + q[[i, j]]
+        });
+        relative(&res, &[&q, x, &axh, &axh, &xgx])
+    };
+    let mut residual = residual_of(&x);
+    for _ in 0..MAX_REFINEMENTS {
+        // (A - G X)^H X' + X' (A - G X) = -(Q + X G X)
+        let ac = sub(&a, &matmul(&g, &x));
+        let rhs = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| -q[[i[0], i[1]]])
+            - matmul(&matmul(&x, &g), &x);
+        let Ok(sol) = lyapunov(
+            bd,
+            &from_spectral::<T>(&adjoint(&ac)),
+            &from_spectral::<T>(&rhs),
+        ) else {
+            break;
+        };
+        let next = hermitian_part(&sol.x.map(to_complex), sol.scale);
+        let next_residual = residual_of(&next);
+        if Float::is_nan(next_residual) || next_residual >= residual {
+            break;
+        }
+        (x, residual) = (next, next_residual);
+    }
+
+    // K = R^-1 B^H X
+    let k = bd.solve(&mut r.clone(), &matmul(&adjoint(&b), &x))?;
+    let closed_loop = sub(&a, &matmul(&b, &k));
+    check_stable(bd, &closed_loop, |z| z.re < T::Real::zero())?;
+    finish(x, k, residual)
+}
+
+/// Solves the discrete algebraic Riccati equation
+/// `A^H X A - X - A^H X B (R + B^H X B)^-1 B^H X A + Q = 0` for `n × n` `A`
+/// and Hermitian `Q`, `n × m` `B` and Hermitian positive definite `m × m` `R`.
+pub fn dare<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    b: &DSlice<T, 2>,
+    q: &DSlice<T, 2>,
+    r: &DSlice<T, 2>,
+) -> Result<RiccatiSolution<T>, RiccatiError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>> + Solve<Complex<T::Real>, Dyn>,
+{
+    let Coefficients { a, b, q, r, g } = coefficients(bd, a, b, q, r)?;
+    let n = a.shape().0;
+    let one = Complex::<T::Real>::one();
+    let zero = Complex::<T::Real>::zero();
+    let eye =
+        DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| if i[0] == i[1] { one } else { zero });
+    let zeros = DArray::<Complex<T::Real>, 2>::from_elem([n, n], zero);
+
+    // L [I; X] = M [I; X] (I + G X)^-1 A with L = [A, 0; -Q, I] and
+    // M = [I, G; 0, A^H].  The Cayley transform (L + M)^-1 (L - M) maps the
+    // eigenvalues λ of the pencil to (λ - 1) / (λ + 1), and the inside of the
+    // unit circle to the left half-plane.  L + M is singular only if λ = -1,
+    // in which case no stabilizing solution exists.
+    let ah = adjoint(&a);
+    let l = block(&a, &zeros, &neg(&q), &eye);
+    let m = block(&eye, &g, &zeros, &ah);
+    let mut lpm = l.clone() + &m;
+    let cayley = bd
+        .solve(&mut lpm, &(l - &m))
+        .map_err(|_| RiccatiError::NoStabilizingSolution)?;
+    let mut x = stable_subspace(bd, &from_spectral::<T>(&cayley), n, |z| {
+        z.re < T::Real::zero()
+    })?;
+
+    // K = (R + B^H X B)^-1 B^H X A
+    let gain = |x: &DArray<Complex<T::Real>, 2>| {
+        let bhx = matmul(&adjoint(&b), x);
+        let mut w = r.clone() + &matmul(&bhx, &b);
+        bd.solve(&mut w, &matmul(&bhx, &a))
+    };
+    let residual_of = |x: &DArray<Complex<T::Real>, 2>, k: &DArray<Complex<T::Real>, 2>| {
+        let axa = matmul(&matmul(&ah, x), &a);
+        let kbx = matmul(&adjoint(&matmul(&matmul(&adjoint(&b), x), &a)), k);
+        let res = axa.clone() - x - &kbx + &q;
+        relative(&res, &[&q, x, &axa, &kbx])
+    };
+    let mut k = gain(&x)?;
+    let mut residual = residual_of(&x, &k);
+    for _ in 0..MAX_REFINEMENTS {
+        // (A - B K)^H X' (A - B K) - X' + Q + K^H R K = 0
+        let ac = sub(&a, &matmul(&b, &k));
+        let rhs = matmul(&matmul(&adjoint(&k), &r), &k) + &q;
+        let Ok(sol) = discrete_lyapunov(
+            bd,
+            &from_spectral::<T>(&adjoint(&ac)),
+            &from_spectral::<T>(&rhs),
+        ) else {
+            break;
+        };
+        let next = hermitian_part(&sol.x.map(to_complex), sol.scale);
+        let Ok(next_k) = gain(&next) else {
+            break;
+        };
+        let next_residual = residual_of(&next, &next_k);
+        if Float::is_nan(next_residual) || next_residual >= residual {
+            break;
+        }
+        (x, k, residual) = (next, next_k, next_residual);
+    }
+
+    let closed_loop = sub(&a, &matmul(&b, &k));
+    check_stable(bd, &closed_loop, |z| z.norm() < T::Real::one())?;
+    finish(x, k, residual)
+}
+
+/// Coefficients in the spectral scalar type, with `G = B R^-1 B^H`.
+struct Coefficients<R> {
+    a: DArray<Complex<R>, 2>,
+    b: DArray<Complex<R>, 2>,
+    q: DArray<Complex<R>, 2>,
+    r: DArray<Complex<R>, 2>,
+    g: DArray<Complex<R>, 2>,
+}
+
+fn coefficients<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    b: &DSlice<T, 2>,
+    q: &DSlice<T, 2>,
+    r: &DSlice<T, 2>,
+) -> Result<Coefficients<T::Real>, RiccatiError>
+where
+    T: ComplexFloat,
+    B: Solve<Complex<T::Real>, Dyn>,
+{
+    let n = square(a)?;
+    let m = square(r)?;
+    check_shape(b, n, m)?;
+    check_shape(q, n, n)?;
+
+    let [a, b, q, r] = [a, b, q, r].map(|x| x.to_array().map(to_complex));
+    let rinv_bh = bd.solve(&mut r.clone(), &adjoint(&b))?;
+    let g = matmul(&b, &rinv_bh);
+    Ok(Coefficients { a, b, q, r, g })
+}
+
+fn square<T>(a: &DSlice<T, 2>) -> Result<usize, RiccatiError> {
+    match *a.shape() {
+        (m, n) if m == n => Ok(n),
+        (rows, cols) => Err(RiccatiError::NotSquare { rows, cols }),
+    }
+}
+
+fn check_shape<T>(c: &DSlice<T, 2>, m: usize, n: usize) -> Result<(), RiccatiError> {
+    match *c.shape() {
+        (rows, cols) if (rows, cols) == (m, n) => Ok(()),
+        (rows, cols) => Err(RiccatiError::DimensionMismatch { m, n, rows, cols }),
+    }
+}
+
+/// Assembles the block matrix `[a, b; c, d]` of `n × n` blocks.
+fn block<S: Copy>(
+    a: &DSlice<S, 2>,
+    b: &DSlice<S, 2>,
+    c: &DSlice<S, 2>,
+    d: &DSlice<S, 2>,
+) -> DArray<S, 2> {
+    let n = a.shape().0;
+    DArray::<S, 2>::from_fn([2 * n, 2 * n], |i| {
+        let (r, c0) = (i[0] % n, i[1] % n);
+        match (i[0] < n, i[1] < n) {
+            (true, true) => a[[r, c0]],
+            (true, false) => b[[r, c0]],
+            (false, true) => c[[r, c0]],
+            (false, false) => d[[r, c0]],
+        }
+    })
+}
+
+fn neg<S: ComplexFloat>(a: &DSlice<S, 2>) -> DArray<S, 2> {
+    a.to_array().map(|x| -x)
+}
+
+fn sub<S: ComplexFloat>(a: &DSlice<S, 2>, b: &DSlice<S, 2>) -> DArray<S, 2> {
+    DArray::<S, 2>::from_fn([a.shape().0, a.shape().1], |i| {
+        a[[i[0], i[1]]] - b[[i[0], i[1]]]
+    })
+}
+
+/// `(X + X^H) / (2 s)`
+fn hermitian_part<R: Float>(x: &DSlice<Complex<R>, 2>, s: R) -> DArray<Complex<R>, 2> {
+    let two = R::one() + R::one();
+    DArray::<Complex<R>, 2>::from_fn([x.shape().0, x.shape().1], |i| {
+        (x[[i[0], i[1]]] + x[[i[1], i[0]]].conj()) / (two * s)
+    })
+}
+
+fn frobenius<R: Float>(x: &DSlice<Complex<R>, 2>) -> R {
+    Float::sqrt(x.iter().fold(R::zero(), |acc, z| acc + z.norm_sqr()))
+}
+
+fn relative<R: Float>(res: &DSlice<Complex<R>, 2>, terms: &[&DSlice<Complex<R>, 2>]) -> R {
+    let scale = terms.iter().fold(R::zero(), |acc, t| acc + frobenius(t));
+    let res = frobenius(res);
+    if scale > R::zero() { res / scale } else { res }
+}
+
+/// Computes `X = U2 U1^-1` from the `n`-dimensional invariant subspace
+/// `[U1; U2]` of `h` belonging to the eigenvalues selected by `stable`.
+fn stable_subspace<T, B>(
+    bd: &B,
+    h: &DSlice<T, 2>,
+    n: usize,
+    stable: impl Fn(Complex<T::Real>) -> bool,
+) -> Result<DArray<Complex<T::Real>, 2>, RiccatiError>
+where
+    T: ComplexFloat,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>> + Solve<Complex<T::Real>, Dyn>,
+{
+    let SchurDecomp { mut t, mut z } = bd.schur_complex(&mut h.to_array())?;
+    let reorder = bd.schur_complex_reorder(
+        &mut t,
+        &mut z,
+        SchurSelect::Predicate(&stable),
+        SchurCondition::None,
+    )?;
+    if reorder.dim != n {
+        return Err(RiccatiError::NoStabilizingSolution);
+    }
+
+    // X U1 = U2, solved as U1^T X^T = U2^T.
+    let mut u1t = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| z[[i[1], i[0]]]);
+    let u2t = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| z[[n + i[1], i[0]]]);
+    let xt = bd
+        .solve(&mut u1t, &u2t)
+        .map_err(|_| RiccatiError::NoStabilizingSolution)?;
+    let x = DArray::<Complex<T::Real>, 2>::from_fn([n, n], |i| xt[[i[1], i[0]]]);
+    if !x.iter().all(|z| z.is_finite()) {
+        return Err(RiccatiError::NoStabilizingSolution);
+    }
+    Ok(hermitian_part(&x, T::Real::one()))
+}
+
+fn check_stable<T, B>(
+    bd: &B,
+    closed_loop: &DSlice<Complex<T::Real>, 2>,
+    stable: impl Fn(Complex<T::Real>) -> bool,
+) -> Result<(), RiccatiError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let eigenvalues = bd.eig_values(&mut from_spectral::<T>(closed_loop))?;
+    if eigenvalues.iter().all(|&z| stable(z)) {
+        Ok(())
+    } else {
+        Err(RiccatiError::NoStabilizingSolution)
+    }
+}
+
+fn finish<T>(
+    x: DArray<Complex<T::Real>, 2>,
+    k: DArray<Complex<T::Real>, 2>,
+    residual: T::Real,
+) -> Result<RiccatiSolution<T>, RiccatiError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    if Float::is_nan(residual) || residual > Float::sqrt(T::Real::epsilon()) {
+        return Err(RiccatiError::InaccurateSolution {
+            residual: residual.to_f64().unwrap_or(f64::NAN),
+        });
+    }
+    Ok(RiccatiSolution {
+        x: from_spectral(&x),
+        k: from_spectral(&k),
+        residual,
+    })
+}
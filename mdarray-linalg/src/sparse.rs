@@ -0,0 +1,539 @@
+//! Compressed sparse row/column matrices and sparse-dense products
+//!
+//! A [`SparseMatrix`] stores its nonzero entries in compressed form, either
+//! row by row ([`SparseFormat::Csr`]) or column by column
+//! ([`SparseFormat::Csc`]).  Along the compressed ("major") axis, entry `p`
+//! of `indptr` gives the offset of the first stored entry of row (or column)
+//! `p` in `indices` and `values`; `indices` holds the column (or row) index
+//! of each entry.  All three arrays are ordinary `mdarray` arrays, and the
+//! indices of every row (or column) are kept strictly increasing.
+//!
+//! Matrices are assembled from COO triplets ([`SparseMatrix::from_coo`],
+//! which sums duplicate entries), from a dense matrix, or directly from the
+//! compressed arrays ([`SparseMatrix::new`], which validates them).
+//!
+//! Products with dense operands go through a backend:
+//!
+//! - [`SparseMatVec::spmv`] computes `y = α A x` with the same builder
+//!   semantics as [`MatVec`](crate::MatVec),
+//! - [`SparseMatmul::spmm`] computes `C = α A B` for a dense `B`, with the
+//!   semantics of [`Contract::matmul`](crate::Contract::matmul).
+//!
+//! [`SparseOperator`] wraps a sparse matrix as a
+//! [`LinearOperator`](crate::operator::LinearOperator) for the iterative
+//! solvers.
+//!```rust
+//!use mdarray::{DArray, tensor};
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::sparse::{SparseFormat, SparseMatrix};
+//!use mdarray_linalg::Naive;
+//!
+//!// [[1, 0, 2],
+//!//  [0, 3, 0]], the entry at (0, 2) given as 1.5 + 0.5
+//!let rows = tensor![0, 1, 0, 0];
+//!let cols = tensor![0, 1, 2, 2];
+//!let values = tensor![1., 3., 1.5, 0.5];
+//!let a = SparseMatrix::from_coo(2, 3, SparseFormat::Csr, &rows, &cols, &values).unwrap();
+//!assert_eq!(a.nnz(), 3);
+//!assert_eq!(a.to_dense(), tensor![[1., 0., 2.], [0., 3., 0.]]);
+//!
+//!let x = tensor![1., 1., 1.];
+//!assert_eq!(Naive.spmv(&a, &x).scale(2.).eval(), tensor![6., 6.]);
+//!
+//!let b = DArray::<f64, 2>::from_elem([3, 2], 1.);
+//!let mut c = DArray::<f64, 2>::from_elem([2, 2], 1.);
+//!Naive.spmm(&a, &b).add_to(&mut c);
+//!assert_eq!(c, tensor![[4., 4.], [4., 4.]]);
+//!```
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+use thiserror::Error;
+
+use crate::operator::LinearOperator;
+
+/// Error types related to building sparse matrices
+#[derive(Debug, Error)]
+pub enum SparseError {
+    /// The pointer array does not have one entry more than the compressed dimension
+    #[error("Pointer array must have length {expected}, got {len}")]
+    InvalidPointerLength { expected: usize, len: usize },
+
+    /// The pointer array does not start at 0, decreases, or does not end at the number of entries
+    #[error("Pointer array must start at 0, be nondecreasing and end at the number of entries")]
+    InvalidPointers,
+
+    /// Two arrays that describe the same entries have different lengths
+    #[error("Array length mismatch: expected {expected}, got {len}")]
+    LengthMismatch { expected: usize, len: usize },
+
+    /// An index exceeds the corresponding dimension
+    #[error("Index {index} out of bounds for dimension {bound}")]
+    IndexOutOfBounds { index: usize, bound: usize },
+
+    /// The indices of a row (or column) are not strictly increasing
+    #[error("Indices of row/column {major} are not strictly increasing")]
+    UnsortedIndices { major: usize },
+}
+
+/// Compression axis of a [`SparseMatrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFormat {
+    /// Compressed sparse rows
+    Csr,
+    /// Compressed sparse columns
+    Csc,
+}
+
+impl SparseFormat {
+    /// The other format.
+    pub fn flipped(self) -> Self {
+        match self {
+            Self::Csr => Self::Csc,
+            Self::Csc => Self::Csr,
+        }
+    }
+}
+
+/// Sparse matrix in compressed row (CSR) or column (CSC) format.
+#[derive(Debug, Clone)]
+pub struct SparseMatrix<T> {
+    rows: usize,
+    cols: usize,
+    format: SparseFormat,
+    indptr: DArray<usize, 1>,
+    indices: DArray<usize, 1>,
+    values: DArray<T, 1>,
+}
+
+impl<T> SparseMatrix<T> {
+    /// Builds a matrix from its compressed arrays after checking them.
+    ///
+    /// The indices of every row (or column) must be strictly increasing.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        format: SparseFormat,
+        indptr: DArray<usize, 1>,
+        indices: DArray<usize, 1>,
+        values: DArray<T, 1>,
+    ) -> Result<Self, SparseError> {
+        let (major, minor) = match format {
+            SparseFormat::Csr => (rows, cols),
+            SparseFormat::Csc => (cols, rows),
+        };
+        if indptr.len() != major + 1 {
+            return Err(SparseError::InvalidPointerLength {
+                expected: major + 1,
+                len: indptr.len(),
+            });
+        }
+        if values.len() != indices.len() {
+            return Err(SparseError::LengthMismatch {
+                expected: indices.len(),
+                len: values.len(),
+            });
+        }
+        if indptr[0] != 0 || indptr[major] != indices.len() || (0..major).any(|p| indptr[p] > indptr[p + 1]) {
+            return Err(SparseError::InvalidPointers);
+        }
+        let all: &[usize] = indices.as_ref();
+        for p in 0..major {
+            let segment = &all[indptr[p]..indptr[p + 1]];
+            if let Some(&index) = segment.iter().find(|&&q| q >= minor) {
+                return Err(SparseError::IndexOutOfBounds { index, bound: minor });
+            }
+            if segment.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(SparseError::UnsortedIndices { major: p });
+            }
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            format,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    /// Number of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn format(&self) -> SparseFormat {
+        self.format
+    }
+
+    /// Number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Offsets of the rows (CSR) or columns (CSC) into [`Self::indices`] and
+    /// [`Self::values`].
+    pub fn indptr(&self) -> &DSlice<usize, 1> {
+        &self.indptr
+    }
+
+    /// Column (CSR) or row (CSC) index of every stored entry.
+    pub fn indices(&self) -> &DSlice<usize, 1> {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &DSlice<T, 1> {
+        &self.values
+    }
+
+    /// Mutable access to the stored values; the sparsity pattern is fixed.
+    pub fn values_mut(&mut self) -> &mut DSlice<T, 1> {
+        &mut self.values
+    }
+
+    /// Returns the compressed arrays `(indptr, indices, values)`.
+    pub fn into_parts(self) -> (DArray<usize, 1>, DArray<usize, 1>, DArray<T, 1>) {
+        (self.indptr, self.indices, self.values)
+    }
+
+    /// Iterates over the stored entries as `(row, column, value)`, in
+    /// storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        let major = self.indptr.len() - 1;
+        (0..major).flat_map(move |p| {
+            (self.indptr[p]..self.indptr[p + 1]).map(move |k| {
+                let q = self.indices[k];
+                match self.format {
+                    SparseFormat::Csr => (p, q, &self.values[k]),
+                    SparseFormat::Csc => (q, p, &self.values[k]),
+                }
+            })
+        })
+    }
+
+    /// Position of entry `(i, j)` in [`Self::values`], if it is stored.
+    fn position(&self, i: usize, j: usize) -> Option<usize> {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        let (p, q) = match self.format {
+            SparseFormat::Csr => (i, j),
+            SparseFormat::Csc => (j, i),
+        };
+        let (start, end) = (self.indptr[p], self.indptr[p + 1]);
+        let indices: &[usize] = self.indices.as_ref();
+        indices[start..end]
+            .binary_search(&q)
+            .ok()
+            .map(|k| start + k)
+    }
+}
+
+impl<T: ComplexFloat> SparseMatrix<T> {
+    /// Builds a matrix from COO triplets `(rows[k], cols[k], values[k])`.
+    ///
+    /// Entries may come in any order; duplicates are summed.
+    pub fn from_coo(
+        rows: usize,
+        cols: usize,
+        format: SparseFormat,
+        row_indices: &DSlice<usize, 1>,
+        col_indices: &DSlice<usize, 1>,
+        values: &DSlice<T, 1>,
+    ) -> Result<Self, SparseError> {
+        let nnz = values.len();
+        for indices in [row_indices, col_indices] {
+            if indices.len() != nnz {
+                return Err(SparseError::LengthMismatch {
+                    expected: nnz,
+                    len: indices.len(),
+                });
+            }
+        }
+        if let Some(&index) = row_indices.iter().find(|&&i| i >= rows) {
+            return Err(SparseError::IndexOutOfBounds { index, bound: rows });
+        }
+        if let Some(&index) = col_indices.iter().find(|&&j| j >= cols) {
+            return Err(SparseError::IndexOutOfBounds { index, bound: cols });
+        }
+
+        let (major, majors, minors) = match format {
+            SparseFormat::Csr => (rows, row_indices, col_indices),
+            SparseFormat::Csc => (cols, col_indices, row_indices),
+        };
+        let mut order: Vec<usize> = (0..nnz).collect();
+        order.sort_by_key(|&k| (majors[k], minors[k]));
+
+        let mut indptr = vec![0; major + 1];
+        let mut indices = Vec::with_capacity(nnz);
+        let mut merged: Vec<T> = Vec::with_capacity(nnz);
+        let mut last = None;
+        for k in order {
+            let key = (majors[k], minors[k]);
+            if last == Some(key) {
+                let v = merged.last_mut().unwrap();
+                *v = *v + values[k];
+            } else {
+                indptr[key.0 + 1] += 1;
+                indices.push(key.1);
+                merged.push(values[k]);
+                last = Some(key);
+            }
+        }
+        for p in 0..major {
+            indptr[p + 1] += indptr[p];
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            format,
+            indptr: indptr.into(),
+            indices: indices.into(),
+            values: merged.into(),
+        })
+    }
+
+    /// Builds a matrix holding the nonzero entries of `a`.
+    pub fn from_dense<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>, format: SparseFormat) -> Self {
+        let (rows, cols) = *a.shape();
+        let (major, minor) = match format {
+            SparseFormat::Csr => (rows, cols),
+            SparseFormat::Csc => (cols, rows),
+        };
+        let entry = |p: usize, q: usize| match format {
+            SparseFormat::Csr => a[[p, q]],
+            SparseFormat::Csc => a[[q, p]],
+        };
+
+        let mut indptr = Vec::with_capacity(major + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+        for p in 0..major {
+            for q in 0..minor {
+                let v = entry(p, q);
+                if v != T::zero() {
+                    indices.push(q);
+                    values.push(v);
+                }
+            }
+            indptr.push(indices.len());
+        }
+
+        Self {
+            rows,
+            cols,
+            format,
+            indptr: indptr.into(),
+            indices: indices.into(),
+            values: values.into(),
+        }
+    }
+
+    /// Returns the COO triplets `(rows, cols, values)` in storage order.
+    pub fn to_coo(&self) -> (DArray<usize, 1>, DArray<usize, 1>, DArray<T, 1>) {
+        let (rows, (cols, values)): (Vec<_>, (Vec<_>, Vec<_>)) =
+            self.iter().map(|(i, j, &v)| (i, (j, v))).unzip();
+        (rows.into(), cols.into(), values.into())
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let mut a = DArray::<T, 2>::from_elem([self.rows, self.cols], T::zero());
+        for (i, j, &v) in self.iter() {
+            a[[i, j]] = v;
+        }
+        a
+    }
+
+    /// Converts to the given format; this copies the matrix if the format
+    /// already matches.
+    pub fn to_format(&self, format: SparseFormat) -> Self {
+        if format == self.format {
+            return self.clone();
+        }
+
+        // Counting sort by the former minor index.  Visiting the entries in
+        // storage order keeps the new indices sorted.
+        let major = self.indptr.len() - 1;
+        let minor = match self.format {
+            SparseFormat::Csr => self.cols,
+            SparseFormat::Csc => self.rows,
+        };
+        let mut indptr = vec![0; minor + 1];
+        for &q in self.indices.iter() {
+            indptr[q + 1] += 1;
+        }
+        for q in 0..minor {
+            indptr[q + 1] += indptr[q];
+        }
+        let mut next = indptr.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = vec![T::zero(); self.nnz()];
+        for p in 0..major {
+            for k in self.indptr[p]..self.indptr[p + 1] {
+                let q = self.indices[k];
+                indices[next[q]] = p;
+                values[next[q]] = self.values[k];
+                next[q] += 1;
+            }
+        }
+
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            format,
+            indptr: indptr.into(),
+            indices: indices.into(),
+            values: values.into(),
+        }
+    }
+
+    pub fn to_csr(&self) -> Self {
+        self.to_format(SparseFormat::Csr)
+    }
+
+    pub fn to_csc(&self) -> Self {
+        self.to_format(SparseFormat::Csc)
+    }
+
+    /// Returns `A^T`.
+    ///
+    /// The CSR arrays of `A` are the CSC arrays of `A^T`, so only the format
+    /// changes.
+    pub fn transpose(&self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            format: self.format.flipped(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns `A^H`.
+    pub fn adjoint(&self) -> Self {
+        let mut at = self.transpose();
+        for v in at.values.iter_mut() {
+            *v = v.conj();
+        }
+        at
+    }
+
+    /// Entry `(i, j)`, which is zero if it is not stored.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        self.position(i, j).map_or(T::zero(), |k| self.values[k])
+    }
+
+    /// Main diagonal, of length `min(rows, cols)`.
+    pub fn diagonal(&self) -> DArray<T, 1> {
+        DArray::<T, 1>::from_fn([self.rows.min(self.cols)], |i| self.get(i[0], i[0]))
+    }
+}
+
+/// Sparse matrix-vector multiplication
+pub trait SparseMatVec<T> {
+    fn spmv<'a, Lx>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        x: &'a Slice<T, (Dyn,), Lx>,
+    ) -> impl SparseMatVecBuilder<'a, T, Lx>
+    where
+        Lx: Layout;
+}
+
+/// Builder interface for configuring sparse matrix-vector operations
+pub trait SparseMatVecBuilder<'a, T, Lx>
+where
+    Lx: Layout,
+    T: 'a,
+    Lx: 'a,
+{
+    /// `α := α·α'`
+    fn scale(self, alpha: T) -> Self;
+
+    /// Returns `α·A·x`
+    fn eval(self) -> DArray<T, 1>;
+
+    /// `y := α·A·x`
+    fn write<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>);
+
+    /// `y := α·A·x + y`
+    fn add_to_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>);
+
+    /// `y := α·A·x + β·y`
+    fn add_to_scaled_vec<Ly: Layout>(self, y: &mut Slice<T, (Dyn,), Ly>, beta: T);
+}
+
+/// Multiplication of a sparse matrix with a dense matrix
+pub trait SparseMatmul<T> {
+    fn spmm<'a, Lb>(
+        &self,
+        a: &'a SparseMatrix<T>,
+        b: &'a Slice<T, (Dyn, Dyn), Lb>,
+    ) -> impl SparseMatmulBuilder<'a, T, Lb>
+    where
+        Lb: Layout;
+}
+
+/// Builder interface for configuring sparse-dense matrix multiplication
+pub trait SparseMatmulBuilder<'a, T, Lb>
+where
+    Lb: Layout,
+    T: 'a,
+    Lb: 'a,
+{
+    /// Multiplies the result by a scalar factor.
+    fn scale(self, factor: T) -> Self;
+
+    /// Returns a new owned matrix containing the result.
+    fn eval(self) -> DArray<T, 2>;
+
+    /// Overwrites the provided slice with the result.
+    fn write<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>);
+
+    /// Adds the result to the provided slice.
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>);
+
+    /// Adds the result to the provided slice after scaling the slice by `beta`
+    /// (i.e. C := beta * C + result).
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, (Dyn, Dyn), Lc>, beta: T);
+}
+
+/// Sparse matrix applied through a [`SparseMatVec`] backend.
+///
+/// The adjoint is formed once, on construction.
+pub struct SparseOperator<'a, T, B> {
+    bd: &'a B,
+    a: &'a SparseMatrix<T>,
+    adjoint: SparseMatrix<T>,
+}
+
+impl<'a, T: ComplexFloat, B> SparseOperator<'a, T, B> {
+    pub fn new(bd: &'a B, a: &'a SparseMatrix<T>) -> Self {
+        Self {
+            bd,
+            a,
+            adjoint: a.adjoint(),
+        }
+    }
+}
+
+impl<T, B> LinearOperator<T> for SparseOperator<'_, T, B>
+where
+    T: ComplexFloat,
+    B: SparseMatVec<T>,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.a.shape()
+    }
+
+    fn apply(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.bd.spmv(self.a, x).write(y);
+    }
+
+    fn apply_adjoint(&self, x: &DSlice<T, 1>, y: &mut DSlice<T, 1>) {
+        self.bd.spmv(&self.adjoint, x).write(y);
+    }
+
+    fn diagonal(&self) -> Option<DArray<T, 1>> {
+        Some(self.a.diagonal())
+    }
+}
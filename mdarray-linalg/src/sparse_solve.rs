@@ -0,0 +1,141 @@
+//! Direct solvers for sparse linear systems
+//!
+//! A sparse factorization runs in two phases.  [`SparseSolve::analyze`]
+//! only looks at the sparsity pattern of `A`: it computes a fill-reducing
+//! ordering and the structure of the factors.  [`SparseSolve::factorize`]
+//! then computes the numeric factors of any matrix with that pattern, so a
+//! sequence of matrices sharing a pattern (e.g. the Jacobians of a Newton
+//! iteration) needs a single analysis.  [`SparseSolve::solve_factored`]
+//! solves for all columns of a dense right-hand side at once.
+//!
+//! | Factorization                     | Matrix                                   | Solution            |
+//! |-----------------------------------|------------------------------------------|---------------------|
+//! | [`SparseFactorization::Cholesky`] | Hermitian positive definite (lower part) | `A X = B`           |
+//! | [`SparseFactorization::Lu`]       | square, nonsingular                      | `A X = B`           |
+//! | [`SparseFactorization::Qr`]       | `rows ≥ cols`, full column rank          | `min ‖A X - B‖`     |
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::sparse::{SparseFormat, SparseMatrix};
+//! use mdarray_linalg::sparse_solve::{SparseFactorization, SparseOrdering, SparseSolve};
+//! use mdarray_linalg_backend::Backend;
+//!
+//! let bd = Backend::default();
+//! let symbolic = bd.analyze(&a, SparseFactorization::Cholesky, SparseOrdering::Auto)?;
+//! for a in matrices_with_the_pattern_of_a {
+//!     let factor = bd.factorize(&symbolic, &a)?;
+//!     let x = bd.solve_factored(&factor, &b)?;
+//! }
+//! ```
+use mdarray::{DArray, Dyn, Layout, Slice};
+use thiserror::Error;
+
+use crate::sparse::SparseMatrix;
+
+/// Error types related to sparse direct solvers
+#[derive(Debug, Error)]
+pub enum SparseSolveError {
+    /// A square matrix is required
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// A least-squares problem needs at least as many rows as columns
+    #[error("Matrix must have at least as many rows as columns: got {rows}x{cols}")]
+    Underdetermined { rows: usize, cols: usize },
+
+    /// The backend does not provide this ordering for this factorization
+    #[error("Ordering not supported for this factorization")]
+    UnsupportedOrdering,
+
+    /// A custom ordering is not a permutation of the matrix indices
+    #[error("Custom ordering is not a permutation of 0..{n}")]
+    InvalidPermutation { n: usize },
+
+    /// A Cholesky pivot is not positive
+    #[error("Matrix is not positive definite: non-positive pivot at position {index}")]
+    NotPositiveDefinite { index: usize },
+
+    /// No nonzero pivot was found
+    #[error("Matrix is singular: no pivot at position {index}")]
+    Singular { index: usize },
+
+    /// The matrix does not have the pattern of the symbolic analysis
+    #[error("Sparsity pattern differs from the analyzed one")]
+    PatternMismatch,
+
+    /// The right-hand side does not have as many rows as the matrix
+    #[error("Right-hand side must have {expected} rows, got {rows}")]
+    DimensionMismatch { expected: usize, rows: usize },
+
+    /// Failure inside the backend, e.g. out of memory
+    #[error("Backend error: {0}")]
+    Backend(String),
+}
+
+/// Factorization computed by a [`SparseSolve`] backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFactorization {
+    /// `P A P^T = L L^H`, reading only the lower triangle of `A`
+    Cholesky,
+    /// `P A Q = L U` with partial pivoting
+    Lu,
+    /// `A Q = Q_r R`, for least-squares problems
+    Qr,
+}
+
+/// Fill-reducing ordering used by the symbolic analysis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SparseOrdering {
+    /// The backend's default for the factorization
+    #[default]
+    Auto,
+    /// No reordering
+    Natural,
+    /// Approximate minimum degree on the pattern of `A` (symmetric)
+    Amd,
+    /// Column approximate minimum degree (unsymmetric)
+    Colamd,
+    /// User-supplied permutation: position `i` of the reordered matrix holds
+    /// index `perm[i]` of `A`
+    Custom(DArray<usize, 1>),
+}
+
+/// Sparse direct solver with reusable symbolic analysis.
+pub trait SparseSolve<T> {
+    /// Result of the symbolic analysis, valid for every matrix with the
+    /// analyzed pattern.
+    type Symbolic;
+
+    /// Numeric factorization of a matrix.
+    type Factor;
+
+    /// Analyzes the sparsity pattern of `a`; its values are not read.
+    fn analyze(
+        &self,
+        a: &SparseMatrix<T>,
+        kind: SparseFactorization,
+        ordering: SparseOrdering,
+    ) -> Result<Self::Symbolic, SparseSolveError>;
+
+    /// Computes the numeric factorization of `a`, which must have the pattern
+    /// given to [`Self::analyze`].
+    fn factorize(
+        &self,
+        symbolic: &Self::Symbolic,
+        a: &SparseMatrix<T>,
+    ) -> Result<Self::Factor, SparseSolveError>;
+
+    /// Solves `A X = B` (least squares for QR) with a newly allocated solution
+    /// of shape `cols × k` for `B` of shape `rows × k`.
+    fn solve_factored<L: Layout>(
+        &self,
+        factor: &Self::Factor,
+        b: &Slice<T, (Dyn, Dyn), L>,
+    ) -> Result<DArray<T, 2>, SparseSolveError>;
+
+    /// Solves the square system `A X = B`, overwriting `B` with `X`.
+    fn solve_factored_write<L: Layout>(
+        &self,
+        factor: &Self::Factor,
+        b: &mut Slice<T, (Dyn, Dyn), L>,
+    ) -> Result<(), SparseSolveError>;
+}
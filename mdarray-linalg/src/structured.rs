@@ -0,0 +1,502 @@
+//! Diagonal, triangular, symmetric, Hermitian and packed matrices
+//!
+//! General operations such as [`Contract::matmul`](crate::Contract::matmul)
+//! or [`Solve::solve`](crate::Solve::solve) cannot know that their input has
+//! structure.  The wrappers of this module attach it to a view of the matrix,
+//! and the [`Structured`] trait dispatches to the specialized kernels (BLAS
+//! `trmm`, `trsm`, `symm`, `hemm`, `spmv` and `hpmv` for the BLAS backend):
+//!
+//! | Wrapper                                    | Storage                                 | Operations                               |
+//! |--------------------------------------------|-----------------------------------------|------------------------------------------|
+//! | [`Diagonal`]                               | vector of the diagonal entries          | `diag_scale`, [`Diagonal::det`]          |
+//! | [`Triangular`]                             | one triangle of a square matrix         | `tri_matmul`, `tri_solve`, [`Triangular::det`] |
+//! | [`Symmetric`], [`Hermitian`]               | one triangle of a square matrix         | `sym_matmul`, `herm_matmul`              |
+//! | [`PackedSymmetric`], [`PackedHermitian`]   | one triangle in `n (n + 1) / 2` entries | `packed_sym_matvec`, `packed_herm_matvec` |
+//!
+//! The wrappers never read the other triangle, which may therefore hold
+//! unrelated data, e.g. the other factor of an LU decomposition.  Packed
+//! storage follows the LAPACK convention: the stored triangle is laid out
+//! column by column.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::structured::{PackedSymmetric, Triangular, Uplo};
+//!use mdarray_linalg::Naive;
+//!
+//!// The strictly lower part is ignored.
+//!let a = tensor![[2., 1.], [9., 4.]];
+//!let u = Triangular::upper(&a);
+//!assert_eq!(u.det(), 8.);
+//!
+//!let mut b = tensor![[3.], [4.]];
+//!Naive.tri_solve_write(&u, &mut b).unwrap();
+//!assert_eq!(b, tensor![[1.], [1.]]);
+//!
+//!// [[1, 2], [2, 3]] stored as its lower triangle: (0, 0), (1, 0), (1, 1)
+//!let p = PackedSymmetric::new(2, Uplo::Lower, tensor![1., 2., 3.]);
+//!assert_eq!(Naive.packed_sym_matvec(&p, &tensor![1., 1.]), tensor![3., 5.]);
+//!```
+use mdarray::{DArray, DSlice, Dense, Dyn, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use crate::solve::SolveError;
+
+/// Triangle of a matrix that holds the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Uplo {
+    /// Entries `(i, j)` with `i ≤ j`
+    Upper,
+    /// Entries `(i, j)` with `i ≥ j`
+    Lower,
+}
+
+impl Uplo {
+    /// The opposite triangle, e.g. the stored one after transposition
+    pub fn flipped(self) -> Self {
+        match self {
+            Uplo::Upper => Uplo::Lower,
+            Uplo::Lower => Uplo::Upper,
+        }
+    }
+
+    fn contains(self, i: usize, j: usize) -> bool {
+        match self {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        }
+    }
+}
+
+/// Diagonal of a triangular matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diag {
+    /// The diagonal is stored
+    NonUnit,
+    /// The diagonal is implicitly one and never read
+    Unit,
+}
+
+fn assert_square<T, L: Layout>(a: &Slice<T, (Dyn, Dyn), L>) -> usize {
+    let (m, n) = *a.shape();
+    assert!(m == n, "Matrix must be square: got {m}x{n}");
+    n
+}
+
+/// Diagonal matrix given by the vector of its diagonal entries.
+#[derive(Debug)]
+pub struct Diagonal<'a, T, L: Layout = Dense> {
+    diag: &'a Slice<T, (Dyn,), L>,
+}
+
+impl<T, L: Layout> Clone for Diagonal<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L: Layout> Copy for Diagonal<'_, T, L> {}
+
+impl<'a, T, L: Layout> Diagonal<'a, T, L> {
+    pub fn new(diag: &'a Slice<T, (Dyn,), L>) -> Self {
+        Self { diag }
+    }
+
+    /// The diagonal entries
+    pub fn diag(&self) -> &'a Slice<T, (Dyn,), L> {
+        self.diag
+    }
+
+    /// Number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.diag.shape().0
+    }
+}
+
+impl<T: ComplexFloat, L: Layout> Diagonal<'_, T, L> {
+    /// Returns the entry at `(i, j)`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i == j { self.diag[[i]] } else { T::zero() }
+    }
+
+    /// Product of the diagonal entries
+    pub fn det(&self) -> T {
+        self.diag.iter().fold(T::one(), |acc, &d| acc * d)
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let n = self.size();
+        DArray::<T, 2>::from_fn([n, n], |i| self.get(i[0], i[1]))
+    }
+}
+
+/// Triangular matrix stored in one triangle of a square matrix.
+#[derive(Debug)]
+pub struct Triangular<'a, T, L: Layout = Dense> {
+    a: &'a Slice<T, (Dyn, Dyn), L>,
+    uplo: Uplo,
+    diag: Diag,
+}
+
+impl<T, L: Layout> Clone for Triangular<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L: Layout> Copy for Triangular<'_, T, L> {}
+
+impl<'a, T, L: Layout> Triangular<'a, T, L> {
+    /// Panics if `a` is not square.
+    pub fn new(a: &'a Slice<T, (Dyn, Dyn), L>, uplo: Uplo, diag: Diag) -> Self {
+        assert_square(a);
+        Self { a, uplo, diag }
+    }
+
+    /// Upper triangle of `a`, including the diagonal
+    pub fn upper(a: &'a Slice<T, (Dyn, Dyn), L>) -> Self {
+        Self::new(a, Uplo::Upper, Diag::NonUnit)
+    }
+
+    /// Lower triangle of `a`, including the diagonal
+    pub fn lower(a: &'a Slice<T, (Dyn, Dyn), L>) -> Self {
+        Self::new(a, Uplo::Lower, Diag::NonUnit)
+    }
+
+    /// Treats the diagonal as ones, as for the `L` factor of an LU decomposition.
+    pub fn unit(self) -> Self {
+        Self {
+            diag: Diag::Unit,
+            ..self
+        }
+    }
+
+    /// The wrapped matrix, including the triangle that is not used
+    pub fn matrix(&self) -> &'a Slice<T, (Dyn, Dyn), L> {
+        self.a
+    }
+
+    pub fn uplo(&self) -> Uplo {
+        self.uplo
+    }
+
+    pub fn diag(&self) -> Diag {
+        self.diag
+    }
+
+    /// Number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.a.shape().0
+    }
+}
+
+impl<T: ComplexFloat, L: Layout> Triangular<'_, T, L> {
+    /// Returns the entry at `(i, j)`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i == j && self.diag == Diag::Unit {
+            T::one()
+        } else if self.uplo.contains(i, j) {
+            self.a[[i, j]]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Product of the diagonal entries
+    pub fn det(&self) -> T {
+        match self.diag {
+            Diag::Unit => T::one(),
+            Diag::NonUnit => (0..self.size()).fold(T::one(), |acc, i| acc * self.a[[i, i]]),
+        }
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let n = self.size();
+        DArray::<T, 2>::from_fn([n, n], |i| self.get(i[0], i[1]))
+    }
+}
+
+/// Symmetric matrix (`A = Aᵀ`) stored in one triangle of a square matrix.
+#[derive(Debug)]
+pub struct Symmetric<'a, T, L: Layout = Dense> {
+    a: &'a Slice<T, (Dyn, Dyn), L>,
+    uplo: Uplo,
+}
+
+/// Hermitian matrix (`A = Aᴴ`) stored in one triangle of a square matrix.
+///
+/// The imaginary part of the diagonal is taken to be zero.
+#[derive(Debug)]
+pub struct Hermitian<'a, T, L: Layout = Dense> {
+    a: &'a Slice<T, (Dyn, Dyn), L>,
+    uplo: Uplo,
+}
+
+macro_rules! impl_self_adjoint {
+    ($name:ident, $mirror:expr, $diag:expr) => {
+        impl<T, L: Layout> Clone for $name<'_, T, L> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T, L: Layout> Copy for $name<'_, T, L> {}
+
+        impl<'a, T, L: Layout> $name<'a, T, L> {
+            /// Panics if `a` is not square.
+            pub fn new(a: &'a Slice<T, (Dyn, Dyn), L>, uplo: Uplo) -> Self {
+                assert_square(a);
+                Self { a, uplo }
+            }
+
+            /// Reads the upper triangle of `a`.
+            pub fn upper(a: &'a Slice<T, (Dyn, Dyn), L>) -> Self {
+                Self::new(a, Uplo::Upper)
+            }
+
+            /// Reads the lower triangle of `a`.
+            pub fn lower(a: &'a Slice<T, (Dyn, Dyn), L>) -> Self {
+                Self::new(a, Uplo::Lower)
+            }
+
+            /// The wrapped matrix, including the triangle that is not used
+            pub fn matrix(&self) -> &'a Slice<T, (Dyn, Dyn), L> {
+                self.a
+            }
+
+            pub fn uplo(&self) -> Uplo {
+                self.uplo
+            }
+
+            /// Number of rows (and columns)
+            pub fn size(&self) -> usize {
+                self.a.shape().0
+            }
+        }
+
+        impl<T: ComplexFloat, L: Layout> $name<'_, T, L> {
+            /// Returns the entry at `(i, j)`.
+            pub fn get(&self, i: usize, j: usize) -> T {
+                let mirror: fn(T) -> T = $mirror;
+                let diag: fn(T) -> T = $diag;
+                if i == j {
+                    diag(self.a[[i, i]])
+                } else if self.uplo.contains(i, j) {
+                    self.a[[i, j]]
+                } else {
+                    mirror(self.a[[j, i]])
+                }
+            }
+
+            pub fn to_dense(&self) -> DArray<T, 2> {
+                let n = self.size();
+                DArray::<T, 2>::from_fn([n, n], |i| self.get(i[0], i[1]))
+            }
+        }
+    };
+}
+
+impl_self_adjoint!(Symmetric, |x| x, |x| x);
+impl_self_adjoint!(Hermitian, |x| x.conj(), real_part);
+
+fn real_part<T: ComplexFloat>(x: T) -> T {
+    T::from(x.re()).unwrap()
+}
+
+/// Position of `(i, j)` in the packed triangle `uplo` of an `n × n` matrix.
+fn packed_index(n: usize, uplo: Uplo, i: usize, j: usize) -> usize {
+    match uplo {
+        Uplo::Upper => i + j * (j + 1) / 2,
+        Uplo::Lower => i + j * (2 * n - j - 1) / 2,
+    }
+}
+
+/// Symmetric matrix with one triangle packed into `n (n + 1) / 2` entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedSymmetric<T> {
+    n: usize,
+    uplo: Uplo,
+    data: DArray<T, 1>,
+}
+
+/// Hermitian matrix with one triangle packed into `n (n + 1) / 2` entries.
+///
+/// The imaginary part of the diagonal is taken to be zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedHermitian<T> {
+    n: usize,
+    uplo: Uplo,
+    data: DArray<T, 1>,
+}
+
+macro_rules! impl_packed {
+    ($name:ident, $mirror:expr, $diag:expr) => {
+        impl<T> $name<T> {
+            /// Wraps packed data: entry `(i, j)` of the `uplo` triangle is
+            /// stored at `i + j (j + 1) / 2` (upper) or `i + j (2n - j - 1) / 2`
+            /// (lower).  Panics if `data` does not have `n (n + 1) / 2` entries.
+            pub fn new(n: usize, uplo: Uplo, data: DArray<T, 1>) -> Self {
+                let len = n * (n + 1) / 2;
+                assert!(
+                    data.len() == len,
+                    "Packed storage of a {n}x{n} matrix needs {len} entries, got {}",
+                    data.len()
+                );
+                Self { n, uplo, data }
+            }
+
+            pub fn uplo(&self) -> Uplo {
+                self.uplo
+            }
+
+            /// Number of rows (and columns)
+            pub fn size(&self) -> usize {
+                self.n
+            }
+
+            /// The packed triangle
+            pub fn data(&self) -> &DSlice<T, 1> {
+                &self.data
+            }
+
+            pub fn data_mut(&mut self) -> &mut DSlice<T, 1> {
+                &mut self.data
+            }
+
+            pub fn into_data(self) -> DArray<T, 1> {
+                self.data
+            }
+        }
+
+        impl<T: ComplexFloat> $name<T> {
+            /// Packs the `uplo` triangle of the square matrix `a`.
+            pub fn from_dense<L: Layout>(a: &Slice<T, (Dyn, Dyn), L>, uplo: Uplo) -> Self {
+                let n = assert_square(a);
+                let mut data = Vec::with_capacity(n * (n + 1) / 2);
+                for j in 0..n {
+                    let rows = match uplo {
+                        Uplo::Upper => 0..j + 1,
+                        Uplo::Lower => j..n,
+                    };
+                    data.extend(rows.map(|i| a[[i, j]]));
+                }
+                Self::new(n, uplo, data.into())
+            }
+
+            /// Returns the entry at `(i, j)`.
+            pub fn get(&self, i: usize, j: usize) -> T {
+                let mirror: fn(T) -> T = $mirror;
+                let diag: fn(T) -> T = $diag;
+                if i == j {
+                    diag(self.data[[packed_index(self.n, self.uplo, i, i)]])
+                } else if self.uplo.contains(i, j) {
+                    self.data[[packed_index(self.n, self.uplo, i, j)]]
+                } else {
+                    mirror(self.data[[packed_index(self.n, self.uplo, j, i)]])
+                }
+            }
+
+            pub fn to_dense(&self) -> DArray<T, 2> {
+                DArray::<T, 2>::from_fn([self.n, self.n], |i| self.get(i[0], i[1]))
+            }
+        }
+    };
+}
+
+impl_packed!(PackedSymmetric, |x| x, |x| x);
+impl_packed!(PackedHermitian, |x| x.conj(), real_part);
+
+/// Operations on structured matrices.
+///
+/// Dimension mismatches cause a panic, as for [`Contract::matmul`](crate::Contract::matmul).
+pub trait Structured<T> {
+    /// Computes `D B` with a newly allocated matrix.
+    fn diag_scale<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2>;
+
+    /// Overwrites `B` with `D B`.
+    fn diag_scale_write<Ld: Layout, Lb: Layout>(
+        &self,
+        d: &Diagonal<'_, T, Ld>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    );
+
+    /// Computes `A B` with a newly allocated matrix (`trmm`).
+    fn tri_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2>;
+
+    /// Overwrites `B` with `A B` (`trmm`).
+    fn tri_matmul_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    );
+
+    /// Solves `A X = B` by substitution with a newly allocated solution (`trsm`).
+    fn tri_solve<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<DArray<T, 2>, SolveError>;
+
+    /// Solves `A X = B` by substitution, overwriting `B` with `X` (`trsm`).
+    ///
+    /// A zero on the diagonal is reported as [`SolveError::SingularMatrix`]
+    /// before `B` is modified.
+    fn tri_solve_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &Triangular<'_, T, La>,
+        b: &mut Slice<T, (Dyn, Dyn), Lb>,
+    ) -> Result<(), SolveError>;
+
+    /// Computes `A B` for a symmetric `A` (`symm`).
+    fn sym_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Symmetric<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2>;
+
+    /// Computes `A B` for a Hermitian `A` (`hemm`).
+    fn herm_matmul<La: Layout, Lb: Layout>(
+        &self,
+        a: &Hermitian<'_, T, La>,
+        b: &Slice<T, (Dyn, Dyn), Lb>,
+    ) -> DArray<T, 2>;
+
+    /// Computes `A x` for a packed symmetric `A` (`spmv`).
+    fn packed_sym_matvec<Lx: Layout>(
+        &self,
+        a: &PackedSymmetric<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1>;
+
+    /// Computes `A x` for a packed Hermitian `A` (`hpmv`).
+    fn packed_herm_matvec<Lx: Layout>(
+        &self,
+        a: &PackedHermitian<T>,
+        x: &Slice<T, (Dyn,), Lx>,
+    ) -> DArray<T, 1>;
+}
+
+/// Checks the diagonal of `a` for zeros before a substitution, reporting the
+/// first one (1-based, as LAPACK does) like [`Structured::tri_solve_write`].
+#[doc(hidden)]
+pub fn check_tri_nonsingular<T: ComplexFloat, L: Layout>(
+    a: &Triangular<'_, T, L>,
+) -> Result<(), SolveError> {
+    if a.diag() == Diag::Unit {
+        return Ok(());
+    }
+    match (0..a.size()).find(|&i| a.matrix()[[i, i]] == T::zero()) {
+        Some(i) => Err(SolveError::SingularMatrix {
+            diagonal: i as i32 + 1,
+        }),
+        None => Ok(()),
+    }
+}
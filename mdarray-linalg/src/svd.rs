@@ -9,7 +9,7 @@
 //! used to represent them through [`SVD::SingularValue`].
 //!```rust,ignore
 //!// ----- Singular Value Decomposition (SVD) -----
-//!use mdarray_linalg::svd::SVDDecomp;
+//!use mdarray_linalg::svd::{SVDCutoff, SVDDecomp, SVDTruncation};
 //!use mdarray_linalg::prelude::*; // Import traits anonymously
 //!use mdarray_linalg_backend::Backend; // Use the real backend here, Lapack, Faer, ...
 //!
@@ -17,10 +17,33 @@
 //!let SVDDecomp { s, u, vt } = bd.svd(&mut a.clone()).expect("SVD failed");
 //!// Or the shorter ...
 //!let SVDDecomp { s, u, vt } = bd.svd(&mut a.clone()).expect("SVD failed");
+//!
+//!// Only the 5 largest singular triplets: s has length 5, u is m × 5, vt is 5 × n
+//!let SVDDecomp { s, u, vt } = bd.svd_partial(&mut a.clone(), 5).expect("SVD failed");
+//!
+//!// Upper bidiagonal matrix from Golub–Kahan bidiagonalization, given by its
+//!// diagonal `alpha` and superdiagonal `beta`
+//!let SVDDecomp { s, u, vt } = bd
+//!    .svd_bidiagonal(&alpha, &beta, Uplo::Upper)
+//!    .expect("Bidiagonal SVD failed");
+//!
+//!// Truncate to at most 16 singular values with a discarded weight of at most 1e-10
+//!let mut decomp = bd.svd_thin(&mut a.clone()).expect("SVD failed");
+//!let info = decomp.truncate(&SVDTruncation {
+//!    max_rank: Some(16),
+//!    cutoff: Some(SVDCutoff::Weight(1e-10)),
+//!    renormalize: true,
+//!    ..Default::default()
+//!});
+//!println!("kept {}, discarded weight {}", info.rank, info.discarded_weight);
 //!```
-use mdarray::{Array, Dim, Layout, Slice};
+use mdarray::{Array, DArray, Dim, Dyn, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{Float, One, Zero};
 use thiserror::Error;
 
+use crate::structured::Uplo;
+
 /// Error types related to singular value decomposition
 #[derive(Debug, Error)]
 pub enum SVDError {
@@ -32,6 +55,12 @@ pub enum SVDError {
 
     #[error("Backend failed to converge: {superdiagonals} superdiagonals did not converge to zero")]
     BackendDidNotConverge { superdiagonals: i32 },
+
+    #[error("Jacobi SVD failed to converge: {sweeps} sweeps exceeded")]
+    JacobiDidNotConverge { sweeps: i32 },
+
+    #[error("Invalid singular value selection range")]
+    InvalidRange,
 }
 
 /// Holds the results of a singular value decomposition, including
@@ -45,6 +74,175 @@ pub struct SVDDecomp<T, S, D: Dim> {
     pub vt: Array<T, (D, D)>,
 }
 
+/// Criterion used by [`SVDDecomp::truncate`] to discard small singular values.
+///
+/// The weight of a singular value is its square, so the weight of the whole
+/// spectrum is the squared Frobenius norm of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SVDCutoff<R> {
+    /// Discard singular values `s_i <= eps`.
+    Absolute(R),
+    /// Discard singular values `s_i <= eps * s_0`.
+    Relative(R),
+    /// Discard the longest tail of singular values whose weight relative to
+    /// the whole spectrum does not exceed `eps`.
+    Weight(R),
+}
+
+/// Settings for [`SVDDecomp::truncate`].
+///
+/// The default keeps every singular value and leaves them unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SVDTruncation<R> {
+    /// Maximum number of singular values kept (bond dimension).
+    pub max_rank: Option<usize>,
+    /// Cutoff applied in addition to `max_rank`.
+    pub cutoff: Option<SVDCutoff<R>>,
+    /// Rescale the kept singular values so that the truncated matrix has the
+    /// Frobenius norm of the original one.
+    pub renormalize: bool,
+    /// Absorb `√s` into `u` and `vt` after truncation, see
+    /// [`SVDDecomp::absorb_sqrt_s`].
+    pub absorb_sqrt_s: bool,
+}
+
+impl<R> Default for SVDTruncation<R> {
+    fn default() -> Self {
+        Self {
+            max_rank: None,
+            cutoff: None,
+            renormalize: false,
+            absorb_sqrt_s: false,
+        }
+    }
+}
+
+/// Truncation error reported by [`SVDDecomp::truncate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SVDTruncationInfo<R> {
+    /// Number of singular values kept.
+    pub rank: usize,
+    /// Weight of the discarded singular values relative to the whole spectrum,
+    /// `Σ_discarded s_i² / Σ s_i²`.
+    pub discarded_weight: R,
+    /// Frobenius norm of the truncated matrix divided by that of the original,
+    /// before renormalization.
+    pub norm_ratio: R,
+}
+
+impl<T, S> SVDDecomp<T, S, Dyn>
+where
+    T: ComplexFloat,
+    S: ComplexFloat<Real = T::Real>,
+    T::Real: Into<T> + Into<S>,
+{
+    /// Truncate `s`, `u` and `vt` in place according to `config`.
+    ///
+    /// Singular values are expected in descending order, as returned by the
+    /// [`SVD`] methods.  The number of singular values kept is the smallest
+    /// allowed by `max_rank` and `cutoff`; `u` and `vt` keep the matching
+    /// columns and rows.  The rank is only known at runtime, so truncation is
+    /// available for dynamically sized decompositions only.
+    pub fn truncate(&mut self, config: &SVDTruncation<T::Real>) -> SVDTruncationInfo<T::Real> {
+        let zero = T::Real::zero();
+        let weights: Vec<T::Real> = self.s.iter().map(|x| x.abs() * x.abs()).collect();
+        let total = weights.iter().fold(zero, |acc, &w| acc + w);
+        let len = weights.len();
+
+        let mut rank = config.max_rank.map_or(len, |r| r.min(len));
+        match config.cutoff {
+            Some(SVDCutoff::Absolute(eps)) => {
+                rank = rank.min(self.s.iter().take_while(|x| x.abs() > eps).count());
+            }
+            Some(SVDCutoff::Relative(eps)) => {
+                let threshold = eps * self.s.iter().next().map_or(zero, |x| x.abs());
+                rank = rank.min(self.s.iter().take_while(|x| x.abs() > threshold).count());
+            }
+            Some(SVDCutoff::Weight(eps)) => {
+                let mut tail = zero;
+                let mut keep = len;
+                while keep > 0 && tail + weights[keep - 1] <= eps * total {
+                    tail = tail + weights[keep - 1];
+                    keep -= 1;
+                }
+                rank = rank.min(keep);
+            }
+            None => {}
+        }
+
+        let discarded = weights[rank..].iter().fold(zero, |acc, &w| acc + w);
+        let kept = total - discarded;
+        let (discarded_weight, norm_ratio) = if total > zero {
+            (discarded / total, Float::sqrt(kept / total))
+        } else {
+            (zero, T::Real::one())
+        };
+
+        self.keep_leading(rank);
+
+        if config.renormalize && kept > zero {
+            let factor: S = Float::sqrt(total / kept).into();
+            for x in self.s.iter_mut() {
+                *x = *x * factor;
+            }
+        }
+
+        if config.absorb_sqrt_s {
+            self.absorb_sqrt_s();
+        }
+
+        SVDTruncationInfo {
+            rank,
+            discarded_weight,
+            norm_ratio,
+        }
+    }
+
+    /// Keep the leading `rank` singular values and the matching columns of
+    /// `u` and rows of `vt`.
+    fn keep_leading(&mut self, rank: usize) {
+        let m = self.u.shape().dim(0);
+        let n = self.vt.shape().dim(1);
+
+        let s = Array::from_fn((rank,), |i| self.s[i[0]]);
+        let u = Array::from_fn((m, rank), |i| self.u[[i[0], i[1]]]);
+        let vt = Array::from_fn((rank, n), |i| self.vt[[i[0], i[1]]]);
+
+        self.s = s;
+        self.u = u;
+        self.vt = vt;
+    }
+}
+
+impl<T, S, D> SVDDecomp<T, S, D>
+where
+    T: ComplexFloat,
+    S: ComplexFloat<Real = T::Real>,
+    T::Real: Into<T>,
+    D: Dim,
+{
+    /// Multiply the columns of `u` and the rows of `vt` by `√|s_i|`, and set
+    /// the singular values to one.
+    ///
+    /// The product `u * diag(s) * vt` is unchanged, so the factors can then be
+    /// used directly as `A ≈ u * vt`.
+    pub fn absorb_sqrt_s(&mut self) {
+        let m = self.u.shape().dim(0);
+        let n = self.vt.shape().dim(1);
+
+        for (i, x) in self.s.iter_mut().enumerate() {
+            let root: T = Float::sqrt(x.abs()).into();
+            for r in 0..m {
+                self.u[[r, i]] = self.u[[r, i]] * root;
+            }
+            for c in 0..n {
+                self.vt[[i, c]] = self.vt[[i, c]] * root;
+            }
+            *x = S::one();
+        }
+    }
+}
+
 /// Singular value decomposition for matrix factorization and analysis
 pub trait SVD<T, D: Dim> {
     /// Scalar type used for singular values.
@@ -65,6 +263,28 @@ pub trait SVD<T, D: Dim> {
         a: &mut Slice<T, (D, D), L>,
     ) -> Result<SVDDecomp<T, Self::SingularValue, D>, SVDError>;
 
+    /// Compute the `k` largest singular triplets with new allocated matrices.
+    ///
+    /// Equivalent to `svd_range(a, 0, k)`.
+    fn svd_partial<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        k: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError>;
+
+    /// Compute the singular triplets with indices `lo..hi` in descending
+    /// order of singular values, with new allocated matrices.
+    ///
+    /// With `k = hi - lo`, `s` has length `k`, `u` is m × k and `vt` is k × n;
+    /// the result has dynamic dimensions.  Returns [`SVDError::InvalidRange`]
+    /// unless `lo <= hi <= min(m, n)`.
+    fn svd_range<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        lo: usize,
+        hi: usize,
+    ) -> Result<SVDDecomp<T, Self::SingularValue, Dyn>, SVDError>;
+
     /// Compute only singular values with new allocated matrix
     fn svd_s<L: Layout>(
         &self,
@@ -86,4 +306,179 @@ pub trait SVD<T, D: Dim> {
         a: &mut Slice<T, (D, D), L>,
         s: &mut Slice<Self::SingularValue, (D,), Ls>,
     ) -> Result<(), SVDError>;
+
+    /// Compute the SVD of a real `n × n` bidiagonal matrix, given by its
+    /// diagonal `d` and its off-diagonal `e` above or below it.
+    ///
+    /// `e` must have `n - 1` entries.  The singular values are returned in
+    /// descending order, and `u` and `vt` are `n × n`.
+    fn svd_bidiagonal<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<SVDDecomp<T::Real, T::Real, D>, SVDError>
+    where
+        T: ComplexFloat;
+
+    /// Compute only the singular values of a real bidiagonal matrix, in
+    /// descending order.
+    fn svd_bidiagonal_s<De: Dim, Ld: Layout, Le: Layout>(
+        &self,
+        d: &Slice<T::Real, (D,), Ld>,
+        e: &Slice<T::Real, (De,), Le>,
+        uplo: Uplo,
+    ) -> Result<Array<T::Real, (D,)>, SVDError>
+    where
+        T: ComplexFloat;
+}
+
+// The following exported items are unstable backend-implementation helpers.
+// They are hidden from generated documentation and may be redesigned before
+// the public API stabilizes.
+
+/// Restrict a full or thin SVD to the singular triplets `lo..hi`, with
+/// dynamic dimensions.
+///
+/// Used by backends without a native partial SVD driver.
+#[doc(hidden)]
+pub fn select_svd<T: Copy, S: Copy, D: Dim>(
+    decomp: SVDDecomp<T, S, D>,
+    lo: usize,
+    hi: usize,
+) -> Result<SVDDecomp<T, S, Dyn>, SVDError> {
+    let SVDDecomp { s, u, vt } = decomp;
+
+    if lo > hi || hi > s.shape().dim(0) {
+        return Err(SVDError::InvalidRange);
+    }
+
+    let k = hi - lo;
+    let m = u.shape().dim(0);
+    let n = vt.shape().dim(1);
+
+    Ok(SVDDecomp {
+        s: Array::from_fn((k,), |i| s[[lo + i[0]]]),
+        u: Array::from_fn((m, k), |i| u[[i[0], lo + i[1]]]),
+        vt: Array::from_fn((k, n), |i| vt[[lo + i[0], i[1]]]),
+    })
+}
+
+/// Computes the SVD of the bidiagonal matrix with diagonal `d` and
+/// off-diagonal `e`, with one-sided Jacobi rotations.
+///
+/// This is the fallback for backends without a native bidiagonal SVD.  The
+/// rotations orthogonalize the columns of the dense matrix, which computes
+/// even tiny singular values to high relative accuracy, in `O(n³)`
+/// operations per sweep.
+#[doc(hidden)]
+pub fn jacobi_svd_bidiagonal<R, D, De, Ld, Le>(
+    d: &Slice<R, (D,), Ld>,
+    e: &Slice<R, (De,), Le>,
+    uplo: Uplo,
+) -> Result<SVDDecomp<R, R, D>, SVDError>
+where
+    R: Float,
+    D: Dim,
+    De: Dim,
+    Ld: Layout,
+    Le: Layout,
+{
+    const MAX_SWEEPS: i32 = 100;
+
+    let n = d.len();
+    assert_eq!(e.len(), n.saturating_sub(1), "Off-diagonal must have n - 1 elements");
+
+    let zero = R::zero();
+    let one = R::one();
+    let two = one + one;
+    let eps = R::epsilon();
+    let mut u = DArray::<R, 2>::from_fn([n, n], |i| match (i[0], i[1], uplo) {
+        (r, c, _) if r == c => d[r],
+        (r, c, Uplo::Upper) if c == r + 1 => e[r],
+        (r, c, Uplo::Lower) if r == c + 1 => e[c],
+        _ => zero,
+    });
+    let mut v = DArray::<R, 2>::from_fn([n, n], |i| if i[0] == i[1] { one } else { zero });
+
+    let mut sweeps = 0;
+    loop {
+        let mut rotated = 0;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (zero, zero, zero);
+                for k in 0..n {
+                    alpha = alpha + u[[k, p]] * u[[k, p]];
+                    beta = beta + u[[k, q]] * u[[k, q]];
+                    gamma = gamma + u[[k, p]] * u[[k, q]];
+                }
+                if gamma.abs() <= R::min_positive_value()
+                    || gamma.abs() <= eps * (alpha * beta).sqrt()
+                {
+                    continue;
+                }
+                rotated += 1;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = one / (zeta.abs() + zeta.hypot(one));
+                let t = if zeta < zero { -t } else { t };
+                let c = one / t.hypot(one);
+                let s = c * t;
+                for x in [&mut u, &mut v] {
+                    for k in 0..n {
+                        let (xp, xq) = (x[[k, p]], x[[k, q]]);
+                        x[[k, p]] = c * xp - s * xq;
+                        x[[k, q]] = s * xp + c * xq;
+                    }
+                }
+            }
+        }
+
+        if rotated == 0 {
+            break;
+        }
+        sweeps += 1;
+        if sweeps == MAX_SWEEPS {
+            return Err(SVDError::JacobiDidNotConverge { sweeps });
+        }
+    }
+
+    // The columns of U V are now orthogonal, with the singular values as
+    // their norms.
+    let sigma: Vec<R> = (0..n)
+        .map(|j| (0..n).fold(zero, |acc, k| acc + u[[k, j]] * u[[k, j]]).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| sigma[j].partial_cmp(&sigma[i]).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut columns: Vec<Vec<R>> = Vec::with_capacity(n);
+    for &j in &order {
+        if sigma[j] > zero {
+            columns.push((0..n).map(|k| u[[k, j]] / sigma[j]).collect());
+            continue;
+        }
+        // Complete U with the standard basis vector farthest from the
+        // columns found so far.
+        let residual = |i: usize| {
+            let mut x: Vec<R> = (0..n).map(|k| if k == i { one } else { zero }).collect();
+            for y in &columns {
+                let c = y[i];
+                x.iter_mut().zip(y).for_each(|(xk, &yk)| *xk = *xk - c * yk);
+            }
+            let norm = x.iter().fold(zero, |acc, &xk| acc + xk * xk).sqrt();
+            (norm, x)
+        };
+        let (norm, x) = (0..n)
+            .map(residual)
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+            .expect("U has a missing column");
+        columns.push(x.into_iter().map(|xk| xk / norm).collect());
+    }
+
+    Ok(SVDDecomp {
+        s: Array::from_fn(<(D,) as Shape>::from_dims(&[n]), |i| sigma[order[i[0]]]),
+        u: Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| columns[i[1]][i[0]]),
+        vt: Array::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| v[[i[1], order[i[0]]]]),
+    })
 }
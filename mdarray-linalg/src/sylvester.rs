@@ -0,0 +1,502 @@
+//! Sylvester and Lyapunov matrix equations
+//!
+//! | Function                 | Equation                      |
+//! |--------------------------|-------------------------------|
+//! | [`sylvester`]            | `A X + X B = s C`             |
+//! | [`lyapunov`]             | `A X + X A^H = s Q`           |
+//! | [`discrete_lyapunov`]    | `A X A^H - X + s Q = 0`       |
+//!
+//! The solvers reduce the coefficients to triangular form with
+//! [`Eig::schur_complex`] and solve the transformed equation column by column
+//! with triangular back substitutions, as LAPACK's `trsyl` (Bartels–Stewart).
+//! The Hessenberg–Schur variant ([`SylvesterMethod::HessenbergSchur`]) only
+//! reduces `A` to upper Hessenberg form, which is cheaper than a Schur
+//! decomposition when `A` is the larger coefficient.
+//!
+//! Real inputs go through the complex Schur form and the real part of the
+//! solution is returned.  The scale factor `0 < s <= 1` is chosen to prevent
+//! overflow in the back substitutions and is `1` unless the solution would
+//! overflow.  The equations have a unique solution if and only if no
+//! eigenvalue of `A` is the negative of an eigenvalue of `B` (for the
+//! discrete equation: if no two eigenvalues satisfy `λ_i conj(λ_j) = 1`);
+//! otherwise an error is returned.
+//!
+//! ```rust,ignore
+//! use mdarray_linalg::sylvester::{SylvesterMethod, sylvester, lyapunov};
+//! use mdarray_linalg_backend::Backend;
+//!
+//! let bd = Backend::default();
+//! let sol = sylvester(&bd, &a, &b, &c, SylvesterMethod::BartelsStewart)?;
+//! // A X + X B = s C
+//! let (x, s) = (sol.x, sol.scale);
+//!
+//! // Controllability Gramian of a stable system: A P + P A^T = -B B^T
+//! let p = lyapunov(&bd, &a, &minus_bbt)?.x;
+//! ```
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    eig::{Eig, SchurDecomp, SchurError},
+    structured::Uplo,
+    utils::{from_complex, imaginary_unit, to_complex},
+};
+
+/// Error types related to Sylvester and Lyapunov equations
+#[derive(Debug, Error)]
+pub enum SylvesterError {
+    /// A square coefficient matrix is required
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// The right-hand side does not match the coefficients
+    #[error("Right-hand side must be {m}x{n}, got {rows}x{cols}")]
+    DimensionMismatch {
+        m: usize,
+        n: usize,
+        rows: usize,
+        cols: usize,
+    },
+
+    /// Eigenvalue `index` (in Schur order) of `B` is, to working precision,
+    /// the negative of an eigenvalue of `A`, so the solution is not unique
+    #[error("A and -B share an eigenvalue: eigenvalue {index} of B")]
+    SharedEigenvalue { index: usize },
+
+    /// Eigenvalue `index` (in Schur order) of `A` is, to working precision,
+    /// the reciprocal of the conjugate of another eigenvalue, so the discrete
+    /// equation does not have a unique solution
+    #[error("A has reciprocal eigenvalues: eigenvalue {index}")]
+    ReciprocalEigenvalue { index: usize },
+
+    #[error(transparent)]
+    Schur(#[from] SchurError),
+}
+
+/// Reduction applied to `A` by [`sylvester`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SylvesterMethod {
+    /// Schur decompositions of `A` and `B` (Bartels–Stewart)
+    #[default]
+    BartelsStewart,
+    /// Hessenberg form of `A` and Schur decomposition of `B`
+    /// (Golub–Nash–Van Loan)
+    HessenbergSchur,
+}
+
+/// Solution `X` of a matrix equation scaled by `scale` to prevent overflow
+#[derive(Debug, Clone)]
+pub struct SylvesterSolution<T: ComplexFloat> {
+    pub x: DArray<T, 2>,
+    /// Scale factor `0 < s <= 1` of the right-hand side
+    pub scale: T::Real,
+}
+
+/// Solves the Sylvester equation `A X + X B = s C` for `m × m` `A`, `n × n`
+/// `B` and `m × n` `C`.
+pub fn sylvester<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    b: &DSlice<T, 2>,
+    c: &DSlice<T, 2>,
+    method: SylvesterMethod,
+) -> Result<SylvesterSolution<T>, SylvesterError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let m = square(a)?;
+    let n = square(b)?;
+    check_rhs(c, m, n)?;
+
+    let SchurDecomp { t: r, z: v } = bd.schur_complex(&mut b.to_array())?;
+    let (s, u) = match method {
+        SylvesterMethod::BartelsStewart => {
+            let SchurDecomp { t, z } = bd.schur_complex(&mut a.to_array())?;
+            (t, z)
+        }
+        SylvesterMethod::HessenbergSchur => hessenberg(a.to_array().map(to_complex)),
+    };
+
+    // S Y + Y R = U^H C V with X = U Y V^H
+    let mut y = matmul(&matmul(&adjoint(&u), &c.to_array().map(to_complex)), &v);
+    let smin = min_pivot(&[&s, &r], m * n);
+    let scale = solve_columns(&mut y, &r, Uplo::Upper, |rjj, col| {
+        shifted_solve(
+            &s,
+            rjj,
+            col,
+            method == SylvesterMethod::HessenbergSchur,
+            smin,
+        )
+    })
+    .map_err(|index| SylvesterError::SharedEigenvalue { index })?;
+
+    Ok(SylvesterSolution {
+        x: from_spectral(&matmul(&matmul(&u, &y), &adjoint(&v))),
+        scale,
+    })
+}
+
+/// Solves the continuous Lyapunov equation `A X + X A^H = s Q` for `n × n`
+/// `A` and `Q`.
+///
+/// If `A` is stable and `Q` is negative semidefinite, `X` is positive
+/// semidefinite.
+pub fn lyapunov<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    q: &DSlice<T, 2>,
+) -> Result<SylvesterSolution<T>, SylvesterError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let n = square(a)?;
+    check_rhs(q, n, n)?;
+
+    // A single Schur decomposition serves both sides: S Y + Y S^H = U^H Q U.
+    let SchurDecomp { t: s, z: u } = bd.schur_complex(&mut a.to_array())?;
+    let sh = adjoint(&s);
+    let mut y = matmul(&matmul(&adjoint(&u), &q.to_array().map(to_complex)), &u);
+    let smin = min_pivot(&[&s], n * n);
+    let scale = solve_columns(&mut y, &sh, Uplo::Lower, |rjj, col| {
+        shifted_solve(&s, rjj, col, false, smin)
+    })
+    .map_err(|index| SylvesterError::SharedEigenvalue { index })?;
+
+    Ok(SylvesterSolution {
+        x: from_spectral(&matmul(&matmul(&u, &y), &adjoint(&u))),
+        scale,
+    })
+}
+
+/// Solves the discrete Lyapunov (Stein) equation `A X A^H - X + s Q = 0` for
+/// `n × n` `A` and `Q`.
+///
+/// If all eigenvalues of `A` lie inside the unit circle and `Q` is positive
+/// semidefinite, `X` is positive semidefinite.
+pub fn discrete_lyapunov<T, B>(
+    bd: &B,
+    a: &DSlice<T, 2>,
+    q: &DSlice<T, 2>,
+) -> Result<SylvesterSolution<T>, SylvesterError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<T::Real>>,
+{
+    let n = square(a)?;
+    check_rhs(q, n, n)?;
+
+    // S Y S^H - Y = -U^H Q U.  Column j reads
+    //     (conj(s_jj) S - I) y_j = f_j - S Σ_(k>j) conj(s_jk) y_k,
+    // solved from the last column to the first.
+    let SchurDecomp { t: s, z: u } = bd.schur_complex(&mut a.to_array())?;
+    let mut y = matmul(&matmul(&adjoint(&u), &q.to_array().map(to_complex)), &u).map(|x| -x);
+    // Pivots are products of two eigenvalues, hence the squared norm.
+    let norm = max_norm(&[&s]);
+    let smin = T::Real::epsilon() * (T::Real::one() + norm * norm);
+    let one = Complex::<T::Real>::one();
+    let mut scale = T::Real::one();
+    for j in (0..n).rev() {
+        let mut w = vec![Complex::zero(); n];
+        for k in j + 1..n {
+            let sjk = s[[j, k]].conj();
+            for (wi, yik) in w.iter_mut().zip(column(&y, k)) {
+                *wi = *wi + sjk * yik;
+            }
+        }
+        let mut col: Vec<_> = column(&y, j).collect();
+        for (i, ci) in col.iter_mut().enumerate() {
+            *ci = *ci - (i..n).fold(Complex::zero(), |acc, k| acc + s[[i, k]] * w[k]);
+        }
+
+        let sjj = s[[j, j]].conj();
+        let (singular, s_col) = back_substitute(
+            |i, k| sjj * s[[i, k]] - if i == k { one } else { Complex::zero() },
+            &mut col,
+            smin,
+        );
+        if singular.is_some() {
+            return Err(SylvesterError::ReciprocalEigenvalue { index: j });
+        }
+        rescale(&mut y, j, s_col);
+        scale = scale * s_col;
+        for (i, ci) in col.into_iter().enumerate() {
+            y[[i, j]] = ci;
+        }
+    }
+
+    Ok(SylvesterSolution {
+        x: from_spectral(&matmul(&matmul(&u, &y), &adjoint(&u))),
+        scale,
+    })
+}
+
+fn square<T>(a: &DSlice<T, 2>) -> Result<usize, SylvesterError> {
+    match *a.shape() {
+        (m, n) if m == n => Ok(n),
+        (rows, cols) => Err(SylvesterError::NotSquare { rows, cols }),
+    }
+}
+
+fn check_rhs<T>(c: &DSlice<T, 2>, m: usize, n: usize) -> Result<(), SylvesterError> {
+    match *c.shape() {
+        (rows, cols) if (rows, cols) == (m, n) => Ok(()),
+        (rows, cols) => Err(SylvesterError::DimensionMismatch { m, n, rows, cols }),
+    }
+}
+
+pub(crate) fn from_spectral<T>(x: &DSlice<Complex<T::Real>, 2>) -> DArray<T, 2>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    let i = imaginary_unit::<T>();
+    x.to_array().map(|z| from_complex(z, i))
+}
+
+pub(crate) fn matmul<S: ComplexFloat>(a: &DSlice<S, 2>, b: &DSlice<S, 2>) -> DArray<S, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    let mut c = DArray::<S, 2>::from_elem([m, n], S::zero());
+    for l in 0..k {
+        for j in 0..n {
+            let blj = b[[l, j]];
+            for i in 0..m {
+                c[[i, j]] = c[[i, j]] + a[[i, l]] * blj;
+            }
+        }
+    }
+    c
+}
+
+pub(crate) fn adjoint<S: ComplexFloat>(a: &DSlice<S, 2>) -> DArray<S, 2> {
+    let (m, n) = *a.shape();
+    DArray::<S, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+fn column<S: Copy>(a: &DSlice<S, 2>, j: usize) -> impl Iterator<Item = S> + '_ {
+    (0..a.shape().0).map(move |i| a[[i, j]])
+}
+
+fn max_norm<R: Float>(factors: &[&DSlice<Complex<R>, 2>]) -> R {
+    factors
+        .iter()
+        .flat_map(|f| f.iter())
+        .fold(R::zero(), |acc, x| acc.max(x.norm()))
+}
+
+/// Pivots below `smin` are treated as zero, as in LAPACK's `trsyl`.
+fn min_pivot<R: Float>(factors: &[&DSlice<Complex<R>, 2>], size: usize) -> R {
+    let eps = R::epsilon();
+    let smlnum = R::min_positive_value() * <R as NumCast>::from(size.max(1)).unwrap() / eps;
+    (eps * max_norm(factors)).max(smlnum)
+}
+
+/// Solves `S Y + Y R = F` for triangular `R`, overwriting `F` with `Y`.
+///
+/// The columns are visited in the dependency order of `R` (`uplo`), and
+/// `solve(r_jj, col)` solves `(S + r_jj I) y = col` in place, returning the
+/// scale factor of `col` or `Err(())` if the shifted matrix is singular.
+/// Returns the accumulated scale factor, or the index `j` of the singular
+/// column.
+fn solve_columns<R: Float>(
+    y: &mut DArray<Complex<R>, 2>,
+    r: &DSlice<Complex<R>, 2>,
+    uplo: Uplo,
+    mut solve: impl FnMut(Complex<R>, &mut [Complex<R>]) -> Result<R, ()>,
+) -> Result<R, usize> {
+    let n = y.shape().1;
+    let mut scale = R::one();
+    let order: Vec<usize> = match uplo {
+        Uplo::Upper => (0..n).collect(),
+        Uplo::Lower => (0..n).rev().collect(),
+    };
+    for (pos, &j) in order.iter().enumerate() {
+        let mut col: Vec<_> = column(y, j).collect();
+        for &k in &order[..pos] {
+            let rkj = r[[k, j]];
+            for (i, ci) in col.iter_mut().enumerate() {
+                *ci = *ci - y[[i, k]] * rkj;
+            }
+        }
+        let s = solve(r[[j, j]], &mut col).map_err(|()| j)?;
+        rescale(y, j, s);
+        scale = scale * s;
+        for (i, ci) in col.into_iter().enumerate() {
+            y[[i, j]] = ci;
+        }
+    }
+    Ok(scale)
+}
+
+/// Multiplies every column of `y` but `j` by `s`.
+fn rescale<R: Float>(y: &mut DArray<Complex<R>, 2>, j: usize, s: R) {
+    if s == R::one() {
+        return;
+    }
+    let (m, n) = *y.shape();
+    for k in (0..n).filter(|&k| k != j) {
+        for i in 0..m {
+            y[[i, k]] = y[[i, k]] * s;
+        }
+    }
+}
+
+/// Solves `(S + shift I) x = b` in place for upper triangular or upper
+/// Hessenberg `S`, returning the scale factor of `b`.
+fn shifted_solve<R: Float>(
+    s: &DSlice<Complex<R>, 2>,
+    shift: Complex<R>,
+    b: &mut [Complex<R>],
+    hessenberg: bool,
+    smin: R,
+) -> Result<R, ()> {
+    let m = b.len();
+    let result = if hessenberg {
+        // Gaussian elimination with partial pivoting only ever swaps adjacent
+        // rows of a Hessenberg matrix, and keeps the factor upper triangular.
+        let mut w = DArray::<Complex<R>, 2>::from_fn([m, m], |i| {
+            let sij = if i[0] <= i[1] + 1 {
+                s[[i[0], i[1]]]
+            } else {
+                Complex::zero()
+            };
+            if i[0] == i[1] { sij + shift } else { sij }
+        });
+        for k in 0..m.saturating_sub(1) {
+            if w[[k + 1, k]].norm() > w[[k, k]].norm() {
+                for j in k..m {
+                    let t = w[[k, j]];
+                    w[[k, j]] = w[[k + 1, j]];
+                    w[[k + 1, j]] = t;
+                }
+                b.swap(k, k + 1);
+            }
+            if w[[k, k]].norm() <= smin {
+                continue;
+            }
+            let l = w[[k + 1, k]].fdiv(w[[k, k]]);
+            w[[k + 1, k]] = Complex::zero();
+            for j in k + 1..m {
+                w[[k + 1, j]] = w[[k + 1, j]] - l * w[[k, j]];
+            }
+            b[k + 1] = b[k + 1] - l * b[k];
+        }
+        back_substitute(|i, j| w[[i, j]], b, smin)
+    } else {
+        back_substitute(
+            |i, j| if i == j { s[[i, j]] + shift } else { s[[i, j]] },
+            b,
+            smin,
+        )
+    };
+    match result {
+        (None, scale) => Ok(scale),
+        (Some(_), _) => Err(()),
+    }
+}
+
+/// Back substitution with the upper triangular matrix `u(i, j)`, overwriting
+/// `b` with `s x` where `s <= 1` keeps the solution from overflowing.
+///
+/// Returns the index of the first pivot not larger than `smin`, if any, and
+/// the scale factor `s`.
+fn back_substitute<R: Float>(
+    u: impl Fn(usize, usize) -> Complex<R>,
+    b: &mut [Complex<R>],
+    smin: R,
+) -> (Option<usize>, R) {
+    let m = b.len();
+    let one = R::one();
+    let bignum = one / (R::min_positive_value() / R::epsilon());
+    let mut scale = one;
+    for i in (0..m).rev() {
+        let rhs = (i + 1..m).fold(b[i], |acc, k| acc - u(i, k) * b[k]);
+        let d = u(i, i);
+        let (dnorm, rnorm) = (d.norm(), rhs.norm());
+        if dnorm <= smin {
+            return (Some(i), scale);
+        }
+        let mut s = one;
+        if dnorm < one && rnorm > one && rnorm > bignum * dnorm {
+            s = one / rnorm;
+            for bk in b.iter_mut() {
+                *bk = *bk * s;
+            }
+            scale = scale * s;
+        }
+        // `fdiv` avoids the underflow of |d|² for tiny pivots.
+        b[i] = (rhs * s).fdiv(d);
+    }
+    (None, scale)
+}
+
+/// Householder reduction `A = Q H Q^H` to upper Hessenberg `H`, returning
+/// `(H, Q)`.
+fn hessenberg<R: Float>(
+    mut a: DArray<Complex<R>, 2>,
+) -> (DArray<Complex<R>, 2>, DArray<Complex<R>, 2>) {
+    let n = a.shape().0;
+    let zero = Complex::<R>::zero();
+    let two = Complex::<R>::new(R::one() + R::one(), R::zero());
+    let mut q =
+        DArray::<Complex<R>, 2>::from_fn(
+            [n, n],
+            |i| {
+                if i[0] == i[1] { Complex::one() } else { zero }
+            },
+        );
+
+    for k in 0..n.saturating_sub(2) {
+        // v = x - α e_1 with |α| = ‖x‖ and the phase opposite to x_0
+        let mut v: Vec<_> = (k + 1..n).map(|i| a[[i, k]]).collect();
+        let xnorm = Float::sqrt(v.iter().fold(R::zero(), |acc, x| acc + x.norm_sqr()));
+        if xnorm == R::zero() {
+            continue;
+        }
+        let phase = if v[0] == zero {
+            Complex::one()
+        } else {
+            v[0] / v[0].norm()
+        };
+        v[0] = v[0] + phase * xnorm;
+        let vnorm = Float::sqrt(v.iter().fold(R::zero(), |acc, x| acc + x.norm_sqr()));
+        for vi in &mut v {
+            *vi = *vi / vnorm;
+        }
+
+        // A ← P A P and Q ← Q P with P = I - 2 v v^H acting on rows and
+        // columns k + 1..n
+        for j in 0..n {
+            let dot = v
+                .iter()
+                .enumerate()
+                .fold(zero, |acc, (l, vl)| acc + vl.conj() * a[[k + 1 + l, j]]);
+            for (l, vl) in v.iter().enumerate() {
+                a[[k + 1 + l, j]] = a[[k + 1 + l, j]] - two * *vl * dot;
+            }
+        }
+        for mat in [&mut a, &mut q] {
+            for i in 0..n {
+                let dot = v
+                    .iter()
+                    .enumerate()
+                    .fold(zero, |acc, (l, vl)| acc + mat[[i, k + 1 + l]] * *vl);
+                for (l, vl) in v.iter().enumerate() {
+                    mat[[i, k + 1 + l]] = mat[[i, k + 1 + l]] - two * dot * vl.conj();
+                }
+            }
+        }
+        for i in k + 2..n {
+            a[[i, k]] = zero;
+        }
+    }
+    (a, q)
+}
@@ -1,10 +1,14 @@
 use approx::assert_relative_eq;
-use mdarray::DArray;
+use mdarray::{Array, Const, DArray};
 use num_complex::{Complex, ComplexFloat};
 
 use super::common::{assert_complex_matrix_eq, assert_matrix_eq, naive_matmul, random_matrix};
 use crate::{
-    eig::{Eig, EigDecomp, EighDecomp, SchurDecomp},
+    eig::{
+        Eig, EigCondition, EigDecomp, EigError, EigSide, EighDecomp, EighRange, SchurCondition,
+        SchurDecomp, SchurError, SchurSelect, jacobi_eigh, reorder_schur,
+    },
+    gauge::{Gauge, SpectrumOrder},
     utils::pretty_print,
 };
 
@@ -54,7 +58,7 @@ fn test_self_adjoint_reconstruction<T>(
 {
     let (n, _) = *a.shape();
 
-    for i in 0..n {
+    for i in 0..eigenvalues.len() {
         let λ = Complex::new(eigenvalues[i], 0.0);
         let v = eigenvectors.view(.., i).to_owned();
 
@@ -75,6 +79,78 @@ fn test_self_adjoint_reconstruction<T>(
     }
 }
 
+/// Basis of the `dim`-dimensional null space of the square matrix `m`: the
+/// eigenvectors of `M^H M` for its smallest eigenvalues, in the canonical
+/// basis of the default [`Gauge`].
+fn null_space(m: &DArray<Complex<f64>, 2>, dim: usize) -> Vec<Vec<Complex<f64>>> {
+    let n = m.shape().0;
+    let mhm = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        (0..n).map(|k| m[[k, i[0]]].conj() * m[[k, i[1]]]).sum()
+    });
+    let mut decomp = jacobi_eigh(&mhm).expect("Self-adjoint eigenvalue decomposition failed");
+    decomp.canonicalize(&Gauge::default());
+    (0..dim).map(|j| (0..n).map(|i| decomp.eigenvectors[[i, j]]).collect()).collect()
+}
+
+/// Compares the eigenvectors of `decomp`, in the default [`Gauge`], with
+/// the null spaces of `A - λ I` (right) and `(A - λ I)^H` (left), computed
+/// independently of the backend.  The matrix must be diagonalizable.
+fn check_canonical_eigenvectors<T>(a: &DArray<T, 2>, mut decomp: EigDecomp<Complex<f64>, usize, usize>)
+where
+    T: ComplexFloat<Real = f64>,
+{
+    decomp.canonicalize(&Gauge::default());
+    let n = a.shape().0;
+    let entry = |i: usize, j: usize| Complex::new(a[[i, j]].re(), a[[i, j]].im());
+    let max = decomp.eigenvalues.iter().fold(1f64, |acc, x| acc.max(x.norm()));
+
+    let mut start = 0;
+    while start < n {
+        // The eigenvectors of a multiple eigenvalue span its eigenspace.
+        let λ = decomp.eigenvalues[start];
+        let end = (start..n)
+            .find(|&k| (decomp.eigenvalues[k] - λ).norm() > 1e-8 * max)
+            .unwrap_or(n);
+        let shift = |i: usize, j: usize| if i == j { λ } else { Complex::new(0., 0.) };
+        let right = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| entry(i[0], i[1]) - shift(i[0], i[1]));
+        let left = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| (entry(i[1], i[0]) - shift(i[1], i[0])).conj());
+
+        for (vectors, m) in [(&decomp.right_eigenvectors, right), (&decomp.left_eigenvectors, left)] {
+            let Some(vectors) = vectors else { continue };
+            for (k, expected) in (start..end).zip(null_space(&m, end - start)) {
+                for (i, x) in expected.into_iter().enumerate() {
+                    assert!(
+                        (vectors[[i, k]] - x).norm() < 1e-8,
+                        "Component {i} of eigenvector {k}: {:?} != {x:?}",
+                        vectors[[i, k]]
+                    );
+                }
+            }
+        }
+        start = end;
+    }
+}
+
+/// Compares the eigenvectors of `decomp`, in the default [`Gauge`], with
+/// those computed independently of the backend by [`jacobi_eigh`].
+fn check_canonical_eigh<T>(a: &DArray<T, 2>, mut decomp: EighDecomp<T, f64, usize, usize>)
+where
+    T: ComplexFloat<Real = f64> + std::fmt::Debug,
+{
+    let mut expected = jacobi_eigh(a).expect("Self-adjoint eigenvalue decomposition failed");
+    expected.canonicalize(&Gauge::default());
+    decomp.canonicalize(&Gauge::default());
+
+    let n = a.shape().0;
+    for k in 0..n {
+        assert_relative_eq!(decomp.eigenvalues[k], expected.eigenvalues[k], epsilon = 1e-10);
+        for i in 0..n {
+            let (x, y) = (decomp.eigenvectors[[i, k]], expected.eigenvectors[[i, k]]);
+            assert!((x - y).abs() < 1e-8, "Component {i} of eigenvector {k}: {x:?} != {y:?}");
+        }
+    }
+}
+
 pub fn test_non_square_matrix(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
     let n = 3;
     let m = 5;
@@ -89,15 +165,12 @@ pub fn test_square_matrix(bd: &impl Eig<f64, usize, usize, SpectralScalar = Comp
     let n = 2;
     let a = random_matrix(n, n);
 
-    let EigDecomp {
-        eigenvalues,
-        right_eigenvectors,
-        ..
-    } = bd
+    let decomp = bd
         .eig(&mut a.clone())
         .expect("Eigenvalue decomposition failed");
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors.unwrap());
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, decomp.right_eigenvectors.as_ref().unwrap());
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eig_cplx_square_matrix(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
@@ -106,36 +179,30 @@ pub fn test_eig_cplx_square_matrix(bd: &impl Eig<Complex<f64>, usize, usize, Spe
         Complex::new((i[0] + i[1]) as f64, (i[0] * i[1]) as f64)
     });
     println!("{a:?}");
-    let EigDecomp {
-        eigenvalues,
-        right_eigenvectors,
-        ..
-    } = bd
+    let decomp = bd
         .eig(&mut a.clone())
         .expect("Eigenvalue decomposition failed");
-    println!("{eigenvalues:?}");
-    println!("{right_eigenvectors:?}");
+    println!("{:?}", decomp.eigenvalues);
+    println!("{:?}", decomp.right_eigenvectors);
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors.unwrap());
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, decomp.right_eigenvectors.as_ref().unwrap());
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eig_full(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
     let n = 4;
     let a = random_matrix(n, n);
 
-    let EigDecomp {
-        eigenvalues,
-        left_eigenvectors,
-        right_eigenvectors,
-    } = bd
+    let decomp = bd
         .eig_full(&mut a.clone())
         .expect("Full eigenvalue decomposition failed");
 
-    let left_eigenvectors = left_eigenvectors.expect("Left eigenvectors were not computed");
-    let right_eigenvectors = right_eigenvectors.expect("Right eigenvectors were not computed");
+    let left_eigenvectors = decomp.left_eigenvectors.as_ref().expect("Left eigenvectors were not computed");
+    let right_eigenvectors = decomp.right_eigenvectors.as_ref().expect("Right eigenvectors were not computed");
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors);
-    test_eigen_reconstruction_full_values(&a, &eigenvalues, &left_eigenvectors, &right_eigenvectors);
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, right_eigenvectors);
+    test_eigen_reconstruction_full_values(&a, &decomp.eigenvalues, left_eigenvectors, right_eigenvectors);
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eig_full_complex(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
@@ -144,19 +211,16 @@ pub fn test_eig_full_complex(bd: &impl Eig<Complex<f64>, usize, usize, SpectralS
         Complex::new((i[0] + i[1]) as f64, (i[0] * i[1] + 1) as f64)
     });
 
-    let EigDecomp {
-        eigenvalues,
-        left_eigenvectors,
-        right_eigenvectors,
-    } = bd
+    let decomp = bd
         .eig_full(&mut a.clone())
         .expect("Full eigen decomposition failed");
 
-    let left_eigenvectors = left_eigenvectors.expect("Left eigenvectors were not computed");
-    let right_eigenvectors = right_eigenvectors.expect("Right eigenvectors were not computed");
+    let left_eigenvectors = decomp.left_eigenvectors.as_ref().expect("Left eigenvectors were not computed");
+    let right_eigenvectors = decomp.right_eigenvectors.as_ref().expect("Right eigenvectors were not computed");
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors);
-    test_eigen_reconstruction_full_values(&a, &eigenvalues, &left_eigenvectors, &right_eigenvectors);
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, right_eigenvectors);
+    test_eigen_reconstruction_full_values(&a, &decomp.eigenvalues, left_eigenvectors, right_eigenvectors);
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eig_full_real_complex_pair(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
@@ -167,37 +231,31 @@ pub fn test_eig_full_real_complex_pair(bd: &impl Eig<f64, usize, usize, Spectral
         _ => 0.0,
     });
 
-    let EigDecomp {
-        eigenvalues,
-        left_eigenvectors,
-        right_eigenvectors,
-    } = bd
+    let decomp = bd
         .eig_full(&mut a.clone())
         .expect("Full eigenvalue decomposition failed on real complex-pair case");
 
-    let left_eigenvectors = left_eigenvectors.expect("Left eigenvectors were not computed");
-    let right_eigenvectors = right_eigenvectors.expect("Right eigenvectors were not computed");
+    let left_eigenvectors = decomp.left_eigenvectors.as_ref().expect("Left eigenvectors were not computed");
+    let right_eigenvectors = decomp.right_eigenvectors.as_ref().expect("Right eigenvectors were not computed");
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors);
-    test_eigen_reconstruction_full_values(&a, &eigenvalues, &left_eigenvectors, &right_eigenvectors);
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, right_eigenvectors);
+    test_eigen_reconstruction_full_values(&a, &decomp.eigenvalues, left_eigenvectors, right_eigenvectors);
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eig_full_complex_singleton(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
     let a = DArray::<Complex<f64>, 2>::from_fn([1, 1], |_| Complex::new(2.0, -3.0));
 
-    let EigDecomp {
-        eigenvalues,
-        left_eigenvectors,
-        right_eigenvectors,
-    } = bd
+    let decomp = bd
         .eig_full(&mut a.clone())
         .expect("Full eigenvalue decomposition failed on 1x1 complex case");
 
-    let left_eigenvectors = left_eigenvectors.expect("Left eigenvectors were not computed");
-    let right_eigenvectors = right_eigenvectors.expect("Right eigenvectors were not computed");
+    let left_eigenvectors = decomp.left_eigenvectors.as_ref().expect("Left eigenvectors were not computed");
+    let right_eigenvectors = decomp.right_eigenvectors.as_ref().expect("Right eigenvectors were not computed");
 
-    test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors);
-    test_eigen_reconstruction_full_values(&a, &eigenvalues, &left_eigenvectors, &right_eigenvectors);
+    test_eigen_reconstruction(&a, &decomp.eigenvalues, right_eigenvectors);
+    test_eigen_reconstruction_full_values(&a, &decomp.eigenvalues, left_eigenvectors, right_eigenvectors);
+    check_canonical_eigenvectors(&a, decomp);
 }
 
 pub fn test_eigen_reconstruction_full_values<T>(
@@ -334,6 +392,7 @@ pub fn test_eigh_symmetric(bd: &impl Eig<f64, usize, usize, SpectralScalar = Com
     println!("{eigenvalues:?}");
 
     test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+    check_canonical_eigh(&a, EighDecomp { eigenvalues, eigenvectors });
 }
 
 pub fn test_eigh_complex_hermitian(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
@@ -368,6 +427,238 @@ pub fn test_eigh_complex_hermitian(bd: &impl Eig<Complex<f64>, usize, usize, Spe
     println!("{eigenvalues:?}");
 
     test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+    check_canonical_eigh(&a, EighDecomp { eigenvalues, eigenvectors });
+}
+
+fn symmetric_matrix(n: usize) -> DArray<f64, 2> {
+    let a = random_matrix(n, n);
+    DArray::<f64, 2>::from_fn([n, n], |i| (a[[i[0], i[1]]] + a[[i[1], i[0]]]) / 2.0)
+}
+
+pub fn test_eigh_range_index(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 6;
+    let a = symmetric_matrix(n);
+
+    let EighDecomp {
+        eigenvalues: all, ..
+    } = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+    let mut all = all.to_vec();
+    all.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_range(&mut a.clone(), EighRange::Index(1..4))
+        .expect("Partial self-adjoint eigenvalue decomposition failed");
+
+    assert_eq!(*eigenvalues.shape(), (3,));
+    assert_eq!(*eigenvectors.shape(), (n, 3));
+    for i in 0..3 {
+        assert_relative_eq!(eigenvalues[i], all[i + 1], epsilon = 1e-10);
+    }
+
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+}
+
+pub fn test_eigh_range_value(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| match (i[0] as isize - i[1] as isize).abs() {
+        0 => 2.0,
+        1 => -1.0,
+        _ => 0.0,
+    });
+
+    // The eigenvalues of this matrix are 2 - 2 cos(kπ/6), k = 1..5.
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_range(&mut a.clone(), EighRange::Value(0.5, 2.0))
+        .expect("Partial self-adjoint eigenvalue decomposition failed");
+
+    assert_eq!(*eigenvalues.shape(), (2,));
+    assert_eq!(*eigenvectors.shape(), (n, 2));
+    assert_relative_eq!(eigenvalues[0], 1.0, epsilon = 1e-10);
+    assert_relative_eq!(eigenvalues[1], 2.0, epsilon = 1e-10);
+
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+}
+
+pub fn test_eigh_range_complex_hermitian(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 4;
+    let b = random_matrix(n, n);
+    let c = random_matrix(n, n);
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let (r, s) = (i[0], i[1]);
+        Complex::new(b[[r, s]] + b[[s, r]], c[[r, s]] - c[[s, r]])
+    });
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_range(&mut a.clone(), EighRange::Index(0..2))
+        .expect("Partial complex Hermitian eigenvalue decomposition failed");
+
+    assert_eq!(*eigenvalues.shape(), (2,));
+    assert!(eigenvalues[0] <= eigenvalues[1]);
+
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+}
+
+pub fn test_eigh_range_invalid(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 3;
+    let a = symmetric_matrix(n);
+
+    assert!(matches!(
+        bd.eigh_range(&mut a.clone(), EighRange::Index(2..5)),
+        Err(EigError::InvalidRange)
+    ));
+    assert!(matches!(
+        bd.eigh_range(&mut a.clone(), EighRange::Value(1.0, 0.0)),
+        Err(EigError::InvalidRange)
+    ));
+}
+
+/// A subset of the eigenpairs of a constant-size matrix has dynamic size.
+pub fn test_eigh_range_const(
+    bd: &impl Eig<f64, Const<3>, Const<3>, SpectralScalar = Complex<f64>, RealScalar = f64>,
+) {
+    // Eigenvalues 1, 3 and 5.
+    let a = [[2., 1., 0.], [1., 2., 0.], [0., 0., 5.]];
+    let mut a_const =
+        Array::<f64, (Const<3>, Const<3>)>::from_fn((Const, Const), |i| a[i[0]][i[1]]);
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_range(&mut a_const, EighRange::Index(0..1))
+        .expect("Partial self-adjoint eigenvalue decomposition failed");
+    assert_eq!(*eigenvalues.shape(), (1,));
+    assert_eq!(*eigenvectors.shape(), (3, 1));
+    assert_relative_eq!(eigenvalues[0], 1., epsilon = 1e-10);
+    for i in 0..3 {
+        let av = (0..3).map(|j| a[i][j] * eigenvectors[[j, 0]]).sum::<f64>();
+        assert_relative_eq!(av, eigenvectors[[i, 0]], epsilon = 1e-10);
+    }
+}
+
+fn sorted(values: &DArray<f64, 1>) -> Vec<f64> {
+    let mut values = values.to_vec();
+    values.sort_by(|x, y| x.total_cmp(y));
+    values
+}
+
+pub fn test_eigh_values(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let a = symmetric_matrix(n);
+
+    let EighDecomp { eigenvalues, .. } = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+    let values = bd
+        .eigh_values(&mut a.clone())
+        .expect("Self-adjoint eigenvalues failed");
+
+    assert_eq!(*values.shape(), (n,));
+    for (x, y) in sorted(&values).iter().zip(sorted(&eigenvalues)) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+}
+
+pub fn test_eigh_values_complex_hermitian(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 4;
+    let b = random_matrix(n, n);
+    let c = random_matrix(n, n);
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let (r, s) = (i[0], i[1]);
+        Complex::new(b[[r, s]] + b[[s, r]], c[[r, s]] - c[[s, r]])
+    });
+
+    let EighDecomp { eigenvalues, .. } = bd
+        .eigh(&mut a.clone())
+        .expect("Complex Hermitian eigenvalue decomposition failed");
+    let values = bd
+        .eigh_values(&mut a.clone())
+        .expect("Complex Hermitian eigenvalues failed");
+
+    for (x, y) in sorted(&values).iter().zip(sorted(&eigenvalues)) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+}
+
+pub fn test_eigh_write(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 4;
+    let mut eigenvalues = DArray::<f64, 1>::from_elem([n], 0.);
+    let mut eigenvectors = DArray::<f64, 2>::from_elem([n, n], 0.);
+    let mut values = DArray::<f64, 1>::from_elem([n], 0.);
+
+    // The same buffers are reused for several matrices.
+    for _ in 0..3 {
+        let a = symmetric_matrix(n);
+        bd.eigh_write(&mut a.clone(), &mut eigenvalues, &mut eigenvectors)
+            .expect("Self-adjoint eigenvalue decomposition failed");
+        test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+
+        bd.eigh_values_write(&mut a.clone(), &mut values)
+            .expect("Self-adjoint eigenvalues failed");
+        for (x, y) in sorted(&values).iter().zip(sorted(&eigenvalues)) {
+            assert_relative_eq!(*x, y, epsilon = 1e-10);
+        }
+    }
+
+    assert!(matches!(
+        bd.eigh_values(&mut random_matrix(2, 3)),
+        Err(EigError::NotSquareMatrix)
+    ));
+}
+
+/// Every algorithm must read only the upper triangle of the matrix.
+pub fn test_eigh_upper_triangle(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    // Eigenvalues 1, 3, 3 with a lower triangle that does not match.
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| [[2., 1., 0.], [7., 2., 0.], [-5., 9., 3.]][i[0]][i[1]]);
+
+    let EighDecomp { eigenvalues, .. } = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+    for (x, y) in sorted(&eigenvalues).into_iter().zip([1., 3., 3.]) {
+        assert_relative_eq!(x, y, epsilon = 1e-10);
+    }
+
+    let values = bd.eigh_values(&mut a.clone()).expect("Self-adjoint eigenvalues failed");
+    for (x, y) in sorted(&values).into_iter().zip([1., 3., 3.]) {
+        assert_relative_eq!(x, y, epsilon = 1e-10);
+    }
+}
+
+pub fn test_eigh_graded(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    // D M D with D = diag(1, 1e-6, 1e-12) and M tridiagonal with 2 on the
+    // diagonal and 1 off it.  The eigenvalues spread over 24 orders of
+    // magnitude, and their product is det(D)² det(M) = 4e-36.
+    let d = [1., 1e-6, 1e-12];
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| {
+        let m = match i[0].abs_diff(i[1]) {
+            0 => 2.,
+            1 => 1.,
+            _ => 0.,
+        };
+        d[i[0]] * m * d[i[1]]
+    });
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+
+    assert!(eigenvalues[0] > 0. && eigenvalues[0] <= eigenvalues[1] && eigenvalues[1] <= eigenvalues[2]);
+    assert_relative_eq!(eigenvalues.iter().product::<f64>(), 4e-36, max_relative = 1e-12);
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
 }
 
 pub fn test_eig_full_non_square(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
@@ -401,6 +692,15 @@ pub fn test_schur(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>
     assert_eq!(t.shape(), &(n, n));
     assert_eq!(z.shape(), &(n, n));
 
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert!(t[[i, j]].abs() < 1e-10, "T is not quasi-triangular at ({i}, {j})");
+        }
+    }
+    for i in 1..n - 1 {
+        assert!(t[[i, i - 1]] == 0. || t[[i + 1, i]] == 0., "T has a 3 × 3 diagonal block at {i}");
+    }
+
     let zt = z.transpose().to_tensor();
 
     println!("{a:?}");
@@ -429,6 +729,12 @@ pub fn test_schur_cplx(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar
     assert_eq!(t.shape(), &(n, n));
     assert_eq!(z.shape(), &(n, n));
 
+    for i in 0..n {
+        for j in 0..i {
+            assert!(t[[i, j]].norm() < 1e-10, "T is not upper triangular at ({i}, {j})");
+        }
+    }
+
     let mut zt = z.transpose().to_tensor();
 
     for i in 0..n {
@@ -450,3 +756,576 @@ pub fn test_schur_cplx(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar
 
     assert_complex_matrix_eq!(&c, &c_reconstructed);
 }
+
+/// `H B H` with `H` a Householder reflector and `B` quasi-triangular with
+/// eigenvalues `3, 1 ± 2i, -1, 0.5, 2`.
+fn known_spectrum_matrix() -> DArray<f64, 2> {
+    let n = 6;
+    let diag = [3., 1., 1., -1., 0.5, 2.];
+    let b = DArray::<f64, 2>::from_fn([n, n], |i| match (i[0], i[1]) {
+        (r, c) if r == c => diag[r],
+        (1, 2) => 2.,
+        (2, 1) => -2.,
+        (r, c) if r < c => ((r + 2 * c) as f64).sin(),
+        _ => 0.,
+    });
+    let v: Vec<f64> = (0..n).map(|i| 1. + i as f64 * 0.7).collect();
+    let vv = v.iter().map(|x| x * x).sum::<f64>();
+    let h = DArray::<f64, 2>::from_fn([n, n], |i| {
+        let id = if i[0] == i[1] { 1. } else { 0. };
+        id - 2. * v[i[0]] * v[i[1]] / vv
+    });
+    naive_matmul(&naive_matmul(&h, &b), &h)
+}
+
+/// Eigenvalues of the diagonal blocks of a real Schur form, in order.
+fn quasi_triangular_eigenvalues(t: &DArray<f64, 2>) -> Vec<Complex<f64>> {
+    let n = t.shape().0;
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut k = 0;
+    while k < n {
+        if k + 1 < n && t[[k + 1, k]] != 0. {
+            let mean = (t[[k, k]] + t[[k + 1, k + 1]]) / 2.;
+            let det = t[[k, k]] * t[[k + 1, k + 1]] - t[[k, k + 1]] * t[[k + 1, k]];
+            let im = (det - mean * mean).sqrt();
+            eigenvalues.push(Complex::new(mean, im));
+            eigenvalues.push(Complex::new(mean, -im));
+            k += 2;
+        } else {
+            eigenvalues.push(Complex::new(t[[k, k]], 0.));
+            k += 1;
+        }
+    }
+    eigenvalues
+}
+
+pub fn test_schur_reorder(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n: usize = 6;
+    let a = known_spectrum_matrix();
+    let SchurDecomp { mut t, mut z } = bd.schur(&mut a.clone()).expect("Schur decomposition failed");
+    let (t0, z0) = (t.clone(), z.clone());
+
+    // The complex pair and the eigenvalues left of 1.5 move to the front.
+    let select = |lambda: Complex<f64>| lambda.re < 1.5;
+    let reorder = bd
+        .schur_reorder(&mut t, &mut z, SchurSelect::Predicate(&select), SchurCondition::Both)
+        .expect("Schur reordering failed");
+    assert_eq!(reorder.dim, 4);
+
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert!(t[[i, j]].abs() < 1e-12, "T is not quasi-triangular at ({i}, {j})");
+        }
+    }
+    let eigenvalues = quasi_triangular_eigenvalues(&t);
+    assert!(eigenvalues[..4].iter().all(|&lambda| select(lambda)));
+    assert!(eigenvalues[4..].iter().all(|&lambda| !select(lambda)));
+    assert_eq!(eigenvalues.iter().filter(|lambda| lambda.im != 0.).count(), 2);
+
+    let a_reconstructed = naive_matmul(&naive_matmul(&z, &t), &z.transpose().to_tensor());
+    assert_matrix_eq!(&a, &a_reconstructed);
+    let identity = DArray::<f64, 2>::from_fn([n, n], |i| if i[0] == i[1] { 1. } else { 0. });
+    assert_matrix_eq!(&identity, &naive_matmul(&z.transpose().to_tensor(), &z));
+
+    let s = reorder.s.unwrap();
+    let sep = reorder.sep.unwrap();
+    assert!(s > 0. && s <= 1.);
+    assert!(sep > 0.);
+
+    // The eigenvalue condition number is computed exactly, so it agrees
+    // with the generic implementation.
+    let (mut t1, mut z1) = (t0.clone(), z0.clone());
+    let generic = reorder_schur::<f64, Complex<f64>, f64, _, _, _, _>(
+        &mut t1,
+        &mut z1,
+        &SchurSelect::Predicate(&select),
+        SchurCondition::Eigenvalues,
+    )
+    .unwrap();
+    assert_eq!(generic.dim, 4);
+    assert_relative_eq!(generic.s.unwrap(), s, epsilon = 1e-8);
+
+    // Selecting one eigenvalue of a conjugate pair selects both.
+    let (mut t, mut z) = (t0.clone(), z0.clone());
+    let eigenvalues = quasi_triangular_eigenvalues(&t);
+    let mut mask = vec![false; n];
+    mask[eigenvalues.iter().position(|lambda| lambda.im > 0.).unwrap()] = true;
+    mask[eigenvalues.iter().position(|lambda| (lambda.re + 1.).abs() < 1e-8).unwrap()] = true;
+    let reorder = bd
+        .schur_reorder(&mut t, &mut z, SchurSelect::Mask(&mask), SchurCondition::None)
+        .expect("Schur reordering failed");
+    assert_eq!(reorder.dim, 3);
+    assert_eq!((reorder.s, reorder.sep), (None, None));
+    let mut leading = quasi_triangular_eigenvalues(&t)[..3].to_vec();
+    leading.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    for (lambda, expected) in leading.iter().zip([Complex::new(-1., 0.), Complex::new(1., -2.), Complex::new(1., 2.)]) {
+        assert!((lambda - expected).norm() < 1e-8, "{lambda} != {expected}");
+    }
+    assert_matrix_eq!(&a, &naive_matmul(&naive_matmul(&z, &t), &z.transpose().to_tensor()));
+
+    // Selecting nothing leaves the decomposition as it is.
+    let (mut t, mut z) = (t0.clone(), z0.clone());
+    let reorder = bd
+        .schur_reorder(&mut t, &mut z, SchurSelect::Mask(&[false; 6]), SchurCondition::Eigenvalues)
+        .unwrap();
+    assert_eq!((reorder.dim, reorder.s), (0, Some(1.)));
+    assert_matrix_eq!(&t0, &t);
+}
+
+pub fn test_schur_reorder_cplx(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let c = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let x = ((1 + i[0]) * (2 + i[1])) as f64;
+        Complex::new((0.7 * x * x).sin(), (1.3 * x).cos())
+    });
+    let SchurDecomp { mut t, mut z } = bd
+        .schur_complex(&mut c.clone())
+        .expect("Schur decomposition failed");
+
+    let select = |lambda: Complex<f64>| lambda.im > 0.1;
+    let expected = (0..n).filter(|&i| select(t[[i, i]])).count();
+    assert!(expected > 0 && expected < n);
+    let reorder = bd
+        .schur_complex_reorder(&mut t, &mut z, SchurSelect::Predicate(&select), SchurCondition::Both)
+        .expect("Schur reordering failed");
+    assert_eq!(reorder.dim, expected);
+    for i in 0..n {
+        assert_eq!(select(t[[i, i]]), i < expected, "{:?}", t[[i, i]]);
+    }
+    for i in 0..n {
+        for j in 0..i {
+            assert!(t[[i, j]].norm() < 1e-12, "T is not upper triangular at ({i}, {j})");
+        }
+    }
+    let s = reorder.s.unwrap();
+    assert!(s > 0. && s <= 1.);
+    assert!(reorder.sep.unwrap() > 0.);
+
+    let zh = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| z[[i[1], i[0]]].conj());
+    let c_reconstructed = naive_matmul(&naive_matmul(&z, &t), &zh);
+    for (x, y) in c.iter().zip(c_reconstructed.iter()) {
+        assert!((x - y).norm() < 1e-8, "{x} != {y}");
+    }
+}
+
+pub fn test_schur_reorder_invalid(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let a = known_spectrum_matrix();
+    let SchurDecomp { mut t, mut z } = bd.schur(&mut a.clone()).expect("Schur decomposition failed");
+    assert!(matches!(
+        bd.schur_reorder(&mut t, &mut z, SchurSelect::Mask(&[true; 3]), SchurCondition::None),
+        Err(SchurError::InvalidSelection)
+    ));
+}
+
+/// Checks `A v = λ v` and `u^H A = λ u^H` for the returned columns, and
+/// their normalization.
+fn check_eigenvectors<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, decomp: &EigDecomp<Complex<f64>, usize, usize>) {
+    let n = a.shape().0;
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| Complex::new(a[[i[0], i[1]]].re(), a[[i[0], i[1]]].im()));
+    let m = decomp.eigenvalues.len();
+    for vectors in [&decomp.left_eigenvectors, &decomp.right_eigenvectors].into_iter().flatten() {
+        assert_eq!(vectors.shape(), &(n, m));
+        for j in 0..m {
+            let norm = (0..n).map(|i| vectors[[i, j]].norm_sqr()).sum::<f64>().sqrt();
+            assert_relative_eq!(norm, 1., epsilon = 1e-10);
+            let largest = (0..n).map(|i| vectors[[i, j]]).max_by(|x, y| x.norm().total_cmp(&y.norm())).unwrap();
+            assert!(largest.im.abs() < 1e-12 && largest.re > 0.);
+        }
+    }
+    for j in 0..m {
+        let lambda = decomp.eigenvalues[j];
+        if let Some(v) = &decomp.right_eigenvectors {
+            for i in 0..n {
+                let av = (0..n).fold(Complex::new(0., 0.), |acc, l| acc + a[[i, l]] * v[[l, j]]);
+                assert!((av - lambda * v[[i, j]]).norm() < 1e-10, "A v != λ v for λ = {lambda}");
+            }
+        }
+        if let Some(u) = &decomp.left_eigenvectors {
+            for i in 0..n {
+                let ua = (0..n).fold(Complex::new(0., 0.), |acc, l| acc + u[[l, j]].conj() * a[[l, i]]);
+                assert!((ua - lambda * u[[i, j]].conj()).norm() < 1e-10, "u^H A != λ u^H for λ = {lambda}");
+            }
+        }
+    }
+}
+
+pub fn test_schur_eigenvectors(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let a = known_spectrum_matrix();
+    let SchurDecomp { t, z } = bd.schur(&mut a.clone()).expect("Schur decomposition failed");
+
+    let decomp = bd
+        .schur_eigenvectors(&t, &z, EigSide::Both, None)
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), 6);
+    let mut eigenvalues: Vec<_> = decomp.eigenvalues.iter().copied().collect();
+    eigenvalues.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    let expected = [(-1., 0.), (0.5, 0.), (1., -2.), (1., 2.), (2., 0.), (3., 0.)];
+    for (lambda, (re, im)) in eigenvalues.iter().zip(expected) {
+        assert!((lambda - Complex::new(re, im)).norm() < 1e-8, "{lambda} != {re} + {im}i");
+    }
+    check_eigenvectors(&a, &decomp);
+
+    // The conjugate eigenvalue of a pair alone, and a real one.
+    let select = |lambda: Complex<f64>| lambda.im < 0. || lambda.re > 2.5;
+    let decomp = bd
+        .schur_eigenvectors(&t, &z, EigSide::Right, Some(SchurSelect::Predicate(&select)))
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), 2);
+    assert!(decomp.eigenvalues.iter().all(|&lambda| select(lambda)));
+    assert!(decomp.left_eigenvectors.is_none());
+    check_eigenvectors(&a, &decomp);
+
+    let decomp = bd
+        .schur_eigenvectors(&t, &z, EigSide::Left, Some(SchurSelect::Mask(&[true, false, false, false, false, true])))
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), 2);
+    assert!(decomp.right_eigenvectors.is_none());
+    check_eigenvectors(&a, &decomp);
+
+    assert!(matches!(
+        bd.schur_eigenvectors(&t, &z, EigSide::Right, Some(SchurSelect::Mask(&[true]))),
+        Err(SchurError::InvalidSelection)
+    ));
+
+    // The same eigenvectors from the complex Schur form.
+    let SchurDecomp { t, z } = bd.schur_complex(&mut a.clone()).expect("Schur decomposition failed");
+    let decomp = bd
+        .schur_complex_eigenvectors(&t, &z, EigSide::Both, None)
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), 6);
+    check_eigenvectors(&a, &decomp);
+}
+
+pub fn test_schur_eigenvectors_cplx(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let c = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let x = ((1 + i[0]) * (2 + i[1])) as f64;
+        Complex::new((0.7 * x * x).sin(), (1.3 * x).cos())
+    });
+    let SchurDecomp { t, z } = bd
+        .schur_complex(&mut c.clone())
+        .expect("Schur decomposition failed");
+
+    let decomp = bd
+        .schur_complex_eigenvectors(&t, &z, EigSide::Both, None)
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), n);
+    for j in 0..n {
+        assert_eq!(decomp.eigenvalues[j], t[[j, j]]);
+    }
+    check_eigenvectors(&c, &decomp);
+
+    let mask = [false, true, false, true, false];
+    let decomp = bd
+        .schur_complex_eigenvectors(&t, &z, EigSide::Left, Some(SchurSelect::Mask(&mask)))
+        .expect("Eigenvectors from the Schur form failed");
+    assert_eq!(decomp.eigenvalues.len(), 2);
+    assert_eq!(decomp.eigenvalues[1], t[[3, 3]]);
+    check_eigenvectors(&c, &decomp);
+
+    // The input scalar field of a complex matrix is the spectral one.
+    let SchurDecomp { t, z } = bd.schur(&mut c.clone()).expect("Schur decomposition failed");
+    let decomp = bd
+        .schur_eigenvectors(&t, &z, EigSide::Right, None)
+        .expect("Eigenvectors from the Schur form failed");
+    check_eigenvectors(&c, &decomp);
+}
+
+pub fn test_eig_condition(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    // Both eigenvalues of [[1, c], [0, 2]] have |u^H v| = 1 / sqrt(1 + c^2),
+    // and they are separated by 1.
+    let c = 10.;
+    let a = DArray::<f64, 2>::from_fn([2, 2], |i| match (i[0], i[1]) {
+        (0, 0) => 1.,
+        (0, 1) => c,
+        (1, 1) => 2.,
+        _ => 0.,
+    });
+    let (decomp, EigCondition { eigenvalues, eigenvectors }) =
+        bd.eig_condition(&mut a.clone()).expect("Eigenvalue condition numbers failed");
+    assert!(decomp.left_eigenvectors.is_some() && decomp.right_eigenvectors.is_some());
+    check_eigenvectors(&a, &decomp);
+    for j in 0..2 {
+        assert_relative_eq!(eigenvalues[j], 1. / (1. + c * c).sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(eigenvectors[j], 1., epsilon = 1e-10);
+    }
+
+    // A symmetric matrix has perfectly conditioned eigenvalues, and the
+    // eigenvalues 3 and 3 ± √3 are each √3 away from the nearest other one.
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| match i[0].abs_diff(i[1]) {
+        0 => (2 + i[0]) as f64,
+        1 => 1.,
+        _ => 0.,
+    });
+    let (decomp, EigCondition { eigenvalues, eigenvectors }) =
+        bd.eig_condition(&mut a.clone()).expect("Eigenvalue condition numbers failed");
+    check_eigenvectors(&a, &decomp);
+    for j in 0..3 {
+        assert_relative_eq!(eigenvalues[j], 1., epsilon = 1e-10);
+        assert_relative_eq!(eigenvectors[j], 3f64.sqrt(), epsilon = 1e-8);
+    }
+
+    assert!(matches!(
+        bd.eig_condition(&mut DArray::<f64, 2>::from_elem([2, 3], 1.)),
+        Err(EigError::NotSquareMatrix)
+    ));
+}
+
+pub fn test_eig_condition_cplx(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let c = Complex::new(3., 4.);
+    let a = DArray::<Complex<f64>, 2>::from_fn([2, 2], |i| match (i[0], i[1]) {
+        (0, 0) => Complex::new(0., 1.),
+        (0, 1) => c,
+        (1, 1) => Complex::new(1., 1.),
+        _ => Complex::new(0., 0.),
+    });
+    let (decomp, EigCondition { eigenvalues, eigenvectors }) =
+        bd.eig_condition(&mut a.clone()).expect("Eigenvalue condition numbers failed");
+    check_eigenvectors(&a, &decomp);
+    for j in 0..2 {
+        assert_relative_eq!(eigenvalues[j], 1. / (1. + c.norm_sqr()).sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(eigenvectors[j], 1., epsilon = 1e-10);
+    }
+}
+
+/// `D^-1 M D` for `D = diag(1, 1, 1e10, 1e20)`, with the eigenvalues 5, 3 and
+/// 3 ± √3 of `M`.  The first row isolates an eigenvalue.
+fn badly_scaled_matrix() -> (DArray<f64, 2>, [f64; 4]) {
+    let m = [[5., 0., 0., 0.], [1., 2., 1., 0.], [1., 1., 3., 1.], [1., 0., 1., 4.]];
+    let d = [1., 1., 1e10, 1e20];
+    (DArray::<f64, 2>::from_fn([4, 4], |i| m[i[0]][i[1]] * d[i[1]] / d[i[0]]), d)
+}
+
+pub fn test_eig_badly_scaled(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let (a, d) = badly_scaled_matrix();
+    let sqrt3 = 3f64.sqrt();
+    let check_eigenvalues = |eigenvalues: &DArray<Complex<f64>, 1>| {
+        let mut eigenvalues: Vec<_> = eigenvalues.iter().copied().collect();
+        eigenvalues.sort_by(|x, y| x.re.total_cmp(&y.re));
+        for (lambda, expected) in eigenvalues.iter().zip([3. - sqrt3, 3., 3. + sqrt3, 5.]) {
+            assert!((lambda - expected).norm() < 1e-10, "{lambda} != {expected}");
+        }
+    };
+
+    check_eigenvalues(&bd.eig_values(&mut a.clone()).expect("Eigenvalue decomposition failed"));
+
+    let EigDecomp {
+        eigenvalues,
+        left_eigenvectors,
+        right_eigenvectors,
+    } = bd.eig_full(&mut a.clone()).expect("Full eigenvalue decomposition failed");
+    check_eigenvalues(&eigenvalues);
+    let (u, v) = (left_eigenvectors.unwrap(), right_eigenvectors.unwrap());
+    // The residuals are small in the coordinates of M.
+    for j in 0..4 {
+        let lambda = eigenvalues[j];
+        let norm = |x: &dyn Fn(usize) -> Complex<f64>| (0..4).map(|i| x(i).norm_sqr()).sum::<f64>().sqrt();
+        let rv = |i: usize| ((0..4).fold(Complex::new(0., 0.), |acc, l| acc + a[[i, l]] * v[[l, j]]) - lambda * v[[i, j]]) * d[i];
+        assert!(norm(&rv) < 1e-10 * norm(&|i| v[[i, j]] * d[i]), "A v != λ v for λ = {lambda}");
+        let ru = |i: usize| ((0..4).fold(Complex::new(0., 0.), |acc, l| acc + u[[l, j]].conj() * a[[l, i]]) - lambda * u[[i, j]].conj()) / d[i];
+        assert!(norm(&ru) < 1e-10 * norm(&|i| u[[i, j]] / d[i]), "u^H A != λ u^H for λ = {lambda}");
+    }
+
+    let (decomp, _) = bd.eig_condition(&mut a.clone()).expect("Eigenvalue condition numbers failed");
+    check_eigenvalues(&decomp.eigenvalues);
+}
+
+fn assert_vectors_eq<T: ComplexFloat<Real = f64> + std::fmt::Debug>(actual: &DArray<T, 2>, expected: &[&[T]]) {
+    assert_eq!(actual.shape().1, expected.len(), "Wrong number of vectors");
+    for (j, column) in expected.iter().enumerate() {
+        for (i, &x) in column.iter().enumerate() {
+            assert!(
+                (actual[[i, j]] - x).abs() < 1e-10,
+                "Component {i} of vector {j}: {:?} != {x:?}",
+                actual[[i, j]]
+            );
+        }
+    }
+}
+
+/// The backend must be configured with the default [`Gauge`].
+pub fn test_eigh_gauge(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    // Eigenvalues 1, 3, 3: the degenerate pair spans (1, 1, 0) and (0, 0, 1).
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| [[2., 1., 0.], [1., 2., 0.], [0., 0., 3.]][i[0]][i[1]]);
+    let r = 0.5f64.sqrt();
+
+    let mut decomp = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+    for (x, y) in decomp.eigenvalues.iter().zip([1., 3., 3.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+    assert_vectors_eq(&decomp.eigenvectors, &[&[r, -r, 0.], &[r, r, 0.], &[0., 0., 1.]]);
+
+    let values = bd.eigh_values(&mut a.clone()).expect("Self-adjoint eigenvalues failed");
+    for (x, y) in values.iter().zip([1., 3., 3.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+
+    let perm = decomp.canonicalize(&Gauge {
+        order: SpectrumOrder::Descending,
+        ..Default::default()
+    });
+    assert_eq!(perm[2], 0);
+    for (x, y) in decomp.eigenvalues.iter().zip([3., 3., 1.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+    assert_vectors_eq(&decomp.eigenvectors, &[&[r, r, 0.], &[0., 0., 1.], &[r, -r, 0.]]);
+}
+
+/// The backend must be configured with the default [`Gauge`].
+pub fn test_eigh_gauge_complex(bd: &impl Eig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let (one, i) = (Complex::new(1., 0.), Complex::new(0., 1.));
+    let a = DArray::<Complex<f64>, 2>::from_fn([2, 2], |idx| [[one * 2., i], [-i, one * 2.]][idx[0]][idx[1]]);
+    let r = 0.5f64.sqrt();
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh(&mut a.clone())
+        .expect("Complex Hermitian eigenvalue decomposition failed");
+    for (x, y) in eigenvalues.iter().zip([1., 3.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+    // Equal magnitudes: the first component is made real and positive.
+    assert_vectors_eq(&eigenvectors, &[&[one * r, i * r], &[one * r, -i * r]]);
+}
+
+/// The backend must be configured with the default [`Gauge`].
+pub fn test_eig_gauge(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    // A normal matrix with eigenvalues -i, i and 2, so that its left and
+    // right eigenvectors coincide.
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| [[0., -1., 0.], [1., 0., 0.], [0., 0., 2.]][i[0]][i[1]]);
+    let (zero, one, i) = (Complex::new(0., 0.), Complex::new(1., 0.), Complex::new(0., 1.));
+    let r = 0.5f64.sqrt();
+    let expected_values = [-i, i, one * 2.];
+    let expected_vectors: [&[Complex<f64>]; 3] = [&[one * r, i * r, zero], &[one * r, -i * r, zero], &[zero, zero, one]];
+    let check_values = |eigenvalues: &DArray<Complex<f64>, 1>| {
+        for (x, y) in eigenvalues.iter().zip(expected_values) {
+            assert!((x - y).norm() < 1e-10, "{x} != {y}");
+        }
+    };
+
+    check_values(&bd.eig_values(&mut a.clone()).expect("Eigenvalue decomposition failed"));
+
+    let EigDecomp {
+        eigenvalues,
+        right_eigenvectors,
+        ..
+    } = bd.eig(&mut a.clone()).expect("Eigenvalue decomposition failed");
+    check_values(&eigenvalues);
+    assert_vectors_eq(&right_eigenvectors.unwrap(), &expected_vectors);
+
+    let EigDecomp {
+        eigenvalues,
+        left_eigenvectors,
+        right_eigenvectors,
+    } = bd.eig_full(&mut a.clone()).expect("Full eigenvalue decomposition failed");
+    check_values(&eigenvalues);
+    assert_vectors_eq(&left_eigenvectors.unwrap(), &expected_vectors);
+    assert_vectors_eq(&right_eigenvectors.unwrap(), &expected_vectors);
+
+    let (decomp, condition) = bd.eig_condition(&mut a.clone()).expect("Eigenvalue condition numbers failed");
+    check_values(&decomp.eigenvalues);
+    // The matrix is normal, so all eigenvalues are perfectly conditioned.
+    for x in condition.eigenvalues.iter() {
+        assert_relative_eq!(*x, 1., epsilon = 1e-10);
+    }
+}
+
+/// Symmetric tridiagonal matrix with diagonal `d` and off-diagonal `e`.
+fn tridiagonal_matrix(d: &DArray<f64, 1>, e: &DArray<f64, 1>) -> DArray<f64, 2> {
+    let n = d.len();
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if r == c {
+            d[[r]]
+        } else if r + 1 == c {
+            e[[r]]
+        } else if c + 1 == r {
+            e[[c]]
+        } else {
+            0.0
+        }
+    })
+}
+
+pub fn test_eigh_tridiagonal(bd: &impl Eig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 6;
+    let d = DArray::<f64, 1>::from_fn([n], |i| [4.0, 1.0, -2.0, 3.0, 0.5, 2.0][i[0]]);
+    let e = DArray::<f64, 1>::from_fn([n - 1], |i| [1.0, -0.5, 2.0, 0.3, 1.5][i[0]]);
+    let a = tridiagonal_matrix(&d, &e);
+
+    let EighDecomp { eigenvalues: all, .. } = bd
+        .eigh(&mut a.clone())
+        .expect("Self-adjoint eigenvalue decomposition failed");
+    let all = sorted(&all);
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_tridiagonal(&d, &e, EighRange::Index(0..n))
+        .expect("Tridiagonal eigenvalue decomposition failed");
+    assert_eq!(*eigenvectors.shape(), (n, n));
+    for i in 0..n {
+        assert_relative_eq!(eigenvalues[i], all[i], epsilon = 1e-10);
+    }
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_tridiagonal(&d, &e, EighRange::Index(2..5))
+        .expect("Partial tridiagonal eigenvalue decomposition failed");
+    assert_eq!(*eigenvectors.shape(), (n, 3));
+    for i in 0..3 {
+        assert_relative_eq!(eigenvalues[i], all[i + 2], epsilon = 1e-10);
+    }
+    test_self_adjoint_reconstruction(&a, &eigenvalues, &eigenvectors);
+
+    // Bounds halfway between eigenvalues select eigenvalues 1, 2 and 3.
+    let range = EighRange::Value((all[0] + all[1]) / 2.0, (all[3] + all[4]) / 2.0);
+    let values = bd
+        .eigh_tridiagonal_values(&d, &e, range)
+        .expect("Tridiagonal eigenvalues failed");
+    assert_eq!(*values.shape(), (3,));
+    for i in 0..3 {
+        assert_relative_eq!(values[i], all[i + 1], epsilon = 1e-10);
+    }
+
+    assert!(matches!(
+        bd.eigh_tridiagonal(&d, &e, EighRange::Index(4..7)),
+        Err(EigError::InvalidRange)
+    ));
+}
+
+pub fn test_eigh_tridiagonal_const(bd: &impl Eig<f64, Const<3>, Const<3>, RealScalar = f64>) {
+    // The off-diagonal has its own, shorter static dimension.
+    let d = Array::<f64, (Const<3>,)>::from_fn((Const,), |_| 2.0);
+    let e = Array::<f64, (Const<2>,)>::from_fn((Const,), |_| -1.0);
+    let a = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]];
+
+    let EighDecomp {
+        eigenvalues,
+        eigenvectors,
+    } = bd
+        .eigh_tridiagonal(&d, &e, EighRange::Index(0..3))
+        .expect("Tridiagonal eigenvalue decomposition failed");
+    let sqrt2 = 2.0_f64.sqrt();
+    for (k, expected) in [2.0 - sqrt2, 2.0, 2.0 + sqrt2].into_iter().enumerate() {
+        assert_relative_eq!(eigenvalues[k], expected, epsilon = 1e-10);
+        for i in 0..3 {
+            let av = (0..3).map(|j| a[i][j] * eigenvectors[[j, k]]).sum::<f64>();
+            assert_relative_eq!(av, expected * eigenvectors[[i, k]], epsilon = 1e-10);
+        }
+    }
+
+    let values = bd
+        .eigh_tridiagonal_values(&d, &e, EighRange::Index(1..3))
+        .expect("Tridiagonal eigenvalues failed");
+    assert_eq!(*values.shape(), (2,));
+    assert_relative_eq!(values[0], 2.0, epsilon = 1e-10);
+}
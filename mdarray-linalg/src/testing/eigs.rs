@@ -0,0 +1,358 @@
+use mdarray::{DArray, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    eig::{Eig, EighDecomp},
+    eigs::{
+        EigsConfig, EigsError, ShiftInvert, Which, arnoldi, arnoldi_shift_invert, lanczos,
+        lanczos_shift_invert,
+    },
+    lu::LU,
+    matvec::{MatVec, MatVecBuilder},
+};
+
+/// Symmetric matrix with well separated extremal eigenvalues.
+fn symmetric_matrix(n: usize) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let d = i[0].abs_diff(i[1]) as f64;
+        if i[0] == i[1] {
+            (i[0] * i[0]) as f64 / n as f64
+        } else {
+            1.0 / (1.0 + d)
+        }
+    })
+}
+
+fn hermitian_matrix(n: usize) -> DArray<Complex<f64>, 2> {
+    let re = symmetric_matrix(n);
+    DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let im = match i[0].cmp(&i[1]) {
+            std::cmp::Ordering::Less => 0.3 / (1.0 + (i[1] - i[0]) as f64),
+            std::cmp::Ordering::Greater => -0.3 / (1.0 + (i[0] - i[1]) as f64),
+            std::cmp::Ordering::Equal => 0.0,
+        };
+        Complex::new(re[[i[0], i[1]]], im)
+    })
+}
+
+/// Non-symmetric matrix whose eigenvalues of largest magnitude include a
+/// complex conjugate pair.
+fn nonsymmetric_matrix(n: usize) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        match (r, c) {
+            (0, 0) | (1, 1) => 3.0 * n as f64,
+            (0, 1) => 5.0,
+            (1, 0) => -5.0,
+            _ if r == c => (r + 1) as f64,
+            _ => 0.2 * ((7 * r + 3 * c) as f64).sin() / (1.0 + r.abs_diff(c) as f64),
+        }
+    })
+}
+
+fn complex_matrix(n: usize) -> DArray<Complex<f64>, 2> {
+    let re = nonsymmetric_matrix(n);
+    DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let im = if i[0] == i[1] {
+            0.5 * i[0] as f64
+        } else {
+            0.1 * ((i[0] + 2 * i[1]) as f64).cos() / (1.0 + i[0].abs_diff(i[1]) as f64)
+        };
+        Complex::new(re[[i[0], i[1]]], im)
+    })
+}
+
+fn residual<T: ComplexFloat<Real = f64>>(
+    a: &DArray<T, 2>,
+    lambda: Complex<f64>,
+    x: impl Fn(usize) -> Complex<f64>,
+) -> f64 {
+    let n = a.shape().0;
+    (0..n)
+        .map(|r| {
+            let ax = (0..n).fold(Complex::new(0.0, 0.0), |acc, c| {
+                acc + Complex::new(a[[r, c]].re(), a[[r, c]].im()) * x(c)
+            });
+            (ax - lambda * x(r)).norm_sqr()
+        })
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn check_lanczos<T: ComplexFloat<Real = f64>>(
+    a: &DArray<T, 2>,
+    decomp: &EighDecomp<T, f64, Dyn, Dyn>,
+    expected: &[f64],
+) {
+    let n = a.shape().0;
+    let k = expected.len();
+    assert_eq!(*decomp.eigenvalues.shape(), (k,));
+    assert_eq!(*decomp.eigenvectors.shape(), (n, k));
+
+    for (j, &e) in expected.iter().enumerate() {
+        let lambda = decomp.eigenvalues[j];
+        assert!((lambda - e).abs() < 1e-8, "eigenvalue {lambda} != {e}");
+
+        let x = |r: usize| {
+            let v = decomp.eigenvectors[[r, j]];
+            Complex::new(v.re(), v.im())
+        };
+        assert!(residual(a, Complex::new(lambda, 0.0), x) < 1e-6);
+
+        for l in 0..k {
+            let dot = (0..n).fold(T::zero(), |acc, r| {
+                acc + decomp.eigenvectors[[r, l]].conj() * decomp.eigenvectors[[r, j]]
+            });
+            let id = if l == j { 1.0 } else { 0.0 };
+            assert!((dot - T::from(id).unwrap()).abs() < 1e-8);
+        }
+    }
+}
+
+fn sorted_eigh<T, B>(bd: &B, a: &DArray<T, 2>) -> Vec<f64>
+where
+    T: ComplexFloat<Real = f64>,
+    B: Eig<T, Dyn, Dyn, RealScalar = f64>,
+{
+    let mut values: Vec<f64> = bd.eigh(&mut a.clone()).expect("eigh failed").eigenvalues.into_iter().collect();
+    values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    values
+}
+
+pub fn test_lanczos_largest(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, RealScalar = f64>,
+) {
+    let n = 80;
+    let a = symmetric_matrix(n);
+    let reference = sorted_eigh(bd, &a);
+
+    let config = EigsConfig {
+        which: Which::LargestReal,
+        ..Default::default()
+    };
+    let decomp = lanczos(bd, n, |x, y| mv.matvec(&a, x).write(y), 4, &config).expect("Lanczos failed");
+
+    let expected: Vec<f64> = reference.iter().rev().take(4).copied().collect();
+    check_lanczos(&a, &decomp, &expected);
+}
+
+pub fn test_lanczos_smallest(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, RealScalar = f64>,
+) {
+    let n = 60;
+    let a = symmetric_matrix(n);
+    let reference = sorted_eigh(bd, &a);
+
+    let config = EigsConfig {
+        which: Which::SmallestReal,
+        krylov_dim: Some(30),
+        ..Default::default()
+    };
+    let decomp = lanczos(bd, n, |x, y| mv.matvec(&a, x).write(y), 3, &config).expect("Lanczos failed");
+
+    check_lanczos(&a, &decomp, &reference[..3]);
+}
+
+pub fn test_lanczos_complex_hermitian(
+    mv: &impl MatVec<Complex<f64>, Dyn, Dyn>,
+    bd: &impl Eig<Complex<f64>, Dyn, Dyn, RealScalar = f64>,
+) {
+    let n = 50;
+    let a = hermitian_matrix(n);
+    let reference = sorted_eigh(bd, &a);
+
+    let config = EigsConfig {
+        which: Which::LargestMagnitude,
+        ..Default::default()
+    };
+    let decomp = lanczos(bd, n, |x, y| mv.matvec(&a, x).write(y), 3, &config).expect("Lanczos failed");
+
+    let expected: Vec<f64> = reference.iter().rev().take(3).copied().collect();
+    check_lanczos(&a, &decomp, &expected);
+}
+
+pub fn test_lanczos_shift_invert(
+    lu: &impl LU<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, RealScalar = f64>,
+) {
+    let n = 60;
+    let a = symmetric_matrix(n);
+    let reference = sorted_eigh(bd, &a);
+    let sigma = 20.3;
+
+    let mut nearest = reference.clone();
+    nearest.sort_by(|x, y| (x - sigma).abs().partial_cmp(&(y - sigma).abs()).unwrap());
+
+    let si = ShiftInvert::new(lu, &a, sigma).expect("shift-invert factorization failed");
+    let decomp = lanczos_shift_invert(bd, &si, 3, &EigsConfig::default()).expect("Lanczos failed");
+
+    check_lanczos(&a, &decomp, &nearest[..3]);
+}
+
+fn check_arnoldi<T, B>(
+    bd: &B,
+    a: &DArray<T, 2>,
+    decomp: &crate::eig::EigDecomp<Complex<f64>, Dyn, Dyn>,
+    k: usize,
+    distance: impl Fn(Complex<f64>) -> f64,
+) where
+    T: ComplexFloat<Real = f64>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+{
+    let n = a.shape().0;
+    let mut reference: Vec<Complex<f64>> = bd
+        .eig_values(&mut a.clone())
+        .expect("eig failed")
+        .into_iter()
+        .collect();
+    reference.sort_by(|x, y| distance(*x).partial_cmp(&distance(*y)).unwrap());
+
+    let vectors = decomp.right_eigenvectors.as_ref().expect("missing eigenvectors");
+    assert_eq!(*decomp.eigenvalues.shape(), (k,));
+    assert_eq!(*vectors.shape(), (n, k));
+
+    for j in 0..k {
+        let lambda = decomp.eigenvalues[j];
+        // The k wanted eigenvalues, up to the order of ties.
+        assert!(
+            reference[..k + 1].iter().any(|r| (r - lambda).norm() < 1e-8),
+            "unexpected eigenvalue {lambda}"
+        );
+        assert!(distance(lambda) <= distance(reference[k - 1]) + 1e-8);
+        assert!(residual(a, lambda, |r| vectors[[r, j]]) < 1e-6);
+
+        let norm: f64 = (0..n).map(|r| vectors[[r, j]].norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-10);
+    }
+}
+
+pub fn test_arnoldi_largest_magnitude(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+) {
+    let n = 60;
+    let a = nonsymmetric_matrix(n);
+
+    let decomp = arnoldi(bd, n, |x, y| mv.matvec(&a, x).write(y), 4, &EigsConfig::default())
+        .expect("Arnoldi failed");
+
+    // The conjugate pair 3n ± 5i comes first.
+    assert!(decomp.eigenvalues[0].im.abs() > 1.0);
+    check_arnoldi(bd, &a, &decomp, 4, |z| -z.norm());
+}
+
+pub fn test_arnoldi_smallest_real(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+) {
+    let n = 40;
+    let a = nonsymmetric_matrix(n);
+
+    let config = EigsConfig {
+        which: Which::SmallestReal,
+        ..Default::default()
+    };
+    let decomp = arnoldi(bd, n, |x, y| mv.matvec(&a, x).write(y), 3, &config).expect("Arnoldi failed");
+
+    check_arnoldi(bd, &a, &decomp, 3, |z| z.re);
+}
+
+pub fn test_arnoldi_complex(
+    mv: &impl MatVec<Complex<f64>, Dyn, Dyn>,
+    bd: &impl Eig<Complex<f64>, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+) {
+    let n = 50;
+    let a = complex_matrix(n);
+
+    let config = EigsConfig {
+        which: Which::LargestReal,
+        ..Default::default()
+    };
+    let decomp = arnoldi(bd, n, |x, y| mv.matvec(&a, x).write(y), 3, &config).expect("Arnoldi failed");
+
+    check_arnoldi(bd, &a, &decomp, 3, |z| -z.re);
+}
+
+pub fn test_arnoldi_shift_invert(
+    lu: &impl LU<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+) {
+    let n = 50;
+    let a = nonsymmetric_matrix(n);
+    let sigma = 25.4;
+
+    let si = ShiftInvert::new(lu, &a, sigma).expect("shift-invert factorization failed");
+    let decomp = arnoldi_shift_invert(bd, &si, 3, &EigsConfig::default()).expect("Arnoldi failed");
+
+    check_arnoldi(bd, &a, &decomp, 3, |z| (z - sigma).norm());
+}
+
+pub fn test_eigs_not_converged(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl Eig<f64, Dyn, Dyn, RealScalar = f64>,
+) {
+    let n = 80;
+    let a = symmetric_matrix(n);
+
+    let config = EigsConfig {
+        tol: 0.0,
+        max_restarts: 2,
+        ..Default::default()
+    };
+    let result = lanczos(bd, n, |x, y| mv.matvec(&a, x).write(y), 4, &config);
+    assert!(matches!(
+        result,
+        Err(EigsError::NotConverged {
+            requested: 4,
+            restarts: 2,
+            ..
+        })
+    ));
+}
+
+pub fn test_eigs_invalid_dimensions(bd: &impl Eig<f64, Dyn, Dyn, RealScalar = f64>) {
+    let a = symmetric_matrix(10);
+    let config = EigsConfig::<f64> {
+        krylov_dim: Some(4),
+        ..Default::default()
+    };
+    let op = |x: &mdarray::DSlice<f64, 1>, y: &mut mdarray::DSlice<f64, 1>| {
+        for i in 0..10 {
+            y[i] = (0..10).map(|j| a[[i, j]] * x[j]).sum();
+        }
+    };
+    assert!(matches!(
+        lanczos(bd, 10, op, 4, &config),
+        Err(EigsError::InvalidDimensions { .. })
+    ));
+    assert!(matches!(
+        lanczos(bd, 10, op, 11, &EigsConfig::default()),
+        Err(EigsError::InvalidDimensions { .. })
+    ));
+}
+
+pub fn test_shift_invert_invalid(lu: &impl LU<f64, Dyn, Dyn>) {
+    let rectangular = DArray::<f64, 2>::from_elem([3, 4], 1.0);
+    assert!(matches!(
+        ShiftInvert::new(lu, &rectangular, 0.0),
+        Err(EigsError::NotSquare { rows: 3, cols: 4 })
+    ));
+
+    // Rank-one matrix: rounding leaves tiny but nonzero pivots at σ = 0.
+    let x = [0.1, 0.7, 0.3, 0.9];
+    let y = [0.3, 0.9, 0.2, 0.7];
+    let a = DArray::<f64, 2>::from_fn([4, 4], |i| x[i[0]] * y[i[1]]);
+    assert!(matches!(
+        ShiftInvert::new(lu, &a, 0.0),
+        Err(EigsError::SingularShift { .. })
+    ));
+
+    // σ = 2 is an eigenvalue of the identity scaled by 2.
+    let d = DArray::<f64, 2>::from_fn([3, 3], |i| if i[0] == i[1] { 2.0 } else { 0.0 });
+    assert!(matches!(
+        ShiftInvert::new(lu, &d, 2.0),
+        Err(EigsError::SingularShift { pivot: 0 })
+    ));
+}
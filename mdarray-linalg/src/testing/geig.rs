@@ -0,0 +1,349 @@
+use approx::assert_relative_eq;
+use mdarray::DArray;
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{assert_matrix_eq, naive_matmul, random_matrix};
+use crate::{
+    eig::SchurSelect,
+    geig::{GEig, GEigDecomp, GEigError, QzDecomp, reorder_qz},
+};
+
+/// Householder reflector `I - 2 v v^T / (v^T v)` with `v_i = 1 + shift i`.
+fn householder(n: usize, shift: f64) -> DArray<f64, 2> {
+    let v: Vec<f64> = (0..n).map(|i| 1. + i as f64 * shift).collect();
+    let vv = v.iter().map(|x| x * x).sum::<f64>();
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let id = if i[0] == i[1] { 1. } else { 0. };
+        id - 2. * v[i[0]] * v[i[1]] / vv
+    })
+}
+
+/// `U (S, T) V` with orthogonal `U`, `V` and a quasi-triangular pencil
+/// `(S, T)` with eigenvalues `2, -1, 1 ± 2i` and one infinite eigenvalue.
+fn known_pencil() -> (DArray<f64, 2>, DArray<f64, 2>) {
+    let n = 5;
+    let s_diag = [2., -1., 1., 1., 3.];
+    let t_diag = [1., 1., 1., 1., 0.];
+    let s = DArray::<f64, 2>::from_fn([n, n], |i| match (i[0], i[1]) {
+        (r, c) if r == c => s_diag[r],
+        (2, 3) => 2.,
+        (3, 2) => -2.,
+        (r, c) if r < c => ((r + 2 * c) as f64).sin(),
+        _ => 0.,
+    });
+    let t = DArray::<f64, 2>::from_fn([n, n], |i| match (i[0], i[1]) {
+        (r, c) if r == c => t_diag[r],
+        (r, c) if r < c && !(r, c).eq(&(2, 3)) => 0.5 * ((3 * r + c) as f64).cos(),
+        _ => 0.,
+    });
+    let (u, v) = (householder(n, 0.7), householder(n, -0.3));
+    (
+        naive_matmul(&naive_matmul(&u, &s), &v),
+        naive_matmul(&naive_matmul(&u, &t), &v),
+    )
+}
+
+fn complex_pencil(n: usize) -> (DArray<Complex<f64>, 2>, DArray<Complex<f64>, 2>) {
+    let entry = |i: &[usize], shift: usize| {
+        let x = ((1 + i[0]) * (2 + i[1]) + shift) as f64;
+        Complex::new((0.7 * x * x).sin(), (1.3 * x).cos())
+    };
+    (
+        DArray::<Complex<f64>, 2>::from_fn([n, n], |i| entry(i, 0)),
+        DArray::<Complex<f64>, 2>::from_fn([n, n], |i| entry(i, 3)),
+    )
+}
+
+fn to_complex<T: ComplexFloat<Real = f64>>(x: T) -> Complex<f64> {
+    Complex::new(x.re(), x.im())
+}
+
+/// Checks `β A v = α B v` and `β u^H A = α u^H B` for the computed
+/// eigenvectors, and their normalization.
+fn check_geig<T: ComplexFloat<Real = f64>>(
+    a: &DArray<T, 2>,
+    b: &DArray<T, 2>,
+    decomp: &GEigDecomp<Complex<f64>, T, usize, usize>,
+) {
+    let n = a.shape().0;
+    let (ac, bc) = (a.clone().map(to_complex), b.clone().map(to_complex));
+    let scale = ac.iter().chain(bc.iter()).map(|x| x.norm()).fold(1., f64::max);
+    let check_normalized = |v: &DArray<Complex<f64>, 2>, j: usize| {
+        let norm = (0..n).map(|i| v[[i, j]].norm_sqr()).sum::<f64>().sqrt();
+        assert!((norm - 1.).abs() < 1e-10, "eigenvector {j} has norm {norm}");
+    };
+    for j in 0..n {
+        let (alpha, beta) = (decomp.alpha[j], to_complex(decomp.beta[j]));
+        let tol = 1e-10 * scale * (alpha.norm() + beta.norm());
+        if let Some(v) = &decomp.right_eigenvectors {
+            check_normalized(v, j);
+            for i in 0..n {
+                let (av, bv) = (0..n).fold((Complex::new(0., 0.), Complex::new(0., 0.)), |(av, bv), k| {
+                    (av + ac[[i, k]] * v[[k, j]], bv + bc[[i, k]] * v[[k, j]])
+                });
+                let residual = (beta * av - alpha * bv).norm();
+                assert!(residual < tol, "right eigenvector {j}: residual {residual}");
+            }
+        }
+        if let Some(u) = &decomp.left_eigenvectors {
+            check_normalized(u, j);
+            for k in 0..n {
+                let (ua, ub) = (0..n).fold((Complex::new(0., 0.), Complex::new(0., 0.)), |(ua, ub), i| {
+                    (ua + u[[i, j]].conj() * ac[[i, k]], ub + u[[i, j]].conj() * bc[[i, k]])
+                });
+                let residual = (beta * ua - alpha * ub).norm();
+                assert!(residual < tol, "left eigenvector {j}: residual {residual}");
+            }
+        }
+    }
+}
+
+fn sorted_finite_eigenvalues<T: ComplexFloat<Real = f64>>(
+    decomp: &GEigDecomp<Complex<f64>, T, usize, usize>,
+) -> (Vec<Complex<f64>>, usize) {
+    let mut finite = Vec::new();
+    let mut infinite = 0;
+    for (alpha, beta) in decomp.alpha.iter().zip(decomp.beta.iter()) {
+        if beta.abs() < 1e-10 * alpha.norm() {
+            infinite += 1;
+        } else {
+            finite.push(alpha / to_complex(*beta));
+        }
+    }
+    finite.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    (finite, infinite)
+}
+
+pub fn test_geig(bd: &impl GEig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let (a, b) = known_pencil();
+    let decomp = bd.geig(&mut a.clone(), &mut b.clone()).expect("Generalized eigenvalue decomposition failed");
+    assert!(decomp.left_eigenvectors.is_none());
+    assert!(decomp.right_eigenvectors.is_some());
+    assert!(decomp.beta.iter().all(|&beta| beta >= 0.), "{:?}", decomp.beta);
+
+    let (finite, infinite) = sorted_finite_eigenvalues(&decomp);
+    assert_eq!(infinite, 1);
+    let expected = [Complex::new(-1., 0.), Complex::new(1., -2.), Complex::new(1., 2.), Complex::new(2., 0.)];
+    assert_eq!(finite.len(), expected.len());
+    for expected in expected {
+        assert!(
+            finite.iter().any(|lambda| (lambda - expected).norm() < 1e-8),
+            "{expected} not in {finite:?}"
+        );
+    }
+    check_geig(&a, &b, &decomp);
+
+    let decomp = bd.geig_full(&mut a.clone(), &mut b.clone()).expect("Generalized eigenvalue decomposition failed");
+    assert!(decomp.left_eigenvectors.is_some());
+    check_geig(&a, &b, &decomp);
+}
+
+pub fn test_geig_cplx(bd: &impl GEig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let (a, b) = complex_pencil(4);
+    let decomp = bd.geig_full(&mut a.clone(), &mut b.clone()).expect("Generalized eigenvalue decomposition failed");
+    check_geig(&a, &b, &decomp);
+
+    let values = bd.geig_values(&mut a.clone(), &mut b.clone()).expect("Generalized eigenvalue decomposition failed");
+    assert!(values.left_eigenvectors.is_none() && values.right_eigenvectors.is_none());
+    let (mut x, _) = sorted_finite_eigenvalues(&decomp);
+    let (mut y, _) = sorted_finite_eigenvalues(&values);
+    assert_eq!(x.len(), 4);
+    x.sort_by(|x, y| x.norm().total_cmp(&y.norm()));
+    y.sort_by(|x, y| x.norm().total_cmp(&y.norm()));
+    for (x, y) in x.iter().zip(&y) {
+        assert!((x - y).norm() < 1e-8 * x.norm().max(1.), "{x} != {y}");
+    }
+}
+
+pub fn test_geig_values(bd: &impl GEig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let (a, b) = known_pencil();
+    let decomp = bd.geig_values(&mut a.clone(), &mut b.clone()).expect("Generalized eigenvalue decomposition failed");
+    assert!(decomp.left_eigenvectors.is_none() && decomp.right_eigenvectors.is_none());
+    let (finite, infinite) = sorted_finite_eigenvalues(&decomp);
+    assert_eq!((finite.len(), infinite), (4, 1));
+    assert!((finite[0] - Complex::new(-1., 0.)).norm() < 1e-8);
+    assert!((finite[3] - Complex::new(2., 0.)).norm() < 1e-8);
+}
+
+/// Eigenvalues `λ = α / β` of the diagonal blocks of a real generalized
+/// Schur form, in order.
+fn quasi_triangular_pencil_eigenvalues(s: &DArray<f64, 2>, t: &DArray<f64, 2>) -> Vec<Complex<f64>> {
+    let n = s.shape().0;
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut k = 0;
+    while k < n {
+        if k + 1 < n && s[[k + 1, k]] != 0. {
+            // T^-1 S on the block, with T upper triangular.
+            let (t11, t12, t22) = (t[[k, k]], t[[k, k + 1]], t[[k + 1, k + 1]]);
+            let m21 = s[[k + 1, k]] / t22;
+            let m22 = s[[k + 1, k + 1]] / t22;
+            let m11 = (s[[k, k]] - t12 * m21) / t11;
+            let m12 = (s[[k, k + 1]] - t12 * m22) / t11;
+            let mean = (m11 + m22) / 2.;
+            let det = m11 * m22 - m12 * m21;
+            let im = (det - mean * mean).sqrt();
+            eigenvalues.push(Complex::new(mean, im));
+            eigenvalues.push(Complex::new(mean, -im));
+            k += 2;
+        } else if t[[k, k]] == 0. {
+            eigenvalues.push(Complex::new(f64::INFINITY, 0.));
+            k += 1;
+        } else {
+            eigenvalues.push(Complex::new(s[[k, k]] / t[[k, k]], 0.));
+            k += 1;
+        }
+    }
+    eigenvalues
+}
+
+fn check_qz(a: &DArray<f64, 2>, b: &DArray<f64, 2>, qz: &QzDecomp<f64, usize, usize>) {
+    let n = a.shape().0;
+    let QzDecomp { s, t, q, z } = qz;
+    for i in 0..n {
+        for j in 0..i {
+            assert!(t[[i, j]].abs() < 1e-12, "T is not upper triangular at ({i}, {j})");
+            if j + 1 < i {
+                assert!(s[[i, j]].abs() < 1e-12, "S is not quasi-triangular at ({i}, {j})");
+            }
+        }
+    }
+    for i in 1..n - 1 {
+        assert!(s[[i, i - 1]] == 0. || s[[i + 1, i]] == 0., "S has a 3 × 3 diagonal block at {i}");
+    }
+    let zt = z.transpose().to_tensor();
+    assert_matrix_eq!(a, &naive_matmul(&naive_matmul(q, s), &zt));
+    assert_matrix_eq!(b, &naive_matmul(&naive_matmul(q, t), &zt));
+    let identity = DArray::<f64, 2>::from_fn([n, n], |i| if i[0] == i[1] { 1. } else { 0. });
+    assert_matrix_eq!(&identity, &naive_matmul(&q.transpose().to_tensor(), q));
+    assert_matrix_eq!(&identity, &naive_matmul(&zt, z));
+}
+
+pub fn test_qz(bd: &impl GEig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 6;
+    let (a, b) = (random_matrix(n, n), random_matrix(n, n));
+    let qz = bd.qz(&mut a.clone(), &mut b.clone()).expect("QZ decomposition failed");
+    assert_eq!(qz.s.shape(), &(n, n));
+    check_qz(&a, &b, &qz);
+
+    let (a, b) = known_pencil();
+    let qz = bd.qz(&mut a.clone(), &mut b.clone()).expect("QZ decomposition failed");
+    check_qz(&a, &b, &qz);
+    let eigenvalues = quasi_triangular_pencil_eigenvalues(&qz.s, &qz.t);
+    assert_eq!(eigenvalues.iter().filter(|lambda| lambda.im != 0.).count(), 2);
+}
+
+pub fn test_qz_cplx(bd: &impl GEig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let (a, b) = complex_pencil(n);
+    let QzDecomp { s, t, q, z } = bd.qz(&mut a.clone(), &mut b.clone()).expect("QZ decomposition failed");
+    for i in 0..n {
+        for j in 0..i {
+            assert!(s[[i, j]].norm() < 1e-12, "S is not upper triangular at ({i}, {j})");
+            assert!(t[[i, j]].norm() < 1e-12, "T is not upper triangular at ({i}, {j})");
+        }
+    }
+    let zh = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| z[[i[1], i[0]]].conj());
+    for (m, f) in [(&a, &s), (&b, &t)] {
+        let reconstructed = naive_matmul(&naive_matmul(&q, f), &zh);
+        for (x, y) in m.iter().zip(reconstructed.iter()) {
+            assert!((x - y).norm() < 1e-8, "{x} != {y}");
+        }
+    }
+}
+
+pub fn test_qz_reorder(bd: &impl GEig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let (a, b) = known_pencil();
+    let qz0 = bd.qz(&mut a.clone(), &mut b.clone()).expect("QZ decomposition failed");
+
+    // The complex pair and -1 move to the front.
+    let select = |lambda: Complex<f64>| lambda.re < 1.5;
+    let QzDecomp { mut s, mut t, mut q, mut z } = bd.qz(&mut a.clone(), &mut b.clone()).unwrap();
+    let dim = bd
+        .qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Predicate(&select))
+        .expect("QZ reordering failed");
+    assert_eq!(dim, 3);
+    let reordered = QzDecomp { s, t, q, z };
+    check_qz(&a, &b, &reordered);
+    let eigenvalues = quasi_triangular_pencil_eigenvalues(&reordered.s, &reordered.t);
+    assert!(eigenvalues[..3].iter().all(|&lambda| select(lambda)), "{eigenvalues:?}");
+    assert!(eigenvalues[3..].iter().all(|&lambda| !select(lambda)), "{eigenvalues:?}");
+
+    // The generic implementation agrees.
+    let QzDecomp { mut s, mut t, mut q, mut z } = bd.qz(&mut a.clone(), &mut b.clone()).unwrap();
+    let dim = reorder_qz(&mut s, &mut t, &mut q, &mut z, &SchurSelect::Predicate(&select)).unwrap();
+    assert_eq!(dim, 3);
+    let generic = QzDecomp { s, t, q, z };
+    check_qz(&a, &b, &generic);
+    let eigenvalues = quasi_triangular_pencil_eigenvalues(&generic.s, &generic.t);
+    assert!(eigenvalues[..3].iter().all(|&lambda| select(lambda)), "{eigenvalues:?}");
+
+    // Predicates see the infinite eigenvalue as infinite.
+    let QzDecomp { mut s, mut t, mut q, mut z } = bd.qz(&mut a.clone(), &mut b.clone()).unwrap();
+    let infinite = |lambda: Complex<f64>| lambda.re.is_infinite();
+    let dim = bd
+        .qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Predicate(&infinite))
+        .expect("QZ reordering failed");
+    assert_eq!(dim, 1);
+    assert!(t[[0, 0]].abs() < 1e-10 && s[[0, 0]].abs() > 1e-3);
+    check_qz(&a, &b, &QzDecomp { s, t, q, z });
+
+    // Selecting one eigenvalue of a conjugate pair selects both.
+    let QzDecomp { mut s, mut t, mut q, mut z } = qz0;
+    let eigenvalues = quasi_triangular_pencil_eigenvalues(&s, &t);
+    let mut mask = vec![false; n];
+    mask[eigenvalues.iter().position(|lambda| lambda.im < 0.).unwrap()] = true;
+    let dim = bd
+        .qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Mask(&mask))
+        .expect("QZ reordering failed");
+    assert_eq!(dim, 2);
+    let eigenvalues = quasi_triangular_pencil_eigenvalues(&s, &t);
+    assert!((eigenvalues[0] - Complex::new(1., 2.)).norm() < 1e-8, "{eigenvalues:?}");
+    check_qz(&a, &b, &QzDecomp { s, t, q, z });
+}
+
+pub fn test_qz_reorder_cplx(bd: &impl GEig<Complex<f64>, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let n = 5;
+    let (a, b) = complex_pencil(n);
+    let QzDecomp { mut s, mut t, mut q, mut z } = bd.qz(&mut a.clone(), &mut b.clone()).expect("QZ decomposition failed");
+
+    let select = |lambda: Complex<f64>| lambda.im > 0.;
+    let expected = (0..n).filter(|&i| select(s[[i, i]] / t[[i, i]])).count();
+    assert!(expected > 0 && expected < n);
+    let dim = bd
+        .qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Predicate(&select))
+        .expect("QZ reordering failed");
+    assert_eq!(dim, expected);
+    for i in 0..n {
+        assert_eq!(select(s[[i, i]] / t[[i, i]]), i < expected, "{:?}", s[[i, i]] / t[[i, i]]);
+        for j in 0..i {
+            assert!(s[[i, j]].norm() < 1e-12, "S is not upper triangular at ({i}, {j})");
+            assert!(t[[i, j]].norm() < 1e-12, "T is not upper triangular at ({i}, {j})");
+        }
+    }
+    let zh = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| z[[i[1], i[0]]].conj());
+    for (m, f) in [(&a, &s), (&b, &t)] {
+        let reconstructed = naive_matmul(&naive_matmul(&q, f), &zh);
+        for (x, y) in m.iter().zip(reconstructed.iter()) {
+            assert!((x - y).norm() < 1e-8, "{x} != {y}");
+        }
+    }
+}
+
+pub fn test_geig_invalid(bd: &impl GEig<f64, usize, usize, SpectralScalar = Complex<f64>, RealScalar = f64>) {
+    let a = random_matrix(3, 4);
+    let b = random_matrix(3, 4);
+    assert!(matches!(bd.geig(&mut a.clone(), &mut b.clone()), Err(GEigError::NotSquareMatrix)));
+    assert!(matches!(bd.qz(&mut a.clone(), &mut b.clone()), Err(GEigError::NotSquareMatrix)));
+
+    let a = random_matrix(3, 3);
+    let b = random_matrix(2, 2);
+    assert!(matches!(bd.geig_values(&mut a.clone(), &mut b.clone()), Err(GEigError::ShapeMismatch)));
+
+    let (a, b) = known_pencil();
+    let QzDecomp { mut s, mut t, mut q, mut z } = bd.qz(&mut a.clone(), &mut b.clone()).unwrap();
+    assert!(matches!(
+        bd.qz_reorder(&mut s, &mut t, &mut q, &mut z, SchurSelect::Mask(&[true; 3])),
+        Err(GEigError::InvalidSelection)
+    ));
+}
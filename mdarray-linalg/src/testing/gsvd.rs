@@ -0,0 +1,140 @@
+use approx::assert_relative_eq;
+use mdarray::{DArray, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{naive_matmul, random_matrix};
+use crate::gsvd::{GSVD, GSVDDecomp, GSVDError};
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, b: &DArray<T, 2>) {
+    assert_eq!(a.shape(), b.shape(), "Matrix shapes don't match");
+    let (m, n) = *a.shape();
+    for i in 0..m {
+        for j in 0..n {
+            assert!(
+                (a[[i, j]] - b[[i, j]]).abs() < 1e-10,
+                "Entries ({i}, {j}) differ"
+            );
+        }
+    }
+}
+
+fn adjoint<T: ComplexFloat>(a: &DArray<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+fn assert_unitary<T: ComplexFloat<Real = f64>>(q: &DArray<T, 2>) {
+    let n = q.shape().0;
+    let identity = DArray::<T, 2>::from_fn([n, n], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+    assert_close(&naive_matmul(&adjoint(q), q), &identity);
+}
+
+/// Checks the structure of the GSVD of `(a, b)` and that it reconstructs
+/// both matrices.
+fn test_gsvd_reconstruction<T>(
+    bd: &impl GSVD<T, Dyn, SingularValue = T>,
+    a: &DArray<T, 2>,
+    b: &DArray<T, 2>,
+) -> GSVDDecomp<T, T, Dyn>
+where
+    T: ComplexFloat<Real = f64> + Default + std::fmt::Debug,
+{
+    let (m, n) = *a.shape();
+    let p = b.shape().0;
+
+    let decomp = bd
+        .gsvd(&mut a.clone(), &mut b.clone())
+        .expect("GSVD failed");
+    let (k, l) = (decomp.k, decomp.l);
+
+    assert!(k + l <= n);
+    assert_eq!(*decomp.u.shape(), (m, m));
+    assert_eq!(*decomp.v.shape(), (p, p));
+    assert_eq!(*decomp.q.shape(), (n, n));
+    assert_eq!(*decomp.r.shape(), (k + l, k + l));
+    assert_unitary(&decomp.u);
+    assert_unitary(&decomp.v);
+    assert_unitary(&decomp.q);
+
+    for i in 0..n {
+        let (alpha, beta) = (decomp.alpha[i].re(), decomp.beta[i].re());
+        if i < k {
+            assert_relative_eq!(alpha, 1.0, epsilon = 1e-10);
+            assert_relative_eq!(beta, 0.0, epsilon = 1e-10);
+        } else if i < k + l {
+            assert!(alpha >= 0.0 && beta >= 0.0);
+            assert_relative_eq!(alpha * alpha + beta * beta, 1.0, epsilon = 1e-10);
+        } else {
+            assert_relative_eq!(alpha, 0.0, epsilon = 1e-10);
+            assert_relative_eq!(beta, 0.0, epsilon = 1e-10);
+        }
+    }
+    for i in 0..k + l {
+        for j in 0..i {
+            assert_eq!(decomp.r[[i, j]], T::zero(), "R must be upper triangular");
+        }
+    }
+
+    let zero_r_qh = naive_matmul(&decomp.zero_r(), &adjoint(&decomp.q));
+    assert_close(a, &naive_matmul(&naive_matmul(&decomp.u, &decomp.sigma_a()), &zero_r_qh));
+    assert_close(b, &naive_matmul(&naive_matmul(&decomp.v, &decomp.sigma_b()), &zero_r_qh));
+
+    decomp
+}
+
+pub fn test_gsvd(bd: &impl GSVD<f64, Dyn, SingularValue = f64>) {
+    // [A; B] has full column rank and m ≥ k + l.
+    let decomp = test_gsvd_reconstruction(bd, &random_matrix(5, 4), &random_matrix(3, 4));
+    assert_eq!((decomp.k, decomp.l), (1, 3));
+}
+
+pub fn test_gsvd_wide(bd: &impl GSVD<f64, Dyn, SingularValue = f64>) {
+    // m < k + l: the trailing rows of R are stored in B.
+    let decomp = test_gsvd_reconstruction(bd, &random_matrix(2, 4), &random_matrix(3, 4));
+    assert_eq!((decomp.k, decomp.l), (1, 3));
+}
+
+pub fn test_gsvd_rank_deficient(bd: &impl GSVD<f64, Dyn, SingularValue = f64>) {
+    // The rows of B are multiples of rows of A, so [A; B] has rank 3 < n.
+    let a = random_matrix(3, 5);
+    let b = DArray::<f64, 2>::from_fn([2, 5], |i| 2.0 * a[[i[0], i[1]]]);
+    let decomp = test_gsvd_reconstruction(bd, &a, &b);
+    assert_eq!(decomp.k + decomp.l, 3);
+}
+
+pub fn test_gsvd_identity(bd: &impl GSVD<f64, Dyn, SingularValue = f64>) {
+    // With B = I, the generalized singular values are the singular values
+    // of A, here 3, 2 and 1/2.
+    let a = DArray::<f64, 2>::from_fn([4, 3], |i| match (i[0], i[1]) {
+        (0, 1) => 2.0,
+        (1, 0) => -3.0,
+        (3, 2) => 0.5,
+        _ => 0.0,
+    });
+    let b = DArray::<f64, 2>::from_fn([3, 3], |i| if i[0] == i[1] { 1.0 } else { 0.0 });
+
+    let decomp = test_gsvd_reconstruction(bd, &a, &b);
+    assert_eq!((decomp.k, decomp.l), (0, 3));
+    let mut sigma: Vec<f64> = (0..3).map(|i| decomp.alpha[i] / decomp.beta[i]).collect();
+    sigma.sort_by(|x, y| y.total_cmp(x));
+    for (x, y) in sigma.iter().zip([3.0, 2.0, 0.5]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+}
+
+pub fn test_gsvd_complex(bd: &impl GSVD<Complex<f64>, Dyn, SingularValue = Complex<f64>>) {
+    let entry = |i: &[usize], shift: usize| {
+        let x = ((1 + i[0]) * (2 + i[1]) + shift) as f64;
+        Complex::new((0.7 * x * x).sin(), (1.3 * x).cos())
+    };
+    let a = DArray::<Complex<f64>, 2>::from_fn([4, 3], |i| entry(i, 0));
+    let b = DArray::<Complex<f64>, 2>::from_fn([3, 3], |i| entry(i, 5));
+    test_gsvd_reconstruction(bd, &a, &b);
+}
+
+pub fn test_gsvd_shape_mismatch(bd: &impl GSVD<f64, Dyn, SingularValue = f64>) {
+    assert!(matches!(
+        bd.gsvd(&mut random_matrix(3, 4), &mut random_matrix(3, 3)),
+        Err(GSVDError::ShapeMismatch)
+    ));
+}
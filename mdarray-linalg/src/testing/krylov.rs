@@ -0,0 +1,309 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    krylov::{KrylovConfig, KrylovError, KrylovInfo, bicgstab, cg, gmres, minres},
+    matvec::{MatVec, MatVecBuilder, VecOps},
+    precond::Identity,
+};
+
+/// 1D Laplacian `tridiag(-1, 2, -1)` plus `shift` on the diagonal.
+fn laplacian(n: usize, shift: f64) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([n, n], |i| match i[0].abs_diff(i[1]) {
+        0 => 2.0 + shift,
+        1 => -1.0,
+        _ => 0.0,
+    })
+}
+
+/// Convection–diffusion matrix with a varying diagonal, non-symmetric.
+fn convection_diffusion(n: usize) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        if i[0] == i[1] {
+            2.5 + (i[0] % 7) as f64
+        } else if i[1] == i[0] + 1 {
+            -1.4
+        } else if i[0] == i[1] + 1 {
+            -0.6
+        } else {
+            0.0
+        }
+    })
+}
+
+fn rhs<T: ComplexFloat<Real = f64>>(n: usize) -> DArray<T, 1> {
+    DArray::<T, 1>::from_fn([n], |i| T::from((i[0] as f64 * 0.37).sin() + 0.5).unwrap())
+}
+
+fn zeros<T: ComplexFloat>(n: usize) -> DArray<T, 1> {
+    DArray::<T, 1>::from_elem([n], T::zero())
+}
+
+fn jacobi<T: ComplexFloat>(a: &DArray<T, 2>) -> impl Fn(&DSlice<T, 1>, &mut DSlice<T, 1>) + '_ {
+    move |r, z| {
+        for i in 0..r.len() {
+            z[i] = r[i] / a[[i, i]];
+        }
+    }
+}
+
+/// `‖b - A x‖ / ‖b‖`, computed independently of the solvers.
+fn true_residual<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, b: &DArray<T, 1>, x: &DArray<T, 1>) -> f64 {
+    let n = b.len();
+    let r: f64 = (0..n)
+        .map(|i| {
+            let ax = (0..n).fold(T::zero(), |acc, j| acc + a[[i, j]] * x[j]);
+            (b[i] - ax).abs().powi(2)
+        })
+        .sum();
+    let b_norm: f64 = b.iter().map(|v| v.abs().powi(2)).sum();
+    (r / b_norm).sqrt()
+}
+
+fn check_info(info: &KrylovInfo<f64>, tol: f64) {
+    assert_eq!(info.history.len(), info.iterations + 1);
+    assert_eq!(info.residual, *info.history.last().unwrap());
+    assert!(info.residual <= tol);
+    assert!((info.history[0] - 1.0).abs() < 1e-12);
+}
+
+pub fn test_cg(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 100;
+    let a = laplacian(n, 0.01);
+    let b = rhs::<f64>(n);
+    let config = KrylovConfig::default();
+
+    let mut x = zeros(n);
+    let info = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config)
+        .expect("CG did not converge");
+    check_info(&info, 1e-10);
+    assert!(info.iterations <= n + 5);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_cg_preconditioned(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 80;
+    // Strongly varying diagonal, for which Jacobi preconditioning pays off.
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| match i[0].abs_diff(i[1]) {
+        0 => 2.0 + (i[0] * i[0]) as f64,
+        1 => -1.0,
+        _ => 0.0,
+    });
+    let b = rhs::<f64>(n);
+    let config = KrylovConfig::default();
+
+    let mut x = zeros(n);
+    let plain = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config)
+        .expect("CG did not converge");
+
+    let mut x = zeros(n);
+    let info = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &jacobi(&a), &config)
+        .expect("preconditioned CG did not converge");
+    check_info(&info, 1e-10);
+    assert!(info.iterations < plain.iterations);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_cg_complex(
+    mv: &impl MatVec<Complex<f64>, Dyn, Dyn>,
+    bd: &impl VecOps<Complex<f64>, Dyn, Real = f64>,
+) {
+    let n = 60;
+    let re = laplacian(n, 0.5);
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let im = match (i[0] as isize) - (i[1] as isize) {
+            1 => 0.3,
+            -1 => -0.3,
+            _ => 0.0,
+        };
+        Complex::new(re[[i[0], i[1]]], im)
+    });
+    let b = rhs::<Complex<f64>>(n);
+
+    let mut x = zeros(n);
+    let info = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("CG did not converge");
+    check_info(&info, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_cg_breakdown(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    // Indefinite: p^T A p vanishes in the first step.
+    let a = DArray::<f64, 2>::from_fn([2, 2], |i| match (i[0], i[1]) {
+        (0, 0) => 1.0,
+        (1, 1) => -1.0,
+        _ => 0.0,
+    });
+    let b = DArray::<f64, 1>::from_elem([2], 1.0);
+    let mut x = zeros(2);
+    let result = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default());
+    assert!(matches!(result, Err(KrylovError::Breakdown { iteration: 1, .. })));
+}
+
+pub fn test_minres_indefinite(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 100;
+    // The Laplacian spectrum lies in (0, 4); the shift makes it indefinite.
+    let a = laplacian(n, -1.03);
+    let b = rhs::<f64>(n);
+
+    let mut x = zeros(n);
+    let info = minres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("MINRES did not converge");
+    check_info(&info, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-8);
+
+    // MINRES residuals never increase.
+    assert!(info.history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+}
+
+pub fn test_minres_preconditioned(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl VecOps<f64, Dyn, Real = f64>,
+) {
+    let n = 80;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| match i[0].abs_diff(i[1]) {
+        0 if i[0] % 2 == 0 => 3.0 + i[0] as f64,
+        0 => -3.0 - i[0] as f64,
+        1 => 1.0,
+        _ => 0.0,
+    });
+    let b = rhs::<f64>(n);
+    // |diag(A)|^{-1} is positive definite.
+    let precond = |r: &DSlice<f64, 1>, z: &mut DSlice<f64, 1>| {
+        for i in 0..r.len() {
+            z[i] = r[i] / a[[i, i]].abs();
+        }
+    };
+
+    let mut x = zeros(n);
+    minres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &precond, &KrylovConfig::default())
+        .expect("preconditioned MINRES did not converge");
+    assert!(true_residual(&a, &b, &x) < 1e-8);
+}
+
+pub fn test_gmres(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 120;
+    let a = convection_diffusion(n);
+    let b = rhs::<f64>(n);
+    let config = KrylovConfig {
+        restart: 10,
+        ..Default::default()
+    };
+
+    let mut x = zeros(n);
+    let info = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config)
+        .expect("GMRES did not converge");
+    check_info(&info, 1e-10);
+    assert!(info.iterations > 10, "expected at least one restart");
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+    assert!(info.history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-8)));
+
+    let mut x = zeros(n);
+    let precond = jacobi(&a);
+    let preconditioned = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &precond, &config)
+        .expect("preconditioned GMRES did not converge");
+    check_info(&preconditioned, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_gmres_complex(
+    mv: &impl MatVec<Complex<f64>, Dyn, Dyn>,
+    bd: &impl VecOps<Complex<f64>, Dyn, Real = f64>,
+) {
+    let n = 60;
+    let re = convection_diffusion(n);
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let im = if i[0] == i[1] { 0.7 } else { 0.1 * re[[i[0], i[1]]] };
+        Complex::new(re[[i[0], i[1]]], im)
+    });
+    let b = rhs::<Complex<f64>>(n);
+
+    let mut x = zeros(n);
+    let info = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("GMRES did not converge");
+    check_info(&info, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_bicgstab(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 120;
+    let a = convection_diffusion(n);
+    let b = rhs::<f64>(n);
+
+    let mut x = zeros(n);
+    let info = bicgstab(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("BiCGSTAB did not converge");
+    check_info(&info, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+
+    let mut x = zeros(n);
+    bicgstab(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &jacobi(&a), &KrylovConfig::default())
+        .expect("preconditioned BiCGSTAB did not converge");
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_bicgstab_complex(
+    mv: &impl MatVec<Complex<f64>, Dyn, Dyn>,
+    bd: &impl VecOps<Complex<f64>, Dyn, Real = f64>,
+) {
+    let n = 60;
+    let re = convection_diffusion(n);
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        Complex::new(re[[i[0], i[1]]], if i[0] == i[1] { -0.4 } else { 0.0 })
+    });
+    let b = rhs::<Complex<f64>>(n);
+
+    let mut x = zeros(n);
+    let info = bicgstab(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("BiCGSTAB did not converge");
+    check_info(&info, 1e-10);
+    assert!(true_residual(&a, &b, &x) < 1e-9);
+}
+
+pub fn test_krylov_not_converged(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 100;
+    let a = laplacian(n, 0.0);
+    let b = rhs::<f64>(n);
+    let config = KrylovConfig {
+        max_iterations: Some(3),
+        ..Default::default()
+    };
+
+    let mut x = zeros(n);
+    let result = cg(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config);
+    match result {
+        Err(KrylovError::NotConverged {
+            iterations,
+            residual,
+            history,
+        }) => {
+            assert_eq!(iterations, 3);
+            assert_eq!(history.len(), 4);
+            assert_eq!(residual, history[3]);
+            assert!(residual > 1e-10);
+        }
+        other => panic!("expected NotConverged, got {other:?}"),
+    }
+    // The last iterate is kept.
+    assert!(x.iter().any(|&v| v != 0.0));
+
+    let mut x = zeros(n);
+    let result = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config);
+    assert!(matches!(result, Err(KrylovError::NotConverged { iterations: 3, .. })));
+}
+
+pub fn test_krylov_trivial(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 10;
+    let a = laplacian(n, 0.0);
+    let b = zeros::<f64>(n);
+
+    let mut x = zeros(n);
+    let info = minres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("zero right-hand side");
+    assert_eq!(info.iterations, 0);
+    assert!(x.iter().all(|&v| v == 0.0));
+
+    let mut x = zeros(n + 1);
+    let result = bicgstab(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &KrylovConfig::default());
+    assert!(matches!(result, Err(KrylovError::DimensionMismatch { b: 10, x: 11 })));
+}
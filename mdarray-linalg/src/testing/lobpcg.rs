@@ -0,0 +1,217 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    contract::Contract,
+    eig::{Eig, EighDecomp},
+    lobpcg::{LobpcgConfig, LobpcgError, lobpcg},
+    lu::LU,
+    matvec::MatVec,
+    operator::{DenseOperator, FnOperator, LinearOperator},
+    precond::{Identity, Jacobi},
+};
+
+/// 5-point Laplacian on a `k × k` grid.
+fn laplacian_2d(k: usize) -> DArray<f64, 2> {
+    let n = k * k;
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if r == c {
+            4.0
+        } else if (r.abs_diff(c) == 1 && r / k == c / k) || r.abs_diff(c) == k {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+fn start_block<T: ComplexFloat<Real = f64>>(n: usize, k: usize) -> DArray<T, 2> {
+    // A distinct frequency per column keeps the block of full rank.
+    DArray::<T, 2>::from_fn([n, k], |i| T::from((0.731 * ((i[1] + 3) * (i[0] + 1)) as f64).sin()).unwrap())
+}
+
+fn sorted_eigenvalues<T, B>(bd: &B, a: &DArray<T, 2>) -> Vec<f64>
+where
+    T: ComplexFloat<Real = f64>,
+    B: Eig<T, Dyn, Dyn, RealScalar = f64>,
+{
+    let mut values: Vec<f64> = bd.eigh(&mut a.clone()).expect("eigh failed").eigenvalues.into_iter().collect();
+    values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    values
+}
+
+/// Checks eigenvalues, residuals `‖A x - λ B x‖` and `B`-orthonormality.
+fn check_pairs<T: ComplexFloat<Real = f64>>(
+    a: &impl LinearOperator<T>,
+    b: Option<&dyn LinearOperator<T>>,
+    decomp: &EighDecomp<T, f64, Dyn, Dyn>,
+    expected: &[f64],
+    tol: f64,
+) {
+    let n = a.shape().0;
+    let k = expected.len();
+    assert_eq!(*decomp.eigenvalues.shape(), (k,));
+    assert_eq!(*decomp.eigenvectors.shape(), (n, k));
+
+    let mut ax = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut bx = ax.clone();
+    let column = |j: usize| DArray::<T, 1>::from_fn([n], |i| decomp.eigenvectors[[i[0], j]]);
+    for (j, &target) in expected.iter().enumerate() {
+        let lambda = decomp.eigenvalues[j];
+        assert!((lambda - target).abs() < tol * target.abs().max(1.0), "{lambda} != {target}");
+
+        let x = column(j);
+        a.apply(&x, &mut ax);
+        match b {
+            Some(b) => b.apply(&x, &mut bx),
+            None => bx.iter_mut().zip(x.iter()).for_each(|(b, &x)| *b = x),
+        }
+        let res: f64 = (0..n).map(|i| (ax[i] - bx[i] * T::from(lambda).unwrap()).abs().powi(2)).sum();
+        assert!(res.sqrt() < 1e-6 * lambda.abs().max(1.0));
+
+        for l in 0..k {
+            let xl = column(l);
+            let dot = (0..n).fold(T::zero(), |acc, i| acc + xl[i].conj() * bx[i]);
+            let id = if l == j { 1.0 } else { 0.0 };
+            assert!((dot - T::from(id).unwrap()).abs() < 1e-8);
+        }
+    }
+}
+
+pub fn test_lobpcg_smallest<B>(mm: &(impl Contract<f64> + MatVec<f64, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<f64, Dyn, Dyn, RealScalar = f64> + LU<f64, Dyn, Dyn>,
+{
+    let a = laplacian_2d(12);
+    let n = 144;
+    let reference = sorted_eigenvalues(bd, &a);
+    let op = DenseOperator::new(mm, &a);
+
+    let decomp = lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(n, 4), &LobpcgConfig::default())
+        .expect("LOBPCG failed");
+    check_pairs(&op, None, &decomp, &reference[..4], 1e-9);
+}
+
+pub fn test_lobpcg_largest<B>(mm: &(impl Contract<f64> + MatVec<f64, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<f64, Dyn, Dyn, RealScalar = f64> + LU<f64, Dyn, Dyn>,
+{
+    let n = 90;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| match i[0].abs_diff(i[1]) {
+        0 => (i[0] as f64).sqrt(),
+        1 => 0.5,
+        _ => 0.0,
+    });
+    let reference = sorted_eigenvalues(bd, &a);
+    let expected: Vec<f64> = reference.iter().rev().take(3).copied().collect();
+    let op = DenseOperator::new(mm, &a);
+
+    let config = LobpcgConfig {
+        largest: true,
+        ..Default::default()
+    };
+    let decomp = lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(n, 3), &config).expect("LOBPCG failed");
+    check_pairs(&op, None, &decomp, &expected, 1e-9);
+}
+
+pub fn test_lobpcg_generalized<B>(mm: &(impl Contract<f64> + MatVec<f64, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<f64, Dyn, Dyn, RealScalar = f64> + LU<f64, Dyn, Dyn>,
+{
+    let a = laplacian_2d(10);
+    let n = 100;
+    let mass: Vec<f64> = (0..n).map(|i| 1.0 + 0.5 * (i as f64).sin()).collect();
+
+    // A x = λ M x with diagonal M is equivalent to M^{-1/2} A M^{-1/2} y = λ y.
+    let scaled = DArray::<f64, 2>::from_fn([n, n], |i| a[[i[0], i[1]]] / (mass[i[0]] * mass[i[1]]).sqrt());
+    let reference = sorted_eigenvalues(bd, &scaled);
+
+    let apply_mass = |x: &DSlice<f64, 1>, y: &mut DSlice<f64, 1>| {
+        for i in 0..x.len() {
+            y[i] = mass[i] * x[i];
+        }
+    };
+    let b = FnOperator::new(n, n, apply_mass, apply_mass);
+    let op = DenseOperator::new(mm, &a);
+    let precond = Jacobi::new(&a).expect("Jacobi failed");
+
+    let decomp = lobpcg(mm, bd, bd, &op, Some(&b), &precond, &start_block(n, 5), &LobpcgConfig::default())
+        .expect("LOBPCG failed");
+    check_pairs(&op, Some(&b), &decomp, &reference[..5], 1e-9);
+}
+
+pub fn test_lobpcg_clustered<B>(mm: &(impl Contract<f64> + MatVec<f64, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<f64, Dyn, Dyn, RealScalar = f64> + LU<f64, Dyn, Dyn>,
+{
+    // Q diag(d) Q^T with a tight cluster at the bottom of the spectrum and a
+    // Householder reflector Q.
+    let n = 80;
+    let d: Vec<f64> = (0..n).map(|i| if i < 4 { 1.0 + 1e-4 * i as f64 } else { 1.5 + i as f64 }).collect();
+    let v: Vec<f64> = (0..n).map(|i| (0.4 * i as f64).cos() + 1.2).collect();
+    let vv: f64 = v.iter().map(|x| x * x).sum();
+    let q = |i: usize, j: usize| (if i == j { 1.0 } else { 0.0 }) - 2.0 * v[i] * v[j] / vv;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| (0..n).map(|l| q(i[0], l) * d[l] * q(i[1], l)).sum());
+    let op = DenseOperator::new(mm, &a);
+
+    let config = LobpcgConfig {
+        tol: 1e-9,
+        ..Default::default()
+    };
+    let decomp = lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(n, 4), &config).expect("LOBPCG failed");
+    check_pairs(&op, None, &decomp, &d[..4], 1e-9);
+}
+
+pub fn test_lobpcg_complex<B>(mm: &(impl Contract<Complex<f64>> + MatVec<Complex<f64>, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<Complex<f64>, Dyn, Dyn, RealScalar = f64> + LU<Complex<f64>, Dyn, Dyn>,
+{
+    let n = 64;
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| match (i[0] as isize) - (i[1] as isize) {
+        0 => Complex::new(2.0 + 0.05 * i[0] as f64, 0.0),
+        1 => Complex::new(-1.0, 0.3),
+        -1 => Complex::new(-1.0, -0.3),
+        _ => Complex::new(0.0, 0.0),
+    });
+    let reference = sorted_eigenvalues(bd, &a);
+    let op = DenseOperator::new(mm, &a);
+
+    let decomp = lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(n, 3), &LobpcgConfig::default())
+        .expect("LOBPCG failed");
+    check_pairs(&op, None, &decomp, &reference[..3], 1e-9);
+}
+
+pub fn test_lobpcg_errors<B>(mm: &(impl Contract<f64> + MatVec<f64, Dyn, Dyn>), bd: &B)
+where
+    B: Eig<f64, Dyn, Dyn, RealScalar = f64> + LU<f64, Dyn, Dyn>,
+{
+    let a = laplacian_2d(4);
+    let op = DenseOperator::new(mm, &a);
+
+    assert!(matches!(
+        lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(16, 6), &LobpcgConfig::default()),
+        Err(LobpcgError::InvalidDimensions { n: 16, k: 6 })
+    ));
+
+    let config = LobpcgConfig {
+        tol: 0.0,
+        max_iterations: 2,
+        ..Default::default()
+    };
+    assert!(matches!(
+        lobpcg(mm, bd, bd, &op, None, &Identity, &start_block(16, 2), &config),
+        Err(LobpcgError::NotConverged {
+            requested: 2,
+            iterations: 2,
+            ..
+        })
+    ));
+
+    // Dependent starting vectors
+    let x0 = DArray::<f64, 2>::from_elem([16, 2], 1.0);
+    assert!(matches!(
+        lobpcg(mm, bd, bd, &op, None, &Identity, &x0, &LobpcgConfig::default()),
+        Err(LobpcgError::Breakdown { iteration: 0 })
+    ));
+}
@@ -4,9 +4,24 @@
 
 pub mod common;
 pub mod eig;
+pub mod eigs;
+pub mod geig;
+pub mod gsvd;
+pub mod krylov;
+pub mod lobpcg;
 pub mod lu;
 pub mod contract;
 pub mod matvec;
+pub mod operator;
+pub mod polar;
+pub mod precond;
 pub mod qr;
+pub mod randomized;
+pub mod riccati;
 pub mod solve;
+pub mod sparse;
+pub mod sparse_solve;
+pub mod structured;
 pub mod svd;
+pub mod sylvester;
+pub mod toeplitz;
@@ -0,0 +1,142 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::Complex;
+
+use crate::{
+    krylov::{KrylovConfig, cg},
+    matvec::{MatVec, VecOps},
+    operator::{Adjoint, Composed, DenseOperator, FnOperator, LinearOperator, Scaled, Sum, Transpose},
+    precond::Identity,
+};
+
+type C = Complex<f64>;
+
+fn complex_matrix(m: usize, n: usize, seed: f64) -> DArray<C, 2> {
+    DArray::<C, 2>::from_fn([m, n], |i| {
+        let t = seed + (3 * i[0] + 5 * i[1]) as f64;
+        C::new(t.sin(), (0.7 * t).cos())
+    })
+}
+
+/// Builds the matrix of `op` column by column.
+fn materialize(op: &impl LinearOperator<C>) -> DArray<C, 2> {
+    let (m, n) = op.shape();
+    let mut a = DArray::<C, 2>::from_elem([m, n], C::new(0.0, 0.0));
+    let mut y = DArray::<C, 1>::from_elem([m], C::new(0.0, 0.0));
+    for j in 0..n {
+        let e = DArray::<C, 1>::from_fn([n], |i| C::new(if i[0] == j { 1.0 } else { 0.0 }, 0.0));
+        op.apply(&e, &mut y);
+        for i in 0..m {
+            a[[i, j]] = y[i];
+        }
+    }
+    a
+}
+
+/// Checks the matrix of `op` and of its adjoint against `expected`.
+fn check_operator(op: &impl LinearOperator<C>, expected: &DArray<C, 2>) {
+    let (m, n) = *expected.shape();
+    assert_eq!(op.shape(), (m, n));
+
+    let a = materialize(op);
+    let ah = materialize(&Adjoint::new(op));
+    for i in 0..m {
+        for j in 0..n {
+            assert!((a[[i, j]] - expected[[i, j]]).norm() < 1e-12);
+            assert!((ah[[j, i]] - expected[[i, j]].conj()).norm() < 1e-12);
+        }
+    }
+}
+
+fn matmul(a: &DArray<C, 2>, b: &DArray<C, 2>) -> DArray<C, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    DArray::<C, 2>::from_fn([m, n], |i| {
+        (0..k).fold(C::new(0.0, 0.0), |acc, l| acc + a[[i[0], l]] * b[[l, i[1]]])
+    })
+}
+
+pub fn test_dense_operator(mv: &impl MatVec<C, Dyn, Dyn>) {
+    let a = complex_matrix(4, 3, 0.0);
+    let op = DenseOperator::new(mv, &a);
+    check_operator(&op, &a);
+
+    let d = op.diagonal().expect("dense diagonal");
+    assert_eq!(d.len(), 3);
+    for i in 0..3 {
+        assert_eq!(d[i], a[[i, i]]);
+    }
+}
+
+pub fn test_operator_combinations(mv: &impl MatVec<C, Dyn, Dyn>) {
+    let a = complex_matrix(4, 3, 0.0);
+    let b = complex_matrix(4, 3, 1.0);
+    let c = complex_matrix(3, 5, 2.0);
+    let (op_a, op_b, op_c) = (
+        DenseOperator::new(mv, &a),
+        DenseOperator::new(mv, &b),
+        DenseOperator::new(mv, &c),
+    );
+    let alpha = C::new(0.5, -2.0);
+
+    let scaled = DArray::<C, 2>::from_fn([4, 3], |i| alpha * a[[i[0], i[1]]]);
+    check_operator(&Scaled::new(alpha, &op_a), &scaled);
+
+    let sum = DArray::<C, 2>::from_fn([4, 3], |i| a[[i[0], i[1]]] + b[[i[0], i[1]]]);
+    check_operator(&Sum::new(&op_a, &op_b), &sum);
+
+    check_operator(&Composed::new(&op_a, &op_c), &matmul(&a, &c));
+
+    let at = DArray::<C, 2>::from_fn([3, 4], |i| a[[i[1], i[0]]]);
+    check_operator(&Transpose::new(&op_a), &at);
+
+    let ah = DArray::<C, 2>::from_fn([3, 4], |i| a[[i[1], i[0]]].conj());
+    check_operator(&Adjoint::new(&op_a), &ah);
+
+    // Nested: (α A + B)^T A
+    let nested = Composed::new(Transpose::new(Sum::new(Scaled::new(alpha, &op_a), &op_b)), &op_a);
+    let lhs = DArray::<C, 2>::from_fn([3, 4], |i| alpha * a[[i[1], i[0]]] + b[[i[1], i[0]]]);
+    check_operator(&nested, &matmul(&lhs, &a));
+
+    // Diagonals propagate where they are cheap.
+    let d = Sum::new(Scaled::new(alpha, &op_a), Adjoint::new(Transpose::new(&op_b)))
+        .diagonal()
+        .expect("diagonal of a sum");
+    for i in 0..3 {
+        assert!((d[i] - (alpha * a[[i, i]] + b[[i, i]].conj())).norm() < 1e-12);
+    }
+    assert!(Composed::new(&op_a, &op_c).diagonal().is_none());
+}
+
+pub fn test_fn_operator_with_cg(bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let n = 64;
+    // Matrix-free 1D Laplacian `tridiag(-1, 2, -1)`, which is self-adjoint.
+    let laplacian = |x: &DSlice<f64, 1>, y: &mut DSlice<f64, 1>| {
+        for i in 0..n {
+            let left = if i > 0 { x[i - 1] } else { 0.0 };
+            let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+            y[i] = 2.0 * x[i] - left - right;
+        }
+    };
+    let op = FnOperator::new(n, n, laplacian, laplacian)
+        .with_diagonal(DArray::<f64, 1>::from_elem([n], 2.0));
+    assert_eq!(op.shape(), (n, n));
+    assert_eq!(op.diagonal().unwrap()[0], 2.0);
+
+    let shifted = Sum::new(&op, Scaled::new(0.5, &op));
+    let b = DArray::<f64, 1>::from_fn([n], |i| (i[0] as f64).cos());
+    let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+    cg(bd, |x, y| shifted.apply(x, y), &b, &mut x, &Identity, &KrylovConfig::default())
+        .expect("CG did not converge");
+
+    let mut ax = DArray::<f64, 1>::from_elem([n], 0.0);
+    shifted.apply(&x, &mut ax);
+    for i in 0..n {
+        assert!((ax[i] - b[i]).abs() < 1e-8);
+    }
+}
+
+pub fn test_operator_shape_mismatch(mv: &impl MatVec<C, Dyn, Dyn>) {
+    let a = complex_matrix(4, 3, 0.0);
+    let op = DenseOperator::new(mv, &a);
+    let _ = Composed::new(&op, &op);
+}
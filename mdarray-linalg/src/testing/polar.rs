@@ -0,0 +1,232 @@
+use mdarray::{DArray, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{naive_matmul, rank_k_matrix};
+use crate::{
+    contract::Contract,
+    polar::{PolarConfig, PolarDecomp, PolarError, PolarSide, polar, polar_newton, polar_qdwh},
+    qr::QR,
+    solve::Solve,
+    svd::SVD,
+};
+
+type C = Complex<f64>;
+
+/// Dense full-rank matrix without structure, with an imaginary part for
+/// complex types.
+fn full<T: ComplexFloat<Real = f64>>(m: usize, n: usize, seed: f64) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        let t = (seed + (1 + 3 * i[0]) as f64) * (1 + i[1]) as f64;
+        let d = if i[0] == i[1] { 2. } else { 0. };
+        T::from(t.sin() + d).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+/// Square matrix `H diag(1, 10^-2, 10^-4, ...) H` with a Householder
+/// reflector `H`.
+fn ill_conditioned(n: usize) -> DArray<f64, 2> {
+    let v: Vec<f64> = (0..n).map(|i| (i as f64 + 1.).sin() + 1.5).collect();
+    let vv: f64 = v.iter().map(|x| x * x).sum();
+    let h = DArray::<f64, 2>::from_fn([n, n], |i| {
+        let id = if i[0] == i[1] { 1. } else { 0. };
+        id - 2. * v[i[0]] * v[i[1]] / vv
+    });
+    let hd = DArray::<f64, 2>::from_fn([n, n], |i| h[[i[0], i[1]]] * 1e-2f64.powi(i[1] as i32));
+    naive_matmul(&hd, &h)
+}
+
+fn adjoint<T: ComplexFloat>(a: &DArray<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, b: &DArray<T, 2>) {
+    assert_eq!(a.shape(), b.shape(), "Matrix shapes don't match");
+    let (m, n) = *a.shape();
+    for i in 0..m {
+        for j in 0..n {
+            assert!(
+                (a[[i, j]] - b[[i, j]]).abs() < 1e-8,
+                "Entries ({i}, {j}) differ"
+            );
+        }
+    }
+}
+
+/// Checks that the Cholesky factorization of the Hermitian `p` exists.
+fn assert_positive_definite<T: ComplexFloat<Real = f64>>(p: &DArray<T, 2>) {
+    let n = p.shape().0;
+    let mut l = DArray::<T, 2>::from_elem([n, n], T::zero());
+    for j in 0..n {
+        let d = (0..j).fold(p[[j, j]].re(), |acc, k| {
+            acc - l[[j, k]].abs() * l[[j, k]].abs()
+        });
+        assert!(d > 0., "P is not positive definite");
+        l[[j, j]] = T::from(d.sqrt()).unwrap();
+        for i in j + 1..n {
+            let x = (0..j).fold(p[[i, j]], |acc, k| acc - l[[i, k]] * l[[j, k]].conj());
+            l[[i, j]] = x / l[[j, j]];
+        }
+    }
+}
+
+/// Checks the factors of the polar decomposition of the full-rank `a`.
+fn assert_polar<T: ComplexFloat<Real = f64>>(
+    a: &DArray<T, 2>,
+    decomp: &PolarDecomp<T, Dyn>,
+    side: PolarSide,
+) {
+    let (m, n) = *a.shape();
+    let PolarDecomp { u, p } = decomp;
+    assert_eq!(*u.shape(), (m, n));
+
+    // U has orthonormal columns or rows.
+    let k = m.min(n);
+    let identity =
+        DArray::<T, 2>::from_fn([k, k], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+    let gram = if m >= n {
+        naive_matmul(&adjoint(u), u)
+    } else {
+        naive_matmul(u, &adjoint(u))
+    };
+    assert_close(&gram, &identity);
+
+    let product = match side {
+        PolarSide::Right => {
+            assert_eq!(*p.shape(), (n, n));
+            naive_matmul(u, p)
+        }
+        PolarSide::Left => {
+            assert_eq!(*p.shape(), (m, m));
+            naive_matmul(p, u)
+        }
+    };
+    assert_close(&product, a);
+    assert_close(p, &adjoint(p));
+    // P is singular on the larger side of a rectangular `a`.
+    let full_rank = match side {
+        PolarSide::Right => m >= n,
+        PolarSide::Left => m <= n,
+    };
+    if full_rank {
+        assert_positive_definite(p);
+    }
+}
+
+pub fn test_polar_svd(svd: &impl SVD<f64, Dyn, SingularValue = f64>, mm: &impl Contract<f64>) {
+    for (m, n) in [(4, 4), (6, 3), (3, 6)] {
+        let a = full::<f64>(m, n, 0.3);
+        for side in [PolarSide::Right, PolarSide::Left] {
+            let decomp = polar(svd, mm, &a, side).expect("Polar decomposition failed");
+            assert_polar(&a, &decomp, side);
+        }
+    }
+}
+
+pub fn test_polar_svd_complex(svd: &impl SVD<C, Dyn, SingularValue = C>, mm: &impl Contract<C>) {
+    for (m, n) in [(4, 4), (5, 3)] {
+        let a = full::<C>(m, n, 1.1);
+        for side in [PolarSide::Right, PolarSide::Left] {
+            let decomp = polar(svd, mm, &a, side).expect("Polar decomposition failed");
+            assert_polar(&a, &decomp, side);
+        }
+    }
+}
+
+pub fn test_polar_svd_rank_deficient(
+    svd: &impl SVD<f64, Dyn, SingularValue = f64>,
+    mm: &impl Contract<f64>,
+) {
+    // U is not unique, but A = U P and P = (A^T A)^½ still hold.
+    let a = rank_k_matrix(5, 4, 2);
+    let PolarDecomp { u, p } =
+        polar(svd, mm, &a, PolarSide::Right).expect("Polar decomposition failed");
+    assert_close(&naive_matmul(&u, &p), &a);
+    assert_close(&naive_matmul(&p, &p), &naive_matmul(&adjoint(&a), &a));
+}
+
+pub fn test_polar_newton(mm: &impl Contract<f64>, solve: &impl Solve<f64, Dyn>) {
+    let config = PolarConfig::default();
+    for a in [full::<f64>(5, 5, 0.3), ill_conditioned(4)] {
+        for side in [PolarSide::Right, PolarSide::Left] {
+            let decomp =
+                polar_newton(mm, solve, &a, side, &config).expect("Newton iteration failed");
+            assert_polar(&a, &decomp, side);
+        }
+    }
+}
+
+pub fn test_polar_newton_complex(mm: &impl Contract<C>, solve: &impl Solve<C, Dyn>) {
+    let a = full::<C>(4, 4, 1.1);
+    for side in [PolarSide::Right, PolarSide::Left] {
+        let decomp = polar_newton(mm, solve, &a, side, &PolarConfig::default())
+            .expect("Newton iteration failed");
+        assert_polar(&a, &decomp, side);
+    }
+}
+
+pub fn test_polar_newton_not_square(mm: &impl Contract<f64>, solve: &impl Solve<f64, Dyn>) {
+    let a = full::<f64>(4, 3, 0.3);
+    assert!(matches!(
+        polar_newton(mm, solve, &a, PolarSide::Right, &PolarConfig::default()),
+        Err(PolarError::NotSquare { rows: 4, cols: 3 })
+    ));
+}
+
+pub fn test_polar_qdwh(mm: &impl Contract<f64>, qr: &impl QR<f64, Dyn, Dyn>) {
+    let config = PolarConfig::default();
+    for a in [
+        full::<f64>(4, 4, 0.3),
+        full::<f64>(6, 3, 0.7),
+        full::<f64>(3, 6, 0.7),
+        ill_conditioned(4),
+    ] {
+        for side in [PolarSide::Right, PolarSide::Left] {
+            let decomp = polar_qdwh(mm, qr, &a, side, &config).expect("QDWH iteration failed");
+            assert_polar(&a, &decomp, side);
+        }
+    }
+}
+
+pub fn test_polar_qdwh_complex(mm: &impl Contract<C>, qr: &impl QR<C, Dyn, Dyn>) {
+    for (m, n) in [(4, 4), (5, 3), (3, 5)] {
+        let a = full::<C>(m, n, 1.1);
+        for side in [PolarSide::Right, PolarSide::Left] {
+            let decomp = polar_qdwh(mm, qr, &a, side, &PolarConfig::default())
+                .expect("QDWH iteration failed");
+            assert_polar(&a, &decomp, side);
+        }
+    }
+}
+
+pub fn test_polar_qdwh_rank_deficient(mm: &impl Contract<f64>, qr: &impl QR<f64, Dyn, Dyn>) {
+    let a = DArray::<f64, 2>::from_elem([4, 3], 0.);
+    assert!(matches!(
+        polar_qdwh(mm, qr, &a, PolarSide::Right, &PolarConfig::default()),
+        Err(PolarError::RankDeficient)
+    ));
+}
+
+/// The iterations and the SVD agree on the unique polar factors of a
+/// full-rank matrix.
+pub fn test_polar_methods_agree<B>(bd: &B)
+where
+    B: SVD<f64, Dyn, SingularValue = f64> + Contract<f64> + Solve<f64, Dyn> + QR<f64, Dyn, Dyn>,
+{
+    let a = full::<f64>(5, 5, 0.9);
+    let config = PolarConfig::default();
+    let reference = polar(bd, bd, &a, PolarSide::Right).expect("Polar decomposition failed");
+    let newton =
+        polar_newton(bd, bd, &a, PolarSide::Right, &config).expect("Newton iteration failed");
+    let qdwh = polar_qdwh(bd, bd, &a, PolarSide::Right, &config).expect("QDWH iteration failed");
+    for decomp in [newton, qdwh] {
+        assert_close(&decomp.u, &reference.u);
+        assert_close(&decomp.p, &reference.p);
+    }
+}
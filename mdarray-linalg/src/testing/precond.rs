@@ -0,0 +1,289 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    krylov::{KrylovConfig, cg, gmres},
+    matvec::{MatVec, MatVecBuilder, VecOps},
+    precond::{
+        BlockJacobi, Chain, Ic0, Identity, Ilu0, Jacobi, PrecondError, Preconditioner, Ssor, Sum,
+    },
+    solve::Solve,
+    sparse::{SparseFormat, SparseMatrix},
+};
+
+/// 5-point Laplacian on a `k × k` grid, with a varying diagonal shift.
+fn laplacian_2d(k: usize) -> DArray<f64, 2> {
+    let n = k * k;
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if r == c {
+            4.0 + 0.1 * (r % 5) as f64
+        } else if (r.abs_diff(c) == 1 && r / k == c / k) || r.abs_diff(c) == k {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Non-symmetric tridiagonal matrix.
+fn tridiagonal(n: usize) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        if i[0] == i[1] {
+            3.0 + (i[0] % 3) as f64
+        } else if i[1] == i[0] + 1 {
+            -1.2
+        } else if i[0] == i[1] + 1 {
+            -0.5
+        } else {
+            0.0
+        }
+    })
+}
+
+fn rhs(n: usize) -> DArray<f64, 1> {
+    DArray::<f64, 1>::from_fn([n], |i| (0.3 * i[0] as f64).sin() + 1.0)
+}
+
+/// Checks that `M^{-1} (A x) = x`, i.e. that `M = A`.
+fn check_exact<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, m: &impl Preconditioner<T>) {
+    let n = a.shape().0;
+    let x = DArray::<T, 1>::from_fn([n], |i| T::from(1.0 + 0.25 * i[0] as f64).unwrap());
+    let ax = DArray::<T, 1>::from_fn([n], |i| (0..n).fold(T::zero(), |acc, j| acc + a[[i[0], j]] * x[j]));
+    let mut z = DArray::<T, 1>::from_elem([n], T::zero());
+    m.apply(&ax, &mut z);
+    for i in 0..n {
+        assert!((z[i] - x[i]).abs() < 1e-10, "entry {i}");
+    }
+}
+
+fn cg_iterations(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl VecOps<f64, Dyn, Real = f64>,
+    a: &DArray<f64, 2>,
+    m: &impl Preconditioner<f64>,
+) -> usize {
+    let n = a.shape().0;
+    let b = rhs(n);
+    let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+    cg(bd, |x, y| mv.matvec(a, x).write(y), &b, &mut x, m, &KrylovConfig::default())
+        .expect("CG did not converge")
+        .iterations
+}
+
+pub fn test_jacobi() {
+    let a = tridiagonal(6);
+    let jacobi = Jacobi::new(&a).expect("Jacobi failed");
+    let r = DArray::<f64, 1>::from_elem([6], 6.0);
+    let mut z = DArray::<f64, 1>::from_elem([6], 0.0);
+    jacobi.apply(&r, &mut z);
+    for i in 0..6 {
+        assert!((z[i] - 6.0 / a[[i, i]]).abs() < 1e-14);
+    }
+
+    let d = DArray::<f64, 1>::from_fn([3], |i| i[0] as f64 - 1.0);
+    assert!(matches!(
+        Jacobi::from_diagonal(&d),
+        Err(PrecondError::ZeroPivot { index: 1 })
+    ));
+}
+
+pub fn test_block_jacobi(solver: &impl Solve<f64, Dyn>) {
+    // Block diagonal with 3 × 3 blocks and a smaller last block: exact.
+    let n = 11;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+        if i[0] / 3 != i[1] / 3 {
+            0.0
+        } else if i[0] == i[1] {
+            4.0 + i[0] as f64
+        } else {
+            1.0 / (1.0 + (i[0] + 2 * i[1]) as f64)
+        }
+    });
+    let m = BlockJacobi::new(solver, &a, 3).expect("block Jacobi failed");
+    check_exact(&a, &m);
+
+    assert!(matches!(
+        BlockJacobi::new(solver, &a, 0),
+        Err(PrecondError::InvalidBlockSize)
+    ));
+}
+
+pub fn test_block_jacobi_cg(
+    mv: &impl MatVec<f64, Dyn, Dyn>,
+    bd: &impl VecOps<f64, Dyn, Real = f64>,
+    solver: &impl Solve<f64, Dyn>,
+) {
+    let a = laplacian_2d(10);
+    // One block per grid row.
+    let m = BlockJacobi::new(solver, &a, 10).expect("block Jacobi failed");
+    assert!(cg_iterations(mv, bd, &a, &m) < cg_iterations(mv, bd, &a, &Identity));
+}
+
+pub fn test_ssor(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let a = laplacian_2d(10);
+    let plain = cg_iterations(mv, bd, &a, &Identity);
+    let m = Ssor::new(&a, 1.4).expect("SSOR failed");
+    assert!(cg_iterations(mv, bd, &a, &m) < plain);
+
+    assert!(matches!(Ssor::new(&a, 2.0), Err(PrecondError::InvalidRelaxation)));
+    assert!(matches!(Ssor::new(&a, 0.0), Err(PrecondError::InvalidRelaxation)));
+}
+
+pub fn test_ilu0(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    // No fill-in occurs for a tridiagonal matrix: ILU(0) is the exact LU.
+    let t = tridiagonal(20);
+    let ilu = Ilu0::new(&t).expect("ILU(0) failed");
+    check_exact(&t, &ilu);
+
+    let n = 100;
+    let a = DArray::<f64, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if r == c {
+            4.0
+        } else if c == r + 1 || c == r + 10 {
+            -1.4
+        } else if r == c + 1 || r == c + 10 {
+            -0.6
+        } else {
+            0.0
+        }
+    });
+    let b = rhs(n);
+    let ilu = Ilu0::new(&a).expect("ILU(0) failed");
+    let config = KrylovConfig::default();
+
+    let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+    let plain = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &Identity, &config)
+        .expect("GMRES did not converge");
+    let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+    let preconditioned = gmres(bd, |x, y| mv.matvec(&a, x).write(y), &b, &mut x, &ilu, &config)
+        .expect("preconditioned GMRES did not converge");
+    assert!(preconditioned.iterations < plain.iterations);
+
+    // Zero pivot in the first row.
+    let singular = DArray::<f64, 2>::from_fn([2, 2], |i| if i[0] == 0 && i[1] == 0 { 0.0 } else { 1.0 });
+    assert!(matches!(Ilu0::new(&singular), Err(PrecondError::ZeroPivot { index: 0 })));
+}
+
+pub fn test_ic0(mv: &impl MatVec<f64, Dyn, Dyn>, bd: &impl VecOps<f64, Dyn, Real = f64>) {
+    let t = DArray::<f64, 2>::from_fn([20, 20], |i| match i[0].abs_diff(i[1]) {
+        0 => 2.5,
+        1 => -1.0,
+        _ => 0.0,
+    });
+    check_exact(&t, &Ic0::new(&t).expect("IC(0) failed"));
+
+    let a = laplacian_2d(10);
+    let ic = Ic0::new(&a).expect("IC(0) failed");
+    assert!(cg_iterations(mv, bd, &a, &ic) < cg_iterations(mv, bd, &a, &Identity));
+
+    let indefinite = DArray::<f64, 2>::from_fn([3, 3], |i| if i[0] == i[1] { 1.0 - i[0] as f64 } else { 0.0 });
+    assert!(matches!(
+        Ic0::new(&indefinite),
+        Err(PrecondError::NotPositiveDefinite { index: 1 })
+    ));
+}
+
+/// Checks that `m` and `reference` apply the same operator.
+fn check_same<T: ComplexFloat<Real = f64>>(n: usize, m: &impl Preconditioner<T>, reference: &impl Preconditioner<T>) {
+    let r = DArray::<T, 1>::from_fn([n], |i| T::from((0.7 * i[0] as f64).cos()).unwrap());
+    let mut z = DArray::<T, 1>::from_elem([n], T::zero());
+    let mut expected = DArray::<T, 1>::from_elem([n], T::zero());
+    m.apply(&r, &mut z);
+    reference.apply(&r, &mut expected);
+    for i in 0..n {
+        assert!((z[i] - expected[i]).abs() < 1e-12, "entry {i}");
+    }
+}
+
+pub fn test_incomplete_sparse() {
+    let a = laplacian_2d(8);
+    let n = a.shape().0;
+    for format in [SparseFormat::Csr, SparseFormat::Csc] {
+        let sparse = SparseMatrix::from_dense(&a, format);
+        let ilu = Ilu0::from_sparse(&sparse).expect("ILU(0) failed");
+        check_same(n, &ilu, &Ilu0::new(&a).expect("ILU(0) failed"));
+        let ic = Ic0::from_sparse(&sparse).expect("IC(0) failed");
+        check_same(n, &ic, &Ic0::new(&a).expect("IC(0) failed"));
+    }
+
+    // No fill-in occurs for a tridiagonal matrix: the factorizations are exact.
+    let t = tridiagonal(30);
+    check_exact(&t, &Ilu0::from_sparse(&SparseMatrix::from_dense(&t, SparseFormat::Csr)).expect("ILU(0) failed"));
+    let h = DArray::<f64, 2>::from_fn([30, 30], |i| match i[0].abs_diff(i[1]) {
+        0 => 2.5,
+        1 => -1.0,
+        _ => 0.0,
+    });
+    check_exact(&h, &Ic0::from_sparse(&SparseMatrix::from_dense(&h, SparseFormat::Csc)).expect("IC(0) failed"));
+
+    // Missing diagonal entries.
+    let off_diagonal = SparseMatrix::from_dense(
+        &DArray::<f64, 2>::from_fn([2, 2], |i| if i[0] == 1 && i[1] == 1 { 0.0 } else { 1.0 }),
+        SparseFormat::Csr,
+    );
+    assert!(matches!(
+        Ilu0::from_sparse(&off_diagonal),
+        Err(PrecondError::ZeroPivot { index: 1 })
+    ));
+    assert!(matches!(
+        Ic0::from_sparse(&off_diagonal),
+        Err(PrecondError::NotPositiveDefinite { index: 1 })
+    ));
+
+    let rectangular = SparseMatrix::from_dense(&DArray::<f64, 2>::from_elem([3, 4], 1.0), SparseFormat::Csr);
+    assert!(matches!(
+        Ic0::from_sparse(&rectangular),
+        Err(PrecondError::NotSquare { rows: 3, cols: 4 })
+    ));
+}
+
+pub fn test_incomplete_complex() {
+    let n = 12;
+    let a = DArray::<Complex<f64>, 2>::from_fn([n, n], |i| match (i[0] as isize) - (i[1] as isize) {
+        0 => Complex::new(3.0, 0.0),
+        1 => Complex::new(-1.0, 0.5),
+        -1 => Complex::new(-1.0, -0.5),
+        _ => Complex::new(0.0, 0.0),
+    });
+    check_exact(&a, &Ic0::new(&a).expect("IC(0) failed"));
+    check_exact(&a, &Ilu0::new(&a).expect("ILU(0) failed"));
+
+    // SSOR with ω = 1 is exact for a diagonal matrix.
+    let d = DArray::<Complex<f64>, 2>::from_fn([3, 3], |i| {
+        if i[0] == i[1] { Complex::new(2.0, 1.0 + i[0] as f64) } else { Complex::new(0.0, 0.0) }
+    });
+    check_exact(&d, &Ssor::new(&d, 1.0).expect("SSOR failed"));
+}
+
+pub fn test_precond_composition() {
+    let a = tridiagonal(5);
+    let r = rhs(5);
+    let mut z = DArray::<f64, 1>::from_elem([5], 0.0);
+
+    // D^{-1} D^{-1} r
+    let chain = Chain::new(Jacobi::new(&a).unwrap(), Jacobi::new(&a).unwrap());
+    chain.apply(&r, &mut z);
+    for i in 0..5 {
+        assert!((z[i] - r[i] / (a[[i, i]] * a[[i, i]])).abs() < 1e-14);
+    }
+
+    // (I + D^{-1}) r, mixing a closure with a built-in preconditioner
+    let halve = |r: &DSlice<f64, 1>, z: &mut DSlice<f64, 1>| {
+        for i in 0..r.len() {
+            z[i] = 0.5 * r[i];
+        }
+    };
+    let sum = Sum::new(Chain::new(halve, Identity), Jacobi::new(&a).unwrap());
+    sum.apply(&r, &mut z);
+    for i in 0..5 {
+        assert!((z[i] - (0.5 * r[i] + r[i] / a[[i, i]])).abs() < 1e-14);
+    }
+
+    let rectangular = DArray::<f64, 2>::from_elem([3, 4], 1.0);
+    assert!(matches!(
+        Ilu0::new(&rectangular),
+        Err(PrecondError::NotSquare { rows: 3, cols: 4 })
+    ));
+}
@@ -0,0 +1,197 @@
+use approx::assert_relative_eq;
+use mdarray::{Array, Const, DArray, Dyn};
+use num_complex::{Complex, ComplexFloat};
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::common::naive_matmul;
+use crate::{
+    contract::Contract,
+    qr::QR,
+    randomized::{RandomizedSVDConfig, randomized_range_finder, randomized_svd},
+    svd::{SVD, SVDError},
+};
+
+/// Singular values `10 * 0.5^k` of the matrices built by [`decaying_matrix`].
+fn decaying_spectrum(len: usize) -> Vec<f64> {
+    (0..len).map(|k| 10.0 * 0.5f64.powi(k as i32)).collect()
+}
+
+/// Householder reflector `I - 2 v v^T / (v^T v)` of size `n`.
+fn householder(n: usize, shift: usize) -> DArray<f64, 2> {
+    let v: Vec<f64> = (0..n).map(|i| ((i + shift) as f64).sin() + 1.5).collect();
+    let vv: f64 = v.iter().map(|x| x * x).sum();
+    DArray::<f64, 2>::from_fn([n, n], |i| {
+        let id = if i[0] == i[1] { 1.0 } else { 0.0 };
+        id - 2.0 * v[i[0]] * v[i[1]] / vv
+    })
+}
+
+/// Full-rank m × n matrix `P diag(s) Q^T` with known singular values.
+fn decaying_matrix(m: usize, n: usize) -> DArray<f64, 2> {
+    let s = decaying_spectrum(m.min(n));
+    let (p, q) = (householder(m, 1), householder(n, 7));
+    DArray::<f64, 2>::from_fn([m, n], |i| {
+        (0..s.len()).map(|k| p[[i[0], k]] * s[k] * q[[i[1], k]]).sum()
+    })
+}
+
+fn max_orthogonality_error<T: ComplexFloat<Real = f64>>(q: &DArray<T, 2>) -> f64 {
+    let (m, l) = (q.shape().0, q.shape().1);
+    let mut err: f64 = 0.0;
+    for i in 0..l {
+        for j in 0..l {
+            let dot = (0..m).fold(T::zero(), |acc, k| acc + q[[k, i]].conj() * q[[k, j]]);
+            let expected = if i == j { 1.0 } else { 0.0 };
+            err = err.max((dot - T::from(expected).unwrap()).abs());
+        }
+    }
+    err
+}
+
+pub fn test_randomized_range_finder(
+    mm: &impl Contract<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+) {
+    let a = decaying_matrix(40, 30);
+    let config = RandomizedSVDConfig {
+        oversampling: 5,
+        power_iterations: 1,
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let q = randomized_range_finder(mm, qr, &a, 6, &config, &mut rng).expect("range finder failed");
+    assert_eq!(*q.shape(), (40, 11));
+    assert!(max_orthogonality_error(&q) < 1e-8);
+
+    // A is well captured: ||A - Q Q^T A|| is of the order of s_11.
+    let qt = DArray::<f64, 2>::from_fn([11, 40], |i| q[[i[1], i[0]]]);
+    let residual = naive_matmul(&q, &naive_matmul(&qt, &a));
+    let err: f64 = (0..40)
+        .flat_map(|i| (0..30).map(move |j| (i, j)))
+        .map(|(i, j)| (a[[i, j]] - residual[[i, j]]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    assert!(err < 1e-2, "range error {err}");
+}
+
+pub fn test_randomized_svd(
+    mm: &impl Contract<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+    bd: &impl SVD<f64, Dyn, SingularValue = f64>,
+) {
+    let (m, n, k) = (40, 30, 5);
+    let a = decaying_matrix(m, n);
+    let s_exact = decaying_spectrum(n);
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let svd = randomized_svd(mm, qr, bd, &a, k, &RandomizedSVDConfig::default(), &mut rng)
+        .expect("randomized SVD failed");
+
+    assert_eq!(*svd.s.shape(), (k,));
+    assert_eq!(*svd.u.shape(), (m, k));
+    assert_eq!(*svd.vt.shape(), (k, n));
+
+    for (i, &expected) in s_exact.iter().take(k).enumerate() {
+        assert_relative_eq!(svd.s[i], expected, max_relative = 1e-8);
+    }
+    assert!(max_orthogonality_error(&svd.u) < 1e-8);
+    let v = DArray::<f64, 2>::from_fn([n, k], |i| svd.vt[[i[1], i[0]]]);
+    assert!(max_orthogonality_error(&v) < 1e-8);
+
+    // A v_i = s_i u_i
+    for i in 0..k {
+        for r in 0..m {
+            let av: f64 = (0..n).map(|j| a[[r, j]] * svd.vt[[i, j]]).sum();
+            assert_relative_eq!(av, svd.s[i] * svd.u[[r, i]], epsilon = 1e-8);
+        }
+    }
+}
+
+pub fn test_randomized_svd_seeded(
+    mm: &impl Contract<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+    bd: &impl SVD<f64, Dyn, SingularValue = f64>,
+) {
+    let a = decaying_matrix(25, 20);
+    let config = RandomizedSVDConfig {
+        oversampling: 4,
+        power_iterations: 0,
+    };
+
+    let first = randomized_svd(mm, qr, bd, &a, 3, &config, &mut StdRng::seed_from_u64(3))
+        .expect("randomized SVD failed");
+    let second = randomized_svd(mm, qr, bd, &a, 3, &config, &mut StdRng::seed_from_u64(3))
+        .expect("randomized SVD failed");
+
+    assert_eq!(first.s, second.s);
+    assert_eq!(first.u, second.u);
+    assert_eq!(first.vt, second.vt);
+}
+
+pub fn test_randomized_svd_complex(
+    mm: &impl Contract<Complex<f64>>,
+    qr: &impl QR<Complex<f64>, Dyn, Dyn>,
+    bd: &impl SVD<Complex<f64>, Dyn, SingularValue = Complex<f64>>,
+) {
+    let (m, n, k) = (30, 20, 4);
+    let real = decaying_matrix(m, n);
+    let s_exact = decaying_spectrum(n);
+
+    // Unitary diagonal phases on both sides keep the singular values.
+    let a = DArray::<Complex<f64>, 2>::from_fn([m, n], |i| {
+        Complex::from_polar(real[[i[0], i[1]]], 0.3 * i[0] as f64 - 0.7 * i[1] as f64)
+    });
+    let mut rng = StdRng::seed_from_u64(11);
+
+    let svd = randomized_svd(mm, qr, bd, &a, k, &RandomizedSVDConfig::default(), &mut rng)
+        .expect("randomized SVD failed");
+
+    for (i, &expected) in s_exact.iter().take(k).enumerate() {
+        assert_relative_eq!(svd.s[i].re, expected, max_relative = 1e-8);
+        assert_eq!(svd.s[i].im, 0.0);
+    }
+    assert!(max_orthogonality_error(&svd.u) < 1e-8);
+
+    for i in 0..k {
+        for r in 0..m {
+            let av = (0..n).fold(Complex::new(0.0, 0.0), |acc, j| {
+                acc + a[[r, j]] * svd.vt[[i, j]].conj()
+            });
+            assert!((av - svd.s[i] * svd.u[[r, i]]).norm() < 1e-8);
+        }
+    }
+}
+
+pub fn test_randomized_svd_invalid_rank(
+    mm: &impl Contract<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+    bd: &impl SVD<f64, Dyn, SingularValue = f64>,
+) {
+    let a = decaying_matrix(6, 4);
+    let mut rng = StdRng::seed_from_u64(0);
+    assert!(matches!(
+        randomized_svd(mm, qr, bd, &a, 5, &RandomizedSVDConfig::default(), &mut rng),
+        Err(SVDError::InvalidRange)
+    ));
+}
+
+pub fn test_randomized_svd_const(
+    mm: &impl Contract<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+    bd: &impl SVD<f64, Dyn, SingularValue = f64>,
+) {
+    let dense = decaying_matrix(8, 8);
+    let a = Array::<f64, (Const<8>, Const<8>)>::from_fn((Const, Const), |i| dense[[i[0], i[1]]]);
+    let s_exact = decaying_spectrum(8);
+    let mut rng = StdRng::seed_from_u64(5);
+
+    let svd = randomized_svd(mm, qr, bd, &a, 2, &RandomizedSVDConfig::default(), &mut rng)
+        .expect("randomized SVD failed");
+
+    assert_eq!(*svd.s.shape(), (2,));
+    assert_eq!(*svd.u.shape(), (8, 2));
+    assert_eq!(*svd.vt.shape(), (2, 8));
+    for (i, &expected) in s_exact.iter().take(2).enumerate() {
+        assert_relative_eq!(svd.s[i], expected, max_relative = 1e-8);
+    }
+}
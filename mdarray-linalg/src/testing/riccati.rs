@@ -0,0 +1,181 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    eig::Eig,
+    riccati::{RiccatiError, care, dare},
+    solve::Solve,
+};
+
+type C = Complex<f64>;
+
+/// Dense full-rank matrix without structure, with an imaginary part for
+/// complex types, shifted by `shift` on the diagonal.
+fn full<T: ComplexFloat<Real = f64>>(m: usize, n: usize, seed: f64, shift: f64) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        let t = (seed + (1 + 3 * i[0]) as f64) * (1 + i[1]) as f64;
+        let d = if i[0] == i[1] { shift } else { 0. };
+        T::from(t.sin() + d).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+fn matmul<T: ComplexFloat>(a: &DSlice<T, 2>, b: &DSlice<T, 2>) -> DArray<T, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        (0..k).fold(T::zero(), |acc, l| acc + a[[i[0], l]] * b[[l, i[1]]])
+    })
+}
+
+fn adjoint<T: ComplexFloat>(a: &DSlice<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+/// `G G^H + shift I`
+fn gram<T: ComplexFloat<Real = f64>>(n: usize, seed: f64, shift: f64) -> DArray<T, 2> {
+    let g = full::<T>(n, n, seed, 0.);
+    let mut gg = matmul(&g, &adjoint(&g));
+    for i in 0..n {
+        gg[[i, i]] = gg[[i, i]] + T::from(shift).unwrap();
+    }
+    gg
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DSlice<T, 2>, b: &DSlice<T, 2>) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() < 1e-8, "{:?} != {:?}", x.re(), y.re());
+    }
+}
+
+fn scalar(x: f64) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_elem([1, 1], x)
+}
+
+fn check_care<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64> + From<f64>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>,
+{
+    // A is unstable, so the feedback has work to do.
+    let (n, m) = (5, 2);
+    let a = full::<T>(n, n, 0.3, 0.5);
+    let b = full::<T>(n, m, 1.7, 0.);
+    let q = gram::<T>(n, 2.9, 0.1);
+    let r = gram::<T>(m, 4.1, 1.);
+    let eigenvalues = bd.eig_values(&mut a.clone()).unwrap();
+    assert!(eigenvalues.iter().any(|z| z.re > 0.));
+
+    let sol = care(bd, &a, &b, &q, &r).unwrap();
+    assert!(sol.residual < 1e-10);
+    assert_close(&sol.x, &adjoint(&sol.x));
+
+    // A^H X + X A - X B R^-1 B^H X + Q = 0 with R K = B^H X
+    let ahx = matmul(&adjoint(&a), &sol.x);
+    let xbk = matmul(&matmul(&sol.x, &b), &sol.k);
+    let lhs = ahx.clone() + adjoint(&ahx) + q - xbk;
+    assert_close(&lhs, &DArray::<T, 2>::from_elem([n, n], T::zero()));
+    assert_close(&matmul(&r, &sol.k), &matmul(&adjoint(&b), &sol.x));
+
+    let closed_loop = a - matmul(&b, &sol.k);
+    let eigenvalues = bd.eig_values(&mut closed_loop.clone()).unwrap();
+    assert!(eigenvalues.iter().all(|z| z.re < 0.));
+}
+
+pub fn test_care(bd: &(impl Eig<f64, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>)) {
+    check_care::<f64, _>(bd);
+
+    // x^2 - 2 x - 1 = 0
+    let sol = care(bd, &scalar(1.), &scalar(1.), &scalar(1.), &scalar(1.)).unwrap();
+    assert!((sol.x[[0, 0]] - (1. + 2f64.sqrt())).abs() < 1e-12);
+    assert!((sol.k[[0, 0]] - (1. + 2f64.sqrt())).abs() < 1e-12);
+}
+
+pub fn test_care_complex(bd: &(impl Eig<C, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>)) {
+    check_care::<C, _>(bd);
+}
+
+fn check_dare<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64> + From<f64>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>,
+{
+    // A is not Schur-stable either.
+    let (n, m) = (5, 2);
+    let a = full::<T>(n, n, 0.8, 1.2);
+    let b = full::<T>(n, m, 2.2, 0.);
+    let q = gram::<T>(n, 3.4, 0.1);
+    let r = gram::<T>(m, 5.3, 1.);
+    let eigenvalues = bd.eig_values(&mut a.clone()).unwrap();
+    assert!(eigenvalues.iter().any(|z| z.norm() > 1.));
+
+    let sol = dare(bd, &a, &b, &q, &r).unwrap();
+    assert!(sol.residual < 1e-10);
+    assert_close(&sol.x, &adjoint(&sol.x));
+
+    // X = A^H X A - (B^H X A)^H K + Q with (R + B^H X B) K = B^H X A
+    let bhxa = matmul(&matmul(&adjoint(&b), &sol.x), &a);
+    let rhs =
+        matmul(&matmul(&adjoint(&a), &sol.x), &a) - matmul(&adjoint(&bhxa), &sol.k) + q.clone();
+    assert_close(&sol.x, &rhs);
+    let w = r + matmul(&matmul(&adjoint(&b), &sol.x), &b);
+    assert_close(&matmul(&w, &sol.k), &bhxa);
+
+    let closed_loop = a - matmul(&b, &sol.k);
+    let eigenvalues = bd.eig_values(&mut closed_loop.clone()).unwrap();
+    assert!(eigenvalues.iter().all(|z| z.norm() < 1.));
+}
+
+pub fn test_dare(bd: &(impl Eig<f64, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>)) {
+    check_dare::<f64, _>(bd);
+
+    // x^2 - 4 x - 1 = 0
+    let sol = dare(bd, &scalar(2.), &scalar(1.), &scalar(1.), &scalar(1.)).unwrap();
+    assert!((sol.x[[0, 0]] - (2. + 5f64.sqrt())).abs() < 1e-12);
+
+    // A singular A has an infinite eigenvalue in the symplectic pencil.
+    let a = DArray::<f64, 2>::from_fn([2, 2], |i| [[0., 1.], [0., 0.]][i[0]][i[1]]);
+    let b = DArray::<f64, 2>::from_fn([2, 1], |i| i[0] as f64);
+    let q = DArray::<f64, 2>::from_fn([2, 2], |i| if i[0] == i[1] { 1. } else { 0. });
+    let sol = dare(bd, &a, &b, &q, &scalar(1.)).unwrap();
+    assert!(sol.residual < 1e-10);
+}
+
+pub fn test_dare_complex(bd: &(impl Eig<C, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>)) {
+    check_dare::<C, _>(bd);
+}
+
+pub fn test_riccati_errors(bd: &(impl Eig<f64, Dyn, Dyn, SpectralScalar = C> + Solve<C, Dyn>)) {
+    // B = 0 leaves the unstable mode of A = 1 uncontrollable.
+    assert!(matches!(
+        care(bd, &scalar(1.), &scalar(0.), &scalar(1.), &scalar(1.)),
+        Err(RiccatiError::NoStabilizingSolution)
+    ));
+    assert!(matches!(
+        dare(bd, &scalar(2.), &scalar(0.), &scalar(1.), &scalar(1.)),
+        Err(RiccatiError::NoStabilizingSolution)
+    ));
+
+    let rect = DArray::<f64, 2>::from_elem([2, 3], 1.);
+    assert!(matches!(
+        care(bd, &rect, &scalar(1.), &scalar(1.), &scalar(1.)),
+        Err(RiccatiError::NotSquare { rows: 2, cols: 3 })
+    ));
+    let a = DArray::<f64, 2>::from_elem([2, 2], 1.);
+    assert!(matches!(
+        dare(bd, &a, &scalar(1.), &a, &scalar(1.)),
+        Err(RiccatiError::DimensionMismatch {
+            m: 2,
+            n: 1,
+            rows: 1,
+            cols: 1
+        })
+    ));
+}
@@ -0,0 +1,277 @@
+use mdarray::{DArray, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    krylov::{KrylovConfig, cg},
+    matvec::VecOps,
+    operator::LinearOperator,
+    precond::Jacobi,
+    sparse::{
+        SparseError, SparseFormat, SparseMatVec, SparseMatVecBuilder, SparseMatmul, SparseMatmulBuilder,
+        SparseMatrix, SparseOperator,
+    },
+};
+
+type C = Complex<f64>;
+
+const FORMATS: [SparseFormat; 2] = [SparseFormat::Csr, SparseFormat::Csc];
+
+/// Dense matrix with roughly a third of its entries nonzero.
+fn pattern<T: ComplexFloat<Real = f64>>(m: usize, n: usize) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if (2 * r + 3 * c) % 3 == 0 {
+            let t = (1 + r + 2 * c) as f64;
+            T::from(t.sin()).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+        } else {
+            T::zero()
+        }
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DArray<T, 2>, b: &DArray<T, 2>) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() < 1e-12);
+    }
+}
+
+fn dense_matmul<T: ComplexFloat>(a: &DArray<T, 2>, b: &DArray<T, 2>) -> DArray<T, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    DArray::<T, 2>::from_fn([m, n], |i| (0..k).fold(T::zero(), |acc, l| acc + a[[i[0], l]] * b[[l, i[1]]]))
+}
+
+pub fn test_sparse_construction() {
+    // Unordered triplets with a duplicate at (1, 2) and an explicit zero.
+    let rows = DArray::<usize, 1>::from(vec![2, 1, 0, 1, 2, 0]);
+    let cols = DArray::<usize, 1>::from(vec![3, 2, 0, 2, 0, 1]);
+    let values = DArray::<f64, 1>::from(vec![4.0, 1.0, 2.0, 0.5, -1.0, 0.0]);
+    let dense = DArray::<f64, 2>::from_fn([3, 4], |i| match (i[0], i[1]) {
+        (0, 0) => 2.0,
+        (1, 2) => 1.5,
+        (2, 0) => -1.0,
+        (2, 3) => 4.0,
+        _ => 0.0,
+    });
+
+    for format in FORMATS {
+        let a = SparseMatrix::from_coo(3, 4, format, &rows, &cols, &values).expect("from_coo failed");
+        assert_eq!(a.format(), format);
+        assert_eq!(a.shape(), (3, 4));
+        assert_eq!(a.nnz(), 5);
+        assert_eq!(a.to_dense(), dense);
+        assert_eq!(a.get(1, 2), 1.5);
+        assert_eq!(a.get(1, 1), 0.0);
+        assert_eq!(a.diagonal()[0], 2.0);
+
+        let other = a.to_format(format.flipped());
+        assert_eq!(other.format(), format.flipped());
+        assert_eq!(other.nnz(), 5);
+        assert_eq!(other.to_dense(), dense);
+
+        let at = a.transpose();
+        assert_eq!(at.shape(), (4, 3));
+        assert_eq!(at.to_dense(), DArray::<f64, 2>::from_fn([4, 3], |i| dense[[i[1], i[0]]]));
+
+        // Round trips through COO and the compressed arrays.
+        let (r, c, v) = a.to_coo();
+        let b = SparseMatrix::from_coo(3, 4, format, &r, &c, &v).unwrap();
+        assert_eq!(b.indices(), a.indices());
+        let (indptr, indices, values) = a.clone().into_parts();
+        let b = SparseMatrix::new(3, 4, format, indptr, indices, values).expect("valid arrays rejected");
+        assert_eq!(b.to_dense(), dense);
+    }
+
+    let a = SparseMatrix::from_dense(&dense, SparseFormat::Csc);
+    assert_eq!(a.nnz(), 4);
+    assert_eq!(a.indptr()[..], [0, 2, 2, 3, 4]);
+    assert_eq!(a.indices()[..], [0, 2, 1, 2]);
+
+    let c = pattern::<C>(5, 4);
+    let ah = SparseMatrix::from_dense(&c, SparseFormat::Csr).adjoint();
+    assert_eq!(ah.to_dense(), DArray::<C, 2>::from_fn([4, 5], |i| c[[i[1], i[0]]].conj()));
+}
+
+pub fn test_sparse_errors() {
+    let csr = |indptr: Vec<usize>, indices: Vec<usize>, values: Vec<f64>| {
+        SparseMatrix::new(2, 3, SparseFormat::Csr, indptr.into(), indices.into(), values.into())
+    };
+    assert!(matches!(
+        csr(vec![0, 1], vec![0], vec![1.0]),
+        Err(SparseError::InvalidPointerLength { expected: 3, len: 2 })
+    ));
+    assert!(matches!(
+        csr(vec![0, 2, 1], vec![0], vec![1.0]),
+        Err(SparseError::InvalidPointers)
+    ));
+    assert!(matches!(
+        csr(vec![0, 1, 2], vec![0, 1], vec![1.0]),
+        Err(SparseError::LengthMismatch { expected: 2, len: 1 })
+    ));
+    assert!(matches!(
+        csr(vec![0, 1, 2], vec![0, 3], vec![1.0, 2.0]),
+        Err(SparseError::IndexOutOfBounds { index: 3, bound: 3 })
+    ));
+    assert!(matches!(
+        csr(vec![0, 0, 2], vec![2, 1], vec![1.0, 2.0]),
+        Err(SparseError::UnsortedIndices { major: 1 })
+    ));
+
+    let idx = DArray::<usize, 1>::from(vec![0, 5]);
+    let values = DArray::<f64, 1>::from(vec![1.0, 2.0]);
+    assert!(matches!(
+        SparseMatrix::from_coo(4, 4, SparseFormat::Csc, &idx, &idx, &values),
+        Err(SparseError::IndexOutOfBounds { index: 5, bound: 4 })
+    ));
+    assert!(matches!(
+        SparseMatrix::from_coo(4, 4, SparseFormat::Csc, &idx, &idx, &values.view(..1).to_array()),
+        Err(SparseError::LengthMismatch { expected: 1, len: 2 })
+    ));
+}
+
+fn check_spmv<T: ComplexFloat<Real = f64>>(bd: &impl SparseMatVec<T>) {
+    let (m, n) = (7, 5);
+    let dense = pattern::<T>(m, n);
+    let x = DArray::<T, 1>::from_fn([n], |i| T::from(1.0 + i[0] as f64).unwrap());
+    let y0 = DArray::<T, 1>::from_fn([m], |i| T::from(0.5 - i[0] as f64).unwrap());
+    let alpha = T::from(2.0).unwrap() + imaginary::<T>();
+    let beta = T::from(-0.5).unwrap();
+    let ax = DArray::<T, 1>::from_fn([m], |i| (0..n).fold(T::zero(), |acc, j| acc + dense[[i[0], j]] * x[j]));
+
+    let check = |y: &DArray<T, 1>, expected: &dyn Fn(usize) -> T| {
+        for i in 0..m {
+            assert!((y[i] - expected(i)).abs() < 1e-12);
+        }
+    };
+
+    for format in FORMATS {
+        let a = SparseMatrix::from_dense(&dense, format);
+
+        check(&bd.spmv(&a, &x).eval(), &|i| ax[i]);
+        check(&bd.spmv(&a, &x).scale(alpha).eval(), &|i| alpha * ax[i]);
+
+        let mut y = y0.clone();
+        bd.spmv(&a, &x).scale(alpha).write(&mut y);
+        check(&y, &|i| alpha * ax[i]);
+
+        let mut y = y0.clone();
+        bd.spmv(&a, &x).add_to_vec(&mut y);
+        check(&y, &|i| ax[i] + y0[i]);
+
+        let mut y = y0.clone();
+        bd.spmv(&a, &x).scale(alpha).add_to_scaled_vec(&mut y, beta);
+        check(&y, &|i| alpha * ax[i] + beta * y0[i]);
+    }
+}
+
+pub fn test_spmv(bd: &impl SparseMatVec<f64>) {
+    check_spmv(bd);
+}
+
+pub fn test_spmv_complex(bd: &impl SparseMatVec<C>) {
+    check_spmv(bd);
+}
+
+pub fn test_spmm(bd: &(impl SparseMatmul<f64> + SparseMatmul<C>)) {
+    let dense = pattern::<f64>(6, 4);
+    let b = DArray::<f64, 2>::from_fn([4, 3], |i| (i[0] as f64) - 0.5 * i[1] as f64);
+    let ab = dense_matmul(&dense, &b);
+    let c0 = DArray::<f64, 2>::from_fn([6, 3], |i| (i[0] + i[1]) as f64);
+
+    for format in FORMATS {
+        let a = SparseMatrix::from_dense(&dense, format);
+
+        assert_close(&bd.spmm(&a, &b).eval(), &ab);
+        assert_close(&bd.spmm(&a, &b).scale(3.0).eval(), &DArray::<f64, 2>::from_fn([6, 3], |i| 3.0 * ab[[i[0], i[1]]]));
+
+        let mut c = c0.clone();
+        bd.spmm(&a, &b).write(&mut c);
+        assert_close(&c, &ab);
+
+        let mut c = c0.clone();
+        bd.spmm(&a, &b).add_to(&mut c);
+        assert_close(&c, &DArray::<f64, 2>::from_fn([6, 3], |i| ab[[i[0], i[1]]] + c0[[i[0], i[1]]]));
+
+        let mut c = c0.clone();
+        bd.spmm(&a, &b).scale(2.0).add_to_scaled(&mut c, -1.0);
+        assert_close(&c, &DArray::<f64, 2>::from_fn([6, 3], |i| 2.0 * ab[[i[0], i[1]]] - c0[[i[0], i[1]]]));
+
+        // Strided right-hand side and output.
+        let bt = DArray::<f64, 2>::from_fn([3, 4], |i| b[[i[1], i[0]]]);
+        let mut ct = DArray::<f64, 2>::from_elem([3, 6], 0.0);
+        bd.spmm(&a, &bt.transpose()).write(&mut ct.transpose_mut());
+        assert_close(&ct, &DArray::<f64, 2>::from_fn([3, 6], |i| ab[[i[1], i[0]]]));
+    }
+
+    let dense = pattern::<C>(5, 6);
+    let b = pattern::<C>(6, 2);
+    let a = SparseMatrix::from_dense(&dense, SparseFormat::Csc);
+    assert_close(&bd.spmm(&a, &b).eval(), &dense_matmul(&dense, &b));
+}
+
+pub fn test_sparse_operator_cg<B>(bd: &B)
+where
+    B: SparseMatVec<f64> + VecOps<f64, Dyn, Real = f64>,
+{
+    // 5-point Laplacian on a 30 × 30 grid, assembled from triplets.
+    let k = 30;
+    let n = k * k;
+    let (mut rows, mut cols, mut values) = (Vec::new(), Vec::new(), Vec::new());
+    for p in 0..n {
+        let (r, c) = (p / k, p % k);
+        let mut push = |q: usize, v: f64| {
+            rows.push(p);
+            cols.push(q);
+            values.push(v);
+        };
+        push(p, 4.0 + 0.1 * (p % 7) as f64);
+        if r > 0 {
+            push(p - k, -1.0);
+        }
+        if r + 1 < k {
+            push(p + k, -1.0);
+        }
+        if c > 0 {
+            push(p - 1, -1.0);
+        }
+        if c + 1 < k {
+            push(p + 1, -1.0);
+        }
+    }
+    let rows: DArray<usize, 1> = rows.into();
+    let cols: DArray<usize, 1> = cols.into();
+    let values: DArray<f64, 1> = values.into();
+    let a = SparseMatrix::from_coo(n, n, SparseFormat::Csr, &rows, &cols, &values).expect("from_coo failed");
+    assert_eq!(a.nnz(), 5 * n - 4 * k);
+
+    let op = SparseOperator::new(bd, &a);
+    let jacobi = Jacobi::from_diagonal(&op.diagonal().unwrap()).expect("Jacobi failed");
+    let b = DArray::<f64, 1>::from_fn([n], |i| (0.01 * i[0] as f64).sin());
+    let mut x = DArray::<f64, 1>::from_elem([n], 0.0);
+    cg(bd, |x, y| op.apply(x, y), &b, &mut x, &jacobi, &KrylovConfig::default()).expect("CG did not converge");
+
+    let mut ax = DArray::<f64, 1>::from_elem([n], 0.0);
+    op.apply(&x, &mut ax);
+    for i in 0..n {
+        assert!((ax[i] - b[i]).abs() < 1e-7);
+    }
+    // Symmetric: the adjoint acts in the same way.
+    let mut ahx = DArray::<f64, 1>::from_elem([n], 0.0);
+    op.apply_adjoint(&x, &mut ahx);
+    for i in 0..n {
+        assert!((ahx[i] - ax[i]).abs() < 1e-12);
+    }
+}
+
+pub fn test_spmv_dimension_mismatch(bd: &impl SparseMatVec<f64>) {
+    let a = SparseMatrix::from_dense(&pattern::<f64>(3, 4), SparseFormat::Csr);
+    let x = DArray::<f64, 1>::from_elem([3], 1.0);
+    let _ = bd.spmv(&a, &x).eval();
+}
@@ -0,0 +1,305 @@
+use mdarray::DArray;
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    sparse::{SparseFormat, SparseMatrix},
+    sparse_solve::{SparseFactorization, SparseOrdering, SparseSolve, SparseSolveError},
+};
+
+type C = Complex<f64>;
+
+/// Matrix on a `k × k` grid with a 5-point stencil: `diag` on the
+/// diagonal, `east`/`west` for horizontal and `-1` for vertical neighbours.
+fn stencil<T: ComplexFloat>(
+    k: usize,
+    diag: impl Fn(usize) -> T,
+    east: T,
+    west: T,
+    format: SparseFormat,
+) -> SparseMatrix<T> {
+    let n = k * k;
+    let (mut rows, mut cols, mut values) = (Vec::new(), Vec::new(), Vec::new());
+    for p in 0..n {
+        let mut push = |q: usize, v: T| {
+            rows.push(p);
+            cols.push(q);
+            values.push(v);
+        };
+        push(p, diag(p));
+        if p % k + 1 < k {
+            push(p + 1, east);
+        }
+        if p % k > 0 {
+            push(p - 1, west);
+        }
+        if p >= k {
+            push(p - k, -T::one());
+        }
+        if p + k < n {
+            push(p + k, -T::one());
+        }
+    }
+    let rows: DArray<usize, 1> = rows.into();
+    let cols: DArray<usize, 1> = cols.into();
+    let values: DArray<T, 1> = values.into();
+    SparseMatrix::from_coo(n, n, format, &rows, &cols, &values).expect("from_coo failed")
+}
+
+fn laplacian(k: usize, format: SparseFormat) -> SparseMatrix<f64> {
+    stencil(k, |p| 4.0 + 0.1 * (p % 3) as f64, -1.0, -1.0, format)
+}
+
+/// Convection-diffusion: not symmetric.
+fn convection(k: usize, format: SparseFormat) -> SparseMatrix<f64> {
+    stencil(k, |_| 4.0, -1.6, -0.4, format)
+}
+
+fn rhs<T: ComplexFloat<Real = f64>>(n: usize, k: usize) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([n, k], |i| {
+        T::from((0.1 * (i[0] + 7 * i[1]) as f64).sin() + 1.0).unwrap()
+    })
+}
+
+/// `A X - B`
+fn residual<T: ComplexFloat>(
+    a: &SparseMatrix<T>,
+    x: &DArray<T, 2>,
+    b: &DArray<T, 2>,
+) -> DArray<T, 2> {
+    let mut r = DArray::<T, 2>::from_fn([b.shape().0, b.shape().1], |i| -b[[i[0], i[1]]]);
+    for (i, l, &v) in a.iter() {
+        for j in 0..x.shape().1 {
+            r[[i, j]] = r[[i, j]] + v * x[[l, j]];
+        }
+    }
+    r
+}
+
+fn check_solution<T: ComplexFloat<Real = f64>>(
+    a: &SparseMatrix<T>,
+    x: &DArray<T, 2>,
+    b: &DArray<T, 2>,
+) {
+    assert_eq!(*x.shape(), (a.shape().1, b.shape().1));
+    for r in residual(a, x, b).iter() {
+        assert!(r.abs() < 1e-10);
+    }
+}
+
+pub fn test_sparse_cholesky<B: SparseSolve<f64>>(bd: &B) {
+    let a = laplacian(12, SparseFormat::Csr);
+    let n = 144;
+    let b = rhs::<f64>(n, 3);
+    let reversed = DArray::<usize, 1>::from_fn([n], |i| n - 1 - i[0]);
+
+    for ordering in [
+        SparseOrdering::Auto,
+        SparseOrdering::Natural,
+        SparseOrdering::Custom(reversed),
+    ] {
+        let symbolic = bd
+            .analyze(&a, SparseFactorization::Cholesky, ordering)
+            .expect("analysis failed");
+        let factor = bd.factorize(&symbolic, &a).expect("Cholesky failed");
+        check_solution(
+            &a,
+            &bd.solve_factored(&factor, &b).expect("solve failed"),
+            &b,
+        );
+    }
+
+    // Only the lower triangle is read.
+    let lower = a.to_csc();
+    let (indptr, indices, values) = lower.clone().into_parts();
+    let mut values = values;
+    for j in 0..n {
+        for k in indptr[j]..indptr[j + 1] {
+            if indices[k] < j {
+                values[k] = 1e3;
+            }
+        }
+    }
+    let garbage_upper =
+        SparseMatrix::new(n, n, SparseFormat::Csc, indptr, indices, values).unwrap();
+    let symbolic = bd
+        .analyze(
+            &garbage_upper,
+            SparseFactorization::Cholesky,
+            SparseOrdering::Auto,
+        )
+        .unwrap();
+    let factor = bd
+        .factorize(&symbolic, &garbage_upper)
+        .expect("Cholesky failed");
+    check_solution(&lower, &bd.solve_factored(&factor, &b).unwrap(), &b);
+}
+
+pub fn test_sparse_cholesky_complex<B: SparseSolve<C>>(bd: &B) {
+    let a = stencil(
+        8,
+        |p| C::new(4.5 + 0.1 * p as f64, 0.0),
+        C::new(-1.0, 0.5),
+        C::new(-1.0, -0.5),
+        SparseFormat::Csc,
+    );
+    let b = rhs::<C>(64, 2);
+    let symbolic = bd
+        .analyze(&a, SparseFactorization::Cholesky, SparseOrdering::Amd)
+        .unwrap();
+    let factor = bd.factorize(&symbolic, &a).expect("Cholesky failed");
+    check_solution(&a, &bd.solve_factored(&factor, &b).unwrap(), &b);
+}
+
+pub fn test_sparse_lu<B: SparseSolve<f64>>(bd: &B) {
+    for format in [SparseFormat::Csr, SparseFormat::Csc] {
+        let a = convection(10, format);
+        let b = rhs::<f64>(100, 4);
+        let symbolic = bd
+            .analyze(&a, SparseFactorization::Lu, SparseOrdering::Auto)
+            .expect("analysis failed");
+        let factor = bd.factorize(&symbolic, &a).expect("LU failed");
+        check_solution(&a, &bd.solve_factored(&factor, &b).unwrap(), &b);
+
+        let mut x = b.clone();
+        bd.solve_factored_write(&factor, &mut x)
+            .expect("solve failed");
+        check_solution(&a, &x, &b);
+    }
+}
+
+pub fn test_sparse_qr<B: SparseSolve<f64>>(bd: &B) {
+    // Tall matrix: the convection matrix stacked on a scaled identity.
+    let square = convection(6, SparseFormat::Csr).to_dense();
+    let (m, n) = (72, 36);
+    let dense = DArray::<f64, 2>::from_fn([m, n], |i| {
+        if i[0] < n {
+            square[[i[0], i[1]]]
+        } else if i[0] - n == i[1] {
+            0.5
+        } else {
+            0.0
+        }
+    });
+    let a = SparseMatrix::from_dense(&dense, SparseFormat::Csc);
+    let b = rhs::<f64>(m, 2);
+
+    let symbolic = bd
+        .analyze(&a, SparseFactorization::Qr, SparseOrdering::Colamd)
+        .expect("analysis failed");
+    let factor = bd.factorize(&symbolic, &a).expect("QR failed");
+    let x = bd.solve_factored(&factor, &b).expect("solve failed");
+    assert_eq!(*x.shape(), (n, 2));
+
+    // Normal equations A^T (A x - b) = 0.
+    let r = residual(&a, &x, &b);
+    for j in 0..n {
+        for c in 0..2 {
+            let g: f64 = (0..m).map(|i| dense[[i, j]] * r[[i, c]]).sum();
+            assert!(g.abs() < 1e-10);
+        }
+    }
+
+    let mut x = b.clone();
+    assert!(matches!(
+        bd.solve_factored_write(&factor, &mut x),
+        Err(SparseSolveError::NotSquare { rows: 72, cols: 36 })
+    ));
+}
+
+pub fn test_sparse_symbolic_reuse<B: SparseSolve<f64>>(bd: &B) {
+    let k = 9;
+    let n = k * k;
+    let b = rhs::<f64>(n, 2);
+
+    for kind in [SparseFactorization::Cholesky, SparseFactorization::Lu] {
+        let symbolic = bd
+            .analyze(&laplacian(k, SparseFormat::Csc), kind, SparseOrdering::Auto)
+            .unwrap();
+        // Same pattern, new values.
+        for shift in [0.0, 1.0, 10.0] {
+            let a = stencil(
+                k,
+                |p| 4.0 + shift + 0.3 * (p % 4) as f64,
+                -1.0,
+                -1.0,
+                SparseFormat::Csc,
+            );
+            let factor = bd.factorize(&symbolic, &a).expect("factorization failed");
+            check_solution(&a, &bd.solve_factored(&factor, &b).unwrap(), &b);
+        }
+
+        // A different pattern is rejected.
+        let other = convection(k, SparseFormat::Csc);
+        let dense = other.to_dense();
+        let pruned = SparseMatrix::from_dense(
+            &DArray::<f64, 2>::from_fn([n, n], |i| {
+                if i[0].abs_diff(i[1]) == k {
+                    0.0
+                } else {
+                    dense[[i[0], i[1]]]
+                }
+            }),
+            SparseFormat::Csc,
+        );
+        assert!(matches!(
+            bd.factorize(&symbolic, &pruned),
+            Err(SparseSolveError::PatternMismatch)
+        ));
+    }
+}
+
+pub fn test_sparse_solve_errors<B: SparseSolve<f64>>(bd: &B) {
+    let a = laplacian(4, SparseFormat::Csr);
+    let tall =
+        SparseMatrix::from_dense(&DArray::<f64, 2>::from_elem([4, 3], 1.0), SparseFormat::Csc);
+
+    assert!(matches!(
+        bd.analyze(&tall, SparseFactorization::Lu, SparseOrdering::Auto),
+        Err(SparseSolveError::NotSquare { rows: 4, cols: 3 })
+    ));
+    assert!(matches!(
+        bd.analyze(
+            &tall.transpose(),
+            SparseFactorization::Qr,
+            SparseOrdering::Auto
+        ),
+        Err(SparseSolveError::Underdetermined { rows: 3, cols: 4 })
+    ));
+    assert!(matches!(
+        bd.analyze(
+            &a,
+            SparseFactorization::Cholesky,
+            SparseOrdering::Custom(vec![0, 1, 1].into())
+        ),
+        Err(SparseSolveError::InvalidPermutation { n: 16 })
+    ));
+
+    // Indefinite
+    let mut indefinite = a.clone();
+    for v in indefinite.values_mut().iter_mut() {
+        *v = -*v;
+    }
+    let symbolic = bd
+        .analyze(
+            &indefinite,
+            SparseFactorization::Cholesky,
+            SparseOrdering::Natural,
+        )
+        .unwrap();
+    assert!(matches!(
+        bd.factorize(&symbolic, &indefinite),
+        Err(SparseSolveError::NotPositiveDefinite { .. })
+    ));
+
+    let symbolic = bd
+        .analyze(&a, SparseFactorization::Lu, SparseOrdering::Auto)
+        .unwrap();
+    let factor = bd.factorize(&symbolic, &a).unwrap();
+    assert!(matches!(
+        bd.solve_factored(&factor, &rhs::<f64>(15, 1)),
+        Err(SparseSolveError::DimensionMismatch {
+            expected: 16,
+            rows: 15
+        })
+    ));
+}
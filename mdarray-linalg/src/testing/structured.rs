@@ -0,0 +1,270 @@
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    solve::SolveError,
+    structured::{
+        Diag, Diagonal, Hermitian, PackedHermitian, PackedSymmetric, Structured, Symmetric,
+        Triangular, Uplo,
+    },
+};
+
+type C = Complex<f64>;
+
+const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+/// Dense matrix without structure, with an imaginary part for complex types.
+fn full<T: ComplexFloat<Real = f64>>(m: usize, n: usize, seed: f64) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        let t = seed + (1 + 3 * i[0] + 7 * i[1]) as f64;
+        T::from(t.sin()).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DSlice<T, 2>, b: &DSlice<T, 2>) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() < 1e-10, "{:?} != {:?}", x.re(), y.re());
+    }
+}
+
+fn dense_matmul<T: ComplexFloat, La: Layout, Lb: Layout>(
+    a: &Slice<T, (Dyn, Dyn), La>,
+    b: &Slice<T, (Dyn, Dyn), Lb>,
+) -> DArray<T, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        (0..k).fold(T::zero(), |acc, l| acc + a[[i[0], l]] * b[[l, i[1]]])
+    })
+}
+
+pub fn test_structured_wrappers() {
+    let a = DArray::<f64, 2>::from_fn([3, 3], |i| (1 + 3 * i[0] + i[1]) as f64);
+
+    let u = Triangular::upper(&a);
+    assert_eq!(u.to_dense()[[2, 0]], 0.);
+    assert_eq!(u.to_dense()[[0, 2]], 3.);
+    assert_eq!(u.det(), 1. * 5. * 9.);
+    let l = Triangular::lower(&a).unit();
+    assert_eq!(
+        (l.diag(), l.get(1, 1), l.get(2, 1), l.get(1, 2)),
+        (Diag::Unit, 1., 8., 0.)
+    );
+    assert_eq!(l.det(), 1.);
+
+    let d: DArray<f64, 1> = vec![2., -1., 3.].into();
+    assert_eq!(Diagonal::new(&d).det(), -6.);
+    assert_eq!(Diagonal::new(&d).to_dense()[[2, 2]], 3.);
+
+    for uplo in UPLOS {
+        let s = Symmetric::new(&a, uplo).to_dense();
+        let p = PackedSymmetric::from_dense(&a, uplo);
+        assert_eq!(p.data().len(), 6);
+        assert_eq!(p.to_dense(), s);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(s[[i, j]], s[[j, i]]);
+                let stored = if uplo == Uplo::Upper {
+                    i.min(j)
+                } else {
+                    i.max(j)
+                };
+                assert_eq!(s[[i, j]], a[[stored, i + j - stored]]);
+            }
+        }
+    }
+    // LAPACK packing order
+    let lower = PackedSymmetric::from_dense(&a, Uplo::Lower).into_data();
+    let upper = PackedSymmetric::from_dense(&a, Uplo::Upper).into_data();
+    let (lower, upper): (&[f64], &[f64]) = (lower.as_ref(), upper.as_ref());
+    assert_eq!(lower, &[1., 4., 7., 5., 8., 9.]);
+    assert_eq!(upper, &[1., 2., 5., 3., 6., 9.]);
+
+    // Hermitian wrappers mirror with conjugation and ignore the imaginary
+    // part of the diagonal.
+    let h = full::<C>(3, 3, 0.);
+    for uplo in UPLOS {
+        let dense = Hermitian::new(&h, uplo).to_dense();
+        assert_eq!(PackedHermitian::from_dense(&h, uplo).to_dense(), dense);
+        for i in 0..3 {
+            assert_eq!(dense[[i, i]].im, 0.);
+            for j in 0..3 {
+                assert_eq!(dense[[i, j]], dense[[j, i]].conj());
+            }
+        }
+    }
+}
+
+pub fn test_diag_scale<B: Structured<f64>>(bd: &B) {
+    let d: DArray<f64, 1> = vec![2., -1., 0.5, 3.].into();
+    let b = full::<f64>(4, 3, 0.);
+    let expected = dense_matmul(&Diagonal::new(&d).to_dense(), &b);
+
+    assert_close(&bd.diag_scale(&Diagonal::new(&d), &b), &expected);
+    let mut c = b.clone();
+    bd.diag_scale_write(&Diagonal::new(&d), &mut c);
+    assert_close(&c, &expected);
+}
+
+fn check_tri_matmul<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64>,
+    B: Structured<T>,
+{
+    let n = 5;
+    // The unused triangle holds garbage.
+    let a = full::<T>(n, n, 1.);
+    let b = full::<T>(n, 3, 2.);
+    let at = a.transpose().to_array();
+    for uplo in UPLOS {
+        for diag in [Diag::NonUnit, Diag::Unit] {
+            let tri = Triangular::new(&a, uplo, diag);
+            let expected = dense_matmul(&tri.to_dense(), &b);
+            assert_close(&bd.tri_matmul(&tri, &b), &expected);
+
+            let mut c = b.clone();
+            bd.tri_matmul_write(&tri, &mut c);
+            assert_close(&c, &expected);
+
+            // Column-major operands
+            let a_view = at.transpose();
+            let tri = Triangular::new(&a_view, uplo, diag);
+            let mut ct = b.transpose().to_array();
+            bd.tri_matmul_write(&tri, &mut ct.transpose_mut());
+            assert_close(&ct.transpose().to_array(), &expected);
+        }
+    }
+}
+
+pub fn test_tri_matmul<B: Structured<f64> + Structured<C>>(bd: &B) {
+    check_tri_matmul::<f64, _>(bd);
+    check_tri_matmul::<C, _>(bd);
+}
+
+fn check_tri_solve<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64>,
+    B: Structured<T>,
+{
+    let n = 6;
+    let a = DArray::<T, 2>::from_fn([n, n], |i| {
+        let x = full::<T>(n, n, 3.)[[i[0], i[1]]];
+        if i[0] == i[1] {
+            x + T::from(4.).unwrap()
+        } else {
+            x
+        }
+    });
+    let b = full::<T>(n, 2, 4.);
+    let at = a.transpose().to_array();
+    for uplo in UPLOS {
+        for diag in [Diag::NonUnit, Diag::Unit] {
+            let tri = Triangular::new(&a, uplo, diag);
+            let x = bd.tri_solve(&tri, &b).expect("triangular solve failed");
+            assert_close(&dense_matmul(&tri.to_dense(), &x), &b);
+
+            let a_view = at.transpose();
+            let tri = Triangular::new(&a_view, uplo, diag);
+            let mut xt = b.transpose().to_array();
+            bd.tri_solve_write(&tri, &mut xt.transpose_mut())
+                .expect("triangular solve failed");
+            assert_close(&xt.transpose().to_array(), &x);
+        }
+    }
+}
+
+pub fn test_tri_solve<B: Structured<f64> + Structured<C>>(bd: &B) {
+    check_tri_solve::<f64, _>(bd);
+    check_tri_solve::<C, _>(bd);
+}
+
+pub fn test_tri_solve_singular<B: Structured<f64>>(bd: &B) {
+    let mut a = full::<f64>(4, 4, 0.);
+    a[[2, 2]] = 0.;
+    let b = full::<f64>(4, 1, 1.);
+
+    let mut x = b.clone();
+    assert!(matches!(
+        bd.tri_solve_write(&Triangular::upper(&a), &mut x),
+        Err(SolveError::SingularMatrix { diagonal: 3 })
+    ));
+    assert_eq!(x, b);
+
+    // The diagonal of a unit triangular matrix is not read.
+    assert!(bd.tri_solve(&Triangular::upper(&a).unit(), &b).is_ok());
+}
+
+fn check_self_adjoint_matmul<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64>,
+    B: Structured<T>,
+{
+    let n = 5;
+    let a = full::<T>(n, n, 5.);
+    let b = full::<T>(n, 4, 6.);
+    let at = a.transpose().to_array();
+    for uplo in UPLOS {
+        let sym = Symmetric::new(&a, uplo);
+        let herm = Hermitian::new(&a, uplo);
+        assert_close(&bd.sym_matmul(&sym, &b), &dense_matmul(&sym.to_dense(), &b));
+        assert_close(
+            &bd.herm_matmul(&herm, &b),
+            &dense_matmul(&herm.to_dense(), &b),
+        );
+
+        // Column-major operands
+        let (a_view, b_view) = (at.transpose(), b.transpose().to_array());
+        let sym = Symmetric::new(&a_view, uplo);
+        let herm = Hermitian::new(&a_view, uplo);
+        let b_view = b_view.transpose();
+        assert_close(
+            &bd.sym_matmul(&sym, &b_view),
+            &dense_matmul(&sym.to_dense(), &b),
+        );
+        assert_close(
+            &bd.herm_matmul(&herm, &b_view),
+            &dense_matmul(&herm.to_dense(), &b),
+        );
+    }
+}
+
+pub fn test_sym_matmul<B: Structured<f64> + Structured<C>>(bd: &B) {
+    check_self_adjoint_matmul::<f64, _>(bd);
+    check_self_adjoint_matmul::<C, _>(bd);
+}
+
+fn check_packed_matvec<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64>,
+    B: Structured<T>,
+{
+    let n = 6;
+    let a = full::<T>(n, n, 7.);
+    let x = full::<T>(n, 2, 8.);
+    let x_strided = x.view(.., 1);
+    let x_col = x_strided.to_array();
+    let x_col = DArray::<T, 2>::from_fn([n, 1], |i| x_col[[i[0]]]);
+    for uplo in UPLOS {
+        let p = PackedSymmetric::from_dense(&a, uplo);
+        let y = bd.packed_sym_matvec(&p, &x_strided);
+        let expected = dense_matmul(&p.to_dense(), &x_col);
+        assert_close(&DArray::<T, 2>::from_fn([n, 1], |i| y[[i[0]]]), &expected);
+
+        let p = PackedHermitian::from_dense(&a, uplo);
+        let y = bd.packed_herm_matvec(&p, &x_strided);
+        let expected = dense_matmul(&p.to_dense(), &x_col);
+        assert_close(&DArray::<T, 2>::from_fn([n, 1], |i| y[[i[0]]]), &expected);
+    }
+}
+
+pub fn test_packed_matvec<B: Structured<f64> + Structured<C>>(bd: &B) {
+    check_packed_matvec::<f64, _>(bd);
+    check_packed_matvec::<C, _>(bd);
+}
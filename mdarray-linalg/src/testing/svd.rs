@@ -1,11 +1,15 @@
 use approx::assert_relative_eq;
-use mdarray::{DArray, Dyn};
+use mdarray::{Array, Const, DArray, Dyn};
 use num_complex::{Complex, ComplexFloat};
+use num_traits::NumCast;
 use rand::Rng;
 
 use super::common::{assert_complex_matrix_eq, assert_matrix_eq, naive_matmul};
 use crate::{
-    svd::{SVD, SVDDecomp},
+    eig::jacobi_eigh,
+    gauge::{Gauge, SpectrumOrder},
+    structured::Uplo,
+    svd::{SVD, SVDCutoff, SVDDecomp, SVDError, SVDTruncation},
     utils::pretty_print,
 };
 
@@ -16,7 +20,7 @@ fn test_svd_reconstruction<T>(
     bd: &impl SVD<T, Dyn, SingularValue = T>,
     a: &DArray<T, 2>,
     debug_print: bool,
-)
+) -> SVDDecomp<T, T, Dyn>
 where
     T: ComplexFloat<Real = f64>
         + Default
@@ -30,7 +34,8 @@ where
     let (m, n) = (a.shape().0, a.shape().1);
     let min_dim = m.min(n);
 
-    let SVDDecomp { s, u, vt } = bd.svd(&mut a.clone()).expect("SVD failed");
+    let decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let SVDDecomp { s, u, vt } = &decomp;
 
     let mut sigma = DArray::<T, 2>::zeros([m, n]);
     for i in 0..min_dim {
@@ -45,18 +50,18 @@ where
         println!("=== Σ (Sigma) ===");
         pretty_print(&sigma);
         println!("=== U ===");
-        pretty_print(&u);
+        pretty_print(u);
         println!("=== Vᵀ ===");
-        pretty_print(&vt);
+        pretty_print(vt);
     }
 
-    let us = naive_matmul(&u, &sigma);
+    let us = naive_matmul(u, &sigma);
     if debug_print {
         println!("=== U × Σ ===");
         pretty_print(&us);
     }
 
-    let usvt = naive_matmul(&us, &vt);
+    let usvt = naive_matmul(&us, vt);
     if debug_print {
         println!("=== U × Σ × Vᵀ  ===");
         pretty_print(&usvt);
@@ -65,24 +70,80 @@ where
     }
 
     assert_matrix_eq!(*a, usvt);
+    decomp
+}
+
+/// Canonical eigenvectors of the Gram matrix `X X^H`, in descending order of
+/// eigenvalues, computed independently of the backend by [`jacobi_eigh`].
+fn gram_eigenvectors<T: ComplexFloat<Real = f64>>(x: &DArray<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *x.shape();
+    let xxh = DArray::<T, 2>::from_fn([m, m], |i| {
+        (0..n).fold(T::zero(), |acc, k| acc + x[[i[0], k]] * x[[i[1], k]].conj())
+    });
+    let mut decomp = jacobi_eigh(&xxh).expect("Self-adjoint eigenvalue decomposition failed");
+    decomp.canonicalize(&Gauge {
+        order: SpectrumOrder::Descending,
+        ..Default::default()
+    });
+    decomp.eigenvectors
+}
+
+/// Compares the singular vectors of the full SVD `decomp` of `a`, in the
+/// default [`Gauge`], with those computed independently of the backend: the
+/// columns of `U` are the eigenvectors of `A A^H`, the rows of `V^H` for
+/// nonzero singular values follow as `u^H A / s`, and the others are the null
+/// vectors of `A`.  The nonzero singular values must be simple.
+fn check_canonical_svd<T, S>(a: &DArray<T, 2>, mut decomp: SVDDecomp<T, S, Dyn>)
+where
+    T: ComplexFloat<Real = f64> + std::fmt::Debug,
+    S: ComplexFloat<Real = f64>,
+{
+    decomp.canonicalize(&Gauge::default());
+    let (m, n) = *a.shape();
+    let ah = DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj());
+    let u = gram_eigenvectors(a);
+    let v = gram_eigenvectors(&ah);
+    let rank = decomp.s.iter().filter(|x| x.re() > 1e-10 * decomp.s[0].re()).count();
+
+    let check = |actual: T, expected: T, what: &str| {
+        assert!((actual - expected).abs() < 1e-8, "{what}: {actual:?} != {expected:?}");
+    };
+    for k in 0..m {
+        for i in 0..m {
+            check(decomp.u[[i, k]], u[[i, k]], &format!("U[{i}, {k}]"));
+        }
+    }
+    for k in 0..n {
+        for l in 0..n {
+            let expected = if k < rank {
+                (0..m).fold(T::zero(), |acc, r| acc + u[[r, k]].conj() * a[[r, l]]) * <T as NumCast>::from(1. / decomp.s[k].re()).unwrap()
+            } else {
+                v[[l, k]].conj()
+            };
+            check(decomp.vt[[k, l]], expected, &format!("V^H[{k}, {l}]"));
+        }
+    }
 }
 
 pub fn test_svd_square_matrix(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
     let n = 3;
     let a = DArray::<f64, 2>::from_fn([n, n], |i| (i[0] * i[1]) as f64);
-    test_svd_reconstruction(bd, &a, true);
+    let decomp = test_svd_reconstruction(bd, &a, true);
+    check_canonical_svd(&a, decomp);
 }
 
 pub fn test_svd_rectangular_m_gt_n(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
     let (m, n) = (4, 3);
     let a = DArray::<f64, 2>::from_fn([m, n], |i| (i[0] * i[1]) as f64);
-    test_svd_reconstruction(bd, &a, true);
+    let decomp = test_svd_reconstruction(bd, &a, true);
+    check_canonical_svd(&a, decomp);
 }
 
 pub fn test_svd_rectangular_n_gt_m(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
     let (m, n) = (3, 4);
     let a = DArray::<f64, 2>::from_fn([m, n], |i| (i[0] * i[1]) as f64);
-    test_svd_reconstruction(bd, &a, true);
+    let decomp = test_svd_reconstruction(bd, &a, true);
+    check_canonical_svd(&a, decomp);
 }
 
 pub fn test_svd_big_square_matrix(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
@@ -95,7 +156,8 @@ pub fn test_svd_random_matrix(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
     let mut rng = rand::rng();
     let n = 4;
     let a = DArray::<f64, 2>::from_fn([n, n], |_| rng.random::<f64>());
-    test_svd_reconstruction(bd, &a, true);
+    let decomp = test_svd_reconstruction(bd, &a, true);
+    check_canonical_svd(&a, decomp);
 }
 
 pub fn test_svd_cplx_square_matrix(
@@ -106,7 +168,8 @@ pub fn test_svd_cplx_square_matrix(
         Complex::new((i[0] * i[1]) as f64, i[1] as f64)
     });
 
-    let SVDDecomp { s, u, vt } = bd.svd(&mut a.clone()).expect("SVD failed");
+    let decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let SVDDecomp { s, u, vt } = &decomp;
 
     // assert_eq!(*s.shape(), (n,));
     // assert_eq!(*u.shape(), (n, n));
@@ -120,20 +183,21 @@ pub fn test_svd_cplx_square_matrix(
     println!("=== Σ (Sigma) ===");
     pretty_print(&sigma);
     println!("=== U ===");
-    pretty_print(&u);
+    pretty_print(u);
     println!("=== Vᵀ ===");
-    pretty_print(&vt);
+    pretty_print(vt);
 
-    let us = naive_matmul(&u, &sigma);
+    let us = naive_matmul(u, &sigma);
     println!("=== U × Σ ===");
     pretty_print(&us);
-    let usvt = naive_matmul(&us, &vt);
+    let usvt = naive_matmul(&us, vt);
     println!("=== U × Σ × Vᵀ  ===");
     pretty_print(&usvt);
     println!("=== A original ===");
     pretty_print(&a);
 
     assert_complex_matrix_eq!(a, usvt);
+    check_canonical_svd(&a, decomp);
 }
 
 /// Test complex SVD with random matrix having significant imaginary parts.
@@ -152,7 +216,8 @@ pub fn test_svd_cplx_random_matrix(
         )
     });
 
-    let SVDDecomp { s, u, vt } = bd.svd(&mut a.clone()).expect("SVD failed");
+    let decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let SVDDecomp { s, u, vt } = &decomp;
 
     // Build sigma matrix
     let mut sigma = DArray::<Complex<f64>, 2>::zeros([n, n]);
@@ -161,8 +226,318 @@ pub fn test_svd_cplx_random_matrix(
     }
 
     // Reconstruct: A = U * Σ * V^H (vt should be V^H)
-    let us = naive_matmul(&u, &sigma);
-    let usvt = naive_matmul(&us, &vt);
+    let us = naive_matmul(u, &sigma);
+    let usvt = naive_matmul(&us, vt);
 
     assert_complex_matrix_eq!(a, usvt);
+    check_canonical_svd(&a, decomp);
+}
+
+/// Check that `(s, u, vt)` holds the singular triplets `lo..hi` of `a`:
+/// the singular values match the full spectrum and `A v_i = s_i u_i`.
+fn check_svd_triplets(
+    bd: &impl SVD<f64, Dyn, SingularValue = f64>,
+    a: &DArray<f64, 2>,
+    lo: usize,
+    hi: usize,
+) {
+    let (m, n) = (a.shape().0, a.shape().1);
+    let k = hi - lo;
+
+    let SVDDecomp { s, u, vt } = bd.svd_range(&mut a.clone(), lo, hi).expect("SVD failed");
+    let s_full = bd.svd_s(&mut a.clone()).expect("SVD failed");
+
+    assert_eq!(*s.shape(), (k,));
+    assert_eq!(*u.shape(), (m, k));
+    assert_eq!(*vt.shape(), (k, n));
+
+    for i in 0..k {
+        assert_relative_eq!(s[i], s_full[lo + i], epsilon = 1e-10);
+
+        for r in 0..m {
+            let av: f64 = (0..n).map(|j| a[[r, j]] * vt[[i, j]]).sum();
+            assert_relative_eq!(av, s[i] * u[[r, i]], epsilon = 1e-10);
+        }
+
+        let norm_u: f64 = (0..m).map(|r| u[[r, i]] * u[[r, i]]).sum();
+        let norm_v: f64 = (0..n).map(|j| vt[[i, j]] * vt[[i, j]]).sum();
+        assert_relative_eq!(norm_u, 1.0, epsilon = 1e-10);
+        assert_relative_eq!(norm_v, 1.0, epsilon = 1e-10);
+    }
+}
+
+fn graded_matrix(m: usize, n: usize) -> DArray<f64, 2> {
+    DArray::<f64, 2>::from_fn([m, n], |i| {
+        1.0 / (i[0] + i[1] + 1) as f64 + if i[0] == i[1] { i[0] as f64 } else { 0.0 }
+    })
+}
+
+pub fn test_svd_partial(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    let a = graded_matrix(6, 4);
+
+    let SVDDecomp { s, u, vt } = bd.svd_partial(&mut a.clone(), 2).expect("SVD failed");
+    assert_eq!(*s.shape(), (2,));
+    assert_eq!(*u.shape(), (6, 2));
+    assert_eq!(*vt.shape(), (2, 4));
+    assert!(s[0] >= s[1]);
+
+    check_svd_triplets(bd, &a, 0, 2);
+    check_svd_triplets(bd, &graded_matrix(3, 5), 0, 3);
+}
+
+pub fn test_svd_range(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    let a = graded_matrix(5, 5);
+    check_svd_triplets(bd, &a, 1, 3);
+    check_svd_triplets(bd, &a, 4, 5);
+
+    let SVDDecomp { s, u, vt } = bd.svd_range(&mut a.clone(), 2, 2).expect("SVD failed");
+    assert_eq!(*s.shape(), (0,));
+    assert_eq!(*u.shape(), (5, 0));
+    assert_eq!(*vt.shape(), (0, 5));
+}
+
+pub fn test_svd_partial_const(bd: &impl SVD<f64, Const<3>, SingularValue = f64>) {
+    // Singular values 5, 3 and 1.
+    let a = [[2., 1., 0.], [1., 2., 0.], [0., 0., 5.]];
+    let mut a_const =
+        Array::<f64, (Const<3>, Const<3>)>::from_fn((Const, Const), |i| a[i[0]][i[1]]);
+
+    let SVDDecomp { s, u, vt } = bd.svd_partial(&mut a_const, 2).expect("SVD failed");
+    assert_eq!(*s.shape(), (2,));
+    assert_eq!(*u.shape(), (3, 2));
+    assert_eq!(*vt.shape(), (2, 3));
+    assert_relative_eq!(s[0], 5., epsilon = 1e-10);
+    assert_relative_eq!(s[1], 3., epsilon = 1e-10);
+    for k in 0..2 {
+        for i in 0..3 {
+            let av = (0..3).map(|j| a[i][j] * vt[[k, j]]).sum::<f64>();
+            assert_relative_eq!(av, s[k] * u[[i, k]], epsilon = 1e-10);
+        }
+    }
+}
+
+pub fn test_svd_range_invalid(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    let a = graded_matrix(4, 3);
+    assert!(matches!(
+        bd.svd_range(&mut a.clone(), 0, 4),
+        Err(SVDError::InvalidRange)
+    ));
+    assert!(matches!(
+        bd.svd_range(&mut a.clone(), 2, 1),
+        Err(SVDError::InvalidRange)
+    ));
+}
+
+fn diagonal_matrix(d: &[f64]) -> DArray<f64, 2> {
+    let n = d.len();
+    DArray::<f64, 2>::from_fn([n + 1, n], |i| if i[0] == i[1] { d[i[0]] } else { 0.0 })
+}
+
+pub fn test_svd_truncate_max_rank(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    let a = graded_matrix(6, 5);
+    let s_full = bd.svd_s(&mut a.clone()).expect("SVD failed");
+    let total: f64 = s_full.iter().map(|x| x * x).sum();
+    let tail: f64 = s_full.iter().skip(2).map(|x| x * x).sum();
+
+    let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let info = decomp.truncate(&SVDTruncation {
+        max_rank: Some(2),
+        ..Default::default()
+    });
+
+    assert_eq!(info.rank, 2);
+    assert_eq!(*decomp.s.shape(), (2,));
+    assert_eq!(*decomp.u.shape(), (6, 2));
+    assert_eq!(*decomp.vt.shape(), (2, 5));
+    assert_relative_eq!(info.discarded_weight, tail / total, epsilon = 1e-12);
+    assert_relative_eq!(info.norm_ratio, (1.0 - tail / total).sqrt(), epsilon = 1e-12);
+    for i in 0..2 {
+        assert_relative_eq!(decomp.s[i], s_full[i], epsilon = 1e-10);
+    }
+
+    // A larger bound than the spectrum keeps everything.
+    let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let info = decomp.truncate(&SVDTruncation {
+        max_rank: Some(10),
+        ..Default::default()
+    });
+    assert_eq!(info.rank, 5);
+    assert_eq!(info.discarded_weight, 0.0);
+    assert_relative_eq!(info.norm_ratio, 1.0);
+}
+
+pub fn test_svd_truncate_cutoffs(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    // Singular values 4, 2, 1, 0.5 with total weight 21.25.
+    let a = diagonal_matrix(&[1.0, 4.0, 0.5, 2.0]);
+    let rank = |cutoff, max_rank| {
+        let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+        decomp
+            .truncate(&SVDTruncation {
+                max_rank,
+                cutoff: Some(cutoff),
+                ..Default::default()
+            })
+            .rank
+    };
+
+    assert_eq!(rank(SVDCutoff::Absolute(0.75), None), 3);
+    assert_eq!(rank(SVDCutoff::Absolute(0.75), Some(2)), 2);
+    assert_eq!(rank(SVDCutoff::Relative(0.3), None), 2);
+    assert_eq!(rank(SVDCutoff::Relative(0.0), None), 4);
+    assert_eq!(rank(SVDCutoff::Weight(0.3 / 21.25), None), 3);
+    assert_eq!(rank(SVDCutoff::Weight(1.3 / 21.25), None), 2);
+    assert_eq!(rank(SVDCutoff::Absolute(5.0), None), 0);
+}
+
+pub fn test_svd_truncate_renormalize_absorb(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    let a = graded_matrix(5, 4);
+    let total: f64 = a.iter().map(|x| x * x).sum();
+
+    let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    let info = decomp.truncate(&SVDTruncation {
+        max_rank: Some(2),
+        renormalize: true,
+        ..Default::default()
+    });
+    let kept: f64 = decomp.s.iter().map(|x| x * x).sum();
+    assert!(info.norm_ratio < 1.0);
+    assert_relative_eq!(kept, total, epsilon = 1e-10);
+
+    // Absorbing √s leaves the truncated product unchanged.
+    let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    decomp.truncate(&SVDTruncation {
+        max_rank: Some(2),
+        ..Default::default()
+    });
+    let mut sigma = DArray::<f64, 2>::zeros([2, 2]);
+    for i in 0..2 {
+        sigma[[i, i]] = decomp.s[i];
+    }
+    let expected = naive_matmul(&naive_matmul(&decomp.u, &sigma), &decomp.vt);
+
+    decomp.absorb_sqrt_s();
+    assert!(decomp.s.iter().all(|&x| x == 1.0));
+    let product = naive_matmul(&decomp.u, &decomp.vt);
+    assert_matrix_eq!(expected, product);
+}
+
+/// The backend must be configured with a [`crate::gauge::Gauge`].
+pub fn test_svd_gauge(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    // Singular values 2, 2, 1 with U = I and V^T determined by the signs.
+    let a = DArray::<f64, 2>::from_fn([4, 3], |i| {
+        [[0., -2., 0.], [2., 0., 0.], [0., 0., -1.], [0., 0., 0.]][i[0]][i[1]]
+    });
+    let expected_u = DArray::<f64, 2>::from_fn([4, 4], |i| if i[0] == i[1] { 1. } else { 0. });
+    let expected_vt = DArray::<f64, 2>::from_fn([3, 3], |i| {
+        [[0., -1., 0.], [1., 0., 0.], [0., 0., -1.]][i[0]][i[1]]
+    });
+
+    let mut decomp = bd.svd(&mut a.clone()).expect("SVD failed");
+    for (x, y) in decomp.s.iter().zip([2., 2., 1.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+    assert_matrix_eq!(decomp.u, expected_u, 1e-10);
+    assert_matrix_eq!(decomp.vt, expected_vt, 1e-10);
+
+    // Canonicalization is idempotent.
+    assert_eq!(decomp.canonicalize(&Gauge::default()), [0, 1, 2]);
+    assert_matrix_eq!(decomp.u, expected_u, 1e-10);
+    assert_matrix_eq!(decomp.vt, expected_vt, 1e-10);
+
+    // An ascending order reverses the triplets and keeps the null vector of
+    // A^H last.
+    let ascending = Gauge {
+        singular_order: SpectrumOrder::Ascending,
+        ..Default::default()
+    };
+    assert_eq!(decomp.canonicalize(&ascending), [2, 1, 0]);
+    for (x, y) in decomp.s.iter().zip([1., 2., 2.]) {
+        assert_relative_eq!(*x, y, epsilon = 1e-10);
+    }
+    for i in 0..4 {
+        let j = if i < 3 { 2 - i } else { i };
+        for r in 0..4 {
+            assert_relative_eq!(decomp.u[[r, i]], expected_u[[r, j]], epsilon = 1e-10);
+        }
+    }
+    decomp.canonicalize(&Gauge::default());
+    assert_matrix_eq!(decomp.u, expected_u, 1e-10);
+    assert_matrix_eq!(decomp.vt, expected_vt, 1e-10);
+}
+
+pub fn test_svd_bidiagonal(bd: &impl SVD<f64, Dyn, SingularValue = f64>) {
+    // The zero on the diagonal makes B singular.
+    let n = 5;
+    let d = DArray::<f64, 1>::from_fn([n], |i| [3.0, 0.0, 1.5, -2.0, 1.0][i[0]]);
+    let e = DArray::<f64, 1>::from_fn([n - 1], |i| [1.0, 0.5, -1.0, 2.0][i[0]]);
+    let identity = DArray::<f64, 2>::from_fn([n, n], |i| if i[0] == i[1] { 1.0 } else { 0.0 });
+
+    for uplo in [Uplo::Upper, Uplo::Lower] {
+        let b = DArray::<f64, 2>::from_fn([n, n], |i| {
+            let (r, c) = (i[0], i[1]);
+            let off_diagonal = match uplo {
+                Uplo::Upper => r + 1 == c,
+                Uplo::Lower => c + 1 == r,
+            };
+            if r == c {
+                d[[r]]
+            } else if off_diagonal {
+                e[[r.min(c)]]
+            } else {
+                0.0
+            }
+        });
+
+        let SVDDecomp { s, u, vt } = bd
+            .svd_bidiagonal(&d, &e, uplo)
+            .expect("Bidiagonal SVD failed");
+        assert_eq!(*u.shape(), (n, n));
+        assert_eq!(*vt.shape(), (n, n));
+        assert!(s.iter().zip(s.iter().skip(1)).all(|(x, y)| x >= y));
+        assert!(s[n - 1].abs() < 1e-10);
+
+        let sigma = DArray::<f64, 2>::from_fn([n, n], |i| if i[0] == i[1] { s[i[0]] } else { 0.0 });
+        let usvt = naive_matmul(&naive_matmul(&u, &sigma), &vt);
+        assert_matrix_eq!(b, usvt, 1e-10);
+
+        let ut = DArray::<f64, 2>::from_fn([n, n], |i| u[[i[1], i[0]]]);
+        let v = DArray::<f64, 2>::from_fn([n, n], |i| vt[[i[1], i[0]]]);
+        let utu = naive_matmul(&ut, &u);
+        let vtv = naive_matmul(&vt, &v);
+        assert_matrix_eq!(utu, identity, 1e-10);
+        assert_matrix_eq!(vtv, identity, 1e-10);
+
+        let dense = bd.svd_s(&mut b.clone()).expect("SVD failed");
+        let values = bd
+            .svd_bidiagonal_s(&d, &e, uplo)
+            .expect("Bidiagonal singular values failed");
+        for i in 0..n {
+            assert_relative_eq!(values[i], dense[i], epsilon = 1e-10);
+            assert_relative_eq!(s[i], dense[i], epsilon = 1e-10);
+        }
+    }
+}
+
+pub fn test_svd_bidiagonal_const(bd: &impl SVD<f64, Const<3>, SingularValue = f64>) {
+    // The off-diagonal has its own, shorter static dimension.
+    let d = Array::<f64, (Const<3>,)>::from_fn((Const,), |i| [3.0, 2.0, 1.0][i[0]]);
+    let e = Array::<f64, (Const<2>,)>::from_fn((Const,), |_| 1.0);
+    let b = [[3.0, 1.0, 0.0], [0.0, 2.0, 1.0], [0.0, 0.0, 1.0]];
+
+    let SVDDecomp { s, u, vt } = bd
+        .svd_bidiagonal(&d, &e, Uplo::Upper)
+        .expect("Bidiagonal SVD failed");
+    for k in 0..3 {
+        for i in 0..3 {
+            let bv = (0..3).map(|j| b[i][j] * vt[[k, j]]).sum::<f64>();
+            assert_relative_eq!(bv, s[k] * u[[i, k]], epsilon = 1e-10);
+        }
+    }
+
+    let values = bd
+        .svd_bidiagonal_s(&d, &e, Uplo::Upper)
+        .expect("Bidiagonal singular values failed");
+    for k in 0..3 {
+        assert_relative_eq!(values[k], s[k], epsilon = 1e-10);
+    }
 }
@@ -0,0 +1,181 @@
+use mdarray::{DArray, DSlice, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::{
+    eig::Eig,
+    sylvester::{SylvesterError, SylvesterMethod, discrete_lyapunov, lyapunov, sylvester},
+};
+
+type C = Complex<f64>;
+
+const METHODS: [SylvesterMethod; 2] = [
+    SylvesterMethod::BartelsStewart,
+    SylvesterMethod::HessenbergSchur,
+];
+
+/// Dense matrix without structure, with an imaginary part for complex types,
+/// shifted by `shift` on the diagonal.
+fn full<T: ComplexFloat<Real = f64>>(m: usize, n: usize, seed: f64, shift: f64) -> DArray<T, 2> {
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        let t = seed + (1 + 3 * i[0] + 7 * i[1]) as f64;
+        let d = if i[0] == i[1] { shift } else { 0. };
+        T::from(t.sin() + d).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+fn matmul<T: ComplexFloat>(a: &DSlice<T, 2>, b: &DSlice<T, 2>) -> DArray<T, 2> {
+    let (m, k) = *a.shape();
+    let n = b.shape().1;
+    DArray::<T, 2>::from_fn([m, n], |i| {
+        (0..k).fold(T::zero(), |acc, l| acc + a[[i[0], l]] * b[[l, i[1]]])
+    })
+}
+
+fn adjoint<T: ComplexFloat>(a: &DSlice<T, 2>) -> DArray<T, 2> {
+    let (m, n) = *a.shape();
+    DArray::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DSlice<T, 2>, b: &DSlice<T, 2>) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() < 1e-9, "{:?} != {:?}", x.re(), y.re());
+    }
+}
+
+fn scaled<T: ComplexFloat<Real = f64>>(a: &DSlice<T, 2>, s: f64) -> DArray<T, 2> {
+    a.to_array().map(|x| x * T::from(s).unwrap())
+}
+
+fn check_sylvester<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64> + From<f64>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+{
+    // The spectra of A and -B are far apart.
+    let a = full::<T>(5, 5, 0.2, 4.);
+    let b = full::<T>(3, 3, 1.1, 3.);
+    let c = full::<T>(5, 3, 2.3, 0.);
+    for method in METHODS {
+        let sol = sylvester(bd, &a, &b, &c, method).unwrap();
+        assert_eq!(sol.scale, 1.);
+        let lhs = matmul(&a, &sol.x) + matmul(&sol.x, &b);
+        assert_close(&lhs, &c);
+    }
+}
+
+pub fn test_sylvester(bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = C>) {
+    check_sylvester::<f64, _>(bd);
+}
+
+pub fn test_sylvester_complex(bd: &impl Eig<C, Dyn, Dyn, SpectralScalar = C>) {
+    check_sylvester::<C, _>(bd);
+}
+
+fn check_lyapunov<T, B>(bd: &B)
+where
+    T: ComplexFloat<Real = f64> + From<f64>,
+    B: Eig<T, Dyn, Dyn, SpectralScalar = Complex<f64>>,
+{
+    let n = 6;
+    let g = full::<T>(n, 3, 0.7, 0.);
+    let gg = matmul(&g, &adjoint(&g));
+
+    // Stable A: A X + X A^H = -G G^H has a positive semidefinite solution.
+    let a = full::<T>(n, n, 0.4, -5.);
+    let minus_gg = scaled(&gg, -1.);
+    let sol = lyapunov(bd, &a, &minus_gg).unwrap();
+    assert_eq!(sol.scale, 1.);
+    let lhs = matmul(&a, &sol.x) + matmul(&sol.x, &adjoint(&a));
+    assert_close(&lhs, &minus_gg);
+    assert_close(&sol.x, &adjoint(&sol.x));
+    for i in 0..n {
+        assert!(sol.x[[i, i]].re() > 0.);
+    }
+
+    // Schur-stable A: A X A^H - X + G G^H = 0
+    let a = scaled(&full::<T>(n, n, 0.9, 0.), 0.2);
+    let sol = discrete_lyapunov(bd, &a, &gg).unwrap();
+    assert_eq!(sol.scale, 1.);
+    let axa = matmul(&matmul(&a, &sol.x), &adjoint(&a));
+    let lhs = DArray::<T, 2>::from_fn([n, n], |i| axa[[i[0], i[1]]] - sol.x[[i[0], i[1]]]);
+    assert_close(&lhs, &scaled(&gg, -1.));
+    assert_close(&sol.x, &adjoint(&sol.x));
+}
+
+pub fn test_lyapunov(bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = C>) {
+    check_lyapunov::<f64, _>(bd);
+}
+
+pub fn test_lyapunov_complex(bd: &impl Eig<C, Dyn, Dyn, SpectralScalar = C>) {
+    check_lyapunov::<C, _>(bd);
+}
+
+pub fn test_sylvester_scale(bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = C>) {
+    // x = 1e30 / 1e-290 overflows, so the right-hand side is scaled down.
+    let a = DArray::<f64, 2>::from_elem([1, 1], 1e-290);
+    let b = DArray::<f64, 2>::from_elem([1, 1], 0.);
+    let c = DArray::<f64, 2>::from_elem([1, 1], 1e30);
+    for method in METHODS {
+        let sol = sylvester(bd, &a, &b, &c, method).unwrap();
+        assert!(sol.scale < 1. && sol.scale > 0.);
+        assert!(sol.x[[0, 0]].is_finite());
+        let rel = (a[[0, 0]] * sol.x[[0, 0]] - sol.scale * c[[0, 0]]) / (sol.scale * c[[0, 0]]);
+        assert!(rel.abs() < 1e-12);
+    }
+}
+
+pub fn test_sylvester_errors(bd: &impl Eig<f64, Dyn, Dyn, SpectralScalar = C>) {
+    let diag = |d: &[f64]| {
+        DArray::<f64, 2>::from_fn(
+            [d.len(), d.len()],
+            |i| if i[0] == i[1] { d[i[0]] } else { 0. },
+        )
+    };
+    let c = DArray::<f64, 2>::from_elem([2, 2], 1.);
+
+    // 2 is an eigenvalue of A and -2 one of B.
+    for method in METHODS {
+        assert!(matches!(
+            sylvester(bd, &diag(&[1., 2.]), &diag(&[-2., 5.]), &c, method),
+            Err(SylvesterError::SharedEigenvalue { .. })
+        ));
+    }
+    // A and -A^H share the eigenvalue 0.
+    assert!(matches!(
+        lyapunov(bd, &diag(&[0., -1.]), &c),
+        Err(SylvesterError::SharedEigenvalue { .. })
+    ));
+    // 2 * 0.5 = 1
+    assert!(matches!(
+        discrete_lyapunov(bd, &diag(&[2., 0.5]), &c),
+        Err(SylvesterError::ReciprocalEigenvalue { .. })
+    ));
+
+    let rect = DArray::<f64, 2>::from_elem([2, 3], 1.);
+    assert!(matches!(
+        sylvester(bd, &rect, &diag(&[1.]), &c, SylvesterMethod::default()),
+        Err(SylvesterError::NotSquare { rows: 2, cols: 3 })
+    ));
+    assert!(matches!(
+        sylvester(
+            bd,
+            &diag(&[1., 2.]),
+            &diag(&[1., 2., 3.]),
+            &c,
+            SylvesterMethod::default()
+        ),
+        Err(SylvesterError::DimensionMismatch {
+            m: 2,
+            n: 3,
+            rows: 2,
+            cols: 2
+        })
+    ));
+}
@@ -0,0 +1,261 @@
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::{Complex, ComplexFloat};
+
+use crate::toeplitz::{Circulant, Hankel, Toeplitz, ToeplitzError, dft, idft, levinson_durbin};
+
+type C = Complex<f64>;
+
+/// Deterministic vector with an imaginary part for complex types.
+fn sequence<T: ComplexFloat<Real = f64>>(n: usize, seed: f64) -> DArray<T, 1> {
+    DArray::<T, 1>::from_fn([n], |i| {
+        let t = seed + (1 + 3 * i[0]) as f64;
+        T::from(t.sin()).unwrap() + T::from(0.5 * t.cos()).unwrap() * imaginary::<T>()
+    })
+}
+
+/// `i` for complex types and `0` for real ones.
+fn imaginary<T: ComplexFloat<Real = f64>>() -> T {
+    let i = (-T::one()).sqrt();
+    if i.is_nan() { T::zero() } else { i }
+}
+
+fn assert_close<T: ComplexFloat<Real = f64>>(a: &DSlice<T, 1>, b: &DSlice<T, 1>) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((*x - *y).abs() < 1e-9, "{:?} != {:?}", x.re(), y.re());
+    }
+}
+
+fn dense_matvec<T: ComplexFloat, La: Layout, Lx: Layout>(
+    a: &Slice<T, (Dyn, Dyn), La>,
+    x: &Slice<T, (Dyn,), Lx>,
+) -> DArray<T, 1> {
+    let (m, n) = *a.shape();
+    DArray::<T, 1>::from_fn([m], |i| {
+        (0..n).fold(T::zero(), |acc, j| acc + a[[i[0], j]] * x[[j]])
+    })
+}
+
+/// Diagonally dominant Toeplitz matrix, so that all leading principal
+/// submatrices are nonsingular.
+fn dominant<T: ComplexFloat<Real = f64>>(n: usize) -> Toeplitz<T> {
+    let mut col = sequence::<T>(n, 0.3);
+    let mut row = sequence::<T>(n, 1.7);
+    col[[0]] = T::from(2.0 * n as f64).unwrap();
+    row[[0]] = col[[0]];
+    Toeplitz::new(col, row).unwrap()
+}
+
+pub fn test_toeplitz_dense() {
+    let t = Toeplitz::new(
+        DArray::<f64, 1>::from(vec![1., 2., 3.]),
+        DArray::<f64, 1>::from(vec![1., 4.]),
+    )
+    .unwrap();
+    let expected =
+        DArray::<f64, 2>::from_fn([3, 2], |i| [[1., 4.], [2., 1.], [3., 2.]][i[0]][i[1]]);
+    assert_eq!(t.to_dense(), expected);
+    assert_eq!(t.transpose().to_dense()[[0, 2]], 3.);
+
+    let h = Hankel::new(
+        DArray::<f64, 1>::from(vec![1., 2., 3.]),
+        DArray::<f64, 1>::from(vec![3., 4., 5.]),
+    )
+    .unwrap();
+    let expected = DArray::<f64, 2>::from_fn([3, 3], |i| (1 + i[0] + i[1]) as f64);
+    assert_eq!(h.to_dense(), expected);
+
+    let c = Circulant::new(DArray::<f64, 1>::from(vec![1., 2., 3.])).unwrap();
+    let expected = DArray::<f64, 2>::from_fn([3, 3], |i| {
+        [[1., 3., 2.], [2., 1., 3.], [3., 2., 1.]][i[0]][i[1]]
+    });
+    assert_eq!(c.to_dense(), expected);
+
+    let h =
+        Toeplitz::hermitian(DArray::<C, 1>::from(vec![C::new(2., 0.), C::new(1., 1.)])).unwrap();
+    assert_eq!(h.get(0, 1), C::new(1., -1.));
+
+    assert!(matches!(
+        Toeplitz::new(
+            DArray::<f64, 1>::from(vec![1., 2.]),
+            DArray::<f64, 1>::from(vec![2., 1.]),
+        ),
+        Err(ToeplitzError::CornerMismatch)
+    ));
+    assert!(matches!(
+        Hankel::new(
+            DArray::<f64, 1>::from(vec![1., 2.]),
+            DArray::<f64, 1>::from(vec![1., 2.]),
+        ),
+        Err(ToeplitzError::CornerMismatch)
+    ));
+}
+
+fn check_matvec<T: ComplexFloat<Real = f64> + From<f64>>() {
+    for (m, n) in [(1, 1), (5, 5), (7, 3), (3, 8)] {
+        let mut col = sequence::<T>(m, 0.1);
+        let row = sequence::<T>(n, 0.9);
+        col[[0]] = row[[0]];
+        let t = Toeplitz::new(col.clone(), row).unwrap();
+        let x = sequence::<T>(n, 2.5);
+        assert_close(&t.matvec(&x), &dense_matvec(&t.to_dense(), &x));
+
+        let mut last = sequence::<T>(n, 1.3);
+        last[[0]] = col[[m - 1]];
+        let h = Hankel::new(col, last).unwrap();
+        assert_close(&h.matvec(&x), &dense_matvec(&h.to_dense(), &x));
+    }
+
+    // Lengths with and without a power of two take different DFT paths.
+    for n in [1, 4, 6, 7] {
+        let c = Circulant::new(sequence::<T>(n, 0.4)).unwrap();
+        let x = sequence::<T>(n, 3.1);
+        assert_close(&c.matvec(&x), &dense_matvec(&c.to_dense(), &x));
+    }
+}
+
+pub fn test_toeplitz_matvec() {
+    check_matvec::<f64>();
+    check_matvec::<C>();
+}
+
+fn check_solve<T: ComplexFloat<Real = f64> + From<f64>>() {
+    for n in [1, 2, 5, 9] {
+        let t = dominant::<T>(n);
+        let b = sequence::<T>(n, 4.2);
+        let x = t.solve(&b).unwrap();
+        assert_close(&dense_matvec(&t.to_dense(), &x), &b);
+
+        let inv = t.inverse().unwrap();
+        for j in 0..n {
+            let e = DArray::<T, 1>::from_fn([n], |i| if i[0] == j { T::one() } else { T::zero() });
+            let col = DArray::<T, 1>::from_fn([n], |i| inv[[i[0], j]]);
+            assert_close(&t.matvec(&col), &e);
+        }
+
+        // H = T J reverses the columns of T.
+        let h = Hankel::new(
+            DArray::<T, 1>::from_fn([n], |i| t.get(i[0], n - 1)),
+            DArray::<T, 1>::from_fn([n], |j| t.get(n - 1, n - 1 - j[0])),
+        )
+        .unwrap();
+        let x = h.solve(&b).unwrap();
+        assert_close(&dense_matvec(&h.to_dense(), &x), &b);
+
+        let c = Circulant::new(t.col().to_array()).unwrap();
+        let x = c.solve(&b).unwrap();
+        assert_close(&c.matvec(&x), &b);
+    }
+}
+
+pub fn test_toeplitz_solve() {
+    check_solve::<f64>();
+    check_solve::<C>();
+}
+
+pub fn test_toeplitz_solve_errors() {
+    // [[0, 1], [1, 0]] is nonsingular but its leading entry vanishes.
+    let t = Toeplitz::<f64>::symmetric(DArray::<f64, 1>::from(vec![0., 1.])).unwrap();
+    let b = DArray::<f64, 1>::from(vec![1., 1.]);
+    assert!(matches!(
+        t.solve(&b),
+        Err(ToeplitzError::Singular { order: 1 })
+    ));
+
+    // [[1, 1], [1, 1]] is singular.
+    let t = Toeplitz::<f64>::symmetric(DArray::<f64, 1>::from(vec![1., 1.])).unwrap();
+    assert!(matches!(
+        t.solve(&b),
+        Err(ToeplitzError::Singular { order: 2 })
+    ));
+    assert!(matches!(
+        t.solve(&DArray::<f64, 1>::from(vec![1.])),
+        Err(ToeplitzError::DimensionMismatch {
+            expected: 2,
+            len: 1
+        })
+    ));
+
+    let t = Toeplitz::new(
+        DArray::<f64, 1>::from(vec![1., 2., 3.]),
+        DArray::<f64, 1>::from(vec![1., 4.]),
+    )
+    .unwrap();
+    assert!(matches!(
+        t.inverse(),
+        Err(ToeplitzError::NotSquare { rows: 3, cols: 2 })
+    ));
+
+    // Eigenvalue 1 + (-1) = 0 at frequency zero
+    let c = Circulant::new(DArray::<f64, 1>::from(vec![1., -1.])).unwrap();
+    assert!(matches!(
+        c.solve(&b),
+        Err(ToeplitzError::ZeroEigenvalue { index: 0 })
+    ));
+}
+
+pub fn test_dft() {
+    for n in [1, 2, 8, 5, 12] {
+        let x = sequence::<C>(n, 0.7);
+        let y = dft(&x);
+        let expected = DArray::<C, 1>::from_fn([n], |k| {
+            (0..n).fold(C::new(0., 0.), |acc, j| {
+                let angle = -2. * std::f64::consts::PI * (j * k[0]) as f64 / n as f64;
+                acc + x[[j]] * C::from_polar(1., angle)
+            })
+        });
+        assert_close(&y, &expected);
+        assert_close(&idft(&y), &x);
+    }
+
+    let c = Circulant::new(sequence::<f64>(6, 0.2)).unwrap();
+    let lambda = c.eigenvalues();
+    let dense = c.to_dense();
+    for k in 0..6 {
+        // Eigenvector (exp(2πi jk / n))_j
+        let v = DArray::<C, 1>::from_fn([6], |j| {
+            C::from_polar(1., 2. * std::f64::consts::PI * (j[0] * k) as f64 / 6.)
+        });
+        let dense = DArray::<C, 2>::from_fn([6, 6], |i| C::new(dense[[i[0], i[1]]], 0.));
+        let av = dense_matvec(&dense, &v);
+        let lv = DArray::<C, 1>::from_fn([6], |j| lambda[[k]] * v[[j[0]]]);
+        assert_close(&av, &lv);
+    }
+}
+
+fn check_levinson_durbin<T: ComplexFloat<Real = f64> + From<f64>>() {
+    // Autocorrelation of a stable AR process, as a positive definite sequence
+    let p = 4;
+    let r = DArray::<T, 1>::from_fn([p + 1], |i| {
+        let k = i[0] as f64;
+        <T as From<f64>>::from(0.6f64.powf(k))
+            * (imaginary::<T>() * <T as From<f64>>::from(0.3 * k)).exp()
+    });
+    let fit = levinson_durbin(&r).unwrap();
+    assert_eq!(fit.coefficients.len(), p + 1);
+    assert_eq!(fit.reflection.len(), p);
+    assert!(fit.coefficients[[0]] == T::one());
+
+    // Yule–Walker: R a[1..] = -r[1..]
+    let yw = Toeplitz::hermitian(DArray::<T, 1>::from_fn([p], |i| r[[i[0]]])).unwrap();
+    let a = DArray::<T, 1>::from_fn([p], |i| fit.coefficients[[i[0] + 1]]);
+    let rhs = DArray::<T, 1>::from_fn([p], |i| -r[[i[0] + 1]]);
+    assert_close(&yw.matvec(&a), &rhs);
+
+    // The prediction error is r(0) + Σ a(k) conj(r(k))
+    let error = (1..=p).fold(r[[0]], |acc, k| acc + fit.coefficients[[k]] * r[[k]].conj());
+    assert!((error.re() - fit.error).abs() < 1e-10);
+    assert!(fit.error > 0.);
+}
+
+pub fn test_levinson_durbin() {
+    check_levinson_durbin::<f64>();
+    check_levinson_durbin::<C>();
+
+    // 1, 1, 1 is only positive semidefinite.
+    let r = DArray::<f64, 1>::from(vec![1., 1., 1.]);
+    assert!(matches!(
+        levinson_durbin(&r),
+        Err(ToeplitzError::NotPositiveDefinite { order: 1 })
+    ));
+}
@@ -0,0 +1,612 @@
+//! Toeplitz, Hankel and circulant matrices
+//!
+//! These matrices are constant along their diagonals (Toeplitz, circulant)
+//! or anti-diagonals (Hankel) and are stored by their first column and row:
+//!
+//! - [`Toeplitz`]: `T[i, j] = t(i - j)`, given by its first column and first row,
+//! - [`Hankel`]: `H[i, j] = h(i + j)`, given by its first column and last row,
+//! - [`Circulant`]: `C[i, j] = c((i - j) mod n)`, given by its first column.
+//!
+//! Products with a vector use a zero-padded circulant embedding and the fast
+//! Fourier transform, in `O((m + n) log(m + n))`.  Square Toeplitz and Hankel
+//! systems are solved with the Levinson recursion and Toeplitz inverses are
+//! formed with the Trench algorithm, both in `O(n²)`; they require every
+//! leading principal submatrix to be nonsingular, which holds for positive
+//! definite matrices.  Circulant matrices are diagonalized by the DFT
+//! ([`dft`], [`Circulant::eigenvalues`]) and solved in `O(n log n)`.
+//!
+//! [`levinson_durbin`] solves the Yule–Walker equations of an autoregressive
+//! model given its autocorrelation sequence.
+//!
+//! ```rust
+//! use mdarray::tensor;
+//! use mdarray_linalg::toeplitz::Toeplitz;
+//!
+//! // [[4, 1, 0],
+//! //  [2, 4, 1],
+//! //  [0, 2, 4]]
+//! let t = Toeplitz::<f64>::new(tensor![4., 2., 0.], tensor![4., 1., 0.]).unwrap();
+//! let x = tensor![1., 1., 1.];
+//! let b = t.matvec(&x);
+//! assert!((b[[1]] - 7.).abs() < 1e-12);
+//!
+//! let y = t.solve(&b).unwrap();
+//! assert!(y.iter().all(|yi| (yi - 1.).abs() < 1e-12));
+//! ```
+use mdarray::{DArray, DSlice, Dyn, Layout, Slice};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::utils::{from_complex, imaginary_unit, to_complex};
+
+/// Error types related to Toeplitz, Hankel and circulant matrices
+#[derive(Debug, Error)]
+pub enum ToeplitzError {
+    /// The generating vectors are empty
+    #[error("Matrix must have at least one row and one column")]
+    Empty,
+
+    /// The first column and row disagree on their shared entry
+    #[error("First column and row must share their corner entry")]
+    CornerMismatch,
+
+    /// A square matrix is required
+    #[error("Matrix must be square: got {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    /// The right-hand side does not have as many entries as the matrix has rows
+    #[error("Right-hand side must have {expected} entries, got {len}")]
+    DimensionMismatch { expected: usize, len: usize },
+
+    /// The leading principal submatrix of this order is singular, so the
+    /// Levinson recursion breaks down
+    #[error("Leading principal submatrix of order {order} is singular")]
+    Singular { order: usize },
+
+    /// A circulant matrix has a zero eigenvalue
+    #[error("Circulant matrix is singular: eigenvalue {index} is zero")]
+    ZeroEigenvalue { index: usize },
+
+    /// The autocorrelation sequence is not positive definite
+    #[error("Autocorrelation is not positive definite at order {order}")]
+    NotPositiveDefinite { order: usize },
+}
+
+/// Toeplitz matrix `T[i, j] = t(i - j)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toeplitz<T> {
+    col: DArray<T, 1>,
+    row: DArray<T, 1>,
+}
+
+impl<T: ComplexFloat> Toeplitz<T> {
+    /// Builds the `m × n` matrix with first column `col` (length `m`) and
+    /// first row `row` (length `n`), which must agree on their first entry.
+    pub fn new(col: DArray<T, 1>, row: DArray<T, 1>) -> Result<Self, ToeplitzError> {
+        if col.is_empty() || row.is_empty() {
+            return Err(ToeplitzError::Empty);
+        }
+        if col[[0]] != row[[0]] {
+            return Err(ToeplitzError::CornerMismatch);
+        }
+        Ok(Self { col, row })
+    }
+
+    /// Symmetric matrix with first column (and row) `col`.
+    pub fn symmetric(col: DArray<T, 1>) -> Result<Self, ToeplitzError> {
+        Self::new(col.clone(), col)
+    }
+
+    /// Hermitian matrix with first column `col`; the first row is its
+    /// conjugate, so `col[0]` must be real.
+    pub fn hermitian(col: DArray<T, 1>) -> Result<Self, ToeplitzError> {
+        let row = DArray::<T, 1>::from_fn([col.len()], |i| col[[i[0]]].conj());
+        Self::new(col, row)
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.col.len(), self.row.len())
+    }
+
+    /// First column
+    pub fn col(&self) -> &DSlice<T, 1> {
+        &self.col
+    }
+
+    /// First row
+    pub fn row(&self) -> &DSlice<T, 1> {
+        &self.row
+    }
+
+    /// Returns the entry at `(i, j)`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= j {
+            self.col[[i - j]]
+        } else {
+            self.row[[j - i]]
+        }
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let (m, n) = self.shape();
+        DArray::<T, 2>::from_fn([m, n], |i| self.get(i[0], i[1]))
+    }
+
+    /// The transposed Toeplitz matrix
+    pub fn transpose(&self) -> Self {
+        Self {
+            col: self.row.clone(),
+            row: self.col.clone(),
+        }
+    }
+
+    fn square(&self) -> Result<usize, ToeplitzError> {
+        match self.shape() {
+            (m, n) if m == n => Ok(n),
+            (rows, cols) => Err(ToeplitzError::NotSquare { rows, cols }),
+        }
+    }
+}
+
+impl<T> Toeplitz<T>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    /// Computes `T x` by FFT.  Panics if `x` does not have as many entries as
+    /// the matrix has columns.
+    pub fn matvec<L: Layout>(&self, x: &Slice<T, (Dyn,), L>) -> DArray<T, 1> {
+        let (m, n) = self.shape();
+        assert!(x.len() == n, "Matrix columns must match x vector length");
+
+        // Embed into a circulant of size p ≥ m + n - 1 with first column
+        // [t(0), …, t(m - 1), 0, …, 0, t(-(n - 1)), …, t(-1)].
+        let p = (m + n - 1).next_power_of_two();
+        let zero = Complex::<T::Real>::zero();
+        let mut c = vec![zero; p];
+        for (k, &v) in self.col.iter().enumerate() {
+            c[k] = to_complex(v);
+        }
+        for (k, &v) in self.row.iter().enumerate().skip(1) {
+            c[p - k] = to_complex(v);
+        }
+        let mut y = vec![zero; p];
+        for (yk, &v) in y.iter_mut().zip(x.iter()) {
+            *yk = to_complex(v);
+        }
+        circular_convolve(&mut c, &mut y);
+
+        let i = imaginary_unit::<T>();
+        DArray::<T, 1>::from_fn([m], |k| from_complex(y[k[0]], i))
+    }
+}
+
+impl<T: ComplexFloat> Toeplitz<T> {
+    /// Solves the square system `T x = b` with the Levinson recursion, in
+    /// `O(n²)`.
+    pub fn solve<L: Layout>(&self, b: &Slice<T, (Dyn,), L>) -> Result<DArray<T, 1>, ToeplitzError> {
+        let n = self.square()?;
+        if b.len() != n {
+            return Err(ToeplitzError::DimensionMismatch {
+                expected: n,
+                len: b.len(),
+            });
+        }
+        Ok(levinson(self, b)?.into())
+    }
+
+    /// Computes the inverse of the square matrix with the Trench algorithm,
+    /// in `O(n²)`.
+    pub fn inverse(&self) -> Result<DArray<T, 2>, ToeplitzError> {
+        let n = self.square()?;
+        let unit = |k: usize| {
+            DArray::<T, 1>::from_fn([n], |i| if i[0] == k { T::one() } else { T::zero() })
+        };
+
+        // First and last columns and rows of the inverse
+        let first_col = self.solve(&unit(0))?;
+        let last_col = self.solve(&unit(n - 1))?;
+        let transposed = self.transpose();
+        let first_row = transposed.solve(&unit(0))?;
+        let last_row = transposed.solve(&unit(n - 1))?;
+
+        // The inverse has displacement rank two:
+        //     B[i + 1, j + 1] = B[i, j] + (x[i + 1] u[j + 1] - y[i] v[j]) / x[0]
+        // for the first column x, last column y, first row u and last row v.
+        let x0 = first_col[[0]];
+        let mut inv = DArray::<T, 2>::from_elem([n, n], T::zero());
+        for j in 0..n {
+            inv[[0, j]] = first_row[[j]];
+            inv[[j, 0]] = first_col[[j]];
+        }
+        for i in 0..n - 1 {
+            for j in 0..n - 1 {
+                inv[[i + 1, j + 1]] = inv[[i, j]]
+                    + (first_col[[i + 1]] * first_row[[j + 1]] - last_col[[i]] * last_row[[j]])
+                        / x0;
+            }
+        }
+        Ok(inv)
+    }
+}
+
+/// Solves `T x = b` with the Levinson recursion on the leading principal
+/// submatrices `T_k` of the square `t`.
+///
+/// The forward and backward vectors `f` and `g` of order `k` solve
+/// `T_k f = e_0` and `T_k g = e_(k-1)`, and `x` solves `T_k x = b[..k]`.
+fn levinson<T: ComplexFloat, L: Layout>(
+    t: &Toeplitz<T>,
+    b: &Slice<T, (Dyn,), L>,
+) -> Result<Vec<T>, ToeplitzError> {
+    let n = t.col.len();
+    let t0 = t.col[[0]];
+    if t0 == T::zero() {
+        return Err(ToeplitzError::Singular { order: 1 });
+    }
+    let mut f = vec![T::one() / t0];
+    let mut g = vec![T::one() / t0];
+    let mut x = vec![b[[0]] / t0];
+
+    for k in 1..n {
+        // T_(k+1) [f; 0] = e_0 + ef e_k  and  T_(k+1) [0; g] = eg e_0 + e_k
+        let ef = (0..k).fold(T::zero(), |acc, j| acc + t.col[[k - j]] * f[j]);
+        let eg = (0..k).fold(T::zero(), |acc, j| acc + t.row[[j + 1]] * g[j]);
+        let denom = T::one() - ef * eg;
+        if denom == T::zero() {
+            return Err(ToeplitzError::Singular { order: k + 1 });
+        }
+        f.push(T::zero());
+        g.insert(0, T::zero());
+        let (f_new, g_new): (Vec<T>, Vec<T>) = f
+            .iter()
+            .zip(&g)
+            .map(|(&fj, &gj)| ((fj - ef * gj) / denom, (gj - eg * fj) / denom))
+            .unzip();
+        f = f_new;
+        g = g_new;
+
+        // T_(k+1) [x; 0] = [b[..k]; ex]
+        let ex = (0..k).fold(T::zero(), |acc, j| acc + t.col[[k - j]] * x[j]);
+        let delta = b[[k]] - ex;
+        x.push(T::zero());
+        for (xj, &gj) in x.iter_mut().zip(&g) {
+            *xj = *xj + delta * gj;
+        }
+    }
+    Ok(x)
+}
+
+/// Hankel matrix `H[i, j] = h(i + j)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hankel<T> {
+    col: DArray<T, 1>,
+    row: DArray<T, 1>,
+}
+
+impl<T: ComplexFloat> Hankel<T> {
+    /// Builds the `m × n` matrix with first column `col` (length `m`) and last
+    /// row `row` (length `n`), which must agree on their shared entry
+    /// `col[m - 1] = row[0]`.
+    pub fn new(col: DArray<T, 1>, row: DArray<T, 1>) -> Result<Self, ToeplitzError> {
+        if col.is_empty() || row.is_empty() {
+            return Err(ToeplitzError::Empty);
+        }
+        if col[[col.len() - 1]] != row[[0]] {
+            return Err(ToeplitzError::CornerMismatch);
+        }
+        Ok(Self { col, row })
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.col.len(), self.row.len())
+    }
+
+    /// First column
+    pub fn col(&self) -> &DSlice<T, 1> {
+        &self.col
+    }
+
+    /// Last row
+    pub fn row(&self) -> &DSlice<T, 1> {
+        &self.row
+    }
+
+    /// Returns the entry at `(i, j)`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let m = self.col.len();
+        if i + j < m {
+            self.col[[i + j]]
+        } else {
+            self.row[[i + j + 1 - m]]
+        }
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let (m, n) = self.shape();
+        DArray::<T, 2>::from_fn([m, n], |i| self.get(i[0], i[1]))
+    }
+
+    /// The Toeplitz matrix `H J`, where `J` reverses the column order.
+    fn reversed(&self) -> Toeplitz<T> {
+        let (m, n) = self.shape();
+        Toeplitz {
+            col: DArray::<T, 1>::from_fn([m], |i| self.get(i[0], n - 1)),
+            row: DArray::<T, 1>::from_fn([n], |j| self.get(0, n - 1 - j[0])),
+        }
+    }
+
+    /// Solves the square system `H x = b` in `O(n²)` with the Levinson
+    /// recursion on the Toeplitz matrix `H J`.
+    pub fn solve<L: Layout>(&self, b: &Slice<T, (Dyn,), L>) -> Result<DArray<T, 1>, ToeplitzError> {
+        let y = self.reversed().solve(b)?;
+        let n = y.len();
+        Ok(DArray::<T, 1>::from_fn([n], |i| y[[n - 1 - i[0]]]))
+    }
+}
+
+impl<T> Hankel<T>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    /// Computes `H x` by FFT.  Panics if `x` does not have as many entries as
+    /// the matrix has columns.
+    pub fn matvec<L: Layout>(&self, x: &Slice<T, (Dyn,), L>) -> DArray<T, 1> {
+        let n = self.row.len();
+        assert!(x.len() == n, "Matrix columns must match x vector length");
+        let reversed = DArray::<T, 1>::from_fn([n], |i| x[[n - 1 - i[0]]]);
+        self.reversed().matvec(&reversed)
+    }
+}
+
+/// Circulant matrix `C[i, j] = c((i - j) mod n)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circulant<T> {
+    col: DArray<T, 1>,
+}
+
+impl<T: ComplexFloat> Circulant<T> {
+    /// Builds the `n × n` matrix with first column `col`.
+    pub fn new(col: DArray<T, 1>) -> Result<Self, ToeplitzError> {
+        if col.is_empty() {
+            return Err(ToeplitzError::Empty);
+        }
+        Ok(Self { col })
+    }
+
+    /// Number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.col.len()
+    }
+
+    /// First column
+    pub fn col(&self) -> &DSlice<T, 1> {
+        &self.col
+    }
+
+    /// Returns the entry at `(i, j)`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let n = self.size();
+        self.col[[(i + n - j % n) % n]]
+    }
+
+    pub fn to_dense(&self) -> DArray<T, 2> {
+        let n = self.size();
+        DArray::<T, 2>::from_fn([n, n], |i| self.get(i[0], i[1]))
+    }
+
+    /// Eigenvalues `λ_k = Σ_j c(j) ω^(jk)`, `ω = exp(-2πi / n)`: the DFT of
+    /// the first column.  The eigenvector of `λ_k` is
+    /// `(ω^(-jk) / √n)_j`, the `k`-th column of the inverse DFT matrix.
+    pub fn eigenvalues(&self) -> DArray<Complex<T::Real>, 1> {
+        let c =
+            DArray::<Complex<T::Real>, 1>::from_fn([self.size()], |i| to_complex(self.col[[i[0]]]));
+        dft(&c)
+    }
+}
+
+impl<T> Circulant<T>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    /// Computes `C x` by FFT.  Panics if `x` does not have `n` entries.
+    pub fn matvec<L: Layout>(&self, x: &Slice<T, (Dyn,), L>) -> DArray<T, 1> {
+        let n = self.size();
+        assert!(x.len() == n, "Matrix columns must match x vector length");
+        let x = DArray::<Complex<T::Real>, 1>::from_fn([n], |i| to_complex(x[[i[0]]]));
+        let y = dft(&x);
+        let lambda = self.eigenvalues();
+        let y = idft(&DArray::<Complex<T::Real>, 1>::from_fn([n], |i| {
+            lambda[[i[0]]] * y[[i[0]]]
+        }));
+        let i = imaginary_unit::<T>();
+        DArray::<T, 1>::from_fn([n], |k| from_complex(y[[k[0]]], i))
+    }
+
+    /// Solves `C x = b` by dividing by the eigenvalues in Fourier space, in
+    /// `O(n log n)`.
+    pub fn solve<L: Layout>(&self, b: &Slice<T, (Dyn,), L>) -> Result<DArray<T, 1>, ToeplitzError> {
+        let n = self.size();
+        if b.len() != n {
+            return Err(ToeplitzError::DimensionMismatch {
+                expected: n,
+                len: b.len(),
+            });
+        }
+        let lambda = self.eigenvalues();
+        if let Some(index) = lambda.iter().position(|l| l.is_zero()) {
+            return Err(ToeplitzError::ZeroEigenvalue { index });
+        }
+        let b = DArray::<Complex<T::Real>, 1>::from_fn([n], |i| to_complex(b[[i[0]]]));
+        let y = dft(&b);
+        let x = idft(&DArray::<Complex<T::Real>, 1>::from_fn([n], |i| {
+            y[[i[0]]] / lambda[[i[0]]]
+        }));
+        let i = imaginary_unit::<T>();
+        Ok(DArray::<T, 1>::from_fn([n], |k| from_complex(x[[k[0]]], i)))
+    }
+}
+
+/// Result of [`levinson_durbin`]
+#[derive(Debug, Clone)]
+pub struct LevinsonDurbin<T: ComplexFloat> {
+    /// Prediction polynomial `[1, a(1), …, a(p)]` of the model
+    /// `x(t) + a(1) x(t - 1) + … + a(p) x(t - p) = e(t)`
+    pub coefficients: DArray<T, 1>,
+    /// Reflection (partial autocorrelation) coefficients `k(1), …, k(p)`
+    pub reflection: DArray<T, 1>,
+    /// Variance of the prediction error `e(t)`
+    pub error: T::Real,
+}
+
+/// Fits an autoregressive model of order `p = r.len() - 1` to the
+/// autocorrelation sequence `r(0), …, r(p)` with the Levinson–Durbin
+/// recursion, in `O(p²)`.
+///
+/// This solves the Yule–Walker equations `R a = -[r(1), …, r(p)]` for the
+/// Hermitian Toeplitz matrix `R` with first column `r(0), …, r(p - 1)`.
+pub fn levinson_durbin<T, L>(r: &Slice<T, (Dyn,), L>) -> Result<LevinsonDurbin<T>, ToeplitzError>
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+    L: Layout,
+{
+    if r.is_empty() {
+        return Err(ToeplitzError::Empty);
+    }
+    let p = r.len() - 1;
+    let mut a = vec![T::one()];
+    let mut reflection = Vec::with_capacity(p);
+    let mut error = r[[0]].re();
+    if error <= T::Real::zero() {
+        return Err(ToeplitzError::NotPositiveDefinite { order: 0 });
+    }
+
+    for m in 1..=p {
+        let acc = (0..m).fold(T::zero(), |acc, j| acc + a[j] * r[[m - j]]);
+        let k = -acc / error.into();
+        a.push(T::zero());
+        let previous = a.clone();
+        for j in 1..=m {
+            a[j] = previous[j] + k * previous[m - j].conj();
+        }
+        error = error * (T::Real::one() - k.abs() * k.abs());
+        if error <= T::Real::zero() {
+            return Err(ToeplitzError::NotPositiveDefinite { order: m });
+        }
+        reflection.push(k);
+    }
+
+    Ok(LevinsonDurbin {
+        coefficients: a.into(),
+        reflection: reflection.into(),
+        error,
+    })
+}
+
+/// Discrete Fourier transform `X(k) = Σ_j x(j) exp(-2πi jk / n)`.
+///
+/// Uses a radix-2 FFT, and Bluestein's algorithm if `n` is not a power of
+/// two, in `O(n log n)`.
+pub fn dft<R: Float, L: Layout>(x: &Slice<Complex<R>, (Dyn,), L>) -> DArray<Complex<R>, 1> {
+    let mut y: Vec<_> = x.iter().copied().collect();
+    fft(&mut y, false);
+    y.into()
+}
+
+/// Inverse discrete Fourier transform `x(j) = Σ_k X(k) exp(2πi jk / n) / n`.
+pub fn idft<R: Float, L: Layout>(x: &Slice<Complex<R>, (Dyn,), L>) -> DArray<Complex<R>, 1> {
+    let mut y: Vec<_> = x.iter().copied().collect();
+    fft(&mut y, true);
+    let scale = <R as NumCast>::from(y.len()).unwrap();
+    for yk in &mut y {
+        *yk = *yk / scale;
+    }
+    y.into()
+}
+
+/// Unnormalized DFT of any length, in place.
+fn fft<R: Float>(x: &mut [Complex<R>], inverse: bool) {
+    let n = x.len();
+    if n.is_power_of_two() || n == 0 {
+        fft_pow2(x, inverse);
+        return;
+    }
+
+    // Bluestein: jk = (j² + k² - (k - j)²) / 2 turns the DFT into a
+    // convolution with the chirp w(j) = exp(∓πi j² / n).
+    let pi = <R as NumCast>::from(std::f64::consts::PI).unwrap();
+    let sign = if inverse { R::one() } else { -R::one() };
+    let chirp: Vec<Complex<R>> = (0..n)
+        .map(|j| {
+            // j² mod 2n keeps the angle accurate for large j.
+            let q = <R as NumCast>::from((j * j) % (2 * n)).unwrap();
+            Complex::from_polar(R::one(), sign * pi * q / <R as NumCast>::from(n).unwrap())
+        })
+        .collect();
+
+    let p = (2 * n - 1).next_power_of_two();
+    let zero = Complex::<R>::zero();
+    let mut a = vec![zero; p];
+    for (aj, (&xj, &wj)) in a.iter_mut().zip(x.iter().zip(&chirp)) {
+        *aj = xj * wj;
+    }
+    let mut b = vec![zero; p];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[p - j] = chirp[j].conj();
+    }
+    circular_convolve(&mut b, &mut a);
+    for (xk, (&ak, &wk)) in x.iter_mut().zip(a.iter().zip(&chirp)) {
+        *xk = ak * wk;
+    }
+}
+
+/// Overwrites `y` with the circular convolution of `c` and `y`, whose common
+/// length is a power of two; `c` is overwritten with its DFT.
+fn circular_convolve<R: Float>(c: &mut [Complex<R>], y: &mut [Complex<R>]) {
+    fft_pow2(c, false);
+    fft_pow2(y, false);
+    let scale = <R as NumCast>::from(y.len()).unwrap();
+    for (yk, &ck) in y.iter_mut().zip(c.iter()) {
+        *yk = *yk * ck / scale;
+    }
+    fft_pow2(y, true);
+}
+
+/// Iterative radix-2 FFT, unnormalized; the length must be a power of two.
+fn fft_pow2<R: Float>(x: &mut [Complex<R>], inverse: bool) {
+    let n = x.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+
+    let pi = <R as NumCast>::from(std::f64::consts::PI).unwrap();
+    let sign = if inverse { R::one() } else { -R::one() };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * (pi + pi) / <R as NumCast>::from(len).unwrap();
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let w = Complex::from_polar(R::one(), angle * <R as NumCast>::from(k).unwrap());
+                let u = x[start + k];
+                let v = x[start + k + len / 2] * w;
+                x[start + k] = u + v;
+                x[start + k + len / 2] = u - v;
+            }
+        }
+        len *= 2;
+    }
+}
@@ -6,7 +6,7 @@
 //! complete collection of linear algebra utilities at this time.
 
 use mdarray::{Array, Dim, Layout, Shape, Slice, tensor};
-use num_complex::ComplexFloat;
+use num_complex::{Complex, ComplexFloat};
 use num_traits::{One, Zero};
 
 /// Displays a numeric `mdarray` in a human-readable format (NumPy-style)
@@ -363,3 +363,33 @@ pub fn diag<T: Zero + One + Clone, D: Dim>(v: &Slice<T, (D,)>) -> Array<T, (D, D
         }
     })
 }
+
+/// The imaginary unit of `T`, or `None` if `T` is a real type.
+pub(crate) fn imaginary_unit<T: ComplexFloat>() -> Option<T> {
+    // `-T::one()` carries a negative zero imaginary part, on which `sqrt`
+    // takes the lower branch.
+    let i = (-T::one()).sqrt();
+    if i.is_nan() {
+        None
+    } else if i.im() < T::Real::zero() {
+        Some(-i)
+    } else {
+        Some(i)
+    }
+}
+
+pub(crate) fn to_complex<T: ComplexFloat>(x: T) -> Complex<T::Real> {
+    Complex::new(x.re(), x.im())
+}
+
+/// Converts back to `T`, dropping the imaginary part if `T` is real.
+pub(crate) fn from_complex<T>(z: Complex<T::Real>, imaginary_unit: Option<T>) -> T
+where
+    T: ComplexFloat,
+    T::Real: Into<T>,
+{
+    match imaginary_unit {
+        Some(i) => z.re.into() + i * z.im.into(),
+        None => z.re.into(),
+    }
+}
@@ -0,0 +1,62 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::krylov::*;
+
+#[test]
+fn cg() {
+    test_cg(&Naive, &Naive);
+}
+
+#[test]
+fn cg_preconditioned() {
+    test_cg_preconditioned(&Naive, &Naive);
+}
+
+#[test]
+fn cg_complex() {
+    test_cg_complex(&Naive, &Naive);
+}
+
+#[test]
+fn cg_breakdown() {
+    test_cg_breakdown(&Naive, &Naive);
+}
+
+#[test]
+fn minres_indefinite() {
+    test_minres_indefinite(&Naive, &Naive);
+}
+
+#[test]
+fn minres_preconditioned() {
+    test_minres_preconditioned(&Naive, &Naive);
+}
+
+#[test]
+fn gmres() {
+    test_gmres(&Naive, &Naive);
+}
+
+#[test]
+fn gmres_complex() {
+    test_gmres_complex(&Naive, &Naive);
+}
+
+#[test]
+fn bicgstab() {
+    test_bicgstab(&Naive, &Naive);
+}
+
+#[test]
+fn bicgstab_complex() {
+    test_bicgstab_complex(&Naive, &Naive);
+}
+
+#[test]
+fn krylov_not_converged() {
+    test_krylov_not_converged(&Naive, &Naive);
+}
+
+#[test]
+fn krylov_trivial() {
+    test_krylov_trivial(&Naive, &Naive);
+}
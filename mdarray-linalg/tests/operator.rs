@@ -0,0 +1,23 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::operator::*;
+
+#[test]
+fn dense_operator() {
+    test_dense_operator(&Naive);
+}
+
+#[test]
+fn operator_combinations() {
+    test_operator_combinations(&Naive);
+}
+
+#[test]
+fn fn_operator_with_cg() {
+    test_fn_operator_with_cg(&Naive);
+}
+
+#[test]
+#[should_panic]
+fn operator_shape_mismatch() {
+    test_operator_shape_mismatch(&Naive);
+}
@@ -0,0 +1,37 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::precond::*;
+
+#[test]
+fn jacobi() {
+    test_jacobi();
+}
+
+#[test]
+fn incomplete_complex() {
+    test_incomplete_complex();
+}
+
+#[test]
+fn incomplete_sparse() {
+    test_incomplete_sparse();
+}
+
+#[test]
+fn precond_composition() {
+    test_precond_composition();
+}
+
+#[test]
+fn ssor() {
+    test_ssor(&Naive, &Naive);
+}
+
+#[test]
+fn ilu0() {
+    test_ilu0(&Naive, &Naive);
+}
+
+#[test]
+fn ic0() {
+    test_ic0(&Naive, &Naive);
+}
@@ -0,0 +1,33 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::randomized::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn randomized_range_finder() {
+    test_randomized_range_finder(&Naive, &Naive);
+}
+
+#[test]
+fn randomized_svd() {
+    test_randomized_svd(&Naive, &Naive, &Faer::default());
+}
+
+#[test]
+fn randomized_svd_seeded() {
+    test_randomized_svd_seeded(&Naive, &Naive, &Faer::default());
+}
+
+#[test]
+fn randomized_svd_complex() {
+    test_randomized_svd_complex(&Naive, &Naive, &Faer::default());
+}
+
+#[test]
+fn randomized_svd_invalid_rank() {
+    test_randomized_svd_invalid_rank(&Naive, &Naive, &Faer::default());
+}
+
+#[test]
+fn randomized_svd_const() {
+    test_randomized_svd_const(&Naive, &Naive, &Faer::default());
+}
@@ -0,0 +1,38 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::sparse::*;
+
+#[test]
+fn sparse_construction() {
+    test_sparse_construction();
+}
+
+#[test]
+fn sparse_errors() {
+    test_sparse_errors();
+}
+
+#[test]
+fn spmv() {
+    test_spmv(&Naive);
+}
+
+#[test]
+fn spmv_complex() {
+    test_spmv_complex(&Naive);
+}
+
+#[test]
+fn spmm() {
+    test_spmm(&Naive);
+}
+
+#[test]
+fn sparse_operator_cg() {
+    test_sparse_operator_cg(&Naive);
+}
+
+#[test]
+#[should_panic]
+fn spmv_dimension_mismatch() {
+    test_spmv_dimension_mismatch(&Naive);
+}
@@ -0,0 +1,37 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::structured::*;
+
+#[test]
+fn structured_wrappers() {
+    test_structured_wrappers();
+}
+
+#[test]
+fn diag_scale() {
+    test_diag_scale(&Naive);
+}
+
+#[test]
+fn tri_matmul() {
+    test_tri_matmul(&Naive);
+}
+
+#[test]
+fn tri_solve() {
+    test_tri_solve(&Naive);
+}
+
+#[test]
+fn tri_solve_singular() {
+    test_tri_solve_singular(&Naive);
+}
+
+#[test]
+fn sym_matmul() {
+    test_sym_matmul(&Naive);
+}
+
+#[test]
+fn packed_matvec() {
+    test_packed_matvec(&Naive);
+}
@@ -0,0 +1,31 @@
+use mdarray_linalg::testing::toeplitz::*;
+
+#[test]
+fn toeplitz_dense() {
+    test_toeplitz_dense();
+}
+
+#[test]
+fn toeplitz_matvec() {
+    test_toeplitz_matvec();
+}
+
+#[test]
+fn toeplitz_solve() {
+    test_toeplitz_solve();
+}
+
+#[test]
+fn toeplitz_solve_errors() {
+    test_toeplitz_solve_errors();
+}
+
+#[test]
+fn dft() {
+    test_dft();
+}
+
+#[test]
+fn levinson_durbin() {
+    test_levinson_durbin();
+}